@@ -0,0 +1,71 @@
+//! Rendering time of every built-in template, and of a whole project.
+//!
+//! Run with `cargo bench`. Each template is rendered with the data of a
+//! library project, and the mean time per render is printed, so a template
+//! that became slower stands out between runs. The last line times planning
+//! and rendering every file of that project in memory, as
+//! [`ProjectBuilder::render_files`] does before a build writes them.
+
+use cppup::project::{PackageManager, TestFramework};
+use cppup::{ProjectBuilder, ProjectConfig, ProjectType, TemplateRenderer};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// Renders per template; the first render is a warm-up and not timed.
+const ITERATIONS: u32 = 200;
+
+fn bench_render(
+    renderer: &TemplateRenderer,
+    name: &str,
+    data: &impl serde::Serialize,
+) -> anyhow::Result<Duration> {
+    renderer.render_to_string(name, data)?;
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        black_box(renderer.render_to_string(black_box(name), data)?);
+    }
+    Ok(start.elapsed() / ITERATIONS)
+}
+
+/// Mean time to plan and render every file of the project, without
+/// writing anything.
+fn bench_full_project_build(builder: &ProjectBuilder) -> anyhow::Result<Duration> {
+    builder.render_files()?;
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        black_box(builder.render_files()?);
+    }
+    Ok(start.elapsed() / ITERATIONS)
+}
+
+fn main() -> anyhow::Result<()> {
+    // The directory is only checked, never created
+    let config = ProjectConfig::builder()
+        .name("bench-project")
+        .path(std::env::temp_dir())
+        .project_type(ProjectType::Library)
+        .test_framework(TestFramework::Doctest)
+        .package_manager(PackageManager::Vcpkg)
+        .build()?;
+    let builder = ProjectBuilder::new(config);
+    let data = builder.template_data();
+    let renderer = TemplateRenderer::new();
+
+    let mut total = Duration::ZERO;
+    for name in renderer.template_names() {
+        match bench_render(&renderer, name, data) {
+            Ok(mean) => {
+                total += mean;
+                println!("render {:<28} {:>10.2?}", name, mean);
+            }
+            Err(error) => println!("render {:<28} failed: {:#}", name, error),
+        }
+    }
+    println!("render {:<28} {:>10.2?}", "(all templates)", total);
+    println!(
+        "build  {:<28} {:>10.2?}",
+        "(full project)",
+        bench_full_project_build(&builder)?
+    );
+    Ok(())
+}
@@ -3,7 +3,8 @@
 //! This module defines the CLI structure and all command-line arguments
 //! for the cppup project generator.
 
-use clap::Parser;
+use crate::error::EXIT_CODES_HELP;
+use clap::{Args, Parser, Subcommand};
 use std::path::PathBuf;
 
 /// Command-line interface for cppup.
@@ -19,9 +20,20 @@ use std::path::PathBuf;
 ///
 /// let cli = Cli::parse();
 /// ```
-#[derive(Parser)]
-#[command(author, version, about, long_about = None)]
+#[derive(Parser, Clone)]
+#[command(
+    author,
+    version,
+    about,
+    long_about = None,
+    after_help = EXIT_CODES_HELP,
+    args_conflicts_with_subcommands = true
+)]
 pub struct Cli {
+    /// Without a subcommand, cppup generates a project
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
     /// Name of the project
     #[arg(short, long)]
     pub name: Option<String>,
@@ -34,34 +46,233 @@ pub struct Cli {
     #[arg(short = 't', long, value_parser = ["executable", "library"])]
     pub project_type: Option<String>,
 
-    /// Build system to use
-    #[arg(short, long, value_parser = ["cmake", "make"], default_value = "cmake")]
-    pub build_system: String,
+    /// Build system to use [default: cmake]
+    #[arg(short, long, value_parser = ["cmake", "make", "meson"])]
+    pub build_system: Option<String>,
+
+    /// CMake generator written to CMakePresets.json [default: default]
+    #[arg(long, value_parser = ["default", "ninja", "ninja-multi-config", "unix-makefiles", "vs2022"])]
+    pub cmake_generator: Option<String>,
+
+    /// C++ standard to use [default: 17]
+    #[arg(short = 's', long, value_parser = ["11", "14", "17", "20", "23"])]
+    pub cpp_standard: Option<String>,
+
+    /// File extension for C++ sources [default: cpp]
+    #[arg(long, value_parser = ["cpp", "cxx", "cc"])]
+    pub cpp_extension: Option<String>,
+
+    /// File extension for C++ headers [default: hpp]
+    #[arg(long, value_parser = ["hpp", "h", "hxx"])]
+    pub hpp_extension: Option<String>,
+
+    /// Line endings of the generated files; .bat and .ps1 files always use
+    /// CRLF [default: lf]
+    #[arg(long, value_parser = ["lf", "crlf", "native"])]
+    pub line_endings: Option<String>,
+
+    /// Sanitizer preset applied by the generated CMake files [default: none]
+    #[arg(long, value_parser = ["none", "address", "thread", "memory", "undefined", "address-undefined", "full"])]
+    pub sanitizer: Option<String>,
+
+    /// Directory where to create the project [default: .]
+    #[arg(short = 'p', long)]
+    pub path: Option<PathBuf>,
+
+    /// Create the --path directory and its missing parents if needed
+    #[arg(long)]
+    pub create_path: bool,
+
+    /// Initialize git repository [default: true]
+    #[arg(short, long, num_args = 0..=1, default_missing_value = "true")]
+    pub git: Option<bool>,
+
+    /// Commit the generated files after initializing git [default: true]
+    #[arg(long, num_args = 0..=1, default_missing_value = "true")]
+    pub git_commit: Option<bool>,
+
+    /// Initialize git even inside an existing repository
+    #[arg(long)]
+    pub force_git: bool,
+
+    /// Create a GitHub repository with gh and push the initial commit
+    #[arg(long)]
+    pub create_github_repo: bool,
+
+    /// Make the repository created by --create-github-repo private
+    #[arg(long, requires = "create_github_repo")]
+    pub private: bool,
+
+    /// Name of the initial git branch [default: main]
+    #[arg(long, value_name = "NAME")]
+    pub git_branch: Option<String>,
+
+    /// URL added as the origin remote of the new repository
+    #[arg(long, value_name = "URL")]
+    pub git_remote: Option<String>,
+
+    /// Create a docs/ directory with placeholder pages [default: true for libraries]
+    #[arg(long, num_args = 0..=1, default_missing_value = "true")]
+    pub docs: Option<bool>,
+
+    /// Engine for the docs/ directory [default: markdown]
+    #[arg(long, value_parser = ["markdown", "sphinx", "doxygen"])]
+    pub doc_engine: Option<String>,
+
+    /// OpenAPI spec (YAML or JSON) to generate include/api/ stubs from (libraries only)
+    #[arg(long, value_name = "FILE")]
+    pub openapi_spec: Option<PathBuf>,
+
+    /// Number of example programs generated for a library [default: 1]
+    #[arg(long, value_name = "N")]
+    pub num_examples: Option<usize>,
 
-    /// C++ standard to use
-    #[arg(short = 's', long, value_parser = ["11", "14", "17", "20", "23"], default_value = "17")]
-    pub cpp_standard: String,
+    /// Create a CHANGELOG.md in Keep a Changelog format [default: false]
+    #[arg(long, num_args = 0..=1, default_missing_value = "true")]
+    pub changelog: Option<bool>,
 
-    /// Directory where to create the project
-    #[arg(short = 'p', long, default_value = ".")]
-    pub path: PathBuf,
+    /// Create CONTRIBUTING.md and CODE_OF_CONDUCT.md [default: false]
+    #[arg(long, num_args = 0..=1, default_missing_value = "true")]
+    pub community_files: Option<bool>,
 
-    /// Initialize git repository
-    #[arg(short, long, default_value_t = true)]
-    pub git: bool,
+    /// CI pipeline to generate; github writes a GitHub Actions workflow [default: none]
+    #[arg(long, value_parser = ["none", "github"])]
+    pub ci: Option<String>,
+
+    /// Create .github/dependabot.yml for projects with GitHub Actions CI [default: false]
+    #[arg(long, num_args = 0..=1, default_missing_value = "true")]
+    pub dependabot: Option<bool>,
+
+    /// Use Link Time Optimization for release builds [default: false]
+    #[arg(long, num_args = 0..=1, default_missing_value = "true")]
+    pub lto: Option<bool>,
+
+    /// Service the test coverage is uploaded to [default: none]
+    #[arg(long, value_parser = ["none", "codecov", "coveralls"])]
+    pub coverage_service: Option<String>,
+
+    /// Platform to configure the project for; android and windows-mingw add cross-compiling setups [default: host]
+    #[arg(long, value_parser = ["host", "android", "windows-mingw"])]
+    pub target: Option<String>,
+
+    /// Android ABI of the Android preset [default: arm64-v8a]
+    #[arg(long, requires = "target", value_parser = ["armeabi-v7a", "arm64-v8a", "x86", "x86_64"])]
+    pub android_abi: Option<String>,
+
+    /// Android API level of the Android preset [default: 24]
+    #[arg(long, requires = "target", value_name = "LEVEL")]
+    pub android_api: Option<u32>,
+
+    /// Build the executable as a macOS application bundle with an Info.plist [default: false]
+    #[arg(long, num_args = 0..=1, default_missing_value = "true")]
+    pub macos_bundle: Option<bool>,
+
+    /// Bundle identifier of the macOS bundle [default: derived from the repository URL]
+    #[arg(long, requires = "macos_bundle", value_name = "ID")]
+    pub bundle_id: Option<String>,
+
+    /// Add a preset that builds universal arm64 and x86_64 macOS binaries [default: false]
+    #[arg(long, num_args = 0..=1, default_missing_value = "true")]
+    pub universal: Option<bool>,
+
+    /// Project homepage URL, used for links in generated files
+    #[arg(long, value_name = "URL")]
+    pub homepage: Option<String>,
+
+    /// Project version, as `major[.minor[.patch[.tweak]]]` [default: 0.1.0]
+    #[arg(long, value_name = "VERSION")]
+    pub project_version: Option<String>,
+
+    /// Generate byte-identical output for the same inputs: requires --author
+    /// and --project-version, and takes dates from SOURCE_DATE_EPOCH
+    #[arg(long, requires_all = ["author", "project_version"])]
+    pub reproducible: bool,
 
     /// Non-interactive mode
-    #[arg(short = 'i', long)]
+    #[arg(short = 'i', long, visible_alias = "yes")]
     pub non_interactive: bool,
 
-    #[arg(long, value_parser = ["doctest", "gtest", "catch2", "boosttest", "none"], default_value = "none")]
-    pub test_framework: String,
+    /// Start from a bundle of options; explicit flags take precedence
+    #[arg(long, value_parser = ["minimal", "recommended", "full", "opensource"])]
+    pub preset: Option<String>,
+
+    /// Skip the confirmation summary in interactive mode
+    #[arg(long)]
+    pub no_confirm: bool,
+
+    /// In interactive mode, ask every question and use the given flags as
+    /// the pre-filled answers instead of skipping their questions
+    #[arg(long, conflicts_with = "non_interactive")]
+    pub interactive_defaults: bool,
+
+    /// Ignore the answers remembered from the previous interactive run
+    #[arg(long)]
+    pub fresh: bool,
+
+    /// Generate with a previously downloaded template set version
+    #[arg(long, value_name = "VERSION")]
+    pub template_version: Option<String>,
+
+    /// Ignore template overrides in the cppup config directory
+    #[arg(long)]
+    pub no_user_templates: bool,
+
+    /// Leave a partially created project in place if generation fails
+    #[arg(long)]
+    pub keep_on_failure: bool,
+
+    /// Shell command run in the new project after generation and git init
+    /// (repeatable)
+    #[arg(long = "post-gen", value_name = "CMD")]
+    pub post_gen: Vec<String>,
 
-    #[arg(long, value_parser = ["conan", "vcpkg", "none"], default_value = "none")]
-    pub package_manager: String,
+    /// Fail if a --post-gen command fails instead of only warning
+    #[arg(long)]
+    pub post_gen_strict: bool,
+
+    /// Run the post_gen commands declared by the --template-pack
+    #[arg(long)]
+    pub allow_pack_hooks: bool,
 
-    #[arg(long, value_parser = ["MIT", "Apache-2.0", "GPL-3.0", "BSD-3-Clause"], default_value = "MIT")]
-    pub license: String,
+    /// Configure the generated project as a smoke test
+    #[arg(long)]
+    pub verify: bool,
+
+    /// Keep the build directory created by --verify
+    #[arg(long, requires = "verify")]
+    pub keep_build: bool,
+
+    /// Run the first full build (and tests) after generating
+    #[arg(long)]
+    pub build: bool,
+
+    /// Number of parallel build jobs used by --build
+    #[arg(short = 'j', long, requires = "build")]
+    pub jobs: Option<usize>,
+
+    /// Print additional details while generating
+    #[arg(short, long)]
+    pub verbose: bool,
+
+    /// When to color the output; `auto` colors a terminal unless NO_COLOR is set [default: auto]
+    #[arg(long, value_name = "WHEN", value_parser = ["auto", "always", "never"])]
+    pub color: Option<String>,
+
+    /// Testing framework [default: none]
+    #[arg(long, value_parser = ["doctest", "gtest", "catch2", "boosttest", "none"])]
+    pub test_framework: Option<String>,
+
+    /// How the generated test executables are launched [default: direct]
+    #[arg(long, value_parser = ["direct", "valgrind", "asan", "ctest"])]
+    pub test_runner: Option<String>,
+
+    /// Package manager [default: none]
+    #[arg(long, value_parser = ["conan", "vcpkg", "none"])]
+    pub package_manager: Option<String>,
+
+    /// License [default: MIT]
+    #[arg(long, value_parser = ["MIT", "Apache-2.0", "GPL-3.0", "BSD-3-Clause", "BSL-1.0"])]
+    pub license: Option<String>,
 
     #[arg(long)]
     pub author: Option<String>,
@@ -69,6 +280,156 @@ pub struct Cli {
     #[arg(long, value_delimiter = ',', value_parser = ["clang-tidy", "cppcheck", "include-what-you-use"])]
     pub quality_tools: Vec<String>,
 
-    #[arg(long, value_delimiter = ',', value_parser = ["clang-format", "cmake-format"])]
+    /// clang-tidy check categories to enable, e.g. modernize,bugprone [default: a general check set]
+    #[arg(long, value_delimiter = ',', value_parser = ["modernize", "bugprone", "performance", "readability", "cppcoreguidelines", "cert", "hicpp", "portability"])]
+    pub clang_tidy_checks: Vec<String>,
+
+    #[arg(long, value_delimiter = ',', value_parser = ["clang-format", "cmake-format", "cmake-lint"])]
     pub code_formatter: Vec<String>,
+
+    /// IDE project files to generate
+    #[arg(long, value_delimiter = ',', value_parser = ["clion", "kdevelop", "emacs", "sublime"])]
+    pub ide: Vec<String>,
+
+    /// Code snippet header copied into include/snippets/ (repeatable)
+    #[arg(long = "snippet", value_delimiter = ',', value_parser = ["thread-pool", "logger", "argument-parser", "http-client"])]
+    pub snippets: Vec<String>,
+
+    /// Code owners written to CODEOWNERS, e.g. @org/cpp-team (comma-separated)
+    #[arg(long, value_name = "OWNERS", value_delimiter = ',')]
+    pub codeowners: Vec<String>,
+
+    /// Conan package option written to conanfile.txt (repeatable)
+    #[arg(long = "conan-option", value_name = "PKG:OPTION=VALUE")]
+    pub conan_options: Vec<String>,
+
+    /// Optional vcpkg.json feature with its dependencies (repeatable)
+    #[arg(long = "vcpkg-feature", value_name = "NAME:DESCRIPTION:DEP1,DEP2")]
+    pub vcpkg_features: Vec<String>,
+
+    /// Git repository of the default vcpkg registry, for vcpkg-configuration.json
+    #[arg(long, value_name = "URL")]
+    pub vcpkg_registry_url: Option<String>,
+
+    /// Commit of the default vcpkg registry to take the versions from
+    #[arg(long, value_name = "COMMIT")]
+    pub vcpkg_baseline: Option<String>,
+
+    /// Preprocessor definition added to every CMake target (repeatable)
+    #[arg(long = "define", value_name = "NAME[=VALUE]")]
+    pub defines: Vec<String>,
+
+    /// Template pack directory or git URL with a pack.toml manifest
+    #[arg(long, value_name = "PATH|URL")]
+    pub template_pack: Option<String>,
+
+    /// Extra variable made available to templates (repeatable)
+    #[arg(long = "template-var", value_name = "KEY=VALUE", value_parser = parse_key_value)]
+    pub template_vars: Vec<(String, String)>,
+}
+
+impl Cli {
+    /// Fills every project setting that was not given with the value from
+    /// `fallback`. Name, path and mode flags are left untouched.
+    pub fn fill_missing(&mut self, fallback: &Cli) {
+        fn or<T: Clone>(value: &mut Option<T>, fallback: &Option<T>) {
+            if value.is_none() {
+                value.clone_from(fallback);
+            }
+        }
+        fn or_vec(value: &mut Vec<String>, fallback: &[String]) {
+            if value.is_empty() {
+                *value = fallback.to_vec();
+            }
+        }
+
+        or(&mut self.description, &fallback.description);
+        or(&mut self.author, &fallback.author);
+        or(&mut self.project_type, &fallback.project_type);
+        or(&mut self.build_system, &fallback.build_system);
+        or(&mut self.cmake_generator, &fallback.cmake_generator);
+        or(&mut self.cpp_standard, &fallback.cpp_standard);
+        or(&mut self.cpp_extension, &fallback.cpp_extension);
+        or(&mut self.hpp_extension, &fallback.hpp_extension);
+        or(&mut self.sanitizer, &fallback.sanitizer);
+        or(&mut self.package_manager, &fallback.package_manager);
+        or(&mut self.test_framework, &fallback.test_framework);
+        or(&mut self.test_runner, &fallback.test_runner);
+        or(&mut self.license, &fallback.license);
+        or(&mut self.git, &fallback.git);
+        or(&mut self.git_commit, &fallback.git_commit);
+        or(&mut self.git_branch, &fallback.git_branch);
+        or(&mut self.docs, &fallback.docs);
+        or(&mut self.doc_engine, &fallback.doc_engine);
+        or(&mut self.changelog, &fallback.changelog);
+        or(&mut self.community_files, &fallback.community_files);
+        or(&mut self.ci, &fallback.ci);
+        or(&mut self.dependabot, &fallback.dependabot);
+        or(&mut self.lto, &fallback.lto);
+        or(&mut self.coverage_service, &fallback.coverage_service);
+        or(&mut self.target, &fallback.target);
+        or(&mut self.android_abi, &fallback.android_abi);
+        or(&mut self.android_api, &fallback.android_api);
+        or(&mut self.macos_bundle, &fallback.macos_bundle);
+        or(&mut self.bundle_id, &fallback.bundle_id);
+        or(&mut self.universal, &fallback.universal);
+        or(&mut self.num_examples, &fallback.num_examples);
+        or_vec(&mut self.quality_tools, &fallback.quality_tools);
+        or_vec(&mut self.clang_tidy_checks, &fallback.clang_tidy_checks);
+        or_vec(&mut self.code_formatter, &fallback.code_formatter);
+        or_vec(&mut self.ide, &fallback.ide);
+    }
+}
+
+/// Subcommands of cppup.
+#[derive(Subcommand, Clone)]
+pub enum Command {
+    /// Write a single configuration file, such as .clang-format, into an
+    /// existing project
+    Generate(GenerateArgs),
+}
+
+/// Arguments of `cppup generate`.
+#[derive(Args, Clone)]
+pub struct GenerateArgs {
+    /// File to generate
+    #[arg(value_parser = ["clang-format", "clang-tidy", "cppcheck-suppressions", "cmake-format", "gitignore", "editorconfig", "license"])]
+    pub artifact: String,
+
+    /// Project directory to write the file to [default: current directory]
+    #[arg(long, value_name = "DIR")]
+    pub into: Option<PathBuf>,
+
+    /// Overwrite the file if it already exists and has changed; asks first
+    /// when run in a terminal
+    #[arg(long)]
+    pub force: bool,
+
+    /// With --force, overwrite without asking; the diff is still printed
+    #[arg(short = 'y', long, requires = "force")]
+    pub assume_yes: bool,
+
+    /// License of the LICENSE file [default: MIT]
+    #[arg(long, value_parser = ["MIT", "Apache-2.0", "GPL-3.0", "BSD-3-Clause", "BSL-1.0"])]
+    pub license: Option<String>,
+
+    /// Copyright holder named in the LICENSE file [default: the current user]
+    #[arg(long)]
+    pub author: Option<String>,
+
+    /// Project name used by the license text [default: the directory name]
+    #[arg(long)]
+    pub name: Option<String>,
+}
+
+/// Parses a `KEY=VALUE` pair. The value may itself contain `=`.
+fn parse_key_value(input: &str) -> Result<(String, String), String> {
+    let (key, value) = input
+        .split_once('=')
+        .ok_or_else(|| format!("expected KEY=VALUE, got `{}`", input))?;
+    let key = key.trim();
+    if key.is_empty() {
+        return Err("template variable name cannot be empty".to_string());
+    }
+    Ok((key.to_string(), value.to_string()))
 }
@@ -0,0 +1,130 @@
+//! Terminal colors for the messages cppup prints.
+//!
+//! Colors are off until [`init`] turns them on, so library users get plain
+//! text unless they opt in.
+
+use owo_colors::{OwoColorize, Style};
+use std::fmt::Display;
+use std::io::IsTerminal;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// When to color the output, as chosen with `--color`.
+///
+/// # Examples
+///
+/// ```
+/// use cppup::color::ColorChoice;
+///
+/// let choice: ColorChoice = "never".parse().unwrap();
+/// assert!(!choice.enabled());
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum ColorChoice {
+    /// Color when stdout is a terminal and `NO_COLOR` is not set
+    #[default]
+    Auto,
+    /// Always color
+    Always,
+    /// Never color
+    Never,
+}
+
+impl ColorChoice {
+    /// Whether this choice colors the output in the current environment.
+    pub fn enabled(self) -> bool {
+        match self {
+            ColorChoice::Always => true,
+            ColorChoice::Never => false,
+            ColorChoice::Auto => {
+                std::env::var_os("NO_COLOR").is_none_or(|value| value.is_empty())
+                    && std::io::stdout().is_terminal()
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for ColorChoice {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            ColorChoice::Auto => write!(f, "auto"),
+            ColorChoice::Always => write!(f, "always"),
+            ColorChoice::Never => write!(f, "never"),
+        }
+    }
+}
+
+impl FromStr for ColorChoice {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "auto" => Ok(ColorChoice::Auto),
+            "always" => Ok(ColorChoice::Always),
+            "never" => Ok(ColorChoice::Never),
+            _ => Err(anyhow::anyhow!("Unknown color choice: {}", value)),
+        }
+    }
+}
+
+/// Turns colors on or off for everything printed afterwards.
+pub fn init(choice: ColorChoice) {
+    ENABLED.store(choice.enabled(), Ordering::Relaxed);
+}
+
+fn paint(text: impl Display, style: Style) -> String {
+    if ENABLED.load(Ordering::Relaxed) {
+        text.style(style).to_string()
+    } else {
+        text.to_string()
+    }
+}
+
+/// Formats a success message: green and bold.
+pub fn success(text: impl Display) -> String {
+    paint(text, Style::new().green().bold())
+}
+
+/// Formats a file or directory path: cyan.
+pub fn path(text: impl Display) -> String {
+    paint(text, Style::new().cyan())
+}
+
+/// Formats a command the user can run: yellow.
+pub fn command(text: impl Display) -> String {
+    paint(text, Style::new().yellow())
+}
+
+/// Formats a line added in a diff: green.
+pub fn insertion(text: impl Display) -> String {
+    paint(text, Style::new().green())
+}
+
+/// Formats a line removed in a diff: red.
+pub fn deletion(text: impl Display) -> String {
+    paint(text, Style::new().red())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_color_choice_round_trip() {
+        for choice in [ColorChoice::Auto, ColorChoice::Always, ColorChoice::Never] {
+            assert_eq!(choice.to_string().parse::<ColorChoice>().unwrap(), choice);
+        }
+        assert!("sometimes".parse::<ColorChoice>().is_err());
+    }
+
+    #[test]
+    fn test_explicit_choices_ignore_the_environment() {
+        temp_env::with_var("NO_COLOR", Some("1"), || {
+            assert!(ColorChoice::Always.enabled());
+            assert!(!ColorChoice::Never.enabled());
+            assert!(!ColorChoice::Auto.enabled());
+        });
+    }
+}
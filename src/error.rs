@@ -0,0 +1,125 @@
+//! Failure categories and the exit codes cppup reports for them.
+//!
+//! Errors stay [`anyhow::Error`]s; a [`CppupError`] somewhere in the chain
+//! marks which category a failure belongs to, so scripts can tell them
+//! apart by exit code.
+
+/// Exit code for failures that belong to no category.
+pub const FAILURE_EXIT_CODE: u8 = 1;
+/// Exit code for invalid or missing command-line arguments, as used by clap.
+pub const USAGE_EXIT_CODE: u8 = 2;
+/// Exit code for invalid settings and missing prerequisites.
+pub const VALIDATION_EXIT_CODE: u8 = 3;
+/// Exit code when the project directory already exists.
+pub const CONFLICT_EXIT_CODE: u8 = 4;
+/// Exit code when writing the project fails.
+pub const GENERATION_EXIT_CODE: u8 = 5;
+/// Exit code used when the user cancels the interactive prompts (128 + SIGINT).
+pub const CANCELLED_EXIT_CODE: u8 = 130;
+
+/// The exit codes, as listed in `--help`.
+pub const EXIT_CODES_HELP: &str = "\
+Exit codes:
+  0    Success
+  1    Other failure, such as --verify, --build or creating the GitHub repository
+  2    Invalid or missing command-line arguments
+  3    Invalid settings or a missing prerequisite
+  4    The project directory already exists
+  5    Writing the project failed
+  130  Cancelled";
+
+/// The category of a failure. The wrapped error is displayed unchanged.
+///
+/// # Examples
+///
+/// ```
+/// use cppup::error::{exit_code, CppupError};
+///
+/// let error = anyhow::Error::from(CppupError::Conflict(anyhow::anyhow!("exists")))
+///     .context("Could not create the project");
+/// assert_eq!(exit_code(&error), 4);
+/// ```
+#[derive(Debug, thiserror::Error)]
+pub enum CppupError {
+    /// Invalid or missing command-line arguments
+    #[error(transparent)]
+    Usage(anyhow::Error),
+    /// Invalid settings or a missing prerequisite
+    #[error(transparent)]
+    Validation(anyhow::Error),
+    /// The project directory already exists
+    #[error(transparent)]
+    Conflict(anyhow::Error),
+    /// Writing the project failed
+    #[error(transparent)]
+    Generation(anyhow::Error),
+    /// The user cancelled or interrupted the interactive prompts
+    #[error(transparent)]
+    Cancelled(anyhow::Error),
+}
+
+impl CppupError {
+    /// Returns the exit code for this category.
+    pub fn exit_code(&self) -> u8 {
+        match self {
+            CppupError::Usage(_) => USAGE_EXIT_CODE,
+            CppupError::Validation(_) => VALIDATION_EXIT_CODE,
+            CppupError::Conflict(_) => CONFLICT_EXIT_CODE,
+            CppupError::Generation(_) => GENERATION_EXIT_CODE,
+            CppupError::Cancelled(_) => CANCELLED_EXIT_CODE,
+        }
+    }
+}
+
+/// Wraps `error` in `category`, unless it already has a category.
+pub fn categorize(
+    error: anyhow::Error,
+    category: fn(anyhow::Error) -> CppupError,
+) -> anyhow::Error {
+    if error.chain().any(|cause| cause.is::<CppupError>()) {
+        error
+    } else {
+        category(error).into()
+    }
+}
+
+/// Returns the exit code for `error`: that of the outermost category in its
+/// chain, or [`FAILURE_EXIT_CODE`] if it has none.
+pub fn exit_code(error: &anyhow::Error) -> u8 {
+    error
+        .chain()
+        .find_map(|cause| cause.downcast_ref::<CppupError>())
+        .map_or(FAILURE_EXIT_CODE, CppupError::exit_code)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exit_code() {
+        assert_eq!(exit_code(&anyhow::anyhow!("failed")), FAILURE_EXIT_CODE);
+
+        let error = anyhow::Error::from(CppupError::Usage(anyhow::anyhow!("missing")));
+        assert_eq!(error.to_string(), "missing");
+        assert_eq!(exit_code(&error), USAGE_EXIT_CODE);
+        assert_eq!(
+            exit_code(&error.context("while configuring")),
+            USAGE_EXIT_CODE
+        );
+
+        let error = anyhow::Error::from(CppupError::Cancelled(anyhow::anyhow!("cancelled")));
+        assert_eq!(exit_code(&error), CANCELLED_EXIT_CODE);
+    }
+
+    #[test]
+    fn test_categorize_keeps_an_existing_category() {
+        let error = categorize(anyhow::anyhow!("failed"), CppupError::Generation);
+        assert_eq!(exit_code(&error), GENERATION_EXIT_CODE);
+
+        let conflict = anyhow::Error::from(CppupError::Conflict(anyhow::anyhow!("exists")));
+        let error = categorize(conflict.context("outer"), CppupError::Validation);
+        assert_eq!(exit_code(&error), CONFLICT_EXIT_CODE);
+        assert_eq!(format!("{:#}", error), "outer: exists");
+    }
+}
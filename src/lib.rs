@@ -13,35 +13,163 @@
 //! - Package manager integration (Conan, Vcpkg)
 //! - Testing framework setup (doctest, Google Test, Catch2, Boost.Test)
 //! - Code quality tools (clang-format, clang-tidy, cppcheck)
-//! - License management (MIT, Apache-2.0, GPL-3.0, BSD-3-Clause)
+//! - License management (MIT, Apache-2.0, GPL-3.0, BSD-3-Clause, BSL-1.0)
 //! - Project templates (Executable, Library)
 //! - Git initialization
 //!
 //! ## Example
 //!
 //! ```no_run
-//! use cppup::{ProjectConfig, ProjectValidator, ProjectBuilder};
+//! use cppup::ProjectConfig;
 //! use anyhow::Result;
 //!
 //! fn main() -> Result<()> {
-//!     // Create project configuration (in real usage, this would be from CLI or interactive mode)
-//!     // let config = ProjectConfig::new(None)?;
+//!     let config = ProjectConfig::builder()
+//!         .name("my-project")
+//!         .path(".")
+//!         .build()?;
 //!
-//!     // Validate prerequisites
-//!     // let validator = ProjectValidator::new(config.clone());
-//!     // validator.check_prerequisites()?;
-//!
-//!     // Build the project
-//!     // let builder = ProjectBuilder::new(config);
-//!     // builder.build()?;
+//!     // Checks the required tools, then writes the project
+//!     cppup::generate_project(config)?;
 //!
 //!     Ok(())
 //! }
 //! ```
+//!
+//! ## Configuration types
+//!
+//! The option types are available from the crate root:
+//!
+//! ```
+//! use cppup::{
+//!     BuildSystem, CodeFormatter, CppStandard, License, PackageManager, ProjectConfig,
+//!     ProjectType, QualityConfig, TestFramework,
+//! };
+//!
+//! let dir = tempfile::tempdir()?;
+//! let config = ProjectConfig::builder()
+//!     .name("acme.networking")
+//!     .project_type(ProjectType::Library)
+//!     .build_system(BuildSystem::Meson)
+//!     .cpp_standard(CppStandard::Cpp20)
+//!     .test_framework(TestFramework::Catch2)
+//!     .package_manager(PackageManager::None)
+//!     .license("Apache-2.0".parse::<License>()?)
+//!     .quality_config(QualityConfig::new(&["clang-tidy"]))
+//!     .code_formatter(CodeFormatter::new(&["clang-format"]))
+//!     .path(dir.path())
+//!     .build()?;
+//!
+//! assert_eq!(config.license, License::Apache2);
+//! assert!(config.quality_config.enable_clang_tidy);
+//! # Ok::<(), anyhow::Error>(())
+//! ```
+//!
+//! Project names can be checked on their own, e.g. while the user types:
+//!
+//! ```
+//! use cppup::validate_project_name;
+//!
+//! assert!(validate_project_name("my-project").is_ok());
+//! assert!(validate_project_name("acme.networking.http").is_ok());
+//!
+//! let error = validate_project_name("my project!").unwrap_err();
+//! assert_eq!(
+//!     error,
+//!     "Project name can only contain alphanumeric characters, '-', '_' and '.'"
+//! );
+//! ```
 
 pub mod cli;
+pub mod color;
+pub mod error;
+pub mod pack;
 pub mod project;
 pub mod templates;
+pub mod validation;
 
-pub use project::{ProjectBuilder, ProjectConfig, ProjectValidator};
+pub use project::{
+    BuildReport, ProjectBuilder, ProjectConfig, ProjectConfigBuilder, ProjectValidator,
+};
+pub use project::{
+    BuildSystem, CiProvider, ClangTidyCategory, CmakeGenerator, CodeFormatter, CoverageService,
+    CppExtension, CppStandard, DocEngine, HppExtension, IdeConfig, License, LineEndings,
+    PackageManager, ProjectType, QualityConfig, SanitizerPreset, TargetPlatform, TestFramework,
+    TestRunner,
+};
 pub use templates::TemplateRenderer;
+pub use validation::validate_project_name;
+
+use anyhow::Result;
+use std::collections::HashMap;
+
+/// Generates a project: checks that the tools it needs are installed, then
+/// writes every file and initializes git.
+///
+/// Use [`ProjectValidator`] and [`ProjectBuilder`] directly for custom
+/// templates or to get the [`BuildReport`].
+///
+/// # Examples
+///
+/// ```no_run
+/// use cppup::project::ProjectType;
+/// use cppup::ProjectConfig;
+///
+/// let config = ProjectConfig::builder()
+///     .name("my-library")
+///     .project_type(ProjectType::Library)
+///     .path(".")
+///     .build()?;
+/// cppup::generate_project(config)?;
+/// # Ok::<(), anyhow::Error>(())
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if a prerequisite is missing or the project cannot be
+/// written.
+pub fn generate_project(config: ProjectConfig) -> Result<()> {
+    ProjectValidator::new(config.clone()).check_prerequisites()?;
+    ProjectBuilder::new(config).build()?;
+    Ok(())
+}
+
+/// Renders every file of a project without writing anything. Returns the
+/// contents keyed by path relative to the project root, with `/` as the
+/// separator.
+///
+/// # Examples
+///
+/// ```
+/// use cppup::ProjectConfig;
+///
+/// let dir = tempfile::tempdir()?;
+/// let config = ProjectConfig::builder()
+///     .name("preview")
+///     .path(dir.path())
+///     .build()?;
+///
+/// let files = cppup::generate_project_dry_run(config)?;
+/// assert!(files["CMakeLists.txt"].contains("project(preview"));
+/// assert!(files.contains_key("src/main.cpp"));
+/// assert!(!dir.path().join("preview").exists());
+/// # Ok::<(), anyhow::Error>(())
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if a template fails to render.
+pub fn generate_project_dry_run(config: ProjectConfig) -> Result<HashMap<String, String>> {
+    let files = ProjectBuilder::new(config).render_files()?;
+    Ok(files
+        .into_iter()
+        .map(|(path, contents)| {
+            let path = path
+                .components()
+                .map(|component| component.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/");
+            (path, contents)
+        })
+        .collect())
+}
@@ -1,24 +1,223 @@
 mod cli;
+mod color;
+mod error;
+mod pack;
 mod project;
 mod templates;
+mod validation;
 
-use crate::cli::Cli;
-use crate::project::{ProjectBuilder, ProjectConfig, ProjectValidator};
+use crate::cli::{Cli, Command};
+use crate::error::{categorize, CppupError};
+use crate::pack::TemplatePack;
+use crate::project::{
+    generate_from_cli, BuildReport, ChangeSummary, ProjectBuilder, ProjectConfig, ProjectValidator,
+    VerifyOutcome,
+};
+use crate::templates::{user_template_dir, TemplateRenderer, TEMPLATES_VERSION};
 use anyhow::Result;
 use clap::Parser;
+use std::process::ExitCode;
 
-fn main() -> Result<()> {
+fn main() -> ExitCode {
+    env_logger::init();
+    match run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            let code = error::exit_code(&error);
+            if code == error::CANCELLED_EXIT_CODE {
+                // Prompts come before anything is written
+                eprintln!("Aborted — no files were created.");
+            } else {
+                eprintln!("Error: {:?}", error);
+            }
+            ExitCode::from(code)
+        }
+    }
+}
+
+fn run() -> Result<()> {
     let cli = Cli::parse();
+    color::init(
+        cli.color
+            .as_deref()
+            .map(str::parse)
+            .transpose()
+            .map_err(|error| categorize(error, CppupError::Usage))?
+            .unwrap_or_default(),
+    );
+
+    if let Some(Command::Generate(args)) = &cli.command {
+        let renderer =
+            load_renderer(&cli).map_err(|error| categorize(error, CppupError::Validation))?;
+        let summary = generate_from_cli(args, &renderer)?;
+        print_change_summary(&summary);
+        return Ok(());
+    }
 
     println!("Welcome to CPP Project Generator!");
 
-    let config = ProjectConfig::new(Some(&cli))?;
+    let (config, renderer, pack) =
+        prepare(&cli).map_err(|error| categorize(error, CppupError::Validation))?;
+
+    if cli.verbose {
+        println!("{}", config);
+    }
+
+    let mut builder = ProjectBuilder::new(config)
+        .with_template_renderer(renderer)
+        .with_keep_on_failure(cli.keep_on_failure)
+        .with_keep_build(cli.keep_build)
+        .with_jobs(cli.jobs)
+        .with_post_gen_strict(cli.post_gen_strict);
+    let mut hooks = Vec::new();
+    if let Some(pack) = &pack {
+        builder = builder.with_extra_outputs(pack.output_files());
+        let pack_hooks = pack.post_gen_hooks();
+        if cli.allow_pack_hooks {
+            hooks.extend_from_slice(pack_hooks);
+        } else if !pack_hooks.is_empty() {
+            // A pack may come from anyone's repository; its commands only
+            // run when asked for
+            eprintln!(
+                "Warning: the template pack declares post-generation hooks that were not run; review them and pass --allow-pack-hooks to run them:"
+            );
+            for hook in pack_hooks {
+                eprintln!("  {}", hook);
+            }
+        }
+    }
+    hooks.extend(cli.post_gen.iter().cloned());
+    builder = builder.with_post_gen_hooks(hooks);
+    let mut report = builder
+        .build()
+        .map_err(|error| categorize(error, CppupError::Generation))?;
+    print_report(&report, cli.verbose);
+
+    match builder.create_github_repo() {
+        Ok(Some(url)) => println!("\nCreated GitHub repository {}", color::path(url)),
+        Ok(None) => {}
+        Err(error) => eprintln!(
+            "Warning: could not create the GitHub repository: {:#}",
+            error
+        ),
+    }
+
+    if cli.verify {
+        match builder.verify()? {
+            VerifyOutcome::Passed(commands) => {
+                for command in &commands {
+                    println!("Verified: `{}` succeeded", color::command(command));
+                }
+            }
+            VerifyOutcome::Skipped(warning) => eprintln!("{}", warning),
+        }
+    }
+
+    if cli.build {
+        builder.run_first_build(&mut report)?;
+        println!(
+            "Built {} successfully",
+            color::path(report.project_path.display())
+        );
+    }
+
+    Ok(())
+}
+
+/// Loads the templates, creates the configuration and checks the
+/// prerequisites, returning the configuration, the renderer and the template
+/// pack.
+fn prepare(cli: &Cli) -> Result<(ProjectConfig, TemplateRenderer, Option<TemplatePack>)> {
+    // Load user templates and the template pack before prompting so invalid
+    // ones fail early
+    let mut renderer = load_renderer(cli)?;
+    let pack = cli
+        .template_pack
+        .as_deref()
+        .map(TemplatePack::load)
+        .transpose()?;
+    if let Some(pack) = &pack {
+        pack.register(&mut renderer)?;
+    }
+
+    let mut config = ProjectConfig::new(Some(cli))?;
+    if let Some(pack) = &pack {
+        pack.resolve_variables(&mut config.extra_vars, !cli.non_interactive)?;
+    }
 
     let validator = ProjectValidator::new(config.clone());
     validator.check_prerequisites()?;
 
-    let builder = ProjectBuilder::new(config);
-    builder.build()?;
+    Ok((config, renderer, pack))
+}
 
-    Ok(())
+/// Loads the requested template set version, or the embedded templates with
+/// any user overrides.
+fn load_renderer(cli: &Cli) -> Result<TemplateRenderer> {
+    if let Some(version) = cli
+        .template_version
+        .as_deref()
+        .filter(|version| *version != TEMPLATES_VERSION)
+    {
+        return TemplateRenderer::new_versioned(version);
+    }
+
+    match user_template_dir().filter(|dir| dir.is_dir()) {
+        Some(dir) if !cli.no_user_templates => {
+            let renderer = TemplateRenderer::with_user_templates(&dir)?;
+            if cli.verbose {
+                for path in renderer.overridden_templates() {
+                    println!("Using user template {}", dir.join(path).display());
+                }
+            }
+            Ok(renderer)
+        }
+        _ => Ok(TemplateRenderer::new()),
+    }
+}
+
+fn print_change_summary(summary: &ChangeSummary) {
+    let groups = [
+        ("Created", &summary.created),
+        ("Overwrote", &summary.changed),
+        ("Unchanged", &summary.unchanged),
+        ("Skipped", &summary.skipped),
+    ];
+    for (label, paths) in groups {
+        for path in paths {
+            println!("{} {}", label, color::path(path.display()));
+        }
+    }
+    println!("Summary: {}", summary);
+}
+
+fn print_report(report: &BuildReport, verbose: bool) {
+    for warning in &report.warnings {
+        eprintln!("{}", warning);
+    }
+
+    if verbose {
+        for path in &report.files_created {
+            println!("Created {}", color::path(path.display()));
+        }
+        for command in &report.commands_run {
+            println!("Ran `{}`", color::command(command));
+        }
+        if let Some(hash) = &report.commit_hash {
+            println!("Created initial commit {}", &hash[..hash.len().min(7)]);
+        }
+        println!(
+            "Generated {} files in {} ({:.2?})",
+            report.files_created.len(),
+            color::path(report.project_path.display()),
+            report.duration
+        );
+    }
+
+    println!("\n{}", color::success("✨ Project created successfully!"));
+
+    println!("\nNext steps:");
+    for (index, step) in report.next_steps.iter().enumerate() {
+        println!("{}. {}", index + 1, color::command(step));
+    }
 }
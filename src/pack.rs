@@ -0,0 +1,499 @@
+//! Template packs.
+//!
+//! A template pack is a directory, or a git repository, with a `pack.toml`
+//! manifest. It adds templates, output files and prompt variables on top of
+//! the built-in templates without forking cppup:
+//!
+//! ```toml
+//! post_gen = ["pre-commit install"]
+//!
+//! [[template]]
+//! name = "copyright"
+//! file = "templates/copyright.hbs"
+//!
+//! [[output]]
+//! template = "copyright"
+//! path = "docs/COPYRIGHT.md"
+//!
+//! [[variable]]
+//! name = "company"
+//! prompt = "Company name"
+//! default = "Acme"
+//! ```
+
+use crate::error::CppupError;
+use crate::project::{categorize_cancelled, CommandRunner, SystemRunner, ToolCommand};
+use crate::templates::TemplateRenderer;
+use anyhow::{Context, Result};
+use inquire::Text;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+use std::time::Duration;
+
+/// Name of the manifest file at the root of a pack.
+pub const MANIFEST_FILE: &str = "pack.toml";
+
+/// How long cloning a pack may take before it is stopped.
+const CLONE_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// Contents of a `pack.toml` manifest.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PackManifest {
+    /// Templates registered in addition to the built-in ones
+    #[serde(default, rename = "template")]
+    pub templates: Vec<PackTemplate>,
+    /// Files rendered after the built-in ones
+    #[serde(default, rename = "output")]
+    pub outputs: Vec<PackOutput>,
+    /// Variables asked for and passed to every template
+    #[serde(default, rename = "variable")]
+    pub variables: Vec<PackVariable>,
+    /// Shell commands run in the generated project, before those given
+    /// with `--post-gen`, when `--allow-pack-hooks` is given
+    #[serde(default)]
+    pub post_gen: Vec<String>,
+}
+
+/// A template file shipped with a pack.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PackTemplate {
+    /// Name the template is registered under
+    pub name: String,
+    /// Template file relative to the pack root
+    pub file: PathBuf,
+    /// Whether the template renders a script, made executable on Unix
+    #[serde(default)]
+    pub executable: bool,
+}
+
+/// A file rendered into the generated project.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PackOutput {
+    /// Pack or built-in template to render
+    pub template: String,
+    /// Output path relative to the project root
+    pub path: PathBuf,
+}
+
+/// A variable the user is asked for.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PackVariable {
+    /// Template variable name
+    pub name: String,
+    /// Question shown in interactive mode
+    pub prompt: String,
+    /// Value used when nothing is entered or in non-interactive mode
+    #[serde(default)]
+    pub default: Option<String>,
+}
+
+/// A loaded template pack.
+///
+/// # Examples
+///
+/// ```no_run
+/// use cppup::pack::TemplatePack;
+/// use cppup::TemplateRenderer;
+///
+/// let pack = TemplatePack::load("/path/to/pack")?;
+/// let mut renderer = TemplateRenderer::new();
+/// pack.register(&mut renderer)?;
+/// # Ok::<(), anyhow::Error>(())
+/// ```
+#[derive(Debug)]
+pub struct TemplatePack {
+    root: PathBuf,
+    manifest: PackManifest,
+}
+
+impl TemplatePack {
+    /// Loads a pack from a local directory or clones it from a git URL.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if cloning fails or the manifest is missing or invalid.
+    pub fn load(source: &str) -> Result<Self> {
+        Self::load_with(source, &SystemRunner)
+    }
+
+    /// Like [`load`](Self::load), cloning with `runner`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if cloning fails or the manifest is missing or invalid.
+    pub fn load_with(source: &str, runner: &dyn CommandRunner) -> Result<Self> {
+        if Path::new(source).is_dir() || !is_git_url(source) {
+            Self::from_dir(Path::new(source))
+        } else {
+            let cache = dirs::cache_dir()
+                .unwrap_or_else(std::env::temp_dir)
+                .join("cppup")
+                .join("packs");
+            Self::from_dir(&fetch(source, &cache, runner)?)
+        }
+    }
+
+    /// Loads a pack from a local directory.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the manifest is missing or invalid.
+    pub fn from_dir(dir: &Path) -> Result<Self> {
+        let manifest_path = dir.join(MANIFEST_FILE);
+        let content = fs::read_to_string(&manifest_path)
+            .with_context(|| format!("Failed to read {}", manifest_path.display()))?;
+        let manifest = parse_manifest(&content)
+            .with_context(|| format!("Invalid template pack {}", manifest_path.display()))?;
+        Ok(Self {
+            root: dir.to_path_buf(),
+            manifest,
+        })
+    }
+
+    /// Registers the pack's templates and checks that every output refers to
+    /// a known template.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error naming the file if a template cannot be read or is
+    /// invalid, or if an output refers to an unknown template.
+    pub fn register(&self, renderer: &mut TemplateRenderer) -> Result<()> {
+        for template in &self.manifest.templates {
+            let path = self.root.join(&template.file);
+            let content = fs::read_to_string(&path)
+                .with_context(|| format!("Failed to read template {}", path.display()))?;
+            renderer
+                .register_template(&template.name, &content)
+                .with_context(|| format!("Invalid template {}", path.display()))?;
+            if template.executable {
+                renderer.mark_executable(&template.name);
+            }
+        }
+
+        for output in &self.manifest.outputs {
+            if !renderer.has_template(&output.template) {
+                return Err(anyhow::anyhow!(
+                    "Output {} uses unknown template '{}'",
+                    output.path.display(),
+                    output.template
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Output files as `(template name, path relative to the project)`.
+    pub fn output_files(&self) -> Vec<(String, PathBuf)> {
+        self.manifest
+            .outputs
+            .iter()
+            .map(|output| (output.template.clone(), output.path.clone()))
+            .collect()
+    }
+
+    /// Shell commands to run in the generated project. They come from the
+    /// pack's author, so they are only run when the user allows it.
+    pub fn post_gen_hooks(&self) -> &[String] {
+        &self.manifest.post_gen
+    }
+
+    /// Fills `vars` with a value for every declared variable that is not set
+    /// yet, asking for it when `interactive` is true.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a variable without a default is missing in
+    /// non-interactive mode.
+    pub fn resolve_variables(
+        &self,
+        vars: &mut HashMap<String, String>,
+        interactive: bool,
+    ) -> Result<()> {
+        for variable in &self.manifest.variables {
+            if vars.contains_key(&variable.name) {
+                continue;
+            }
+
+            let value = if interactive {
+                let mut prompt = Text::new(&variable.prompt);
+                if let Some(default) = &variable.default {
+                    prompt = prompt.with_default(default);
+                }
+                categorize_cancelled(prompt.prompt().map_err(anyhow::Error::from))?
+            } else {
+                variable.default.clone().ok_or_else(|| {
+                    CppupError::Usage(anyhow::anyhow!(
+                        "Template pack variable '{}' is required in non-interactive mode (use --template-var {}=VALUE)",
+                        variable.name,
+                        variable.name
+                    ))
+                })?
+            };
+            vars.insert(variable.name.clone(), value);
+        }
+        Ok(())
+    }
+}
+
+/// Parses and validates a manifest.
+fn parse_manifest(content: &str) -> Result<PackManifest> {
+    let manifest: PackManifest = toml::from_str(content)?;
+
+    for output in &manifest.outputs {
+        let escapes = output
+            .path
+            .components()
+            .any(|component| !matches!(component, Component::Normal(_) | Component::CurDir));
+        if escapes || output.path.as_os_str().is_empty() {
+            return Err(anyhow::anyhow!(
+                "Output path {} must be relative to the project and stay inside it",
+                output.path.display()
+            ));
+        }
+    }
+    Ok(manifest)
+}
+
+/// Whether `source` names a remote repository: a URL with a scheme git
+/// understands, or an scp-like `git@host:path`.
+fn is_git_url(source: &str) -> bool {
+    ["https://", "http://", "ssh://", "git://", "file://", "git@"]
+        .iter()
+        .any(|prefix| source.starts_with(prefix))
+}
+
+/// Clones `url` with `runner` into a directory below `cache`, replacing an
+/// earlier clone.
+fn fetch(url: &str, cache: &Path, runner: &dyn CommandRunner) -> Result<PathBuf> {
+    let name: String = url
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    let dir = cache.join(name);
+
+    if dir.exists() {
+        fs::remove_dir_all(&dir).with_context(|| format!("Failed to remove {}", dir.display()))?;
+    }
+    if let Some(parent) = dir.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create {}", parent.display()))?;
+    }
+
+    // `--` keeps a URL starting with `-` from being read as an option
+    let clone = ToolCommand::new("git")
+        .args(["clone", "--depth", "1", "--", url])
+        .args([dir.to_string_lossy()])
+        .timeout(CLONE_TIMEOUT);
+    runner
+        .run_checked(&clone)
+        .with_context(|| format!("Failed to clone template pack {}", url))?;
+    Ok(dir)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::project::{CommandOutput, MockRunner};
+    use tempfile::TempDir;
+
+    const MANIFEST: &str = r#"
+post_gen = ["pre-commit install"]
+
+[[template]]
+name = "copyright"
+file = "copyright.hbs"
+
+[[output]]
+template = "copyright"
+path = "docs/COPYRIGHT.md"
+
+[[variable]]
+name = "company"
+prompt = "Company name"
+
+[[variable]]
+name = "team"
+prompt = "Team"
+default = "platform"
+"#;
+
+    fn write_pack(dir: &Path, template: &str) {
+        fs::write(dir.join(MANIFEST_FILE), MANIFEST).unwrap();
+        fs::write(dir.join("copyright.hbs"), template).unwrap();
+    }
+
+    #[test]
+    fn test_parse_manifest() {
+        let manifest = parse_manifest(MANIFEST).unwrap();
+        assert_eq!(manifest.templates[0].name, "copyright");
+        assert_eq!(manifest.outputs[0].path, PathBuf::from("docs/COPYRIGHT.md"));
+        assert_eq!(manifest.variables.len(), 2);
+        assert_eq!(manifest.variables[1].default.as_deref(), Some("platform"));
+        assert_eq!(manifest.post_gen, ["pre-commit install"]);
+    }
+
+    #[test]
+    fn test_parse_empty_manifest() {
+        let manifest = parse_manifest("").unwrap();
+        assert!(manifest.templates.is_empty());
+        assert!(manifest.outputs.is_empty());
+        assert!(manifest.post_gen.is_empty());
+    }
+
+    #[test]
+    fn test_parse_manifest_rejects_escaping_outputs() {
+        for path in ["../outside.txt", "/etc/passwd", "docs/../../x", ""] {
+            let manifest = format!("[[output]]\ntemplate = \"README.md\"\npath = \"{}\"", path);
+            assert!(parse_manifest(&manifest).is_err(), "{} accepted", path);
+        }
+    }
+
+    #[test]
+    fn test_parse_manifest_rejects_unknown_fields() {
+        assert!(parse_manifest("[[output]]\ntemplate = \"a\"\npath = \"b\"\nmode = 1").is_err());
+    }
+
+    #[test]
+    fn test_register_pack_templates() {
+        let temp_dir = TempDir::new().unwrap();
+        write_pack(temp_dir.path(), "(c) {{company}} for {{name}}");
+
+        let pack = TemplatePack::from_dir(temp_dir.path()).unwrap();
+        let mut renderer = TemplateRenderer::new();
+        pack.register(&mut renderer).unwrap();
+
+        let rendered = renderer
+            .render_to_string(
+                "copyright",
+                &serde_json::json!({ "company": "Acme", "name": "demo" }),
+            )
+            .unwrap();
+        assert_eq!(rendered, "(c) Acme for demo");
+        assert_eq!(
+            pack.output_files(),
+            vec![("copyright".to_string(), PathBuf::from("docs/COPYRIGHT.md"))]
+        );
+    }
+
+    #[test]
+    fn test_register_marks_executable_templates() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(
+            temp_dir.path().join(MANIFEST_FILE),
+            "[[template]]\nname = \"bootstrap\"\nfile = \"bootstrap.sh.hbs\"\nexecutable = true\n",
+        )
+        .unwrap();
+        fs::write(temp_dir.path().join("bootstrap.sh.hbs"), "#!/bin/sh\n").unwrap();
+
+        let pack = TemplatePack::from_dir(temp_dir.path()).unwrap();
+        let mut renderer = TemplateRenderer::new();
+        pack.register(&mut renderer).unwrap();
+
+        assert!(renderer.is_executable("bootstrap"));
+        assert!(!renderer.is_executable("README.md"));
+    }
+
+    #[test]
+    fn test_register_rejects_unknown_output_template() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(
+            temp_dir.path().join(MANIFEST_FILE),
+            "[[output]]\ntemplate = \"missing\"\npath = \"x.txt\"",
+        )
+        .unwrap();
+
+        let pack = TemplatePack::from_dir(temp_dir.path()).unwrap();
+        let error = pack.register(&mut TemplateRenderer::new()).unwrap_err();
+        assert!(error.to_string().contains("unknown template 'missing'"));
+    }
+
+    #[test]
+    fn test_register_reports_invalid_template_file() {
+        let temp_dir = TempDir::new().unwrap();
+        write_pack(temp_dir.path(), "{{#if company}}");
+
+        let pack = TemplatePack::from_dir(temp_dir.path()).unwrap();
+        let error = pack.register(&mut TemplateRenderer::new()).unwrap_err();
+        assert!(format!("{:#}", error).contains("copyright.hbs"));
+    }
+
+    #[test]
+    fn test_missing_manifest() {
+        let temp_dir = TempDir::new().unwrap();
+        assert!(TemplatePack::from_dir(temp_dir.path()).is_err());
+    }
+
+    #[test]
+    fn test_resolve_variables_non_interactive() {
+        let temp_dir = TempDir::new().unwrap();
+        write_pack(temp_dir.path(), "");
+        let pack = TemplatePack::from_dir(temp_dir.path()).unwrap();
+
+        let mut vars = HashMap::new();
+        let error = pack.resolve_variables(&mut vars, false).unwrap_err();
+        assert!(error.to_string().contains("'company'"));
+
+        let mut vars = HashMap::from([("company".to_string(), "Acme".to_string())]);
+        pack.resolve_variables(&mut vars, false).unwrap();
+        assert_eq!(vars["company"], "Acme");
+        assert_eq!(vars["team"], "platform");
+    }
+
+    #[test]
+    fn test_fetch_clones_with_the_runner() {
+        let cache = TempDir::new().unwrap();
+        let runner = MockRunner::new(|command: &ToolCommand| {
+            let dir = PathBuf::from(command.args.last().unwrap());
+            fs::create_dir_all(&dir).unwrap();
+            fs::write(dir.join(MANIFEST_FILE), "").unwrap();
+            Ok(CommandOutput::ok(""))
+        });
+
+        let dir = fetch("https://example.com/pack.git", cache.path(), &runner).unwrap();
+
+        assert!(dir.starts_with(cache.path()));
+        assert!(dir.join(MANIFEST_FILE).exists());
+        assert_eq!(
+            runner.calls(),
+            [format!(
+                "git clone --depth 1 -- https://example.com/pack.git {}",
+                dir.display()
+            )]
+        );
+    }
+
+    #[test]
+    fn test_fetch_reports_clone_failures() {
+        let cache = TempDir::new().unwrap();
+        let runner = MockRunner::new(|_: &ToolCommand| {
+            Ok(CommandOutput::failed(128, "fatal: repository not found"))
+        });
+
+        let error = fetch("git@example.com:pack", cache.path(), &runner).unwrap_err();
+
+        let message = format!("{:#}", error);
+        assert!(message.starts_with("Failed to clone template pack git@example.com:pack"));
+        assert!(
+            message.contains("fatal: repository not found"),
+            "{}",
+            message
+        );
+    }
+
+    #[test]
+    fn test_is_git_url() {
+        assert!(is_git_url("https://github.com/org/cppup-templates.git"));
+        assert!(is_git_url("git@github.com:org/templates"));
+        assert!(is_git_url("git://example.com/templates"));
+        assert!(!is_git_url("templates.git"));
+        assert!(!is_git_url("--upload-pack=touch /tmp/pwned.git"));
+        assert!(!is_git_url("/path/to/pack"));
+        assert!(!is_git_url("pack"));
+    }
+}
@@ -0,0 +1,344 @@
+//! Single configuration files rendered into an existing project, for
+//! `cppup generate`.
+
+use super::builder::generation_date;
+use super::config::{categorize_cancelled, default_author, normalize_path};
+use super::overwrite::{prompt_decision, review, ChangeSummary, Decision, FileChange};
+use super::License;
+use crate::cli::GenerateArgs;
+use crate::error::{categorize, CppupError};
+use crate::templates::TemplateRenderer;
+use anyhow::{Context, Result};
+use chrono::Datelike;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fs;
+use std::io::IsTerminal;
+use std::path::Path;
+use std::str::FromStr;
+
+/// A file `cppup generate` can write on its own.
+///
+/// # Examples
+///
+/// ```
+/// use cppup::project::Artifact;
+///
+/// let artifact: Artifact = "clang-tidy".parse().unwrap();
+/// assert_eq!(artifact.file_name(), ".clang-tidy");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Artifact {
+    /// `.clang-format`
+    ClangFormat,
+    /// `.clang-tidy` with the default check set
+    ClangTidy,
+    /// `cppcheck-suppressions.xml`
+    CppcheckSuppressions,
+    /// `cmake-format.yaml`
+    CmakeFormat,
+    /// `.gitignore`
+    Gitignore,
+    /// `.editorconfig`
+    Editorconfig,
+    /// `LICENSE`
+    License,
+}
+
+impl Artifact {
+    /// Name of the written file, relative to the project root.
+    pub fn file_name(self) -> &'static str {
+        match self {
+            Artifact::ClangFormat => ".clang-format",
+            Artifact::ClangTidy => ".clang-tidy",
+            Artifact::CppcheckSuppressions => "cppcheck-suppressions.xml",
+            Artifact::CmakeFormat => "cmake-format.yaml",
+            Artifact::Gitignore => ".gitignore",
+            Artifact::Editorconfig => ".editorconfig",
+            Artifact::License => "LICENSE",
+        }
+    }
+
+    /// Name of the template rendering this file; a license is rendered with
+    /// the template of `license`.
+    fn template_name(self, license: &License) -> String {
+        match self {
+            Artifact::ClangFormat => "clang-format".to_string(),
+            Artifact::ClangTidy => "clang-tidy".to_string(),
+            Artifact::CppcheckSuppressions => "cppcheck-suppressions.xml".to_string(),
+            Artifact::CmakeFormat => "cmake-format".to_string(),
+            Artifact::Gitignore => "gitignore".to_string(),
+            Artifact::Editorconfig => "editorconfig".to_string(),
+            Artifact::License => license.to_string(),
+        }
+    }
+}
+
+impl std::fmt::Display for Artifact {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Artifact::ClangFormat => write!(f, "clang-format"),
+            Artifact::ClangTidy => write!(f, "clang-tidy"),
+            Artifact::CppcheckSuppressions => write!(f, "cppcheck-suppressions"),
+            Artifact::CmakeFormat => write!(f, "cmake-format"),
+            Artifact::Gitignore => write!(f, "gitignore"),
+            Artifact::Editorconfig => write!(f, "editorconfig"),
+            Artifact::License => write!(f, "license"),
+        }
+    }
+}
+
+impl FromStr for Artifact {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "clang-format" => Ok(Artifact::ClangFormat),
+            "clang-tidy" => Ok(Artifact::ClangTidy),
+            "cppcheck-suppressions" => Ok(Artifact::CppcheckSuppressions),
+            "cmake-format" => Ok(Artifact::CmakeFormat),
+            "gitignore" => Ok(Artifact::Gitignore),
+            "editorconfig" => Ok(Artifact::Editorconfig),
+            "license" => Ok(Artifact::License),
+            _ => Err(anyhow::anyhow!("Unknown artifact: {}", value)),
+        }
+    }
+}
+
+/// The template variables a single artifact needs, without a full
+/// [`ProjectConfig`](super::ProjectConfig). Templates that also check
+/// project options, like `.gitignore`, render their generic parts.
+#[derive(Serialize, Debug, Clone)]
+pub struct ArtifactTemplateData {
+    /// Project name
+    pub name: String,
+    /// Copyright holder
+    pub author: String,
+    /// Year for copyright notices
+    pub year: String,
+    /// License, serialized as its SPDX identifier
+    pub license: License,
+    /// clang-tidy `Checks:` globs (empty for the default check set)
+    pub clang_tidy_checks: Vec<String>,
+}
+
+impl ArtifactTemplateData {
+    /// Creates the data for project `name`, dated like a generated project.
+    pub fn new(name: &str, author: &str, license: &License) -> Self {
+        Self {
+            name: name.to_string(),
+            author: author.to_string(),
+            year: generation_date().year().to_string(),
+            license: license.clone(),
+            clang_tidy_checks: Vec::new(),
+        }
+    }
+}
+
+/// Renders `artifact` into the directory `dir`. An existing file is only
+/// replaced with `force`, and only if it has changed and `decide` agrees.
+///
+/// # Errors
+///
+/// Returns an error if `dir` is not a directory, if the file exists and
+/// `force` is not set, or if rendering, `decide` or writing fails.
+pub fn generate_artifact(
+    renderer: &TemplateRenderer,
+    artifact: Artifact,
+    data: &ArtifactTemplateData,
+    dir: &Path,
+    force: bool,
+    decide: impl FnMut(&FileChange) -> Result<Decision>,
+) -> Result<ChangeSummary> {
+    if !dir.is_dir() {
+        anyhow::bail!("{} is not a directory", dir.display());
+    }
+    let path = dir.join(artifact.file_name());
+    if path.exists() && !force {
+        return Err(CppupError::Conflict(anyhow::anyhow!(
+            "{} already exists; use --force to overwrite it",
+            path.display()
+        ))
+        .into());
+    }
+
+    let change = FileChange {
+        existing: path
+            .exists()
+            .then(|| fs::read_to_string(&path))
+            .transpose()
+            .with_context(|| format!("Failed to read {}", path.display()))?,
+        rendered: renderer.render_with_extra(
+            &artifact.template_name(&data.license),
+            data,
+            &HashMap::new(),
+        )?,
+        path,
+    };
+    let (writes, summary) = review(vec![change], decide)?;
+    for change in writes {
+        fs::write(&change.path, change.rendered)
+            .with_context(|| format!("Failed to write file {}", change.path.display()))?;
+    }
+    Ok(summary)
+}
+
+/// Writes the file requested with `cppup generate`. The project name
+/// defaults to that of the target directory. Before replacing a changed
+/// file, its diff is printed, or the user is asked when stdin is a terminal
+/// and `--assume-yes` is not given.
+///
+/// # Errors
+///
+/// Returns the errors of [`generate_artifact`].
+pub fn generate_from_cli(
+    args: &GenerateArgs,
+    renderer: &TemplateRenderer,
+) -> Result<ChangeSummary> {
+    let dir = normalize_path(args.into.as_deref().unwrap_or(Path::new(".")));
+    let artifact: Artifact = args.artifact.parse()?;
+    let license: License = args
+        .license
+        .as_deref()
+        .map(str::parse)
+        .transpose()?
+        .unwrap_or_default();
+    let name = args.name.clone().unwrap_or_else(|| {
+        dir.file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default()
+    });
+    let author = args.author.clone().unwrap_or_else(default_author);
+
+    let interactive = !args.assume_yes && std::io::stdin().is_terminal();
+
+    let data = ArtifactTemplateData::new(&name, &author, &license);
+    generate_artifact(renderer, artifact, &data, &dir, args.force, |change| {
+        if interactive {
+            categorize_cancelled(prompt_decision(change))
+        } else {
+            println!("{}", change.diff());
+            Ok(Decision::Overwrite)
+        }
+    })
+    .map_err(|error| categorize(error, CppupError::Validation))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::{exit_code, CONFLICT_EXIT_CODE};
+    use tempfile::TempDir;
+
+    const ARTIFACTS: [Artifact; 7] = [
+        Artifact::ClangFormat,
+        Artifact::ClangTidy,
+        Artifact::CppcheckSuppressions,
+        Artifact::CmakeFormat,
+        Artifact::Gitignore,
+        Artifact::Editorconfig,
+        Artifact::License,
+    ];
+
+    #[test]
+    fn test_artifact_round_trip() {
+        for artifact in ARTIFACTS {
+            assert_eq!(artifact.to_string().parse::<Artifact>().unwrap(), artifact);
+        }
+        assert!("readme".parse::<Artifact>().is_err());
+    }
+
+    fn render_artifact(artifact: Artifact, data: &ArtifactTemplateData) -> String {
+        TemplateRenderer::new()
+            .render_to_string(&artifact.template_name(&data.license), data)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_every_artifact_renders() {
+        let data = ArtifactTemplateData::new("demo", "Jane Doe", &License::GPL3);
+        for artifact in ARTIFACTS {
+            let rendered = render_artifact(artifact, &data);
+            assert!(!rendered.trim().is_empty(), "{}", artifact);
+        }
+
+        let license = render_artifact(Artifact::License, &data);
+        assert!(license.contains("GNU GENERAL PUBLIC LICENSE"));
+        assert!(license.contains("Jane Doe"));
+        let gitignore = render_artifact(Artifact::Gitignore, &data);
+        assert!(gitignore.contains("build/"));
+    }
+
+    #[test]
+    fn test_generate_artifact_refuses_to_overwrite() {
+        let temp_dir = TempDir::new().unwrap();
+        let renderer = TemplateRenderer::new();
+        let data = ArtifactTemplateData::new("demo", "Jane Doe", &License::MIT);
+        let path = temp_dir.path().join(".clang-tidy");
+        fs::write(&path, "Checks: '-*'\n").unwrap();
+
+        let error = generate_artifact(
+            &renderer,
+            Artifact::ClangTidy,
+            &data,
+            temp_dir.path(),
+            false,
+            |_| unreachable!(),
+        )
+        .unwrap_err();
+        assert_eq!(exit_code(&error), CONFLICT_EXIT_CODE);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "Checks: '-*'\n");
+
+        let summary = generate_artifact(
+            &renderer,
+            Artifact::ClangTidy,
+            &data,
+            temp_dir.path(),
+            true,
+            |_| Ok(Decision::Skip),
+        )
+        .unwrap();
+        assert_eq!(summary.skipped, std::slice::from_ref(&path));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "Checks: '-*'\n");
+
+        let summary = generate_artifact(
+            &renderer,
+            Artifact::ClangTidy,
+            &data,
+            temp_dir.path(),
+            true,
+            |_| Ok(Decision::Overwrite),
+        )
+        .unwrap();
+        assert_eq!(summary.changed, std::slice::from_ref(&path));
+        assert_ne!(fs::read_to_string(&path).unwrap(), "Checks: '-*'\n");
+
+        let summary = generate_artifact(
+            &renderer,
+            Artifact::ClangTidy,
+            &data,
+            temp_dir.path(),
+            true,
+            |_| unreachable!(),
+        )
+        .unwrap();
+        assert_eq!(summary.unchanged, [path]);
+    }
+
+    #[test]
+    fn test_generate_artifact_requires_a_directory() {
+        let temp_dir = TempDir::new().unwrap();
+        let data = ArtifactTemplateData::new("demo", "Jane Doe", &License::MIT);
+
+        let error = generate_artifact(
+            &TemplateRenderer::new(),
+            Artifact::Editorconfig,
+            &data,
+            &temp_dir.path().join("missing"),
+            false,
+            |_| unreachable!(),
+        )
+        .unwrap_err();
+        assert!(error.to_string().contains("is not a directory"));
+    }
+}
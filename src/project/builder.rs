@@ -1,10 +1,140 @@
+use super::codegen::{self, ApiHeader};
 use super::config::{ProjectConfig, ProjectType};
-use super::{BuildSystem, PackageManager, TestFramework};
-use crate::templates::{ProjectTemplateData, TemplateRenderer};
+use super::github;
+use super::tool::{CommandOutput, CommandRunner, SystemRunner, ToolCommand};
+use super::{
+    BuildSystem, CiProvider, CmakeGenerator, CoverageService, DocEngine, HppExtension, MesonConfig,
+    PackageManager, SanitizerPreset, TargetPlatform, TestFramework, VcpkgFeature,
+};
+use crate::templates::{
+    make_executable, normalize_line_endings, ProjectTemplateData, TemplateFeatures,
+    TemplateRenderer, TemplateRepository,
+};
 use anyhow::{Context, Result};
 use chrono::prelude::*;
+use serde::Serialize;
+use std::collections::BTreeMap;
 use std::fs;
-use std::process::Command;
+use std::path::{Component, Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// File in the project root recording how the project was generated.
+pub const METADATA_FILE: &str = ".cppup.json";
+
+/// Version of the generated project layout, stored in [`METADATA_FILE`].
+/// Bump it whenever the set of generated files changes so older projects
+/// can be recognised.
+pub const PROJECT_SCHEMA_VERSION: u32 = 1;
+
+/// Oldest CMake release the generated CMake files work with, whatever the
+/// C++ standard.
+const CMAKE_BASELINE_VERSION: &str = "3.27";
+
+/// Toolchain written by `conan install`. `cmake_layout` puts it under the
+/// build type of the default profile.
+const CONAN_TOOLCHAIN: &str = "build/Release/generators/conan_toolchain.cmake";
+
+/// How long a single verification command may run before it is stopped.
+const VERIFY_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// A generated file, with its path relative to the project root.
+struct PlannedFile {
+    path: PathBuf,
+    contents: String,
+    /// Whether the file is a script, made executable on Unix
+    executable: bool,
+}
+
+impl PlannedFile {
+    fn new(path: PathBuf, contents: String) -> Self {
+        Self {
+            path,
+            contents,
+            executable: false,
+        }
+    }
+}
+
+/// Generated files in the order they are written.
+type FilePlan = Vec<PlannedFile>;
+
+/// Template data of `CODEOWNERS`.
+#[derive(Serialize)]
+struct Codeowners<'a> {
+    #[serde(flatten)]
+    project: &'a ProjectTemplateData,
+    /// Owners separated by spaces
+    owners: String,
+    /// Directories with their own entry, without slashes
+    owned_dirs: Vec<&'static str>,
+}
+
+/// An external command run while building the project.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CommandSummary {
+    /// Program name, e.g. `git`
+    pub program: String,
+    /// Arguments passed to the program
+    pub args: Vec<String>,
+    /// Whether the command exited successfully
+    pub success: bool,
+    /// Exit code, if the command was not terminated by a signal
+    pub exit_code: Option<i32>,
+}
+
+impl CommandSummary {
+    /// Summarizes `command`, with the outcome of `output` if it could be
+    /// started.
+    fn new(command: &ToolCommand, output: Option<&CommandOutput>) -> Self {
+        Self {
+            program: command.program.clone(),
+            args: command.args.clone(),
+            success: output.is_some_and(CommandOutput::success),
+            exit_code: output.and_then(|output| output.exit_code),
+        }
+    }
+}
+
+impl std::fmt::Display for CommandSummary {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.program)?;
+        for arg in &self.args {
+            write!(f, " {}", arg)?;
+        }
+        Ok(())
+    }
+}
+
+/// What [`ProjectBuilder::build`] did, for the caller to present.
+#[derive(Debug, Clone, Default)]
+pub struct BuildReport {
+    /// Root directory of the generated project
+    pub project_path: PathBuf,
+    /// Files written, relative to `project_path`, sorted by path
+    pub files_created: Vec<PathBuf>,
+    /// External commands run after the files were written
+    pub commands_run: Vec<CommandSummary>,
+    /// Problems that did not stop generation
+    pub warnings: Vec<String>,
+    /// Hash of the initial git commit, if one was created
+    pub commit_hash: Option<String>,
+    /// Commands to build the project, in order, for the platform's shell
+    /// (PowerShell on Windows), starting with a `cd` into the project
+    pub next_steps: Vec<String>,
+    /// Time taken by the build
+    pub duration: Duration,
+}
+
+/// Result of [`ProjectBuilder::verify`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum VerifyOutcome {
+    /// The configure step succeeded; holds the commands run
+    Passed(Vec<CommandSummary>),
+    /// A tool or setting needed to configure the project is missing; holds
+    /// the warning to show
+    Skipped(String),
+}
 
 /// Builds and generates C++ project structure and files.
 ///
@@ -21,30 +151,533 @@ use std::process::Command;
 ///
 /// // let config = ProjectConfig::new(None)?;
 /// // let builder = ProjectBuilder::new(config);
-/// // builder.build()?;
+/// // let report = builder.build()?;
 /// ```
 pub struct ProjectBuilder {
     config: ProjectConfig,
     template_renderer: TemplateRenderer,
     template_data: ProjectTemplateData,
+    extra_outputs: Vec<(String, PathBuf)>,
+    keep_on_failure: bool,
+    keep_build: bool,
+    jobs: Option<usize>,
+    post_gen_hooks: Vec<String>,
+    post_gen_strict: bool,
+    runner: Arc<dyn CommandRunner>,
+}
+
+/// Converts a project name into a C++ namespace. Dot-separated names such
+/// as `acme.networking.http` become nested namespaces.
+fn sanitize_cpp_namespace(name: &str) -> String {
+    name.replace('-', "_").replace('.', "::")
+}
+
+/// Converts a project name into a CMake target name.
+fn sanitize_target_name(name: &str) -> String {
+    name.replace('.', "_")
+}
+
+/// Path of the public header relative to `include/`, with one directory
+/// per dot-separated component of the project name.
+fn header_path(name: &str, extension: &HppExtension) -> String {
+    format!("{}.{}", name.replace('.', "/"), extension)
+}
+
+fn binary_path(config: &ProjectConfig) -> String {
+    let target_name = sanitize_target_name(&config.name);
+    let target = match config.project_type {
+        ProjectType::Executable => target_name,
+        ProjectType::Library => format!("{}_{}", target_name, example_names(config)[0]),
+    };
+    match config.build_system {
+        BuildSystem::CMake => format!("build/Debug/bin/{}", target),
+        BuildSystem::Make => target,
+        BuildSystem::Meson => format!("build/{}", target),
+    }
+}
+
+/// Commands, run from the project root, that fetch the dependencies and
+/// build the project. The README and the "Next steps" printed after
+/// generation both list these, so they cannot disagree.
+pub(super) fn build_steps(config: &ProjectConfig, shell: Shell) -> Vec<String> {
+    match config.build_system {
+        BuildSystem::CMake => {
+            let mut steps = Vec::new();
+            let presets = config.cmake_generator != CmakeGenerator::Default;
+            let mut configure = if !presets {
+                "cmake -S . -B build".to_string()
+            } else if config.cmake_generator.is_multi_config() {
+                "cmake --preset default".to_string()
+            } else {
+                "cmake --preset Debug".to_string()
+            };
+            match config.package_manager {
+                PackageManager::Conan => {
+                    steps.push("conan install . --build=missing".to_string());
+                    configure.push_str(&format!(" -DCMAKE_TOOLCHAIN_FILE={}", CONAN_TOOLCHAIN));
+                }
+                PackageManager::Vcpkg => configure.push_str(match shell {
+                    Shell::Posix => {
+                        " -DCMAKE_TOOLCHAIN_FILE=${VCPKG_ROOT}/scripts/buildsystems/vcpkg.cmake"
+                    }
+                    Shell::PowerShell => {
+                        " \"-DCMAKE_TOOLCHAIN_FILE=$env:VCPKG_ROOT/scripts/buildsystems/vcpkg.cmake\""
+                    }
+                }),
+                PackageManager::None => {}
+            }
+            steps.push(configure);
+            steps.push(if presets {
+                "cmake --build --preset Debug".to_string()
+            } else {
+                "cmake --build build".to_string()
+            });
+            steps
+        }
+        BuildSystem::Make => vec!["make".to_string()],
+        BuildSystem::Meson => vec![
+            "meson setup build".to_string(),
+            "meson compile -C build".to_string(),
+        ],
+    }
+}
+
+/// Commands, run from the project root after [`build_steps`], that run the
+/// tests. Empty without a test framework.
+pub(super) fn test_steps(config: &ProjectConfig) -> Vec<String> {
+    if config.test_framework == TestFramework::None {
+        return Vec::new();
+    }
+    let step = match config.build_system {
+        BuildSystem::CMake => match config.cmake_generator {
+            CmakeGenerator::Default => "ctest --test-dir build",
+            ref generator if generator.is_multi_config() => "ctest --test-dir build -C Debug",
+            _ => "ctest --test-dir build/Debug",
+        },
+        BuildSystem::Make => "make test",
+        BuildSystem::Meson => "meson test -C build",
+    };
+    vec![step.to_string()]
+}
+
+/// Base names of the example programs: `example` for a single one,
+/// otherwise `example_01`, `example_02`, ...
+fn example_names(config: &ProjectConfig) -> Vec<String> {
+    if config.num_examples <= 1 {
+        return vec!["example".to_string()];
+    }
+    (1..=config.num_examples)
+        .map(|index| format!("example_{:02}", index))
+        .collect()
+}
+
+/// Returns the web page of a GitHub or GitLab repository from its remote
+/// URL, e.g. `git@github.com:me/proj.git` gives `https://github.com/me/proj`.
+pub(super) fn repository_web_url(remote: &str) -> Option<String> {
+    let location = match ["https://", "http://", "ssh://"]
+        .iter()
+        .find_map(|scheme| remote.strip_prefix(scheme))
+    {
+        Some(rest) => rest.to_string(),
+        // scp-like syntax: user@host:path
+        None => remote.replacen(':', "/", 1),
+    };
+    let location = location
+        .rsplit_once('@')
+        .map_or(location.as_str(), |(_, rest)| rest);
+    let (host, path) = location.split_once('/')?;
+    let path = path.trim_end_matches('/').trim_end_matches(".git");
+    let host = host.split(':').next()?;
+    if !["github.com", "gitlab.com"].contains(&host) || path.is_empty() {
+        return None;
+    }
+    Some(format!("https://{}/{}", host, path))
+}
+
+/// Derives a macOS bundle identifier from the repository web URL, e.g.
+/// `https://github.com/me/proj` gives `com.github.me.proj`, falling back to
+/// `com.example.<target>`.
+fn default_bundle_id(repository_url: Option<&str>, target_name: &str) -> String {
+    let parts = match repository_url.and_then(|url| url.strip_prefix("https://")) {
+        Some(location) => {
+            let (host, path) = location.split_once('/').unwrap_or((location, ""));
+            host.rsplit('.')
+                .chain(path.split('/'))
+                .map(str::to_string)
+                .collect()
+        }
+        None => vec![
+            "com".to_string(),
+            "example".to_string(),
+            target_name.to_string(),
+        ],
+    };
+    parts
+        .iter()
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            part.chars()
+                .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+/// Extracts the email address from an author written as `Name <email>`.
+fn author_email(author: &str) -> Option<&str> {
+    let (_, rest) = author.split_once('<')?;
+    let (email, _) = rest.split_once('>')?;
+    let email = email.trim();
+    email.contains('@').then_some(email)
+}
+
+/// Returns the root of the git repository that `path` lies in, if any.
+/// `path` does not need to exist yet.
+pub(super) fn enclosing_git_repository(path: &Path) -> Option<PathBuf> {
+    let path = std::path::absolute(path).ok()?;
+    path.ancestors()
+        .find(|dir| dir.join(".git").exists())
+        .map(Path::to_path_buf)
+}
+
+/// The shell commands are written for.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(super) enum Shell {
+    /// `sh` and compatible shells; also used in generated documentation
+    Posix,
+    /// Windows PowerShell
+    PowerShell,
 }
 
-fn create_template_data(config: &ProjectConfig) -> ProjectTemplateData {
+impl Shell {
+    /// The usual shell of the platform cppup runs on.
+    fn current() -> Self {
+        if cfg!(windows) {
+            Shell::PowerShell
+        } else {
+            Shell::Posix
+        }
+    }
+
+    /// Quotes `word` if the shell would otherwise split or expand it.
+    fn quote(self, word: &str) -> String {
+        let safe = |c: char| {
+            c.is_alphanumeric()
+                || matches!(c, '-' | '_' | '.' | '/' | ':')
+                || (c == '\\' && self == Shell::PowerShell)
+        };
+        if !word.is_empty() && word.chars().all(safe) {
+            return word.to_string();
+        }
+        match self {
+            Shell::Posix => format!("'{}'", word.replace('\'', r"'\''")),
+            Shell::PowerShell => format!("'{}'", word.replace('\'', "''")),
+        }
+    }
+
+    /// Joins two commands so that `second` only runs if `first` succeeds.
+    /// Windows PowerShell 5 has no `&&`.
+    fn and_then(self, first: &str, second: &str) -> String {
+        match self {
+            Shell::Posix => format!("{} && {}", first, second),
+            Shell::PowerShell => format!("{}; if ($?) {{ {} }}", first, second),
+        }
+    }
+}
+
+/// Returns `path` and its ancestors that do not exist yet, deepest first.
+fn missing_directories(path: &Path) -> Vec<&Path> {
+    path.ancestors().take_while(|dir| !dir.exists()).collect()
+}
+
+/// Checks that `path` is relative and has no `..` or root components, so
+/// joining it to the project root cannot leave the project.
+fn check_relative_path(path: &Path) -> Result<()> {
+    let escapes = path
+        .components()
+        .any(|component| !matches!(component, Component::Normal(_) | Component::CurDir));
+    if escapes || path.as_os_str().is_empty() {
+        return Err(anyhow::anyhow!(
+            "Output path {} must be relative to the project and stay inside it",
+            path.display()
+        ));
+    }
+    Ok(())
+}
+
+/// The date generated files are dated with: that of `SOURCE_DATE_EPOCH` if
+/// set, so reproducible runs get the same dates, otherwise today.
+pub(super) fn generation_date() -> NaiveDate {
+    source_date(std::env::var("SOURCE_DATE_EPOCH").ok().as_deref())
+        .unwrap_or_else(|| Local::now().date_naive())
+}
+
+/// Returns the UTC date of `epoch`, a number of seconds since 1970-01-01.
+fn source_date(epoch: Option<&str>) -> Option<NaiveDate> {
+    let seconds = epoch?.trim().parse().ok()?;
+    Some(DateTime::from_timestamp(seconds, 0)?.date_naive())
+}
+
+/// Returns the newer of two `major.minor` CMake versions.
+fn newer_cmake_version<'a>(a: &'a str, b: &'a str) -> &'a str {
+    let parse = |version: &str| -> Vec<u32> {
+        version
+            .split('.')
+            .map(|part| part.parse().unwrap_or_default())
+            .collect()
+    };
+    if parse(b) > parse(a) {
+        b
+    } else {
+        a
+    }
+}
+
+pub(super) fn create_template_data(config: &ProjectConfig) -> ProjectTemplateData {
+    let repository_url = config.git_remote.as_deref().and_then(repository_web_url);
+    let repository = repository_url.as_deref().and_then(template_repository);
+    let bundle_id = config.bundle_id.clone().unwrap_or_else(|| {
+        default_bundle_id(
+            repository_url.as_deref(),
+            &sanitize_target_name(&config.name),
+        )
+    });
     ProjectTemplateData {
         name: config.name.clone(),
         cpp_standard: config.cpp_standard.to_string(),
+        cpp_standard_num: config.cpp_standard.to_string().parse().unwrap_or_default(),
+        cmake_min_version: newer_cmake_version(
+            CMAKE_BASELINE_VERSION,
+            config.cpp_standard.cmake_min_version(),
+        )
+        .to_string(),
+        cpp_extension: config.cpp_extension.to_string(),
+        hpp_extension: config.hpp_extension.to_string(),
         is_library: matches!(config.project_type, ProjectType::Library),
-        namespace: config.name.replace('-', "_"),
+        namespace: sanitize_cpp_namespace(&config.name),
+        namespace_parts: sanitize_cpp_namespace(&config.name)
+            .split("::")
+            .map(String::from)
+            .collect(),
+        target_name: sanitize_target_name(&config.name),
+        header_path: header_path(&config.name, &config.hpp_extension),
         build_system: config.build_system.to_string(),
+        is_meson: config.build_system == BuildSystem::Meson,
+        cmake_generator: config
+            .cmake_generator
+            .cmake_name()
+            .unwrap_or_default()
+            .to_string(),
         description: config.description.clone(),
         author: config.author.clone(),
+        author_email: author_email(&config.author).unwrap_or_default().to_string(),
         version: config.version.to_string(),
-        year: Local::now().year().to_string(),
+        example_names: example_names(config),
+        year: generation_date().year().to_string(),
+        release_date: generation_date().format("%Y-%m-%d").to_string(),
+        homepage: config.homepage.clone().unwrap_or_default(),
+        generate_changelog: config.generate_changelog,
+        generate_community_files: config.generate_community_files,
+        git_branch: config.git_branch.clone(),
+        git_remote: config.git_remote.clone().unwrap_or_default(),
+        repository_url: repository_url.clone().unwrap_or_default(),
+        changelog_url: config
+            .homepage
+            .clone()
+            .or(repository_url)
+            .map(|homepage| format!("{}/blob/{}/CHANGELOG.md", homepage, config.git_branch))
+            .unwrap_or_default(),
         enable_tests: config.test_framework != TestFramework::None,
         test_framework: config.test_framework.to_string(),
+        test_runner: config.test_runner.to_string(),
         package_manager: config.package_manager.to_string(),
+        license: config.license.to_string(),
         quality_config: config.quality_config.to_string(),
         code_formatter: config.code_formatter.to_string(),
+        quality_tools: config
+            .quality_config
+            .names()
+            .into_iter()
+            .map(String::from)
+            .collect(),
+        enable_clang_tidy: config.quality_config.enable_clang_tidy,
+        clang_tidy_checks: config.quality_config.clang_tidy_check_globs(),
+        enable_cppcheck: config.quality_config.enable_cppcheck,
+        enable_iwyu: config.quality_config.enable_include_what_you_use,
+        enable_clang_format: config.code_formatter.enable_clang_format,
+        enable_cmake_format: config.code_formatter.enable_cmake_format,
+        enable_cmake_lint: config.code_formatter.enable_cmake_lint,
+        sanitizer_preset: config.sanitizer_preset.to_string(),
+        sanitizer_flags: config.sanitizer_preset.sanitizers().join(","),
+        doc_engine: config.doc_engine.to_string(),
+        binary_path: binary_path(config),
+        ide: config.ide.names().into_iter().map(String::from).collect(),
+        build_steps: build_steps(config, Shell::Posix),
+        test_steps: test_steps(config),
+        snippets: config
+            .snippets
+            .iter()
+            .map(|snippet| snippet.file_stem().to_string())
+            .collect(),
+        conan_generators: conan_generators(&config.build_system)
+            .iter()
+            .map(|generator| generator.to_string())
+            .collect(),
+        conan_options: config
+            .conan_options
+            .iter()
+            .map(|option| conan_option_pattern(option))
+            .collect(),
+        vcpkg_features: vcpkg_features(config),
+        vcpkg_has_config: config.package_manager == PackageManager::Vcpkg
+            && config.vcpkg_default_registry.is_some(),
+        vcpkg_registry_url: config.vcpkg_default_registry.clone().unwrap_or_default(),
+        vcpkg_baseline: config.vcpkg_baseline.clone().unwrap_or_default(),
+        compile_definitions: config.compile_definitions.clone(),
+        enable_lto: config.enable_lto,
+        coverage_service: config.coverage_service.to_string(),
+        android: config.target_platform == TargetPlatform::Android,
+        android_abi: config.android_abi.clone(),
+        android_api: config.android_api,
+        mingw: config.target_platform == TargetPlatform::WindowsMingw,
+        macos_bundle: config.macos_bundle,
+        bundle_id,
+        macos_universal: config.macos_universal,
+        features: template_features(config),
+        repository,
+        ci: match config.ci {
+            CiProvider::None => String::new(),
+            ref ci => ci.to_string(),
+        },
+        has_github_actions: config.ci == CiProvider::GitHub,
+        ci_packages: ci_packages(config),
+        ci_coverage: ci_coverage(config),
+    }
+}
+
+/// Splits a repository web page, as returned by [`repository_web_url`],
+/// into its hosting service and project path.
+fn template_repository(url: &str) -> Option<TemplateRepository> {
+    let (host, slug) = url.strip_prefix("https://")?.split_once('/')?;
+    let provider = host.strip_suffix(".com")?;
+    Some(TemplateRepository {
+        provider: provider.to_string(),
+        slug: slug.to_string(),
+        url: url.to_string(),
+    })
+}
+
+/// Ubuntu packages the CI needs besides the compiler and CMake: Ninja for
+/// the Ninja generators, and the test framework unless a package manager
+/// or a Meson wrap provides it.
+fn ci_packages(config: &ProjectConfig) -> Vec<String> {
+    let mut packages = Vec::new();
+    if config.build_system == BuildSystem::CMake
+        && matches!(
+            config.cmake_generator,
+            CmakeGenerator::Ninja | CmakeGenerator::NinjaMultiConfig
+        )
+    {
+        packages.push("ninja-build");
+    }
+    let from_wrap = config.build_system == BuildSystem::Meson
+        && config.test_framework != TestFramework::BoostTest;
+    if config.package_manager == PackageManager::None && !from_wrap {
+        packages.extend(match config.test_framework {
+            TestFramework::Doctest => Some("doctest-dev"),
+            TestFramework::GTest => Some("libgtest-dev"),
+            TestFramework::Catch2 => Some("catch2"),
+            TestFramework::BoostTest => Some("libboost-test-dev"),
+            TestFramework::None => None,
+        });
+    }
+    if ci_coverage(config) {
+        packages.push("gcovr");
+    }
+    packages.into_iter().map(String::from).collect()
+}
+
+/// Whether the CI pipeline measures the test coverage and uploads it to
+/// the coverage service. Without tests there is nothing to measure.
+fn ci_coverage(config: &ProjectConfig) -> bool {
+    config.ci == CiProvider::GitHub
+        && config.coverage_service != CoverageService::None
+        && config.test_framework != TestFramework::None
+}
+
+/// The `features` object of `vcpkg.json`, or `null` without features.
+/// Projects with tests get a `testing` feature that installs the test
+/// framework, merged into a user-defined feature of that name.
+fn vcpkg_features(config: &ProjectConfig) -> serde_json::Value {
+    let mut features: BTreeMap<&str, VcpkgFeature> = config
+        .vcpkg_features
+        .iter()
+        .map(|(name, feature)| (name.as_str(), feature.clone()))
+        .collect();
+    if let Some(port) = vcpkg_test_port(&config.test_framework) {
+        let testing = features.entry("testing").or_insert_with(|| VcpkgFeature {
+            description: "Build the tests".to_string(),
+            dependencies: Vec::new(),
+        });
+        if !testing
+            .dependencies
+            .iter()
+            .any(|dependency| dependency == port)
+        {
+            testing.dependencies.push(port.to_string());
+        }
+    }
+    if features.is_empty() {
+        return serde_json::Value::Null;
+    }
+    serde_json::to_value(features).unwrap_or_default()
+}
+
+/// vcpkg port providing the test framework.
+fn vcpkg_test_port(framework: &TestFramework) -> Option<&'static str> {
+    match framework {
+        TestFramework::Doctest => Some("doctest"),
+        TestFramework::GTest => Some("gtest"),
+        TestFramework::Catch2 => Some("catch2"),
+        TestFramework::BoostTest => Some("boost"),
+        TestFramework::None => None,
+    }
+}
+
+/// Conan generators that integrate the dependencies with `build_system`.
+fn conan_generators(build_system: &BuildSystem) -> &'static [&'static str] {
+    match build_system {
+        BuildSystem::CMake => &["CMakeDeps", "CMakeToolchain"],
+        BuildSystem::Make => &["MakeDeps"],
+        BuildSystem::Meson => &["PkgConfigDeps", "MesonToolchain"],
+    }
+}
+
+/// Writes a `pkg:option=value` option as the `pkg/*:option=value` pattern
+/// Conan 2 expects. Options that already use a pattern are kept.
+fn conan_option_pattern(option: &str) -> String {
+    match option.split_once(':') {
+        Some((package, rest)) if !package.contains(['/', '*']) => {
+            format!("{}/*:{}", package, rest)
+        }
+        _ => option.to_string(),
+    }
+}
+
+fn template_features(config: &ProjectConfig) -> TemplateFeatures {
+    TemplateFeatures {
+        cmake: config.build_system == BuildSystem::CMake,
+        make: config.build_system == BuildSystem::Make,
+        meson: config.build_system == BuildSystem::Meson,
+        conan: config.package_manager == PackageManager::Conan,
+        vcpkg: config.package_manager == PackageManager::Vcpkg,
+        clion: config.ide.enable_clion,
+        kdevelop: config.ide.enable_kdevelop,
+        emacs: config.ide.enable_emacs,
+        sublime: config.ide.enable_sublime,
+        clangd: config.build_system != BuildSystem::Make,
+        sphinx: config.doc_engine == DocEngine::Sphinx,
     }
 }
 
@@ -69,21 +702,119 @@ impl ProjectBuilder {
             config,
             template_renderer: TemplateRenderer::new(),
             template_data,
+            extra_outputs: Vec::new(),
+            keep_on_failure: false,
+            keep_build: false,
+            jobs: None,
+            post_gen_hooks: Vec::new(),
+            post_gen_strict: false,
+            runner: Arc::new(SystemRunner),
         }
     }
 
+    /// Replaces the template renderer, e.g. with one that uses user templates.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use cppup::{ProjectBuilder, ProjectConfig, TemplateRenderer};
+    ///
+    /// // let config = ProjectConfig::new(None)?;
+    /// // let builder = ProjectBuilder::new(config)
+    /// //     .with_template_renderer(TemplateRenderer::new());
+    /// ```
+    pub fn with_template_renderer(mut self, renderer: TemplateRenderer) -> Self {
+        self.template_renderer = renderer;
+        self
+    }
+
+    /// Replaces the data computed from the configuration that every template
+    /// is rendered with. Start from [`template_data`](Self::template_data) to
+    /// change only some fields.
+    ///
+    /// Only the rendered contents change; which files are written, and where,
+    /// still follows the configuration.
+    #[allow(dead_code)]
+    pub fn with_template_data(mut self, data: ProjectTemplateData) -> Self {
+        self.template_data = data;
+        self
+    }
+
+    /// The data every template is rendered with.
+    #[allow(dead_code)]
+    pub fn template_data(&self) -> &ProjectTemplateData {
+        &self.template_data
+    }
+
+    /// Leaves a partially created project in place when generation fails,
+    /// instead of removing it.
+    pub fn with_keep_on_failure(mut self, keep: bool) -> Self {
+        self.keep_on_failure = keep;
+        self
+    }
+
+    /// Leaves the `build` directory created by [`verify`](Self::verify) in
+    /// place instead of removing it.
+    pub fn with_keep_build(mut self, keep: bool) -> Self {
+        self.keep_build = keep;
+        self
+    }
+
+    /// Sets the number of parallel jobs used by
+    /// [`run_first_build`](Self::run_first_build). By default the build tool
+    /// decides.
+    pub fn with_jobs(mut self, jobs: Option<usize>) -> Self {
+        self.jobs = jobs;
+        self
+    }
+
+    /// Sets shell commands that [`build`](Self::build) runs in the project
+    /// directory after git init, in order. They see the project in the
+    /// `CPPUP_PROJECT_NAME`, `CPPUP_PROJECT_PATH` and `CPPUP_BUILD_SYSTEM`
+    /// environment variables.
+    pub fn with_post_gen_hooks(mut self, hooks: Vec<String>) -> Self {
+        self.post_gen_hooks = hooks;
+        self
+    }
+
+    /// Makes a failing post-generation hook fail the build. By default it
+    /// only adds a warning to the report.
+    pub fn with_post_gen_strict(mut self, strict: bool) -> Self {
+        self.post_gen_strict = strict;
+        self
+    }
+
+    /// Replaces how external commands such as git, conan and cmake are run,
+    /// e.g. with a fake in tests.
+    #[allow(dead_code)]
+    pub fn with_command_runner(mut self, runner: Arc<dyn CommandRunner>) -> Self {
+        self.runner = runner;
+        self
+    }
+
+    /// Adds files rendered after the built-in ones, given as
+    /// `(template name, path relative to the project)`.
+    pub fn with_extra_outputs(mut self, outputs: Vec<(String, PathBuf)>) -> Self {
+        self.extra_outputs = outputs;
+        self
+    }
+
     /// Builds the complete project structure.
     ///
     /// This method orchestrates the entire project generation process:
-    /// 1. Creates directory structure
-    /// 2. Renders all template files (build files, source code, configs)
-    /// 3. Sets up package manager configuration files
-    /// 4. Initializes git repository (if enabled)
-    /// 5. Prints success message with next steps
+    /// 1. Renders all files (build files, source code, configs, package
+    ///    manager manifests) in memory
+    /// 2. Creates directory structure and writes the files
+    /// 3. Initializes git repository (if enabled)
+    /// 4. Runs the post-generation hooks
     ///
-    /// # Returns
+    /// If any template fails to render, nothing is written to disk. If a
+    /// later step fails, the files written by this run are removed again,
+    /// unless [`with_keep_on_failure`](Self::with_keep_on_failure) is set.
+    /// A failing hook leaves the project in place.
     ///
-    /// Returns `Ok(())` on success, or an error if any step fails.
+    /// Nothing is printed; the returned [`BuildReport`] lists the files
+    /// written, the commands run and the next steps.
     ///
     /// # Errors
     ///
@@ -91,6 +822,8 @@ impl ProjectBuilder {
     /// - Directory creation fails
     /// - Template rendering fails
     /// - Git initialization fails
+    /// - A post-generation hook fails and
+    ///   [`with_post_gen_strict`](Self::with_post_gen_strict) is set
     ///
     /// # Examples
     ///
@@ -101,217 +834,900 @@ impl ProjectBuilder {
     /// // let builder = ProjectBuilder::new(config);
     /// // builder.build()?;
     /// ```
-    pub fn build(&self) -> Result<()> {
-        self.create_directory_structure()?;
-        self.render_templates()?;
-        self.setup_package_manager()?;
-        self.initialize_git()?;
-        self.print_success_message();
-        Ok(())
-    }
+    pub fn build(&self) -> Result<BuildReport> {
+        let started = Instant::now();
 
-    fn create_directory_structure(&self) -> Result<()> {
-        // Create main project directory
-        fs::create_dir_all(&self.config.path).with_context(|| {
-            format!(
-                "Failed to create project directory at {:?}",
-                self.config.path
-            )
-        })?;
+        // Render everything before touching the disk, so a failing template
+        // leaves nothing behind
+        let plan = self.plan_files()?;
 
-        // Create standard directories
-        let mut dirs = vec![
-            "src",
-            "cmake",
-            "include",
-            match self.config.project_type {
-                ProjectType::Library => "examples",
-                ProjectType::Executable => "assets",
-            },
-        ];
+        let created_root = missing_directories(&self.config.path)
+            .last()
+            .map(|dir| dir.to_path_buf());
+        let mut report = BuildReport {
+            project_path: self.config.path.clone(),
+            files_created: Vec::new(),
+            commands_run: Vec::new(),
+            warnings: Vec::new(),
+            commit_hash: None,
+            next_steps: self.next_steps(),
+            duration: Duration::ZERO,
+        };
+        if let Err(error) = self.write_project(&plan, &mut report) {
+            self.clean_up(created_root.as_deref(), &report.files_created);
+            return Err(error);
+        }
+        report.files_created.sort();
+        self.run_post_gen_hooks(&mut report)?;
 
-        if self.config.test_framework != TestFramework::None {
-            dirs.push("tests");
+        report.duration = started.elapsed();
+        Ok(report)
+    }
+
+    /// Renders every file [`build`](Self::build) would write, without
+    /// touching the disk. Paths are relative to the project root.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a template fails to render.
+    #[allow(dead_code)]
+    pub fn render_files(&self) -> Result<Vec<(PathBuf, String)>> {
+        Ok(self
+            .plan_files()?
+            .into_iter()
+            .map(|file| (file.path, file.contents))
+            .collect())
+    }
+
+    /// Creates the GitHub repository requested in the configuration with
+    /// `gh` and pushes the initial commit. Returns the repository URL, or
+    /// `None` if no repository was requested.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the project is inside another repository or
+    /// `gh` fails.
+    pub fn create_github_repo(&self) -> Result<Option<String>> {
+        let Some(visibility) = &self.config.github_repo else {
+            return Ok(None);
+        };
+        if let Some(root) = self.enclosing_repository() {
+            return Err(anyhow::anyhow!(
+                "{} is inside the git repository at {}, which has no commit of its own to push",
+                self.config.path.display(),
+                root.display()
+            ));
+        }
+        let url = github::create_repository(self.runner.as_ref(), &self.config, visibility)?;
+        if let Some(remote) = &self.config.git_remote {
+            // gh picks the URL protocol itself; keep the URL that was given
+            self.runner.run_checked(
+                &ToolCommand::new("git")
+                    .args(["remote", "set-url", "origin", remote])
+                    .current_dir(&self.config.path),
+            )?;
         }
+        Ok(Some(url))
+    }
 
-        for dir in dirs {
-            fs::create_dir_all(self.config.path.join(dir))
-                .with_context(|| format!("Failed to create {} directory", dir))?;
+    /// Configures the generated project as a smoke test: `cmake -S . -B
+    /// build` (after `conan install` for Conan, with the vcpkg toolchain for
+    /// vcpkg), `make -n` or `meson setup build`.
+    ///
+    /// For Conan, a default profile is detected first if there is none. The
+    /// `build` directory is removed afterwards unless
+    /// [`with_keep_build`](Self::with_keep_build) is set. Verification is
+    /// skipped if a needed tool is not installed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error with the tool's stderr if a command fails or does
+    /// not finish within five minutes.
+    pub fn verify(&self) -> Result<VerifyOutcome> {
+        let commands = self.verify_commands().and_then(|commands| {
+            match commands
+                .iter()
+                .find(|(program, _)| !self.runner.is_installed(program))
+            {
+                Some((program, _)) => Err(format!("{} is not installed", program)),
+                None => Ok(commands),
+            }
+        });
+        let commands = match commands {
+            Ok(commands) => commands,
+            Err(reason) => {
+                return Ok(VerifyOutcome::Skipped(format!(
+                    "Warning: skipping verification: {}",
+                    reason
+                )))
+            }
+        };
+
+        let mut run = Vec::new();
+        let result = self
+            .ensure_conan_profile(&commands, &mut run)
+            .map_err(|error| anyhow::anyhow!("Verification failed: {:#}", error))
+            .and_then(|()| {
+                for (program, args) in &commands {
+                    run.push(self.run_verify_command(program, args)?);
+                }
+                Ok(run)
+            });
+
+        let build_dir = self.config.path.join("build");
+        if !self.keep_build && build_dir.exists() {
+            if let Err(error) = fs::remove_dir_all(&build_dir) {
+                eprintln!(
+                    "Warning: could not remove {}: {}",
+                    build_dir.display(),
+                    error
+                );
+            }
         }
 
-        Ok(())
+        result.map(VerifyOutcome::Passed)
     }
 
-    fn render_templates(&self) -> Result<()> {
+    /// Commands that configure the project, or why they cannot run here.
+    fn verify_commands(&self) -> std::result::Result<Vec<(&'static str, Vec<String>)>, String> {
+        let mut commands = Vec::new();
         match self.config.build_system {
-            BuildSystem::CMake => self.generate_cmake_files()?,
-            BuildSystem::Make => self.generate_makefile()?,
-        }
-        self.generate_source_files()?;
-        self.generate_test_files()?;
-        self.generate_readme()?;
-        self.generate_quality_files()?;
-        self.generate_code_formatter_files()?;
-        self.generate_license()?;
+            BuildSystem::CMake => {
+                let mut cmake_args = vec!["-S", ".", "-B", "build"]
+                    .into_iter()
+                    .map(String::from)
+                    .collect::<Vec<_>>();
+                if let Some(generator) = self.config.cmake_generator.cmake_name() {
+                    cmake_args.extend(["-G".to_string(), generator.to_string()]);
+                }
+                match self.config.package_manager {
+                    PackageManager::Conan => {
+                        commands.push((
+                            "conan",
+                            vec![
+                                "install".to_string(),
+                                ".".to_string(),
+                                "--build=missing".to_string(),
+                            ],
+                        ));
+                        cmake_args.push(format!("-DCMAKE_TOOLCHAIN_FILE={}", CONAN_TOOLCHAIN));
+                    }
+                    PackageManager::Vcpkg => {
+                        let root = std::env::var("VCPKG_ROOT")
+                            .map_err(|_| "VCPKG_ROOT is not set".to_string())?;
+                        cmake_args.push(format!(
+                            "-DCMAKE_TOOLCHAIN_FILE={}/scripts/buildsystems/vcpkg.cmake",
+                            root
+                        ));
+                    }
+                    PackageManager::None => {}
+                }
+                commands.push(("cmake", cmake_args));
+            }
+            BuildSystem::Make => commands.push(("make", vec!["-n".to_string()])),
+            BuildSystem::Meson => {
+                commands.push(("meson", vec!["setup".to_string(), "build".to_string()]))
+            }
+        }
+
+        Ok(commands)
+    }
+
+    /// Runs the first full build of the generated project: `conan install`
+    /// when applicable, CMake configure and build followed by `ctest` for
+    /// projects with tests, `make`, or Meson setup and compile, after
+    /// detecting a Conan profile if there is none. Output is streamed to the
+    /// terminal and each command is recorded in `report`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error naming the step that failed. The project is left in
+    /// place.
+    pub fn run_first_build(&self, report: &mut BuildReport) -> Result<()> {
+        let commands = self.build_commands()?;
+        self.ensure_conan_profile(&commands, &mut report.commands_run)
+            .with_context(|| {
+                format!(
+                    "Cannot build the project; it was left at {}",
+                    self.config.path.display()
+                )
+            })?;
+        for (program, args) in commands {
+            let command = ToolCommand::new(program)
+                .args(args)
+                .current_dir(&self.config.path)
+                .user_locale()
+                .inherit_output();
+            let output = self.run_recorded(&command, &mut report.commands_run)?;
+            if !output.success() {
+                return Err(anyhow::anyhow!(
+                    "Build step `{}` failed with {}; the project was left at {}",
+                    command,
+                    output.status(),
+                    self.config.path.display()
+                ));
+            }
+        }
         Ok(())
     }
 
-    fn initialize_git(&self) -> Result<()> {
-        if self.config.use_git {
-            Command::new("git")
-                .arg("init")
+    /// Makes sure Conan has the default profile `conan install` needs when
+    /// `commands` run it: if `conan profile path default` fails, one is
+    /// created with `conan profile detect`. The commands run are added to
+    /// `commands_run`.
+    fn ensure_conan_profile(
+        &self,
+        commands: &[(&str, Vec<String>)],
+        commands_run: &mut Vec<CommandSummary>,
+    ) -> Result<()> {
+        if !commands.iter().any(|(program, _)| *program == "conan") {
+            return Ok(());
+        }
+        let conan = |args: &[&str]| {
+            ToolCommand::new("conan")
+                .args(args.iter().copied())
                 .current_dir(&self.config.path)
-                .output()
-                .context("Failed to initialize git repository")?;
+        };
 
-            self.template_renderer.render(
-                "gitignore",
-                &self.template_data,
-                &self.config.path.join(".gitignore"),
-            )?;
+        if self
+            .run_recorded(&conan(&["profile", "path", "default"]), commands_run)?
+            .success()
+        {
+            return Ok(());
         }
+        let detect = conan(&["profile", "detect"]);
+        self.run_recorded(&detect, commands_run)?
+            .check(&detect)
+            .context("Conan has no default profile and detecting one failed")?;
         Ok(())
     }
 
-    fn setup_package_manager(&self) -> Result<()> {
-        match self.config.package_manager {
-            PackageManager::Conan => {
-                self.template_renderer.render(
-                    "conanfile.txt",
-                    &self.template_data,
-                    &self.config.path.join("conanfile.txt"),
-                )?;
+    /// Commands of the first full build, in order.
+    fn build_commands(&self) -> Result<Vec<(&'static str, Vec<String>)>> {
+        let jobs = self
+            .jobs
+            .map(|jobs| vec!["-j".to_string(), jobs.to_string()])
+            .unwrap_or_default();
+        let mut commands = match self.config.build_system {
+            BuildSystem::Make => Vec::new(),
+            BuildSystem::CMake | BuildSystem::Meson => self
+                .verify_commands()
+                .map_err(|reason| anyhow::anyhow!("Cannot build the project: {}", reason))?,
+        };
+
+        match self.config.build_system {
+            BuildSystem::CMake => {
+                let mut args = ["--build", "build", "--config", "Debug"]
+                    .map(String::from)
+                    .to_vec();
+                args.extend(jobs);
+                commands.push(("cmake", args));
+                if self.config.test_framework != TestFramework::None {
+                    commands.push((
+                        "ctest",
+                        ["--test-dir", "build", "-C", "Debug", "--output-on-failure"]
+                            .map(String::from)
+                            .to_vec(),
+                    ));
+                }
             }
-            PackageManager::Vcpkg => {
-                self.template_renderer.render(
-                    "vcpkg.json",
-                    &self.template_data,
-                    &self.config.path.join("vcpkg.json"),
-                )?;
+            BuildSystem::Make => commands.push(("make", jobs)),
+            BuildSystem::Meson => {
+                let mut args = ["compile", "-C", "build"].map(String::from).to_vec();
+                args.extend(jobs);
+                commands.push(("meson", args));
+                if self.config.test_framework != TestFramework::None {
+                    commands.push(("meson", ["test", "-C", "build"].map(String::from).to_vec()));
+                }
             }
-            PackageManager::None => {}
         }
-        Ok(())
+        Ok(commands)
     }
 
-    fn generate_cmake_files(&self) -> Result<()> {
-        self.template_renderer.render(
-            "CMakeLists.txt",
-            &self.template_data,
-            &self.config.path.join("CMakeLists.txt"),
-        )?;
+    /// Runs one verification command in the project directory, stopping it
+    /// after [`VERIFY_TIMEOUT`].
+    fn run_verify_command(&self, program: &str, args: &[String]) -> Result<CommandSummary> {
+        let command = ToolCommand::new(program)
+            .args(args.iter().cloned())
+            .current_dir(&self.config.path)
+            .timeout(VERIFY_TIMEOUT);
+        let output = self.runner.run(&command)?;
+        let summary = CommandSummary::new(&command, Some(&output));
 
-        self.template_renderer.render(
-            "options.cmake",
-            &self.template_data,
-            &self.config.path.join("cmake/options.cmake"),
-        )?;
+        if output.success() {
+            Ok(summary)
+        } else if output.timed_out {
+            Err(anyhow::anyhow!(
+                "Verification failed: `{}` did not finish within {} seconds",
+                summary,
+                VERIFY_TIMEOUT.as_secs()
+            ))
+        } else {
+            Err(anyhow::anyhow!(
+                "Verification failed: `{}` exited with an error:\n{}",
+                summary,
+                output.stderr.trim()
+            ))
+        }
+    }
 
-        self.template_renderer.render(
-            "compilation-flags.cmake",
-            &self.template_data,
-            &self.config.path.join("cmake/compilation-flags.cmake"),
-        )?;
+    /// Runs each post-generation hook with the platform's shell in the
+    /// project directory and records it in `report`. A failure becomes a
+    /// warning with the hook's output, or an error when strict.
+    fn run_post_gen_hooks(&self, report: &mut BuildReport) -> Result<()> {
+        let (shell, flag) = if cfg!(windows) {
+            ("cmd", "/C")
+        } else {
+            ("sh", "-c")
+        };
+        for hook in &self.post_gen_hooks {
+            let command = ToolCommand::new(shell)
+                .args([flag, hook.as_str()])
+                .current_dir(&self.config.path)
+                .env("CPPUP_PROJECT_NAME", &self.config.name)
+                .env(
+                    "CPPUP_PROJECT_PATH",
+                    self.config.path.to_string_lossy().into_owned(),
+                )
+                .env("CPPUP_BUILD_SYSTEM", self.config.build_system.to_string())
+                .user_locale();
+            let failure = match self.run_recorded(&command, &mut report.commands_run) {
+                Ok(output) if output.success() => continue,
+                Ok(output) => {
+                    let mut message = format!(
+                        "post-generation hook `{}` failed with {}",
+                        hook,
+                        output.status()
+                    );
+                    let text = format!("{}{}", output.stdout, output.stderr);
+                    if !text.trim().is_empty() {
+                        message.push_str(&format!(":\n{}", text.trim()));
+                    }
+                    message
+                }
+                Err(error) => format!("could not run post-generation hook `{}`: {}", hook, error),
+            };
+            if self.post_gen_strict {
+                return Err(anyhow::anyhow!(
+                    "The {}\nThe project was left at {}",
+                    failure,
+                    self.config.path.display()
+                ));
+            }
+            report.warnings.push(format!("Warning: {}", failure));
+        }
+        Ok(())
+    }
 
-        self.template_renderer.render(
-            "source.cmake",
-            &self.template_data,
-            &self.config.path.join("src/CMakeLists.txt"),
-        )?;
+    fn write_project(&self, plan: &FilePlan, report: &mut BuildReport) -> Result<()> {
+        self.create_directory_structure()?;
+        self.write_files(plan, &mut report.files_created)?;
+        self.initialize_git(report)
+    }
 
-        if self.config.project_type == ProjectType::Library {
-            self.template_renderer.render(
-                "example.cmake",
-                &self.template_data,
-                &self.config.path.join("examples/CMakeLists.txt"),
-            )?;
+    /// Removes what a failed [`build`](Self::build) wrote: the topmost
+    /// directory it created, or else only the files.
+    fn clean_up(&self, created_root: Option<&Path>, written: &[PathBuf]) {
+        let root = &self.config.path;
+        if self.keep_on_failure {
+            eprintln!(
+                "Keeping the partially created project at {}",
+                root.display()
+            );
+            for path in written {
+                eprintln!("  {}", path.display());
+            }
+            return;
+        }
+
+        let result = if let Some(created_root) = created_root {
+            fs::remove_dir_all(created_root)
+        } else {
+            written
+                .iter()
+                .try_for_each(|path| fs::remove_file(root.join(path)))
+        };
+        if let Err(error) = result {
+            eprintln!("Warning: could not clean up {}: {}", root.display(), error);
+        }
+    }
+
+    /// Renders every generated file without writing anything.
+    fn plan_files(&self) -> Result<FilePlan> {
+        let mut plan = FilePlan::new();
+        self.render_templates(&mut plan)?;
+        self.plan_metadata(&mut plan)?;
+        self.setup_package_manager(&mut plan)?;
+        if self.config.use_git {
+            self.plan_file(&mut plan, "gitignore", ".gitignore")?;
+            self.plan_file(&mut plan, "gitattributes", ".gitattributes")?;
         }
+        self.plan_codeowners(&mut plan)?;
+        Ok(plan)
+    }
 
+    /// Assigns the code owners to the whole project, and again to each of
+    /// `cmake/`, `tests/` and `.github/` that is generated. GitLab projects
+    /// keep the file in `docs/`, all others in `.github/`.
+    fn plan_codeowners(&self, plan: &mut FilePlan) -> Result<()> {
+        if self.config.codeowners.is_empty() {
+            return Ok(());
+        }
+        let gitlab = self
+            .template_data
+            .repository
+            .as_ref()
+            .is_some_and(|repository| repository.provider == "gitlab");
+        let path = PathBuf::from(if gitlab { "docs" } else { ".github" }).join("CODEOWNERS");
+        let owned_dirs = ["cmake", "tests", ".github"]
+            .into_iter()
+            .filter(|dir| {
+                path.starts_with(dir) || plan.iter().any(|file| file.path.starts_with(dir))
+            })
+            .collect();
+        let data = Codeowners {
+            project: &self.template_data,
+            owners: self.config.codeowners.join(" "),
+            owned_dirs,
+        };
+        let contents = self.template_renderer.render_with_extra(
+            "CODEOWNERS",
+            &data,
+            &self.config.extra_vars,
+        )?;
+        plan.push(PlannedFile::new(path, contents));
         Ok(())
     }
 
-    fn generate_makefile(&self) -> Result<()> {
-        self.template_renderer.render(
-            "Makefile",
+    /// Renders `template` with the project data into `plan` at `path`,
+    /// relative to the project root.
+    fn plan_file(
+        &self,
+        plan: &mut FilePlan,
+        template: &str,
+        path: impl Into<PathBuf>,
+    ) -> Result<()> {
+        let contents = self.template_renderer.render_with_extra(
+            template,
             &self.template_data,
-            &self.config.path.join("Makefile"),
+            &self.config.extra_vars,
         )?;
-
+        plan.push(PlannedFile {
+            path: path.into(),
+            contents,
+            executable: self.template_renderer.is_executable(template),
+        });
         Ok(())
     }
 
-    fn generate_source_files(&self) -> Result<()> {
-        match self.config.project_type {
-            ProjectType::Executable => {
-                self.template_renderer.render(
-                    "main.cpp",
-                    &self.template_data,
-                    &self.config.path.join("src/main.cpp"),
-                )?;
-            }
-            ProjectType::Library => {
-                self.template_renderer.render(
-                    "header.hpp",
-                    &self.template_data,
-                    &self
-                        .config
-                        .path
-                        .join(format!("include/{}.hpp", self.config.name)),
-                )?;
-                self.template_renderer.render(
-                    "library.cpp",
-                    &self.template_data,
-                    &self.config.path.join("src/lib.cpp"),
-                )?;
-                self.template_renderer.render(
-                    "example.cpp",
-                    &self.template_data,
-                    &self.config.path.join("examples/example.cpp"),
-                )?;
+    /// Writes the planned files with the configured line endings, recording
+    /// each one in `written`.
+    fn write_files(&self, plan: &FilePlan, written: &mut Vec<PathBuf>) -> Result<()> {
+        for file in plan {
+            let contents = normalize_line_endings(
+                &file.contents,
+                self.config
+                    .line_endings
+                    .uses_crlf(&file.path, &file.contents),
+            );
+            let output_path = self.write_project_file(&file.path, &contents)?;
+            written.push(file.path.clone());
+            if file.executable {
+                make_executable(&output_path)?;
             }
         }
-
         Ok(())
     }
 
-    fn generate_test_files(&self) -> Result<()> {
+    /// Writes `contents` to `rel_path` below the project root, creating
+    /// missing directories, and returns the path written. Every generated
+    /// file goes through here.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error naming `rel_path` if it is absolute, has `..`
+    /// components or resolves outside the project through a symlink, or if
+    /// creating a directory or writing fails.
+    fn write_project_file(&self, rel_path: &Path, contents: &str) -> Result<PathBuf> {
+        let path = self.resolve_project_path(rel_path, false)?;
+        fs::write(&path, contents)
+            .with_context(|| format!("Failed to write file {}", path.display()))?;
+        Ok(path)
+    }
+
+    /// Creates the directory `rel_path` below the project root, with its
+    /// missing parents, and returns its path. Every generated directory
+    /// goes through here.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error naming `rel_path` for the paths
+    /// [`write_project_file`](Self::write_project_file) rejects, if it
+    /// exists but is not a directory, or if creating a directory fails.
+    fn create_project_dir(&self, rel_path: &Path) -> Result<PathBuf> {
+        let path = self.resolve_project_path(rel_path, true)?;
+        if !path.is_dir() {
+            return Err(anyhow::anyhow!(
+                "Output directory {} exists but is not a directory",
+                rel_path.display()
+            ));
+        }
+        Ok(path)
+    }
+
+    /// Resolves `rel_path` below the project root one directory at a time,
+    /// so a symlink leading out of the project is found before anything is
+    /// created through it. Missing directories on the way are created, the
+    /// last component only if `create_last` is set.
+    fn resolve_project_path(&self, rel_path: &Path, create_last: bool) -> Result<PathBuf> {
+        check_relative_path(rel_path)?;
+        let root = self
+            .config
+            .path
+            .canonicalize()
+            .with_context(|| format!("Failed to resolve {}", self.config.path.display()))?;
+
+        let mut path = root.clone();
+        let mut components = rel_path
+            .components()
+            .filter(|component| matches!(component, Component::Normal(_)))
+            .peekable();
+        while let Some(component) = components.next() {
+            path.push(component);
+            if path.symlink_metadata().is_ok() {
+                path = path.canonicalize().with_context(|| {
+                    format!("Output path {} cannot be resolved", rel_path.display())
+                })?;
+                if !path.starts_with(&root) {
+                    return Err(anyhow::anyhow!(
+                        "Output path {} leads outside the project through a symlink",
+                        rel_path.display()
+                    ));
+                }
+            } else if create_last || components.peek().is_some() {
+                fs::create_dir(&path)
+                    .with_context(|| format!("Failed to create {}", path.display()))?;
+            }
+        }
+        Ok(path)
+    }
+
+    fn create_directory_structure(&self) -> Result<()> {
+        // Create the project directory one level at a time, including
+        // parents missing with --create-path, to name the one that fails
+        for dir in missing_directories(&self.config.path).iter().rev() {
+            fs::create_dir(dir)
+                .with_context(|| format!("Failed to create directory {}", dir.display()))?;
+        }
+
+        // Create standard directories
+        let mut dirs = vec![
+            "src",
+            "cmake",
+            "include",
+            match self.config.project_type {
+                ProjectType::Library => "examples",
+                ProjectType::Executable => "assets",
+            },
+        ];
+
         if self.config.test_framework != TestFramework::None {
-            if self.config.build_system == BuildSystem::CMake {
-                self.template_renderer.render(
-                    "tests.cmake",
-                    &self.template_data,
-                    &self.config.path.join("tests/CMakeLists.txt"),
+            dirs.push("tests");
+        }
+
+        if self.config.generate_docs_dir {
+            dirs.push("docs");
+        }
+
+        for dir in dirs {
+            self.create_project_dir(Path::new(dir))
+                .with_context(|| format!("Failed to create {} directory", dir))?;
+        }
+
+        Ok(())
+    }
+
+    fn render_templates(&self, plan: &mut FilePlan) -> Result<()> {
+        match self.config.build_system {
+            BuildSystem::CMake => self.generate_cmake_files(plan)?,
+            BuildSystem::Make => self.generate_makefile(plan)?,
+            BuildSystem::Meson => self.generate_meson_files(plan)?,
+        }
+        self.generate_source_files(plan)?;
+        self.generate_snippet_files(plan)?;
+        self.generate_api_stubs(plan)?;
+        self.generate_test_files(plan)?;
+        self.generate_readme(plan)?;
+        self.generate_docs_files(plan)?;
+        self.generate_changelog(plan)?;
+        self.generate_community_files(plan)?;
+        self.generate_ci(plan)?;
+        self.generate_dependabot(plan)?;
+        self.generate_coverage_config(plan)?;
+        self.generate_quality_files(plan)?;
+        self.generate_code_formatter_files(plan)?;
+        self.generate_license(plan)?;
+        self.generate_ide_files(plan)?;
+        self.generate_extra_outputs(plan)?;
+        Ok(())
+    }
+
+    fn plan_metadata(&self, plan: &mut FilePlan) -> Result<()> {
+        let metadata = serde_json::json!({
+            "schema_version": PROJECT_SCHEMA_VERSION,
+            "cppup_version": env!("CARGO_PKG_VERSION"),
+            "template_version": self.template_renderer.version(),
+        });
+        let content =
+            serde_json::to_string_pretty(&metadata).context("Failed to serialize metadata")?;
+        plan.push(PlannedFile::new(
+            PathBuf::from(METADATA_FILE),
+            content + "\n",
+        ));
+        Ok(())
+    }
+
+    fn generate_extra_outputs(&self, plan: &mut FilePlan) -> Result<()> {
+        for (template, path) in &self.extra_outputs {
+            // Fail before anything is written, and in dry runs
+            check_relative_path(path)
+                .with_context(|| format!("Invalid output of template '{}'", template))?;
+            self.plan_file(plan, template, path)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the repository the project would be nested in, unless
+    /// nesting was forced. The project's own repository does not count.
+    fn enclosing_repository(&self) -> Option<PathBuf> {
+        if self.config.force_git {
+            return None;
+        }
+        enclosing_git_repository(self.config.path.parent()?)
+    }
+
+    fn initialize_git(&self, report: &mut BuildReport) -> Result<()> {
+        if let (true, Some(root)) = (self.config.use_git, self.enclosing_repository()) {
+            report.warnings.push(format!(
+                "Warning: skipping git init: {} is inside the git repository at {}; \
+                 pass --force-git to create a nested repository",
+                self.config.path.display(),
+                root.display()
+            ));
+            return Ok(());
+        }
+        if self.config.use_git {
+            let branch = &self.config.git_branch;
+            let git = |args: &[&str]| {
+                ToolCommand::new("git")
+                    .args(args.iter().copied())
+                    .current_dir(&self.config.path)
+            };
+            let init = git(&["init", "-b", branch]);
+            let output = self
+                .run_recorded(&init, &mut report.commands_run)
+                .context("Failed to initialize git repository")?;
+            if !output.success() {
+                // Git before 2.28 has no `-b`; point HEAD at the branch instead
+                let head = format!("refs/heads/{}", branch);
+                for command in [git(&["init"]), git(&["symbolic-ref", "HEAD", &head])] {
+                    self.run_recorded(&command, &mut report.commands_run)
+                        .and_then(|output| output.check(&command))
+                        .context("Failed to initialize git repository")?;
+                }
+            }
+            if self.config.git_commit {
+                self.create_initial_commit(report);
+            }
+            // `gh repo create` adds origin itself
+            if let (Some(remote), None) = (&self.config.git_remote, &self.config.github_repo) {
+                self.run_git(
+                    &["remote", "add", "origin", remote.as_str()].map(String::from),
+                    report,
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Commits every generated file. Failures are reported as warnings
+    /// since the project itself is complete at this point.
+    fn create_initial_commit(&self, report: &mut BuildReport) {
+        let message = format!("Initial commit from cppup v{}", env!("CARGO_PKG_VERSION"));
+        // A fresh machine may have no identity configured, which makes
+        // `git commit` fail; fill in one for this commit only
+        let mut commit_args = Vec::new();
+        if !self.git_config_is_set("user.name") {
+            let name = if self.config.author.trim().is_empty() {
+                "cppup"
+            } else {
+                self.config.author.trim()
+            };
+            commit_args.extend(["-c".to_string(), format!("user.name={}", name)]);
+        }
+        if !self.git_config_is_set("user.email") {
+            commit_args.extend(["-c".to_string(), "user.email=cppup@localhost".to_string()]);
+        }
+        commit_args.extend(["commit", "--quiet", "-m", message.as_str()].map(String::from));
+
+        let committed = self
+            .run_git(&["add", "--all"].map(String::from), report)
+            .is_some()
+            && self.run_git(&commit_args, report).is_some();
+        if committed {
+            report.commit_hash = self
+                .run_git(&["rev-parse", "HEAD"].map(String::from), report)
+                .map(|stdout| stdout.trim().to_string());
+        }
+    }
+
+    fn git_config_is_set(&self, key: &str) -> bool {
+        self.runner
+            .stdout(
+                &ToolCommand::new("git")
+                    .args(["config", key])
+                    .current_dir(&self.config.path),
+            )
+            .is_some_and(|stdout| !stdout.trim().is_empty())
+    }
+
+    /// Runs `command` and records it in `commands_run`.
+    fn run_recorded(
+        &self,
+        command: &ToolCommand,
+        commands_run: &mut Vec<CommandSummary>,
+    ) -> Result<CommandOutput> {
+        let output = self.runner.run(command);
+        commands_run.push(CommandSummary::new(command, output.as_ref().ok()));
+        output
+    }
+
+    /// Runs git in the project and records the command. Returns its stdout,
+    /// or `None` after adding a warning if it failed.
+    fn run_git(&self, args: &[String], report: &mut BuildReport) -> Option<String> {
+        let command = ToolCommand::new("git")
+            .args(args.iter().cloned())
+            .current_dir(&self.config.path);
+        match self
+            .run_recorded(&command, &mut report.commands_run)
+            .and_then(|output| output.check(&command))
+        {
+            Ok(output) => Some(output.stdout),
+            Err(error) => {
+                report.warnings.push(format!("Warning: {:#}", error));
+                None
+            }
+        }
+    }
+
+    fn setup_package_manager(&self, plan: &mut FilePlan) -> Result<()> {
+        match self.config.package_manager {
+            PackageManager::Conan => self.plan_file(plan, "conanfile.txt", "conanfile.txt")?,
+            PackageManager::Vcpkg => self.plan_file(plan, "vcpkg.json", "vcpkg.json")?,
+            PackageManager::None => {}
+        }
+        if self.template_data.vcpkg_has_config {
+            self.plan_file(plan, "vcpkg-configuration.json", "vcpkg-configuration.json")?;
+        }
+        if self.config.build_system == BuildSystem::Meson {
+            self.generate_wrap_files(plan)?;
+        }
+        Ok(())
+    }
+
+    fn generate_wrap_files(&self, plan: &mut FilePlan) -> Result<()> {
+        let meson_config = MesonConfig::new(&self.config.test_framework);
+        for wrap_file in &meson_config.wrap_files {
+            let contents = self.template_renderer.render_with_extra(
+                "wrap",
+                wrap_file,
+                &self.config.extra_vars,
+            )?;
+            plan.push(PlannedFile::new(
+                PathBuf::from("subprojects").join(format!("{}.wrap", wrap_file.name)),
+                contents,
+            ));
+        }
+        Ok(())
+    }
+
+    fn generate_cmake_files(&self, plan: &mut FilePlan) -> Result<()> {
+        self.plan_file(plan, "CMakeLists.txt", "CMakeLists.txt")?;
+        self.plan_file(plan, "options.cmake", "cmake/options.cmake")?;
+        self.plan_file(
+            plan,
+            "compilation-flags.cmake",
+            "cmake/compilation-flags.cmake",
+        )?;
+        self.plan_file(plan, "source.cmake", "src/CMakeLists.txt")?;
+
+        if self.config.sanitizer_preset != SanitizerPreset::None {
+            self.plan_file(plan, "sanitizers.cmake", "cmake/sanitizers.cmake")?;
+        }
+
+        if self.config.cmake_generator.cmake_name().is_some()
+            || self.config.target_platform != TargetPlatform::Host
+            || self.config.macos_universal
+        {
+            self.plan_file(plan, "CMakePresets.json", "CMakePresets.json")?;
+        }
+        if self.config.macos_bundle {
+            self.plan_file(plan, "Info.plist.in", "cmake/Info.plist.in")?;
+        }
+        if self.config.target_platform == TargetPlatform::WindowsMingw {
+            self.plan_file(
+                plan,
+                "mingw-toolchain",
+                "cmake/toolchains/x86_64-w64-mingw32.cmake",
+            )?;
+        }
+
+        if self.config.project_type == ProjectType::Library {
+            self.plan_file(plan, "example.cmake", "examples/CMakeLists.txt")?;
+        }
+
+        Ok(())
+    }
+
+    fn generate_makefile(&self, plan: &mut FilePlan) -> Result<()> {
+        self.plan_file(plan, "Makefile", "Makefile")
+    }
+
+    fn generate_meson_files(&self, plan: &mut FilePlan) -> Result<()> {
+        self.plan_file(plan, "meson.build", "meson.build")
+    }
+
+    fn generate_source_files(&self, plan: &mut FilePlan) -> Result<()> {
+        let extension = &self.template_data.cpp_extension;
+        match self.config.project_type {
+            ProjectType::Executable => {
+                self.plan_file(plan, "main.cpp", format!("src/main.{}", extension))?;
+            }
+            ProjectType::Library => {
+                self.plan_file(
+                    plan,
+                    "header.hpp",
+                    Path::new("include").join(&self.template_data.header_path),
                 )?;
+                self.plan_file(plan, "library.cpp", format!("src/lib.{}", extension))?;
+                for name in &self.template_data.example_names {
+                    self.plan_file(
+                        plan,
+                        "example.cpp",
+                        format!("examples/{}.{}", name, extension),
+                    )?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn generate_test_files(&self, plan: &mut FilePlan) -> Result<()> {
+        if self.config.test_framework != TestFramework::None {
+            match self.config.build_system {
+                BuildSystem::CMake => {
+                    self.plan_file(plan, "tests.cmake", "tests/CMakeLists.txt")?;
+                }
+                BuildSystem::Meson => {
+                    self.plan_file(plan, "tests.meson", "tests/meson.build")?;
+                }
+                BuildSystem::Make => {}
             }
 
+            let test_main = format!("tests/main_test.{}", self.template_data.cpp_extension);
             match self.config.test_framework {
                 TestFramework::Doctest => {
-                    self.template_renderer.render(
-                        "doctest_main.cpp",
-                        &self.template_data,
-                        &self.config.path.join("tests/main_test.cpp"),
-                    )?;
+                    self.plan_file(plan, "doctest_main.cpp", test_main)?;
                 }
                 TestFramework::GTest => {
-                    self.template_renderer.render(
-                        "gtest_main.cpp",
-                        &self.template_data,
-                        &self.config.path.join("tests/main_test.cpp"),
-                    )?;
+                    self.plan_file(plan, "gtest_main.cpp", test_main)?;
                 }
                 TestFramework::BoostTest => {
-                    self.template_renderer.render(
-                        "boost_test_main.cpp",
-                        &self.template_data,
-                        &self.config.path.join("tests/main_test.cpp"),
-                    )?;
+                    self.plan_file(plan, "boost_test_main.cpp", test_main)?;
                 }
                 TestFramework::Catch2 => {
-                    self.template_renderer.render(
-                        "catch2_main.cpp",
-                        &self.template_data,
-                        &self.config.path.join("tests/main_test.cpp"),
-                    )?;
+                    self.plan_file(plan, "catch2_main.cpp", test_main)?;
                 }
                 TestFramework::None => {}
             }
@@ -319,94 +1735,221 @@ impl ProjectBuilder {
         Ok(())
     }
 
-    fn generate_readme(&self) -> Result<()> {
-        self.template_renderer.render(
-            "README.md",
-            &self.template_data,
-            &self.config.path.join("README.md"),
-        )?;
+    fn generate_snippet_files(&self, plan: &mut FilePlan) -> Result<()> {
+        for snippet in &self.config.snippets {
+            self.plan_file(
+                plan,
+                &format!("snippet-{}", snippet),
+                format!(
+                    "include/snippets/{}.{}",
+                    snippet.file_stem(),
+                    self.template_data.hpp_extension
+                ),
+            )?;
+        }
+        Ok(())
+    }
 
+    /// Declares one function per OpenAPI operation in `include/api/`, one
+    /// header per first path segment.
+    fn generate_api_stubs(&self, plan: &mut FilePlan) -> Result<()> {
+        let Some(spec_path) = &self.config.openapi_spec else {
+            return Ok(());
+        };
+        let spec = codegen::load_spec(spec_path, self.config.cpp_standard.year() >= 2017)?;
+        for group in &spec.groups {
+            let data = ApiHeader {
+                project: &self.template_data,
+                api_title: &spec.title,
+                api_version: &spec.version,
+                api_group: group,
+            };
+            let contents = self.template_renderer.render_with_extra(
+                "api-header",
+                &data,
+                &self.config.extra_vars,
+            )?;
+            plan.push(PlannedFile::new(
+                PathBuf::from("include/api").join(format!(
+                    "{}.{}",
+                    group.name, self.template_data.hpp_extension
+                )),
+                contents,
+            ));
+        }
         Ok(())
     }
 
-    fn generate_license(&self) -> Result<()> {
-        self.template_renderer.render(
-            &self.config.license.to_string(),
-            &self.template_data,
-            &self.config.path.join("LICENSE"),
-        )?;
+    fn generate_readme(&self, plan: &mut FilePlan) -> Result<()> {
+        self.plan_file(plan, "README.md", "README.md")
+    }
+
+    fn generate_docs_files(&self, plan: &mut FilePlan) -> Result<()> {
+        if !self.config.generate_docs_dir {
+            return Ok(());
+        }
+
+        let files: &[(&str, &str)] = match self.config.doc_engine {
+            DocEngine::Markdown => &[("docs-index.md", "index.md"), ("docs-api.md", "api.md")],
+            DocEngine::Sphinx => &[
+                ("docs-conf.py", "conf.py"),
+                ("docs-index.rst", "index.rst"),
+                ("docs-Makefile", "Makefile"),
+                ("docs-Doxyfile", "Doxyfile"),
+                ("docs-requirements.txt", "requirements.txt"),
+            ],
+            DocEngine::Doxygen => &[("docs-Doxyfile", "Doxyfile")],
+        };
+        for (template, file) in files {
+            self.plan_file(plan, template, Path::new("docs").join(file))?;
+        }
+        if self.config.doc_engine == DocEngine::Sphinx
+            && self.config.build_system == BuildSystem::CMake
+        {
+            self.plan_file(plan, "docs.cmake", "cmake/docs.cmake")?;
+        }
+        if self.config.doc_engine == DocEngine::Doxygen
+            && self.config.build_system == BuildSystem::CMake
+        {
+            self.plan_file(plan, "docs-CMakeLists.txt", "docs/CMakeLists.txt")?;
+        }
+
+        Ok(())
+    }
+
+    fn generate_changelog(&self, plan: &mut FilePlan) -> Result<()> {
+        if self.config.generate_changelog {
+            self.plan_file(plan, "CHANGELOG.md", "CHANGELOG.md")?;
+            // The script reads the commit history
+            if self.config.use_git {
+                self.plan_file(plan, "update-changelog.sh", "scripts/update-changelog.sh")?;
+            }
+        }
+        Ok(())
+    }
+
+    fn generate_community_files(&self, plan: &mut FilePlan) -> Result<()> {
+        if self.config.generate_community_files {
+            self.plan_file(plan, "CONTRIBUTING.md", "CONTRIBUTING.md")?;
+            self.plan_file(plan, "CODE_OF_CONDUCT.md", "CODE_OF_CONDUCT.md")?;
+        }
+        Ok(())
+    }
+
+    fn generate_ci(&self, plan: &mut FilePlan) -> Result<()> {
+        if self.config.ci == CiProvider::GitHub {
+            self.plan_file(plan, "github-ci.yml", ".github/workflows/ci.yml")?;
+        }
+        Ok(())
+    }
+
+    /// Dependabot keeps the workflow actions up to date, so it is only set
+    /// up next to a GitHub Actions pipeline.
+    fn generate_dependabot(&self, plan: &mut FilePlan) -> Result<()> {
+        if self.config.enable_dependabot && self.template_data.has_github_actions {
+            self.plan_file(plan, "dependabot.yml", ".github/dependabot.yml")?;
+        }
+        Ok(())
+    }
 
+    fn generate_coverage_config(&self, plan: &mut FilePlan) -> Result<()> {
+        if self.config.coverage_service == CoverageService::Codecov {
+            self.plan_file(plan, "codecov.yml", ".github/.codecov.yml")?;
+        }
         Ok(())
     }
 
-    fn generate_quality_files(&self) -> Result<()> {
+    fn generate_license(&self, plan: &mut FilePlan) -> Result<()> {
+        self.plan_file(plan, &self.config.license.to_string(), "LICENSE")
+    }
+
+    fn generate_quality_files(&self, plan: &mut FilePlan) -> Result<()> {
         if self.config.quality_config.enable_clang_tidy {
-            self.template_renderer.render(
-                "clang-tidy",
-                &self.template_data,
-                &self.config.path.join(".clang-tidy"),
-            )?;
+            self.plan_file(plan, "clang-tidy", ".clang-tidy")?;
         }
         if self.config.quality_config.enable_cppcheck {
-            self.template_renderer.render(
+            self.plan_file(
+                plan,
+                "cppcheck-suppressions.xml",
                 "cppcheck-suppressions.xml",
-                &self.template_data,
-                &self.config.path.join("cppcheck-suppressions.xml"),
             )?;
         }
         Ok(())
     }
 
-    fn generate_code_formatter_files(&self) -> Result<()> {
+    fn generate_code_formatter_files(&self, plan: &mut FilePlan) -> Result<()> {
         if self.config.code_formatter.enable_clang_format {
-            self.template_renderer.render(
-                "clang-format",
-                &self.template_data,
-                &self.config.path.join(".clang-format"),
-            )?;
+            self.plan_file(plan, "clang-format", ".clang-format")?;
         }
         if self.config.code_formatter.enable_cmake_format {
-            self.template_renderer.render(
-                "cmake-format",
-                &self.template_data,
-                &self.config.path.join("cmake-format.yaml"),
-            )?;
+            self.plan_file(plan, "cmake-format", "cmake-format.yaml")?;
+        }
+        if self.config.code_formatter.enable_cmake_lint {
+            self.plan_file(plan, "cmake-lint", ".cmake-lint.yml")?;
         }
         Ok(())
     }
 
-    fn print_success_message(&self) {
-        println!("\n✨ Project created successfully!");
+    fn generate_ide_files(&self, plan: &mut FilePlan) -> Result<()> {
+        let name = &self.config.name;
+        if self.config.ide.enable_clion {
+            self.plan_file(plan, "clion-cmake.xml", ".idea/cmake.xml")?;
+            self.plan_file(
+                plan,
+                "clion-run.xml",
+                format!(".idea/runConfigurations/{}.xml", name),
+            )?;
+        }
+        if self.config.ide.enable_kdevelop {
+            self.plan_file(plan, "kdevelop-project.kdev4", format!("{}.kdev4", name))?;
+            self.plan_file(
+                plan,
+                "kdevelop-builddir.kdev4",
+                format!(".kdev4/{}.kdev4", name),
+            )?;
+        }
+        if self.config.ide.enable_emacs {
+            self.plan_file(plan, "dir-locals.el", ".dir-locals.el")?;
+        }
+        if self.config.ide.enable_sublime {
+            self.plan_file(plan, "sublime-project", format!("{}.sublime-project", name))?;
+        }
+        Ok(())
+    }
 
-        // Print next steps
-        println!("\nNext steps:");
-        println!("1. cd {}", self.config.path.display());
+    /// The next steps for the current platform's shell, with the project
+    /// directory relative to the current directory where possible.
+    fn next_steps(&self) -> Vec<String> {
+        let current_dir = std::env::current_dir()
+            .ok()
+            .map(|dir| dir.canonicalize().unwrap_or(dir));
+        self.next_steps_for(Shell::current(), current_dir.as_deref())
+    }
 
-        match self.config.package_manager {
-            PackageManager::Conan => {
-                println!("2. mkdir build && cd build");
-                println!("3. conan install .. --output-folder=. --build=missing");
-                println!("4. cmake .. -DCMAKE_TOOLCHAIN_FILE=./conan_toolchain.cmake");
-                println!("5. cmake --build .");
-            }
-            PackageManager::Vcpkg => {
-                println!("2. mkdir build && cd build");
-                println!(
-                    "3. cmake .. -DCMAKE_TOOLCHAIN_FILE=${{VCPKG_ROOT}}/scripts/buildsystems/vcpkg.cmake"
-                );
-                println!("4. cmake --build .");
-            }
-            PackageManager::None => match self.config.build_system {
-                BuildSystem::CMake => {
-                    println!("2. mkdir build && cd build");
-                    println!("3. cmake ..");
-                    println!("4. cmake --build .");
-                }
-                BuildSystem::Make => {
-                    println!("2. make");
+    fn next_steps_for(&self, shell: Shell, current_dir: Option<&Path>) -> Vec<String> {
+        let path = current_dir
+            .and_then(|dir| self.config.path.strip_prefix(dir).ok())
+            .map(|relative| {
+                if relative.as_os_str().is_empty() {
+                    Path::new(".")
+                } else {
+                    relative
                 }
-            },
+            })
+            .unwrap_or(&self.config.path);
+        let mut steps = vec![format!("cd {}", shell.quote(&path.to_string_lossy()))];
+        steps.extend(build_steps(&self.config, shell));
+        if self.config.use_git
+            && self.config.git_remote.is_some()
+            && self.config.github_repo.is_none()
+            && self.enclosing_repository().is_none()
+        {
+            if !self.config.git_commit {
+                steps.push(shell.and_then("git add --all", "git commit -m \"Initial commit\""));
+            }
+            steps.push(format!("git push -u origin {}", self.config.git_branch));
         }
+        steps
     }
 }
 
@@ -414,7 +1957,12 @@ impl ProjectBuilder {
 mod tests {
     use super::*;
     use crate::project::config::CppStandard;
-    use crate::project::{CodeFormatter, License, QualityConfig};
+    use crate::project::tool::MockRunner;
+    use crate::project::{
+        CmakeGenerator, CodeFormatter, CppExtension, DocEngine, IdeConfig, License, LineEndings,
+        QualityConfig, SanitizerPreset, TestRunner,
+    };
+    use std::collections::HashMap;
 
     fn create_test_config() -> ProjectConfig {
         ProjectConfig {
@@ -422,16 +1970,53 @@ mod tests {
             description: "A test project".to_string(),
             project_type: ProjectType::Executable,
             build_system: BuildSystem::CMake,
+            cmake_generator: CmakeGenerator::Default,
             cpp_standard: CppStandard::Cpp17,
             test_framework: TestFramework::Doctest,
+            test_runner: TestRunner::Direct,
             package_manager: PackageManager::Conan,
             license: License::MIT,
             use_git: true,
+            git_commit: false,
+            force_git: false,
+            git_branch: "main".to_string(),
+            git_remote: None,
+            github_repo: None,
+            cpp_extension: CppExtension::Cpp,
+            hpp_extension: HppExtension::Hpp,
+            line_endings: LineEndings::Lf,
+            sanitizer_preset: SanitizerPreset::None,
+            generate_docs_dir: false,
+            doc_engine: DocEngine::Markdown,
+            num_examples: 1,
+            openapi_spec: None,
+            generate_changelog: false,
+            generate_community_files: false,
+            ci: CiProvider::None,
+            enable_dependabot: false,
+            homepage: None,
             path: std::path::PathBuf::from("/tmp/test-project"),
             author: "Test Author".to_string(),
             version: "1.0.0".to_string(),
             quality_config: QualityConfig::new(&["clang-tidy", "cppcheck"]),
+            ide: IdeConfig::new(&[]),
+            snippets: Vec::new(),
+            codeowners: Vec::new(),
+            conan_options: Vec::new(),
+            vcpkg_features: HashMap::new(),
+            vcpkg_default_registry: None,
+            vcpkg_baseline: None,
+            compile_definitions: Vec::new(),
+            enable_lto: false,
+            coverage_service: CoverageService::None,
+            target_platform: TargetPlatform::Host,
+            android_abi: "arm64-v8a".to_string(),
+            android_api: 24,
+            macos_bundle: false,
+            bundle_id: None,
+            macos_universal: false,
             code_formatter: CodeFormatter::new(&["clang-format"]),
+            extra_vars: HashMap::new(),
         }
     }
 
@@ -472,6 +2057,48 @@ mod tests {
         assert_eq!(data.namespace, "my_awesome_project");
     }
 
+    #[test]
+    fn test_create_template_data_dot_notation() {
+        let mut config = create_test_config();
+        config.name = "acme.networking.http".to_string();
+        config.project_type = ProjectType::Library;
+        let data = create_template_data(&config);
+
+        assert_eq!(data.namespace, "acme::networking::http");
+        assert_eq!(data.namespace_parts, vec!["acme", "networking", "http"]);
+        assert_eq!(data.target_name, "acme_networking_http");
+        assert_eq!(data.header_path, "acme/networking/http.hpp");
+        assert_eq!(
+            data.binary_path,
+            "build/Debug/bin/acme_networking_http_example"
+        );
+    }
+
+    #[test]
+    fn test_create_template_data_extensions() {
+        let mut config = create_test_config();
+        config.name = "acme.http".to_string();
+        config.cpp_extension = CppExtension::Cc;
+        config.hpp_extension = HppExtension::H;
+        let data = create_template_data(&config);
+
+        assert_eq!(data.cpp_extension, "cc");
+        assert_eq!(data.hpp_extension, "h");
+        assert_eq!(data.header_path, "acme/http.h");
+    }
+
+    #[test]
+    fn test_create_template_data_cmake_generator() {
+        let mut config = create_test_config();
+        assert_eq!(create_template_data(&config).cmake_generator, "");
+
+        config.cmake_generator = CmakeGenerator::NinjaMultiConfig;
+        assert_eq!(
+            create_template_data(&config).cmake_generator,
+            "Ninja Multi-Config"
+        );
+    }
+
     #[test]
     fn test_create_template_data_no_tests() {
         let mut config = create_test_config();
@@ -507,6 +2134,42 @@ mod tests {
         assert_eq!(data.package_manager, "none");
     }
 
+    #[test]
+    fn test_create_template_data_binary_path() {
+        let mut config = create_test_config();
+        assert_eq!(
+            create_template_data(&config).binary_path,
+            "build/Debug/bin/test-project"
+        );
+
+        config.project_type = ProjectType::Library;
+        assert_eq!(
+            create_template_data(&config).binary_path,
+            "build/Debug/bin/test-project_example"
+        );
+
+        config.build_system = BuildSystem::Make;
+        assert_eq!(
+            create_template_data(&config).binary_path,
+            "test-project_example"
+        );
+
+        config.build_system = BuildSystem::Meson;
+        assert_eq!(
+            create_template_data(&config).binary_path,
+            "build/test-project_example"
+        );
+    }
+
+    #[test]
+    fn test_create_template_data_ide() {
+        let mut config = create_test_config();
+        assert!(create_template_data(&config).ide.is_empty());
+
+        config.ide = IdeConfig::new(&["clion", "emacs"]);
+        assert_eq!(create_template_data(&config).ide, vec!["clion", "emacs"]);
+    }
+
     #[test]
     fn test_project_builder_creation() {
         let config = create_test_config();
@@ -515,4 +2178,1035 @@ mod tests {
         assert_eq!(builder.config.name, "test-project");
         assert_eq!(builder.template_data.name, "test-project");
     }
+
+    fn rendered_file(files: &[(PathBuf, String)], path: &str) -> String {
+        files
+            .iter()
+            .find(|(file, _)| file == Path::new(path))
+            .map(|(_, contents)| contents.clone())
+            .unwrap()
+    }
+
+    #[test]
+    fn test_with_template_renderer_uses_custom_templates() {
+        let mut renderer = TemplateRenderer::new();
+        renderer
+            .register_template("README.md", "# {{name}}, custom")
+            .unwrap();
+        let builder = ProjectBuilder::new(create_test_config()).with_template_renderer(renderer);
+
+        let files = builder.render_files().unwrap();
+        assert_eq!(rendered_file(&files, "README.md"), "# test-project, custom");
+        // Templates that were not replaced still come from the embedded set
+        assert!(rendered_file(&files, "LICENSE").contains("MIT License"));
+    }
+
+    #[test]
+    fn test_with_template_data_overrides_computed_data() {
+        let builder = ProjectBuilder::new(create_test_config());
+        let mut data = builder.template_data().clone();
+        data.name = "Renamed".to_string();
+        data.author = "Someone Else".to_string();
+        let builder = builder.with_template_data(data);
+
+        let files = builder.render_files().unwrap();
+        assert!(rendered_file(&files, "README.md").starts_with("# Renamed\n"));
+        assert!(rendered_file(&files, "LICENSE").contains("Someone Else"));
+    }
+
+    #[test]
+    fn test_dependabot_needs_github_actions() {
+        let mut config = create_test_config();
+        config.enable_dependabot = true;
+        config.package_manager = PackageManager::Conan;
+        let builder = ProjectBuilder::new(config.clone());
+        assert!(!builder
+            .render_files()
+            .unwrap()
+            .iter()
+            .any(|(path, _)| path.ends_with("dependabot.yml")));
+
+        config.ci = CiProvider::GitHub;
+        let files = ProjectBuilder::new(config).render_files().unwrap();
+        let dependabot = rendered_file(&files, ".github/dependabot.yml");
+        assert!(dependabot.contains(r#"- package-ecosystem: "github-actions""#));
+        assert!(dependabot.contains(r#"- package-ecosystem: "pip""#));
+    }
+
+    #[test]
+    fn test_github_actions_workflow() {
+        let mut config = create_test_config();
+        config.build_system = BuildSystem::CMake;
+        config.cmake_generator = CmakeGenerator::Ninja;
+        config.test_framework = TestFramework::GTest;
+        config.package_manager = PackageManager::None;
+        config.git_remote = Some("git@github.com:me/test-project.git".to_string());
+        assert!(!ProjectBuilder::new(config.clone())
+            .render_files()
+            .unwrap()
+            .iter()
+            .any(|(path, _)| path.starts_with(".github/workflows")));
+
+        config.ci = CiProvider::GitHub;
+        let files = ProjectBuilder::new(config).render_files().unwrap();
+        let workflow = rendered_file(&files, ".github/workflows/ci.yml");
+        assert!(workflow.contains("sudo apt-get install -y ninja-build libgtest-dev\n"));
+        assert!(workflow
+            .contains("          cmake --preset Debug\n          cmake --build --preset Debug\n"));
+        assert!(workflow.contains("          ctest --test-dir build/Debug\n"));
+        assert!(workflow.contains("branches: [main]"));
+        assert!(!workflow.contains("Conan"));
+        let readme = rendered_file(&files, "README.md");
+        assert!(readme
+            .contains("https://github.com/me/test-project/actions/workflows/ci.yml/badge.svg"));
+    }
+
+    #[test]
+    fn test_ci_packages() {
+        let mut config = create_test_config();
+        config.build_system = BuildSystem::Meson;
+        config.package_manager = PackageManager::None;
+        config.test_framework = TestFramework::Catch2;
+        assert!(ci_packages(&config).is_empty());
+
+        config.test_framework = TestFramework::BoostTest;
+        assert_eq!(ci_packages(&config), ["libboost-test-dev"]);
+
+        config.build_system = BuildSystem::Make;
+        config.test_framework = TestFramework::Doctest;
+        assert_eq!(ci_packages(&config), ["doctest-dev"]);
+
+        config.build_system = BuildSystem::CMake;
+        config.package_manager = PackageManager::Vcpkg;
+        assert!(ci_packages(&config).is_empty());
+
+        config.ci = CiProvider::GitHub;
+        config.coverage_service = CoverageService::Codecov;
+        assert_eq!(ci_packages(&config), ["gcovr"]);
+    }
+
+    #[test]
+    fn test_github_actions_coverage_with_make() {
+        let mut config = create_test_config();
+        config.build_system = BuildSystem::Make;
+        config.test_framework = TestFramework::Doctest;
+        config.ci = CiProvider::GitHub;
+        config.coverage_service = CoverageService::Coveralls;
+
+        let files = ProjectBuilder::new(config).render_files().unwrap();
+        let workflow = rendered_file(&files, ".github/workflows/ci.yml");
+        assert!(!workflow.contains("CXXFLAGS"));
+        assert!(workflow.contains("          make CXX=\"g++ --coverage\"\n"));
+        assert!(workflow.contains("          make test CXX=\"g++ --coverage\"\n"));
+        assert!(workflow.contains("          format: cobertura\n"));
+    }
+
+    #[test]
+    fn test_codeowners_follow_provider_and_directories() {
+        let mut config = create_test_config();
+        assert!(!ProjectBuilder::new(config.clone())
+            .render_files()
+            .unwrap()
+            .iter()
+            .any(|(path, _)| path.ends_with("CODEOWNERS")));
+
+        config.codeowners = vec!["@org/cpp-team".to_string(), "@jane".to_string()];
+        config.git_remote = Some("git@github.com:org/test-project.git".to_string());
+        let files = ProjectBuilder::new(config.clone()).render_files().unwrap();
+        assert_eq!(
+            rendered_file(&files, ".github/CODEOWNERS"),
+            "# Owners are asked to review changes to matching paths. The last matching\n\
+             # pattern takes precedence.\n\
+             * @org/cpp-team @jane\n\
+             /cmake/ @org/cpp-team @jane\n\
+             /tests/ @org/cpp-team @jane\n\
+             /.github/ @org/cpp-team @jane\n"
+        );
+
+        config.git_remote = Some("git@gitlab.com:org/test-project.git".to_string());
+        config.build_system = BuildSystem::Make;
+        config.package_manager = PackageManager::None;
+        config.test_framework = TestFramework::None;
+        let files = ProjectBuilder::new(config).render_files().unwrap();
+        let codeowners = rendered_file(&files, "docs/CODEOWNERS");
+        assert!(codeowners.ends_with("\n* @org/cpp-team @jane\n"));
+        assert!(!files
+            .iter()
+            .any(|(path, _)| path == Path::new(".github/CODEOWNERS")));
+    }
+
+    fn minimal_config(path: PathBuf) -> ProjectConfig {
+        let mut config = create_test_config();
+        config.build_system = BuildSystem::Make;
+        config.test_framework = TestFramework::None;
+        config.package_manager = PackageManager::None;
+        config.quality_config = QualityConfig::new(&[]);
+        config.code_formatter = CodeFormatter::new(&[]);
+        config.use_git = false;
+        config.path = path;
+        config
+    }
+
+    #[test]
+    fn test_build_report_lists_created_files() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let config = minimal_config(temp_dir.path().join("test-project"));
+
+        let report = ProjectBuilder::new(config).build().unwrap();
+
+        assert_eq!(report.project_path, temp_dir.path().join("test-project"));
+        let mut files = report.files_created.clone();
+        files.sort();
+        assert_eq!(
+            files,
+            [
+                METADATA_FILE,
+                "LICENSE",
+                "Makefile",
+                "README.md",
+                "src/main.cpp"
+            ]
+            .map(PathBuf::from)
+        );
+        for file in &report.files_created {
+            assert!(report.project_path.join(file).is_file());
+        }
+        assert!(report.commands_run.is_empty());
+        assert!(report.warnings.is_empty());
+        assert_eq!(
+            report.next_steps,
+            vec![
+                format!("cd {}", report.project_path.display()),
+                "make".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_build_rejects_escaping_output_paths() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let outside = temp_dir.path().join("outside.txt");
+        let absolute = outside.to_str().unwrap().to_string();
+        for path in [
+            "../outside.txt",
+            absolute.as_str(),
+            "docs/../../outside.txt",
+            "",
+        ] {
+            let config = minimal_config(temp_dir.path().join("escape"));
+            let result = ProjectBuilder::new(config)
+                .with_extra_outputs(vec![("README.md".to_string(), PathBuf::from(path))])
+                .build();
+
+            let error = format!("{:#}", result.unwrap_err());
+            assert!(
+                error.contains("Invalid output of template 'README.md'"),
+                "{}",
+                error
+            );
+            assert!(
+                error.contains("must be relative to the project"),
+                "{}",
+                error
+            );
+            assert!(!outside.exists());
+            assert!(!temp_dir.path().join("escape").exists());
+        }
+    }
+
+    #[test]
+    fn test_write_project_file_rejects_escaping_paths() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let project = temp_dir.path().join("project");
+        fs::create_dir(&project).unwrap();
+        let builder = ProjectBuilder::new(minimal_config(project.clone()));
+
+        for path in ["../outside.txt", "a/../../outside.txt", "/outside.txt", ""] {
+            let error = builder
+                .write_project_file(Path::new(path), "x")
+                .unwrap_err();
+            assert!(error.to_string().contains(path), "{}", error);
+        }
+        assert!(!temp_dir.path().join("outside.txt").exists());
+
+        let written = builder
+            .write_project_file(Path::new("./nested/dir/file.txt"), "x")
+            .unwrap();
+        assert!(written.ends_with("nested/dir/file.txt"));
+        assert_eq!(
+            fs::read_to_string(project.join("nested/dir/file.txt")).unwrap(),
+            "x"
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_write_project_file_rejects_symlinked_escapes() {
+        use std::os::unix::fs::symlink;
+
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let project = temp_dir.path().join("project");
+        let outside = temp_dir.path().join("outside");
+        fs::create_dir_all(project.join("docs")).unwrap();
+        fs::create_dir(&outside).unwrap();
+        symlink(&outside, project.join("escape")).unwrap();
+        symlink(outside.join("file.txt"), project.join("link.txt")).unwrap();
+        symlink(project.join("docs"), project.join("manual")).unwrap();
+        let builder = ProjectBuilder::new(minimal_config(project.clone()));
+
+        for path in ["escape/file.txt", "escape/sub/file.txt", "link.txt"] {
+            let error = builder
+                .write_project_file(Path::new(path), "x")
+                .unwrap_err();
+            assert!(error.to_string().contains(path), "{}", error);
+        }
+        assert_eq!(fs::read_dir(&outside).unwrap().count(), 0);
+
+        // Symlinks that stay inside the project are fine
+        builder
+            .write_project_file(Path::new("manual/index.md"), "x")
+            .unwrap();
+        assert!(project.join("docs/index.md").is_file());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_create_directory_structure_rejects_symlinked_escapes() {
+        use std::os::unix::fs::symlink;
+
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let project = temp_dir.path().join("project");
+        let outside = temp_dir.path().join("outside");
+        fs::create_dir_all(&project).unwrap();
+        fs::create_dir(&outside).unwrap();
+        symlink(&outside, project.join("include")).unwrap();
+        let builder = ProjectBuilder::new(minimal_config(project.clone()));
+
+        let error = builder.create_directory_structure().unwrap_err();
+        assert_eq!(error.to_string(), "Failed to create include directory");
+        assert!(format!("{:#}", error).contains("leads outside the project through a symlink"));
+
+        fs::remove_file(project.join("include")).unwrap();
+        fs::write(project.join("include"), "").unwrap();
+        let error = builder.create_directory_structure().unwrap_err();
+        assert!(format!("{:#}", error).contains("exists but is not a directory"));
+
+        fs::remove_file(project.join("include")).unwrap();
+        builder.create_directory_structure().unwrap();
+        assert!(project.join("include").is_dir());
+        assert_eq!(fs::read_dir(&outside).unwrap().count(), 0);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_build_runs_post_gen_hooks() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("hooks");
+        let config = minimal_config(path.clone());
+
+        let report = ProjectBuilder::new(config)
+            .with_post_gen_hooks(vec![
+                "echo \"$CPPUP_PROJECT_NAME:$CPPUP_BUILD_SYSTEM\" > marker".to_string(),
+                "false".to_string(),
+            ])
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            fs::read_to_string(path.join("marker")).unwrap(),
+            "test-project:make\n"
+        );
+        assert_eq!(report.commands_run.len(), 2);
+        assert_eq!(report.commands_run[0].args[0], "-c");
+        assert!(report.commands_run[0].success);
+        assert_eq!(report.commands_run[1].exit_code, Some(1));
+        assert_eq!(
+            report.warnings,
+            ["Warning: post-generation hook `false` failed with exit code 1"]
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_build_fails_on_post_gen_hook_when_strict() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("hooks");
+        let config = minimal_config(path.clone());
+
+        let error = ProjectBuilder::new(config)
+            .with_post_gen_hooks(vec!["echo missing >&2; exit 2".to_string()])
+            .with_post_gen_strict(true)
+            .build()
+            .unwrap_err();
+
+        let message = error.to_string();
+        assert!(
+            message.contains("failed with exit code 2:\nmissing"),
+            "{}",
+            message
+        );
+        assert!(message.contains("left at"), "{}", message);
+        assert!(path.join("Makefile").exists());
+    }
+
+    #[test]
+    fn test_build_writes_configured_line_endings() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let mut config = minimal_config(temp_dir.path().join("crlf"));
+        config.line_endings = LineEndings::Crlf;
+        let mut renderer = TemplateRenderer::new();
+        renderer
+            .register_template("setup", "@echo off\necho {{name}}\n")
+            .unwrap();
+
+        ProjectBuilder::new(config)
+            .with_template_renderer(renderer)
+            .with_extra_outputs(vec![("setup".to_string(), PathBuf::from("setup.bat"))])
+            .build()
+            .unwrap();
+
+        let project = temp_dir.path().join("crlf");
+        let makefile = fs::read_to_string(project.join("Makefile")).unwrap();
+        assert!(makefile.contains("\r\n"));
+        assert!(!makefile.replace("\r\n", "").contains('\n'));
+
+        let mut config = minimal_config(temp_dir.path().join("lf"));
+        config.line_endings = LineEndings::Lf;
+        let mut renderer = TemplateRenderer::new();
+        renderer
+            .register_template("setup", "@echo off\necho {{name}}\n")
+            .unwrap();
+        ProjectBuilder::new(config)
+            .with_template_renderer(renderer)
+            .with_extra_outputs(vec![("setup".to_string(), PathBuf::from("setup.bat"))])
+            .build()
+            .unwrap();
+
+        let project = temp_dir.path().join("lf");
+        assert!(!fs::read_to_string(project.join("Makefile"))
+            .unwrap()
+            .contains('\r'));
+        assert_eq!(
+            fs::read_to_string(project.join("setup.bat")).unwrap(),
+            "@echo off\r\necho test-project\r\n"
+        );
+    }
+
+    #[test]
+    fn test_build_keeps_lf_in_scripts_with_crlf() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let mut config = minimal_config(temp_dir.path().join("crlf-scripts"));
+        config.line_endings = LineEndings::Crlf;
+        config.use_git = true;
+        config.generate_changelog = true;
+        let mut renderer = TemplateRenderer::new();
+        renderer
+            .register_template("run", "#!/usr/bin/env python3\nprint('{{name}}')\n")
+            .unwrap();
+
+        ProjectBuilder::new(config)
+            .with_template_renderer(renderer)
+            .with_extra_outputs(vec![("run".to_string(), PathBuf::from("tools/run"))])
+            .build()
+            .unwrap();
+
+        let project = temp_dir.path().join("crlf-scripts");
+        let script = fs::read_to_string(project.join("scripts/update-changelog.sh")).unwrap();
+        assert!(script.starts_with("#!"));
+        assert!(!script.contains('\r'));
+        assert_eq!(
+            fs::read_to_string(project.join("tools/run")).unwrap(),
+            "#!/usr/bin/env python3\nprint('test-project')\n"
+        );
+        assert!(fs::read_to_string(project.join("CHANGELOG.md"))
+            .unwrap()
+            .contains("\r\n"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_build_makes_scripts_executable() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let config = minimal_config(temp_dir.path().join("scripts"));
+        let mut renderer = TemplateRenderer::new();
+        renderer
+            .register_template("bootstrap", "#!/bin/sh\n")
+            .unwrap();
+        renderer.mark_executable("bootstrap");
+
+        ProjectBuilder::new(config)
+            .with_template_renderer(renderer)
+            .with_extra_outputs(vec![(
+                "bootstrap".to_string(),
+                PathBuf::from("scripts/bootstrap.sh"),
+            )])
+            .build()
+            .unwrap();
+
+        let project = temp_dir.path().join("scripts");
+        let mode = |path: &str| {
+            fs::metadata(project.join(path))
+                .unwrap()
+                .permissions()
+                .mode()
+                & 0o777
+        };
+        assert_eq!(mode("scripts/bootstrap.sh"), 0o755);
+        assert_eq!(mode("Makefile") & 0o111, 0);
+    }
+
+    #[test]
+    fn test_build_creates_missing_parent_directories() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("src/sandbox/2025/demo");
+        let config = minimal_config(path.clone());
+
+        ProjectBuilder::new(config).build().unwrap();
+        assert!(path.join("Makefile").is_file());
+    }
+
+    #[test]
+    fn test_build_names_the_directory_it_cannot_create() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        fs::write(temp_dir.path().join("file"), "").unwrap();
+        let config = minimal_config(temp_dir.path().join("file/nested/demo"));
+
+        let error = ProjectBuilder::new(config).build().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            format!(
+                "Failed to create directory {}",
+                temp_dir.path().join("file/nested").display()
+            )
+        );
+    }
+
+    #[test]
+    fn test_build_report_records_git_init() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let mut config = minimal_config(temp_dir.path().join("git-project"));
+        config.use_git = true;
+
+        let report = ProjectBuilder::new(config).build().unwrap();
+
+        assert!(report.files_created.contains(&PathBuf::from(".gitignore")));
+        assert!(report
+            .files_created
+            .contains(&PathBuf::from(".gitattributes")));
+        assert_eq!(report.commands_run.len(), 1);
+        assert_eq!(report.commands_run[0].to_string(), "git init -b main");
+        assert!(report.commit_hash.is_none());
+    }
+
+    #[test]
+    fn test_build_report_records_initial_commit() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let mut config = minimal_config(temp_dir.path().join("commit-project"));
+        config.use_git = true;
+        config.git_commit = true;
+
+        let report = ProjectBuilder::new(config).build().unwrap();
+
+        assert!(report.warnings.is_empty(), "{:?}", report.warnings);
+        let hash = report.commit_hash.expect("no commit hash recorded");
+        assert_eq!(hash.len(), 40);
+        assert!(report
+            .commands_run
+            .iter()
+            .any(|command| command.args.contains(&"commit".to_string())));
+    }
+
+    #[test]
+    fn test_build_fails_when_git_init_fails() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let root = temp_dir.path().join("no-git-project");
+        let mut config = minimal_config(root.clone());
+        config.use_git = true;
+        let runner = Arc::new(MockRunner::new(|command: &ToolCommand| {
+            Ok(if command.program == "git" && command.args[0] == "init" {
+                CommandOutput::failed(128, "fatal: cannot mkdir .git: Permission denied")
+            } else {
+                CommandOutput::ok("")
+            })
+        }));
+
+        let error = ProjectBuilder::new(config)
+            .with_command_runner(runner.clone())
+            .build()
+            .unwrap_err();
+
+        let message = format!("{:#}", error);
+        assert!(
+            message.contains("Failed to initialize git repository"),
+            "{}",
+            message
+        );
+        assert!(message.contains("Permission denied"), "{}", message);
+        assert_eq!(runner.calls(), ["git init -b main", "git init"]);
+        assert!(!root.exists());
+    }
+
+    #[test]
+    fn test_first_build_detects_missing_conan_profile() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let mut config = minimal_config(temp_dir.path().join("conan-project"));
+        config.build_system = BuildSystem::CMake;
+        config.package_manager = PackageManager::Conan;
+        let answer = |has_profile: bool| {
+            move |command: &ToolCommand| {
+                Ok(
+                    if command.to_string() == "conan profile path default" && !has_profile {
+                        CommandOutput::failed(1, "ERROR: The default build profile doesn't exist")
+                    } else {
+                        CommandOutput::ok("")
+                    },
+                )
+            }
+        };
+
+        let runner = Arc::new(MockRunner::new(answer(false)));
+        let mut report = BuildReport::default();
+        ProjectBuilder::new(config.clone())
+            .with_command_runner(runner.clone())
+            .run_first_build(&mut report)
+            .unwrap();
+        let calls = runner.calls();
+        assert_eq!(
+            calls[..2],
+            ["conan profile path default", "conan profile detect"]
+        );
+        assert!(calls[2].starts_with("conan install"), "{:?}", calls);
+        assert_eq!(report.commands_run.len(), calls.len());
+
+        let runner = Arc::new(MockRunner::new(answer(true)));
+        ProjectBuilder::new(config)
+            .with_command_runner(runner.clone())
+            .run_first_build(&mut BuildReport::default())
+            .unwrap();
+        assert!(!runner.calls().contains(&"conan profile detect".to_string()));
+    }
+
+    #[test]
+    fn test_first_build_stops_when_conan_profile_detection_fails() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let mut config = minimal_config(temp_dir.path().join("conan-project"));
+        config.build_system = BuildSystem::CMake;
+        config.package_manager = PackageManager::Conan;
+        let runner = Arc::new(MockRunner::new(|command: &ToolCommand| {
+            Ok(if command.program == "conan" {
+                CommandOutput::failed(1, "ERROR: no compiler found")
+            } else {
+                CommandOutput::ok("")
+            })
+        }));
+
+        let error = ProjectBuilder::new(config)
+            .with_command_runner(runner.clone())
+            .run_first_build(&mut BuildReport::default())
+            .unwrap_err();
+
+        let message = format!("{:#}", error);
+        assert!(
+            message.contains("detecting one failed") && message.contains("no compiler found"),
+            "{}",
+            message
+        );
+        assert_eq!(
+            runner.calls(),
+            ["conan profile path default", "conan profile detect"]
+        );
+    }
+
+    #[test]
+    fn test_newer_cmake_version() {
+        assert_eq!(newer_cmake_version("3.27", "3.20"), "3.27");
+        assert_eq!(newer_cmake_version("3.27", "3.30"), "3.30");
+        assert_eq!(newer_cmake_version("3.8", "3.12"), "3.12");
+        assert_eq!(newer_cmake_version("3.12", "4.0"), "4.0");
+
+        let mut config = create_test_config();
+        config.cpp_standard = CppStandard::latest();
+        assert_eq!(create_template_data(&config).cmake_min_version, "3.27");
+    }
+
+    #[test]
+    fn test_enclosing_git_repository() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let outer = temp_dir.path().join("outer");
+        let project = outer.join("libs/new-project");
+        fs::create_dir_all(outer.join("libs")).unwrap();
+        assert_eq!(enclosing_git_repository(&project), None);
+
+        fs::create_dir(outer.join(".git")).unwrap();
+        assert_eq!(enclosing_git_repository(&project), Some(outer.clone()));
+
+        // Worktrees and submodules have a `.git` file instead
+        fs::remove_dir(outer.join(".git")).unwrap();
+        fs::write(outer.join(".git"), "gitdir: elsewhere").unwrap();
+        assert_eq!(enclosing_git_repository(&project), Some(outer));
+    }
+
+    #[test]
+    fn test_build_skips_git_init_inside_repository() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        fs::create_dir(temp_dir.path().join(".git")).unwrap();
+        let mut config = minimal_config(temp_dir.path().join("nested-project"));
+        config.use_git = true;
+
+        let report = ProjectBuilder::new(config.clone()).build().unwrap();
+        assert!(report.commands_run.is_empty());
+        assert!(report.warnings[0].starts_with("Warning: skipping git init:"));
+        assert!(report.files_created.contains(&PathBuf::from(".gitignore")));
+
+        config.path = temp_dir.path().join("forced-project");
+        config.force_git = true;
+        let report = ProjectBuilder::new(config).build().unwrap();
+        assert_eq!(report.commands_run[0].to_string(), "git init -b main");
+    }
+
+    #[test]
+    fn test_default_bundle_id() {
+        assert_eq!(
+            default_bundle_id(Some("https://github.com/me/my_proj"), "my_proj"),
+            "com.github.me.my-proj"
+        );
+        assert_eq!(
+            default_bundle_id(Some("https://gitlab.com/group/sub/proj"), "proj"),
+            "com.gitlab.group.sub.proj"
+        );
+        assert_eq!(default_bundle_id(None, "app"), "com.example.app");
+    }
+
+    #[test]
+    fn test_repository_web_url() {
+        for remote in [
+            "git@github.com:me/proj.git",
+            "https://github.com/me/proj.git",
+            "ssh://git@github.com/me/proj.git",
+            "https://github.com/me/proj/",
+        ] {
+            assert_eq!(
+                repository_web_url(remote).as_deref(),
+                Some("https://github.com/me/proj"),
+                "{}",
+                remote
+            );
+        }
+        assert_eq!(
+            repository_web_url("git@gitlab.com:group/sub/proj.git").as_deref(),
+            Some("https://gitlab.com/group/sub/proj")
+        );
+        assert_eq!(repository_web_url("git@example.com:me/proj.git"), None);
+
+        let repository = template_repository("https://gitlab.com/group/sub/proj").unwrap();
+        assert_eq!(repository.provider, "gitlab");
+        assert_eq!(repository.slug, "group/sub/proj");
+    }
+
+    #[test]
+    fn test_conan_template_data() {
+        let mut config = create_test_config();
+        config.conan_options = vec![
+            "fmt:shared=True".to_string(),
+            "boost/*:header_only=True".to_string(),
+        ];
+        let data = create_template_data(&config);
+        assert_eq!(data.conan_generators, vec!["CMakeDeps", "CMakeToolchain"]);
+        assert_eq!(
+            data.conan_options,
+            vec!["fmt/*:shared=True", "boost/*:header_only=True"]
+        );
+
+        config.build_system = BuildSystem::Meson;
+        assert_eq!(
+            create_template_data(&config).conan_generators,
+            vec!["PkgConfigDeps", "MesonToolchain"]
+        );
+    }
+
+    #[test]
+    fn test_vcpkg_features_template_data() {
+        let mut config = create_test_config();
+        config.package_manager = PackageManager::Vcpkg;
+        config.test_framework = TestFramework::None;
+        assert_eq!(
+            create_template_data(&config).vcpkg_features,
+            serde_json::Value::Null
+        );
+
+        config.test_framework = TestFramework::GTest;
+        config.vcpkg_features = HashMap::from([(
+            "testing".to_string(),
+            VcpkgFeature {
+                description: "Test helpers".to_string(),
+                dependencies: vec!["gmock-extras".to_string()],
+            },
+        )]);
+        let features = create_template_data(&config).vcpkg_features;
+        assert_eq!(features["testing"]["description"], "Test helpers");
+        assert_eq!(
+            features["testing"]["dependencies"],
+            serde_json::json!(["gmock-extras", "gtest"])
+        );
+    }
+
+    #[test]
+    fn test_author_email() {
+        assert_eq!(
+            author_email("Jane Doe <jane@example.com>"),
+            Some("jane@example.com")
+        );
+        assert_eq!(author_email("Jane Doe"), None);
+        assert_eq!(author_email("Jane <not an address>"), None);
+    }
+
+    #[test]
+    fn test_remote_next_steps() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let mut config = minimal_config(temp_dir.path().join("remote-project"));
+        config.use_git = true;
+        config.git_commit = true;
+        config.git_branch = "trunk".to_string();
+        config.git_remote = Some("git@github.com:me/proj.git".to_string());
+
+        let steps = ProjectBuilder::new(config).next_steps();
+        assert_eq!(steps.last().unwrap(), "git push -u origin trunk");
+    }
+
+    #[test]
+    fn test_next_steps_for_shell() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let parent = temp_dir.path().join("my projects");
+        let mut config = minimal_config(parent.join("demo"));
+        config.build_system = BuildSystem::CMake;
+        config.package_manager = PackageManager::Vcpkg;
+        config.use_git = true;
+        config.git_commit = false;
+        config.git_remote = Some("git@github.com:me/demo.git".to_string());
+        let builder = ProjectBuilder::new(config);
+
+        let steps = builder.next_steps_for(Shell::Posix, Some(&parent));
+        assert_eq!(
+            steps,
+            vec![
+                "cd demo",
+                "cmake -S . -B build -DCMAKE_TOOLCHAIN_FILE=${VCPKG_ROOT}/scripts/buildsystems/vcpkg.cmake",
+                "cmake --build build",
+                "git add --all && git commit -m \"Initial commit\"",
+                "git push -u origin main",
+            ]
+        );
+
+        let steps = builder.next_steps_for(Shell::PowerShell, Some(&parent));
+        assert_eq!(
+            steps[1],
+            "cmake -S . -B build \"-DCMAKE_TOOLCHAIN_FILE=$env:VCPKG_ROOT/scripts/buildsystems/vcpkg.cmake\""
+        );
+        assert_eq!(
+            steps[3],
+            "git add --all; if ($?) { git commit -m \"Initial commit\" }"
+        );
+
+        let outside = Path::new("/somewhere/else");
+        assert_eq!(
+            builder.next_steps_for(Shell::Posix, Some(outside))[0],
+            format!("cd '{}'", parent.join("demo").display())
+        );
+        assert_eq!(
+            builder.next_steps_for(Shell::Posix, Some(&parent.join("demo")))[0],
+            "cd ."
+        );
+    }
+
+    #[test]
+    fn test_next_steps_with_presets() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let mut config = minimal_config(temp_dir.path().join("demo"));
+        config.build_system = BuildSystem::CMake;
+        config.cmake_generator = CmakeGenerator::Ninja;
+
+        let steps = ProjectBuilder::new(config).next_steps_for(Shell::Posix, Some(temp_dir.path()));
+        assert_eq!(
+            steps,
+            vec![
+                "cd demo",
+                "cmake --preset Debug",
+                "cmake --build --preset Debug"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_source_date() {
+        assert_eq!(
+            source_date(Some("1700000000")),
+            NaiveDate::from_ymd_opt(2023, 11, 14)
+        );
+        assert_eq!(
+            source_date(Some(" 0\n")),
+            NaiveDate::from_ymd_opt(1970, 1, 1)
+        );
+        assert_eq!(source_date(Some("yesterday")), None);
+        assert_eq!(source_date(None), None);
+    }
+
+    #[test]
+    fn test_shell_quote() {
+        assert_eq!(Shell::Posix.quote("build/demo-1.0"), "build/demo-1.0");
+        assert_eq!(Shell::Posix.quote("my dir"), "'my dir'");
+        assert_eq!(Shell::Posix.quote("it's"), r"'it'\''s'");
+        assert_eq!(Shell::Posix.quote(r"C:\dev"), r"'C:\dev'");
+        assert_eq!(Shell::PowerShell.quote(r"C:\dev\demo"), r"C:\dev\demo");
+        assert_eq!(Shell::PowerShell.quote("it's here"), "'it''s here'");
+        assert_eq!(Shell::Posix.quote(""), "''");
+    }
+
+    #[test]
+    fn test_build_and_test_steps() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let mut config = minimal_config(temp_dir.path().join("steps-project"));
+        config.test_framework = TestFramework::Doctest;
+        assert_eq!(build_steps(&config, Shell::Posix), vec!["make"]);
+        assert_eq!(test_steps(&config), vec!["make test"]);
+
+        config.build_system = BuildSystem::CMake;
+        config.package_manager = PackageManager::Vcpkg;
+        assert_eq!(
+            build_steps(&config, Shell::Posix),
+            vec![
+                "cmake -S . -B build -DCMAKE_TOOLCHAIN_FILE=${VCPKG_ROOT}/scripts/buildsystems/vcpkg.cmake",
+                "cmake --build build",
+            ]
+        );
+        assert_eq!(test_steps(&config), vec!["ctest --test-dir build"]);
+
+        config.package_manager = PackageManager::Conan;
+        config.cmake_generator = CmakeGenerator::NinjaMultiConfig;
+        assert_eq!(
+            build_steps(&config, Shell::Posix),
+            vec![
+                "conan install . --build=missing",
+                "cmake --preset default -DCMAKE_TOOLCHAIN_FILE=build/Release/generators/conan_toolchain.cmake",
+                "cmake --build --preset Debug",
+            ]
+        );
+        assert_eq!(test_steps(&config), vec!["ctest --test-dir build -C Debug"]);
+
+        config.cmake_generator = CmakeGenerator::Ninja;
+        assert_eq!(test_steps(&config), vec!["ctest --test-dir build/Debug"]);
+
+        config.test_framework = TestFramework::None;
+        assert!(test_steps(&config).is_empty());
+
+        let builder = ProjectBuilder::new(config.clone());
+        assert_eq!(
+            builder.next_steps()[1..],
+            build_steps(&config, Shell::Posix)[..]
+        );
+        assert_eq!(
+            builder.template_data.build_steps,
+            build_steps(&config, Shell::Posix)
+        );
+    }
+
+    #[test]
+    fn test_verify_commands() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let mut config = minimal_config(temp_dir.path().join("verify-project"));
+        let commands = ProjectBuilder::new(config.clone())
+            .verify_commands()
+            .unwrap();
+        assert_eq!(commands, vec![("make", vec!["-n".to_string()])]);
+
+        config.build_system = BuildSystem::CMake;
+        config.package_manager = PackageManager::Conan;
+        let commands = ProjectBuilder::new(config).verify_commands().unwrap();
+        let commands: Vec<String> = commands
+            .into_iter()
+            .map(|(program, args)| format!("{} {}", program, args.join(" ")))
+            .collect();
+        assert_eq!(
+            commands,
+            vec![
+                "conan install . --build=missing",
+                "cmake -S . -B build -DCMAKE_TOOLCHAIN_FILE=build/Release/generators/conan_toolchain.cmake",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_verify_skips_missing_tools() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let mut config = minimal_config(temp_dir.path().join("conan-project"));
+        config.build_system = BuildSystem::CMake;
+        config.package_manager = PackageManager::Conan;
+        let runner = Arc::new(
+            MockRunner::new(|_: &ToolCommand| Ok(CommandOutput::ok(""))).missing(["cmake"]),
+        );
+
+        let outcome = ProjectBuilder::new(config)
+            .with_command_runner(runner.clone())
+            .verify()
+            .unwrap();
+        assert_eq!(
+            outcome,
+            VerifyOutcome::Skipped(
+                "Warning: skipping verification: cmake is not installed".to_string()
+            )
+        );
+        assert!(runner.calls().is_empty());
+    }
+
+    #[test]
+    fn test_verify_make_project() {
+        if !SystemRunner.is_installed("make") {
+            return;
+        }
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let builder = ProjectBuilder::new(minimal_config(temp_dir.path().join("make-project")));
+        builder.build().unwrap();
+
+        match builder.verify().unwrap() {
+            VerifyOutcome::Passed(commands) => {
+                assert_eq!(commands.len(), 1);
+                assert_eq!(commands[0].to_string(), "make -n");
+                assert!(commands[0].success);
+            }
+            VerifyOutcome::Skipped(warning) => panic!("unexpected skip: {}", warning),
+        }
+    }
+
+    #[test]
+    fn test_build_commands() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let mut config = minimal_config(temp_dir.path().join("build-project"));
+        let commands = ProjectBuilder::new(config.clone())
+            .with_jobs(Some(4))
+            .build_commands()
+            .unwrap();
+        assert_eq!(
+            commands,
+            vec![("make", vec!["-j".to_string(), "4".to_string()])]
+        );
+
+        config.build_system = BuildSystem::CMake;
+        config.cmake_generator = CmakeGenerator::Ninja;
+        config.test_framework = TestFramework::Doctest;
+        let commands: Vec<String> = ProjectBuilder::new(config)
+            .build_commands()
+            .unwrap()
+            .into_iter()
+            .map(|(program, args)| format!("{} {}", program, args.join(" ")))
+            .collect();
+        assert_eq!(
+            commands,
+            vec![
+                "cmake -S . -B build -G Ninja",
+                "cmake --build build --config Debug",
+                "ctest --test-dir build -C Debug --output-on-failure",
+            ]
+        );
+    }
 }
@@ -0,0 +1,571 @@
+//! C++ stubs generated from an OpenAPI spec for library projects.
+//!
+//! [`load_spec`] reads the paths of an OpenAPI 3 (or Swagger 2) document and
+//! groups their operations by the first path segment. Each group becomes a
+//! header in `include/api/` declaring one function per operation. Only
+//! scalar parameters (strings, integers, numbers and booleans) are passed;
+//! others are listed in a comment above the declaration.
+
+use crate::templates::{snake_case, ProjectTemplateData};
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+/// Keys of a path item that hold operations.
+const METHODS: [&str; 8] = [
+    "get", "put", "post", "delete", "options", "head", "patch", "trace",
+];
+
+/// C++ keywords that cannot be used as function or parameter names.
+const CPP_KEYWORDS: &[&str] = &[
+    "alignas",
+    "alignof",
+    "and",
+    "asm",
+    "auto",
+    "bool",
+    "break",
+    "case",
+    "catch",
+    "char",
+    "class",
+    "const",
+    "constexpr",
+    "continue",
+    "default",
+    "delete",
+    "do",
+    "double",
+    "else",
+    "enum",
+    "explicit",
+    "export",
+    "extern",
+    "false",
+    "float",
+    "for",
+    "friend",
+    "goto",
+    "if",
+    "inline",
+    "int",
+    "long",
+    "mutable",
+    "namespace",
+    "new",
+    "noexcept",
+    "not",
+    "nullptr",
+    "operator",
+    "or",
+    "private",
+    "protected",
+    "public",
+    "register",
+    "return",
+    "short",
+    "signed",
+    "sizeof",
+    "static",
+    "struct",
+    "switch",
+    "template",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "typedef",
+    "typename",
+    "union",
+    "unsigned",
+    "using",
+    "virtual",
+    "void",
+    "volatile",
+    "while",
+    "xor",
+];
+
+/// Endpoints of an OpenAPI spec, grouped into headers.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ApiSpec {
+    /// `info.title` of the spec
+    pub title: String,
+    /// `info.version` of the spec
+    pub version: String,
+    /// Headers to generate, ordered by name
+    pub groups: Vec<ApiGroup>,
+}
+
+/// Operations declared in one header, e.g. everything under `/pets`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ApiGroup {
+    /// Header file name without extension
+    pub name: String,
+    /// Operations in path order
+    pub operations: Vec<ApiOperation>,
+}
+
+/// One HTTP operation, declared as a C++ function.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ApiOperation {
+    /// C++ function name, from `operationId` or the method and path
+    pub function: String,
+    /// HTTP method in upper case
+    pub method: String,
+    /// Path as written in the spec, e.g. `/pets/{petId}`
+    pub path: String,
+    /// `summary` of the operation, if any
+    pub summary: String,
+    /// Scalar parameters passed to the function
+    pub parameters: Vec<ApiParameter>,
+    /// Names of the parameters left out because they are not scalars
+    pub skipped: Vec<String>,
+}
+
+/// A scalar operation parameter.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ApiParameter {
+    /// C++ parameter name
+    pub name: String,
+    /// Where the value is sent: "path", "query", "header" or "cookie"
+    pub location: String,
+    /// Whether the spec marks the parameter as required
+    pub required: bool,
+    /// Type as written in the declaration, e.g. `const std::string&`
+    pub declaration: String,
+}
+
+/// Template data of one `include/api/` header.
+#[derive(Serialize)]
+pub(super) struct ApiHeader<'a> {
+    #[serde(flatten)]
+    pub project: &'a ProjectTemplateData,
+    pub api_title: &'a str,
+    pub api_version: &'a str,
+    pub api_group: &'a ApiGroup,
+}
+
+#[derive(Deserialize)]
+struct RawSpec {
+    openapi: Option<String>,
+    swagger: Option<String>,
+    #[serde(default)]
+    info: RawInfo,
+    #[serde(default)]
+    paths: BTreeMap<String, BTreeMap<String, serde_yaml::Value>>,
+}
+
+#[derive(Deserialize, Default)]
+struct RawInfo {
+    #[serde(default)]
+    title: String,
+    #[serde(default)]
+    version: String,
+}
+
+#[derive(Deserialize)]
+struct RawOperation {
+    #[serde(rename = "operationId")]
+    operation_id: Option<String>,
+    #[serde(default)]
+    summary: String,
+    #[serde(default)]
+    parameters: Vec<RawParameter>,
+}
+
+#[derive(Deserialize, Clone)]
+struct RawParameter {
+    name: Option<String>,
+    #[serde(rename = "in", default)]
+    location: String,
+    #[serde(default)]
+    required: bool,
+    schema: Option<RawSchema>,
+    /// Swagger 2 puts the type on the parameter itself
+    #[serde(flatten)]
+    inline: RawSchema,
+    #[serde(rename = "$ref")]
+    reference: Option<String>,
+}
+
+#[derive(Deserialize, Clone, Default)]
+struct RawSchema {
+    #[serde(rename = "type")]
+    kind: Option<String>,
+    format: Option<String>,
+}
+
+/// Reads and parses the OpenAPI spec at `path`. With `use_optional`,
+/// parameters that are not required are passed as `std::optional` (C++17).
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be read, is not YAML or JSON, is not
+/// an OpenAPI document, or defines no operations.
+pub fn load_spec(path: &Path, use_optional: bool) -> Result<ApiSpec> {
+    let text = fs::read_to_string(path)
+        .with_context(|| format!("Failed to read OpenAPI spec {}", path.display()))?;
+    parse_spec(&text, use_optional)
+        .with_context(|| format!("Invalid OpenAPI spec {}", path.display()))
+}
+
+/// Parses an OpenAPI spec from YAML or JSON text.
+pub(super) fn parse_spec(text: &str, use_optional: bool) -> Result<ApiSpec> {
+    let document: serde_yaml::Value =
+        serde_yaml::from_str(text).context("Not a valid YAML document")?;
+    let raw: RawSpec = serde_yaml::from_value(document).context("Not a valid OpenAPI document")?;
+    let version = raw.openapi.as_deref().or(raw.swagger.as_deref());
+    if !version.is_some_and(|version| version.starts_with('3') || version.starts_with('2')) {
+        return Err(anyhow::anyhow!(
+            "Missing a supported `openapi` (3.x) or `swagger` (2.0) version field"
+        ));
+    }
+
+    let mut groups: BTreeMap<String, Vec<ApiOperation>> = BTreeMap::new();
+    // All headers share one namespace, so function names must be unique
+    let mut functions: Vec<String> = Vec::new();
+    for (path, item) in &raw.paths {
+        let shared: Vec<RawParameter> = match item.get("parameters") {
+            Some(value) => serde_yaml::from_value(value.clone())
+                .with_context(|| format!("Invalid parameters of {}", path))?,
+            None => Vec::new(),
+        };
+        for method in METHODS {
+            let Some(value) = item.get(method) else {
+                continue;
+            };
+            let operation: RawOperation = serde_yaml::from_value(value.clone())
+                .with_context(|| format!("Invalid operation {} {}", method, path))?;
+            let function = operation
+                .operation_id
+                .as_deref()
+                .map(identifier)
+                .unwrap_or_else(|| default_function_name(method, path));
+            let function = unique_name(function, functions.iter());
+            functions.push(function.clone());
+            let (parameters, skipped) =
+                convert_parameters(&shared, &operation.parameters, use_optional);
+            groups
+                .entry(group_name(path))
+                .or_default()
+                .push(ApiOperation {
+                    function,
+                    method: method.to_uppercase(),
+                    path: path.clone(),
+                    summary: operation.summary.trim().replace('\n', " "),
+                    parameters,
+                    skipped,
+                });
+        }
+    }
+    if groups.is_empty() {
+        return Err(anyhow::anyhow!("No operations found under `paths`"));
+    }
+
+    Ok(ApiSpec {
+        title: raw.info.title,
+        version: raw.info.version,
+        groups: groups
+            .into_iter()
+            .map(|(name, operations)| ApiGroup { name, operations })
+            .collect(),
+    })
+}
+
+/// Header name for `path`: its first literal segment, e.g. `pets` for
+/// `/pets/{petId}`, or `root` if there is none.
+fn group_name(path: &str) -> String {
+    path.split('/')
+        .find(|segment| !segment.is_empty() && !segment.starts_with('{'))
+        .map(snake_case)
+        .filter(|name| !name.is_empty())
+        .unwrap_or_else(|| "root".to_string())
+}
+
+/// Function name for an operation without `operationId`, e.g.
+/// `get_pets_by_pet_id` for `GET /pets/{petId}`.
+fn default_function_name(method: &str, path: &str) -> String {
+    let mut words = vec![method.to_string()];
+    for segment in path.split('/').filter(|segment| !segment.is_empty()) {
+        match segment.strip_prefix('{').and_then(|s| s.strip_suffix('}')) {
+            Some(parameter) => words.push(format!("by_{}", snake_case(parameter))),
+            None => words.push(snake_case(segment)),
+        }
+    }
+    identifier(&words.join("_"))
+}
+
+/// Converts `name` into a snake_case C++ identifier that is not a keyword.
+fn identifier(name: &str) -> String {
+    let mut identifier = snake_case(name);
+    if identifier.is_empty() || identifier.starts_with(|c: char| c.is_ascii_digit()) {
+        identifier.insert_str(0, "op_");
+    }
+    if CPP_KEYWORDS.contains(&identifier.as_str()) {
+        identifier.push('_');
+    }
+    identifier
+}
+
+/// Appends `_2`, `_3`, ... to `name` until it differs from `taken`.
+fn unique_name<'a>(name: String, taken: impl Iterator<Item = &'a String> + Clone) -> String {
+    let mut candidate = name.clone();
+    let mut suffix = 2;
+    while taken.clone().any(|existing| *existing == candidate) {
+        // A keyword's trailing '_' is dropped to avoid a reserved `__`
+        candidate = format!("{}_{}", name.trim_end_matches('_'), suffix);
+        suffix += 1;
+    }
+    candidate
+}
+
+/// Merges path-level and operation parameters (the operation wins for the
+/// same name and location) and splits them into scalar parameters and the
+/// names of the others.
+fn convert_parameters(
+    shared: &[RawParameter],
+    own: &[RawParameter],
+    use_optional: bool,
+) -> (Vec<ApiParameter>, Vec<String>) {
+    let mut merged: Vec<RawParameter> = Vec::new();
+    for parameter in shared.iter().chain(own) {
+        let same = |other: &RawParameter| {
+            other.name == parameter.name && other.location == parameter.location
+        };
+        match merged.iter_mut().find(|other| same(other)) {
+            Some(existing) if parameter.reference.is_none() => *existing = parameter.clone(),
+            _ => merged.push(parameter.clone()),
+        }
+    }
+
+    let mut parameters: Vec<ApiParameter> = Vec::new();
+    let mut skipped = Vec::new();
+    for parameter in merged {
+        let label = parameter
+            .name
+            .clone()
+            .or(parameter.reference.clone())
+            .unwrap_or_default();
+        let schema = parameter.schema.as_ref().unwrap_or(&parameter.inline);
+        let cpp_type = match (&parameter.name, parameter.location.as_str()) {
+            (Some(_), "path" | "query" | "header" | "cookie") => scalar_type(schema),
+            _ => None,
+        };
+        let Some((cpp_type, by_reference)) = cpp_type else {
+            skipped.push(label);
+            continue;
+        };
+
+        // Path parameters are always required
+        let required = parameter.required || parameter.location == "path";
+        let value_type = if required || !use_optional {
+            cpp_type.to_string()
+        } else {
+            format!("std::optional<{}>", cpp_type)
+        };
+        let declaration = if by_reference || value_type.starts_with("std::optional") {
+            format!("const {}&", value_type)
+        } else {
+            value_type
+        };
+        let name = unique_name(identifier(&label), parameters.iter().map(|p| &p.name));
+        parameters.push(ApiParameter {
+            name,
+            location: parameter.location,
+            required,
+            declaration,
+        });
+    }
+    (parameters, skipped)
+}
+
+/// C++ type for a scalar schema, and whether it is passed by reference.
+fn scalar_type(schema: &RawSchema) -> Option<(&'static str, bool)> {
+    match (schema.kind.as_deref()?, schema.format.as_deref()) {
+        ("string", _) => Some(("std::string", true)),
+        ("integer", Some("int32")) => Some(("std::int32_t", false)),
+        ("integer", _) => Some(("std::int64_t", false)),
+        ("number", Some("float")) => Some(("float", false)),
+        ("number", _) => Some(("double", false)),
+        ("boolean", _) => Some(("bool", false)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PETSTORE: &str = r#"
+openapi: 3.0.3
+info:
+  title: Petstore
+  version: 1.0.0
+paths:
+  /pets:
+    get:
+      operationId: listPets
+      summary: List all pets
+      parameters:
+        - name: limit
+          in: query
+          schema:
+            type: integer
+            format: int32
+        - name: tags
+          in: query
+          schema:
+            type: array
+            items:
+              type: string
+    post:
+      summary: Create a pet
+      requestBody:
+        content:
+          application/json: {}
+  /pets/{petId}:
+    parameters:
+      - name: petId
+        in: path
+        required: true
+        schema:
+          type: string
+    delete:
+      parameters:
+        - name: force
+          in: header
+          required: true
+          schema:
+            type: boolean
+  /:
+    get:
+      operationId: default
+"#;
+
+    #[test]
+    fn test_parse_spec_groups_operations() {
+        let spec = parse_spec(PETSTORE, true).unwrap();
+        assert_eq!(spec.title, "Petstore");
+        assert_eq!(spec.version, "1.0.0");
+        let names: Vec<&str> = spec.groups.iter().map(|g| g.name.as_str()).collect();
+        assert_eq!(names, ["pets", "root"]);
+
+        let pets = &spec.groups[0].operations;
+        let functions: Vec<&str> = pets.iter().map(|op| op.function.as_str()).collect();
+        assert_eq!(
+            functions,
+            ["list_pets", "post_pets", "delete_pets_by_pet_id"]
+        );
+        assert_eq!(pets[0].method, "GET");
+        assert_eq!(pets[0].summary, "List all pets");
+        assert_eq!(spec.groups[1].operations[0].function, "default_");
+    }
+
+    #[test]
+    fn test_parse_spec_parameters() {
+        let spec = parse_spec(PETSTORE, true).unwrap();
+        let pets = &spec.groups[0].operations;
+
+        let list = &pets[0];
+        assert_eq!(list.parameters.len(), 1);
+        assert_eq!(list.parameters[0].name, "limit");
+        assert_eq!(
+            list.parameters[0].declaration,
+            "const std::optional<std::int32_t>&"
+        );
+        assert_eq!(list.skipped, ["tags"]);
+
+        let delete = &pets[2];
+        let declarations: Vec<(&str, &str)> = delete
+            .parameters
+            .iter()
+            .map(|p| (p.name.as_str(), p.declaration.as_str()))
+            .collect();
+        assert_eq!(
+            declarations,
+            [("pet_id", "const std::string&"), ("force", "bool")]
+        );
+
+        let spec = parse_spec(PETSTORE, false).unwrap();
+        assert_eq!(
+            spec.groups[0].operations[0].parameters[0].declaration,
+            "std::int32_t"
+        );
+    }
+
+    #[test]
+    fn test_parse_spec_swagger_inline_types() {
+        let spec = parse_spec(
+            r#"{"swagger": "2.0", "paths": {"/users/{id}": {"get": {"parameters": [
+                {"name": "id", "in": "path", "type": "integer"},
+                {"name": "body", "in": "body", "schema": {"type": "object"}}
+            ]}}}}"#,
+            true,
+        )
+        .unwrap();
+        let operation = &spec.groups[0].operations[0];
+        assert_eq!(operation.function, "get_users_by_id");
+        assert_eq!(operation.parameters[0].declaration, "std::int64_t");
+        assert_eq!(operation.skipped, ["body"]);
+    }
+
+    #[test]
+    fn test_parse_spec_errors() {
+        let error = parse_spec("paths: [unclosed", true).unwrap_err();
+        assert_eq!(error.to_string(), "Not a valid YAML document");
+
+        let error = parse_spec("paths: [a, b]\n", true).unwrap_err();
+        assert_eq!(error.to_string(), "Not a valid OpenAPI document");
+
+        let error = parse_spec("info:\n  title: x\n", true).unwrap_err();
+        assert!(error.to_string().contains("`openapi`"));
+
+        let error = parse_spec("openapi: 3.1.0\npaths: {}\n", true).unwrap_err();
+        assert_eq!(error.to_string(), "No operations found under `paths`");
+    }
+
+    #[test]
+    fn test_duplicate_names_are_made_unique() {
+        let spec = parse_spec(
+            r#"
+openapi: 3.0.0
+paths:
+  /items:
+    get:
+      operationId: fetch
+      parameters:
+        - {name: class, in: query, schema: {type: string}}
+        - {name: Class, in: header, schema: {type: string}}
+  /items/all:
+    get:
+      operationId: fetch
+  /other:
+    get:
+      operationId: fetch
+"#,
+            false,
+        )
+        .unwrap();
+        let operations = &spec.groups[0].operations;
+        assert_eq!(operations[1].function, "fetch_2");
+        assert_eq!(spec.groups[1].operations[0].function, "fetch_3");
+        let names: Vec<&str> = operations[0]
+            .parameters
+            .iter()
+            .map(|p| p.name.as_str())
+            .collect();
+        assert_eq!(names, ["class_", "class_2"]);
+    }
+
+    #[test]
+    fn test_load_spec_missing_file() {
+        let error = load_spec(Path::new("/nonexistent/api.yaml"), true).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Failed to read OpenAPI spec /nonexistent/api.yaml"
+        );
+    }
+}
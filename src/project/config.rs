@@ -1,13 +1,32 @@
-use super::{BuildSystem, CodeFormatter, License, PackageManager, QualityConfig, TestFramework};
+use super::presets::Preset;
+use super::{codegen, github, last_run, prompts};
+use super::{
+    BuildSystem, CiProvider, CmakeGenerator, CodeFormatter, CoverageService, CppExtension,
+    DocEngine, HppExtension, IdeConfig, License, LineEndings, PackageManager, QualityConfig,
+    RepoVisibility, SanitizerPreset, Snippet, TargetPlatform, TestFramework, TestRunner,
+    VcpkgFeature,
+};
 use crate::cli::Cli;
+use crate::error::CppupError;
+use crate::validation::validate_project_name;
 use anyhow::{Context, Result};
-use inquire::validator::Validation;
-use inquire::{Confirm, MultiSelect, Select, Text};
+use clap::{ArgAction, CommandFactory, Parser};
+use inquire::InquireError;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Component, Path, PathBuf};
+use std::str::FromStr;
 
 const DEFAULT_VERSION: &str = "0.1.0";
 const DEFAULT_DESCRIPTION: &str = "A C++ project generated with cppup";
+const DEFAULT_GIT_BRANCH: &str = "main";
+const DEFAULT_ANDROID_ABI: &str = "arm64-v8a";
+const DEFAULT_ANDROID_API: u32 = 24;
+/// ABIs supported by the Android NDK.
+const ANDROID_ABIS: [&str; 4] = ["armeabi-v7a", "arm64-v8a", "x86", "x86_64"];
+/// Lowest API level the current NDKs build for.
+const MIN_ANDROID_API: u32 = 21;
 
 /// Complete configuration for a C++ project.
 ///
@@ -26,7 +45,21 @@ const DEFAULT_DESCRIPTION: &str = "A C++ project generated with cppup";
 /// // let cli = Cli::parse();
 /// // let config = ProjectConfig::new(Some(&cli))?;
 /// ```
-#[derive(Debug, Clone)]
+///
+/// Without validation, starting from the defaults:
+///
+/// ```
+/// use cppup::{BuildSystem, ProjectConfig};
+///
+/// let config = ProjectConfig {
+///     name: "foo".into(),
+///     path: "projects/foo".into(),
+///     build_system: BuildSystem::Meson,
+///     ..Default::default()
+/// };
+/// assert!(config.use_git);
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProjectConfig {
     /// Project name (used for directory and CMake project name)
     pub name: String,
@@ -36,16 +69,56 @@ pub struct ProjectConfig {
     pub project_type: ProjectType,
     /// Build system to use
     pub build_system: BuildSystem,
+    /// CMake generator used by the presets file
+    pub cmake_generator: CmakeGenerator,
     /// C++ standard version
     pub cpp_standard: CppStandard,
+    /// Extension of C++ source files
+    pub cpp_extension: CppExtension,
+    /// Extension of C++ header files
+    pub hpp_extension: HppExtension,
+    /// Line endings of the generated files
+    pub line_endings: LineEndings,
+    /// Sanitizers enabled by the generated CMake files
+    pub sanitizer_preset: SanitizerPreset,
     /// Testing framework
     pub test_framework: TestFramework,
+    /// How the generated test executables are launched
+    pub test_runner: TestRunner,
     /// Package manager for dependencies
     pub package_manager: PackageManager,
     /// License type
     pub license: License,
     /// Whether to initialize a git repository
     pub use_git: bool,
+    /// Whether to commit the generated files when git is used
+    pub git_commit: bool,
+    /// Whether to create a repository even inside an existing one
+    pub force_git: bool,
+    /// Name of the initial git branch
+    pub git_branch: String,
+    /// URL added as the `origin` remote
+    pub git_remote: Option<String>,
+    /// Visibility of the GitHub repository to create, if one is wanted
+    pub github_repo: Option<RepoVisibility>,
+    /// Whether to create a `docs/` directory with placeholder pages
+    pub generate_docs_dir: bool,
+    /// Engine used for the `docs/` directory
+    pub doc_engine: DocEngine,
+    /// Number of example programs generated for a library
+    pub num_examples: usize,
+    /// OpenAPI spec that `include/api/` stubs are generated from
+    pub openapi_spec: Option<PathBuf>,
+    /// Whether to create a `CHANGELOG.md`
+    pub generate_changelog: bool,
+    /// Whether to create `CONTRIBUTING.md` and `CODE_OF_CONDUCT.md`
+    pub generate_community_files: bool,
+    /// CI pipeline generated with the project
+    pub ci: CiProvider,
+    /// Whether to create `.github/dependabot.yml` for GitHub Actions CI
+    pub enable_dependabot: bool,
+    /// Project homepage URL, without a trailing slash
+    pub homepage: Option<String>,
     /// Directory path where the project will be created
     pub path: PathBuf,
     /// Project author name
@@ -56,14 +129,113 @@ pub struct ProjectConfig {
     pub quality_config: QualityConfig,
     /// Code formatter configuration
     pub code_formatter: CodeFormatter,
+    /// IDE project files configuration
+    pub ide: IdeConfig,
+    /// Code snippets copied into `include/snippets/`
+    pub snippets: Vec<Snippet>,
+    /// Owners written to `CODEOWNERS`, e.g. `@org/cpp-team` (none: no file)
+    pub codeowners: Vec<String>,
+    /// Conan package options as `pkg:option=value`
+    pub conan_options: Vec<String>,
+    /// Optional features of `vcpkg.json`, by name
+    pub vcpkg_features: HashMap<String, VcpkgFeature>,
+    /// Git repository of the default vcpkg registry, written to
+    /// `vcpkg-configuration.json` (none: the built-in registry)
+    pub vcpkg_default_registry: Option<String>,
+    /// Commit of the default vcpkg registry to take the versions from
+    pub vcpkg_baseline: Option<String>,
+    /// Preprocessor definitions for every target, as `NAME` or `NAME=VALUE`
+    pub compile_definitions: Vec<String>,
+    /// Whether release builds use Link Time Optimization
+    pub enable_lto: bool,
+    /// Service the test coverage is uploaded to
+    pub coverage_service: CoverageService,
+    /// Platform the project is configured for
+    pub target_platform: TargetPlatform,
+    /// Android ABI of the Android CMake preset
+    pub android_abi: String,
+    /// Android API level passed to the NDK as `ANDROID_PLATFORM`
+    pub android_api: u32,
+    /// Whether the executable is built as a macOS application bundle
+    pub macos_bundle: bool,
+    /// Bundle identifier of the macOS bundle, e.g. `com.example.app`
+    pub bundle_id: Option<String>,
+    /// Whether a preset builds universal (arm64 and x86_64) macOS binaries
+    pub macos_universal: bool,
+    /// Extra variables passed to templates alongside the generated data
+    pub extra_vars: HashMap<String, String>,
+}
+
+impl Default for ProjectConfig {
+    /// The settings a project gets when nothing is chosen, with an empty
+    /// name and path. Unlike [`ProjectConfigBuilder::build`], nothing is
+    /// validated.
+    fn default() -> Self {
+        ProjectConfig {
+            name: String::new(),
+            description: DEFAULT_DESCRIPTION.to_string(),
+            project_type: ProjectType::default(),
+            build_system: BuildSystem::default(),
+            cmake_generator: CmakeGenerator::default(),
+            cpp_standard: CppStandard::default(),
+            cpp_extension: CppExtension::default(),
+            hpp_extension: HppExtension::default(),
+            line_endings: LineEndings::default(),
+            sanitizer_preset: SanitizerPreset::default(),
+            test_framework: TestFramework::default(),
+            test_runner: TestRunner::default(),
+            package_manager: PackageManager::default(),
+            license: License::default(),
+            use_git: true,
+            git_commit: true,
+            force_git: false,
+            git_branch: DEFAULT_GIT_BRANCH.to_string(),
+            git_remote: None,
+            github_repo: None,
+            generate_docs_dir: false,
+            doc_engine: DocEngine::default(),
+            num_examples: 1,
+            openapi_spec: None,
+            generate_changelog: false,
+            generate_community_files: false,
+            ci: CiProvider::default(),
+            enable_dependabot: false,
+            homepage: None,
+            path: PathBuf::new(),
+            author: default_author(),
+            version: DEFAULT_VERSION.to_string(),
+            quality_config: QualityConfig::default(),
+            code_formatter: CodeFormatter::default(),
+            ide: IdeConfig::default(),
+            snippets: Vec::new(),
+            codeowners: Vec::new(),
+            conan_options: Vec::new(),
+            vcpkg_features: HashMap::new(),
+            vcpkg_default_registry: None,
+            vcpkg_baseline: None,
+            compile_definitions: Vec::new(),
+            enable_lto: false,
+            coverage_service: CoverageService::default(),
+            target_platform: TargetPlatform::default(),
+            android_abi: DEFAULT_ANDROID_ABI.to_string(),
+            android_api: DEFAULT_ANDROID_API,
+            macos_bundle: false,
+            bundle_id: None,
+            macos_universal: false,
+            extra_vars: HashMap::new(),
+        }
+    }
 }
 
 /// Type of C++ project to generate.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub enum ProjectType {
     /// Standard executable application
+    #[serde(rename = "executable")]
+    #[default]
     Executable,
     /// Static or dynamic library
+    #[serde(rename = "library")]
     Library,
 }
 
@@ -76,18 +248,36 @@ impl std::fmt::Display for ProjectType {
     }
 }
 
+impl FromStr for ProjectType {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "executable" => Ok(ProjectType::Executable),
+            "library" => Ok(ProjectType::Library),
+            _ => Err(anyhow::anyhow!("Unknown project type: {}", value)),
+        }
+    }
+}
+
 /// C++ language standard version.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub enum CppStandard {
     /// C++11 standard
+    #[serde(rename = "11")]
     Cpp11,
     /// C++14 standard
+    #[serde(rename = "14")]
     Cpp14,
     /// C++17 standard
+    #[serde(rename = "17")]
+    #[default]
     Cpp17,
     /// C++20 standard
+    #[serde(rename = "20")]
     Cpp20,
     /// C++23 standard
+    #[serde(rename = "23")]
     Cpp23,
 }
 
@@ -103,499 +293,2268 @@ impl std::fmt::Display for CppStandard {
     }
 }
 
+impl CppStandard {
+    /// Returns the newest supported standard.
+    #[allow(dead_code)]
+    pub fn latest() -> CppStandard {
+        CppStandard::Cpp23
+    }
+
+    /// Returns the oldest supported standard.
+    #[allow(dead_code)]
+    pub fn oldest() -> CppStandard {
+        CppStandard::Cpp11
+    }
+
+    /// Returns the year the standard was published, which orders standards.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cppup::CppStandard;
+    ///
+    /// assert_eq!(CppStandard::Cpp17.year(), 2017);
+    /// assert!(CppStandard::Cpp14.year() < CppStandard::latest().year());
+    /// ```
+    pub fn year(&self) -> u16 {
+        match self {
+            CppStandard::Cpp11 => 2011,
+            CppStandard::Cpp14 => 2014,
+            CppStandard::Cpp17 => 2017,
+            CppStandard::Cpp20 => 2020,
+            CppStandard::Cpp23 => 2023,
+        }
+    }
+
+    /// Returns the oldest CMake release that knows the standard, i.e.
+    /// accepts it in `CMAKE_CXX_STANDARD` and as the `cxx_std_*` compile
+    /// feature.
+    pub fn cmake_min_version(&self) -> &'static str {
+        match self {
+            CppStandard::Cpp11 | CppStandard::Cpp14 => "3.1",
+            CppStandard::Cpp17 => "3.8",
+            CppStandard::Cpp20 => "3.12",
+            CppStandard::Cpp23 => "3.20",
+        }
+    }
+}
+
+impl FromStr for CppStandard {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "11" => Ok(CppStandard::Cpp11),
+            "14" => Ok(CppStandard::Cpp14),
+            "17" => Ok(CppStandard::Cpp17),
+            "20" => Ok(CppStandard::Cpp20),
+            "23" => Ok(CppStandard::Cpp23),
+            _ => Err(anyhow::anyhow!("Unknown C++ standard: {}", value)),
+        }
+    }
+}
+
 // Validation functions
-fn validate_project_name(name: &str) -> Result<()> {
+pub(super) fn validate_description(description: &str) -> Result<()> {
+    if description.chars().count() > 500 {
+        return Err(anyhow::anyhow!(
+            "Description is too long (maximum 500 characters)"
+        ));
+    }
+    if description.contains(['\n', '\r']) {
+        return Err(anyhow::anyhow!("Description cannot contain line breaks"));
+    }
+    Ok(())
+}
+
+pub(super) fn validate_author(author: &str) -> Result<()> {
+    if author.trim().is_empty() {
+        return Err(anyhow::anyhow!("Author cannot be empty"));
+    }
+    Ok(())
+}
+
+/// Checks `name` against git's rules for branch names, as enforced by
+/// `git check-ref-format --branch`.
+pub(super) fn validate_branch_name(name: &str) -> Result<()> {
+    let invalid = |reason: &str| {
+        Err(anyhow::anyhow!(
+            "Invalid git branch name '{}': {}",
+            name,
+            reason
+        ))
+    };
+
     if name.is_empty() {
-        return Err(anyhow::anyhow!("Project name cannot be empty"));
+        return invalid("it cannot be empty");
     }
-    if name.len() > 100 {
-        return Err(anyhow::anyhow!("Project name is too long"));
+    if name.starts_with('-') {
+        return invalid("it cannot start with '-'");
     }
-    if name.starts_with(|c: char| c.is_numeric()) {
-        return Err(anyhow::anyhow!("Project name cannot start with a number"));
+    if name == "@" {
+        return invalid("it cannot be '@'");
     }
-    if !name
+    if let Some(c) = name
         .chars()
-        .all(|c| c.is_alphanumeric() || c == '-' || c == '_')
+        .find(|c| c.is_ascii_control() || " ~^:?*[\\".contains(*c))
     {
-        return Err(anyhow::anyhow!(
-            "Project name can only contain alphanumeric characters, '-' and '_'"
-        ));
+        return invalid(&format!("it cannot contain {:?}", c));
+    }
+    if name.contains("..") || name.contains("@{") {
+        return invalid("it cannot contain '..' or '@{'");
+    }
+    if name.ends_with('.') || name.ends_with(".lock") {
+        return invalid("it cannot end with '.' or '.lock'");
+    }
+    for component in name.split('/') {
+        if component.is_empty() {
+            return invalid("it cannot start or end with '/' or contain '//'");
+        }
+        if component.starts_with('.') || component.ends_with(".lock") {
+            return invalid("its components cannot start with '.' or end with '.lock'");
+        }
     }
     Ok(())
 }
 
-fn validate_project_path(path: &PathBuf) -> Result<()> {
-    if !path.exists() {
+/// Loosely checks that `remote` is an https, ssh or scp-like
+/// (`user@host:path`) git URL and returns it trimmed.
+pub(super) fn validate_git_remote(remote: &str, use_git: bool) -> Result<String> {
+    if !use_git {
+        return Err(anyhow::anyhow!("A git remote requires git to be enabled"));
+    }
+    let remote = remote.trim();
+    let has_host_and_path = |rest: &str| {
+        rest.split_once('/')
+            .is_some_and(|(host, path)| !host.is_empty() && !path.is_empty())
+    };
+    let is_url = if let Some(rest) = ["https://", "http://", "ssh://", "git://"]
+        .iter()
+        .find_map(|scheme| remote.strip_prefix(scheme))
+    {
+        has_host_and_path(rest)
+    } else {
+        remote.split_once(':').is_some_and(|(user_host, path)| {
+            user_host
+                .split_once('@')
+                .is_some_and(|(user, host)| !user.is_empty() && !host.is_empty())
+                && !path.is_empty()
+        })
+    };
+    if !is_url || remote.contains(char::is_whitespace) {
         return Err(anyhow::anyhow!(
-            "Directory doesn't exist: {}",
-            path.display()
+            "Git remote must be an ssh or https git URL: {}",
+            remote
         ));
     }
-    if !path.is_dir() {
+    Ok(remote.to_string())
+}
+
+fn github_visibility(cli: &Cli) -> RepoVisibility {
+    if cli.private {
+        RepoVisibility::Private
+    } else {
+        RepoVisibility::Public
+    }
+}
+
+/// Checks that a GitHub repository can be created: `gh` pushes the initial
+/// commit, and names the repository after a GitHub remote if one is given.
+pub(super) fn validate_github_repo(
+    github_repo: Option<&RepoVisibility>,
+    use_git: bool,
+    git_commit: bool,
+    git_remote: Option<&str>,
+) -> Result<()> {
+    if github_repo.is_none() {
+        return Ok(());
+    }
+    if !use_git || !git_commit {
         return Err(anyhow::anyhow!(
-            "Path is not a directory: {}",
-            path.display()
+            "Creating a GitHub repository requires git and the initial commit"
         ));
     }
-    // Check if we have write permissions
-    match fs::metadata(path) {
-        Ok(metadata) => {
-            if metadata.permissions().readonly() {
-                return Err(anyhow::anyhow!(
-                    "Directory is read-only: {}",
-                    path.display()
-                ));
-            }
-        }
-        Err(_) => {
+    if let Some(remote) = git_remote {
+        if github::repository_slug(remote).is_none() {
             return Err(anyhow::anyhow!(
-                "Cannot access directory: {}",
-                path.display()
-            ))
+                "The git remote must be a github.com URL to create a GitHub repository: {}",
+                remote
+            ));
         }
     }
     Ok(())
 }
 
-fn create_config_from_cli(cli: &Cli) -> Result<ProjectConfig> {
-    let name = cli
-        .name
-        .clone()
-        .context("Project name is required in non-interactive mode")?;
-
-    // Validate project name
-    validate_project_name(&name)?;
-
-    // Validate project path
-    validate_project_path(&cli.path)?;
-
-    let description = cli
-        .description
-        .clone()
-        .unwrap_or(DEFAULT_DESCRIPTION.to_string());
+/// Largest number of examples; their file names use two digits.
+const MAX_EXAMPLES: usize = 99;
 
-    let default_author = std::env::var("USER")
-        .or_else(|_| std::env::var("USERNAME")) // Try Windows username
-        .or_else(|_| Ok::<String, std::env::VarError>("Unknown".to_string()))
-        .unwrap();
-    let author = cli.author.clone().unwrap_or(default_author);
+/// Checks the number of examples. Only libraries get examples, so an
+/// executable accepts nothing but the default of one.
+pub(super) fn validate_num_examples(num_examples: usize, project_type: &ProjectType) -> Result<()> {
+    if !(1..=MAX_EXAMPLES).contains(&num_examples) {
+        return Err(anyhow::anyhow!(
+            "The number of examples must be between 1 and {}",
+            MAX_EXAMPLES
+        ));
+    }
+    if num_examples > 1 && *project_type != ProjectType::Library {
+        return Err(anyhow::anyhow!(
+            "Examples are only generated for library projects"
+        ));
+    }
+    Ok(())
+}
 
-    let project_type = match cli.project_type.as_deref() {
-        Some("executable") => ProjectType::Executable,
-        Some("library") => ProjectType::Library,
-        _ => {
+/// Checks that every code owner is a GitHub or GitLab handle such as
+/// `@user` or `@org/team`.
+pub(super) fn validate_codeowners(owners: &[String]) -> Result<()> {
+    for owner in owners {
+        let handle = owner.strip_prefix('@').unwrap_or_default();
+        if handle.is_empty() || owner.chars().any(char::is_whitespace) {
             return Err(anyhow::anyhow!(
-                "Project type is required in non-interactive mode"
-            ))
+                "Invalid code owner '{}': owners must start with '@', e.g. @org/cpp-team",
+                owner
+            ));
         }
-    };
-
-    let build_system = match cli.build_system.as_str() {
-        "cmake" => BuildSystem::CMake,
-        "make" => BuildSystem::Make,
-        _ => BuildSystem::CMake,
-    };
-
-    let cpp_standard = match cli.cpp_standard.as_str() {
-        "11" => CppStandard::Cpp11,
-        "14" => CppStandard::Cpp14,
-        "17" => CppStandard::Cpp17,
-        "20" => CppStandard::Cpp20,
-        "23" => CppStandard::Cpp23,
-        _ => CppStandard::Cpp17,
-    };
-
-    let path = cli.path.join(&name);
+    }
+    Ok(())
+}
 
-    // Check if project directory already exists
-    if path.exists() {
+/// Checks that Conan options are only given for Conan projects and have
+/// the form `pkg:option=value`.
+pub(super) fn validate_conan_options(
+    options: &[String],
+    package_manager: &PackageManager,
+) -> Result<()> {
+    if options.is_empty() {
+        return Ok(());
+    }
+    if *package_manager != PackageManager::Conan {
         return Err(anyhow::anyhow!(
-            "Project directory already exists: {}",
-            path.display()
+            "Conan options can only be set with the Conan package manager"
         ));
     }
+    for option in options {
+        let valid = option
+            .split_once(':')
+            .and_then(|(package, setting)| Some((package, setting.split_once('=')?)))
+            .is_some_and(|(package, (name, value))| {
+                [package, name, value]
+                    .iter()
+                    .all(|part| !part.is_empty() && !part.contains(char::is_whitespace))
+            });
+        if !valid {
+            return Err(anyhow::anyhow!(
+                "Invalid Conan option '{}': expected pkg:option=value, e.g. fmt:shared=True",
+                option
+            ));
+        }
+    }
+    Ok(())
+}
 
-    let package_manager = match cli.package_manager.as_str() {
-        "conan" => PackageManager::Conan,
-        "vcpkg" => PackageManager::Vcpkg,
-        _ => PackageManager::None,
-    };
-
-    let license = match cli.license.as_str() {
-        "MIT" => License::MIT,
-        "Apache-2.0" => License::Apache2,
-        "GPL-3.0" => License::GPL3,
-        "BSD-3-Clause" => License::BSD3,
-        _ => unreachable!(),
-    };
-
-    let quality_config = QualityConfig::new(
+/// Reads the quality tools and clang-tidy check categories from the CLI.
+fn quality_config_from_cli(cli: &Cli) -> Result<QualityConfig> {
+    let mut quality_config = QualityConfig::new(
         &cli.quality_tools
             .iter()
             .map(String::as_str)
             .collect::<Vec<&str>>(),
     );
+    quality_config.clang_tidy_checks = cli
+        .clang_tidy_checks
+        .iter()
+        .map(|category| category.parse())
+        .collect::<Result<_>>()?;
+    Ok(quality_config)
+}
 
-    let code_formatter = CodeFormatter::new(
-        &cli.code_formatter
-            .iter()
-            .map(String::as_str)
-            .collect::<Vec<&str>>(),
-    );
+/// Checks that clang-tidy check categories are only selected with clang-tidy.
+pub(super) fn validate_clang_tidy_checks(quality_config: &QualityConfig) -> Result<()> {
+    if !quality_config.clang_tidy_checks.is_empty() && !quality_config.enable_clang_tidy {
+        return Err(anyhow::anyhow!(
+            "clang-tidy check categories require the clang-tidy quality tool"
+        ));
+    }
+    Ok(())
+}
 
-    let test_framework = match cli.test_framework.as_str() {
-        "doctest" => TestFramework::Doctest,
-        "gtest" => TestFramework::GTest,
-        "catch2" => TestFramework::Catch2,
-        "boosttest" => TestFramework::BoostTest,
-        "none" => TestFramework::None,
-        _ => unreachable!(),
-    };
+/// Checks that the Android target is used for CMake libraries, which are
+/// built as shared libraries for JNI, with an NDK ABI and API level, and
+/// that the MinGW target is used with CMake or Make.
+pub(super) fn validate_target_platform(
+    target_platform: &TargetPlatform,
+    android_abi: &str,
+    android_api: u32,
+    project_type: &ProjectType,
+    build_system: &BuildSystem,
+) -> Result<()> {
+    match target_platform {
+        TargetPlatform::Host => return Ok(()),
+        TargetPlatform::WindowsMingw if *build_system == BuildSystem::Meson => {
+            return Err(anyhow::anyhow!(
+                "The Windows MinGW target requires the CMake or Make build system"
+            ));
+        }
+        TargetPlatform::WindowsMingw => return Ok(()),
+        TargetPlatform::Android => {}
+    }
+    if *build_system != BuildSystem::CMake {
+        return Err(anyhow::anyhow!(
+            "The Android target requires the CMake build system"
+        ));
+    }
+    if *project_type != ProjectType::Library {
+        return Err(anyhow::anyhow!(
+            "The Android target is only available for library projects, which are loaded through JNI"
+        ));
+    }
+    if !ANDROID_ABIS.contains(&android_abi) {
+        return Err(anyhow::anyhow!(
+            "Unknown Android ABI '{}': expected one of {}",
+            android_abi,
+            ANDROID_ABIS.join(", ")
+        ));
+    }
+    if android_api < MIN_ANDROID_API {
+        return Err(anyhow::anyhow!(
+            "Android API level {} is too old: the NDK supports {} and newer",
+            android_api,
+            MIN_ANDROID_API
+        ));
+    }
+    Ok(())
+}
 
-    Ok(ProjectConfig {
-        name,
-        project_type,
-        build_system,
-        cpp_standard,
-        use_git: cli.git,
-        path,
-        test_framework,
-        package_manager,
-        license,
-        description,
-        author,
-        version: DEFAULT_VERSION.to_string(),
-        quality_config,
-        code_formatter,
-    })
+/// Checks that `version` is a version CMake accepts in `project()`: one to
+/// four dot-separated numbers.
+pub(super) fn validate_version(version: &str) -> Result<()> {
+    let pattern = regex::Regex::new(r"^\d+(\.\d+){0,3}$").expect("valid regex");
+    if !pattern.is_match(version) {
+        return Err(anyhow::anyhow!(
+            "Invalid project version '{}': use major[.minor[.patch[.tweak]]], such as 1.2.0",
+            version
+        ));
+    }
+    Ok(())
 }
 
-impl ProjectConfig {
-    /// Creates a new project configuration.
-    ///
-    /// This method can work in two modes:
-    /// - **Interactive mode**: Prompts the user for all configuration options
-    /// - **Non-interactive mode**: Uses CLI arguments to configure the project
-    ///
-    /// # Arguments
-    ///
-    /// * `defaults` - Optional CLI arguments. If `None`, uses interactive mode.
-    ///   If provided with `non_interactive` flag, uses CLI values without prompting.
-    ///
-    /// # Returns
-    ///
-    /// Returns a `Result` containing the `ProjectConfig` or an error.
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if:
-    /// - Project name is invalid
-    /// - Project directory already exists
-    /// - Required CLI arguments are missing in non-interactive mode
-    /// - User cancels interactive prompts
-    ///
-    /// # Examples
-    ///
-    /// ```no_run
-    /// use cppup::ProjectConfig;
-    ///
-    /// // Interactive mode
-    /// // let config = ProjectConfig::new(None)?;
-    ///
-    /// // Non-interactive mode with CLI
-    /// // let cli = Cli::parse();
-    /// // let config = ProjectConfig::new(Some(&cli))?;
-    /// ```
-    pub fn new(defaults: Option<&Cli>) -> Result<Self> {
-        if let Some(default) = defaults {
-            if default.non_interactive {
-                return create_config_from_cli(default);
-            }
+/// Checks the `SOURCE_DATE_EPOCH` that `--reproducible` takes its dates
+/// from, and warns if it is not set.
+fn check_source_date_epoch(value: Option<&str>) -> Result<()> {
+    match value {
+        None => eprintln!(
+            "Warning: SOURCE_DATE_EPOCH is not set, so generated dates are today's; set it to pin them"
+        ),
+        Some(value) if value.trim().parse::<i64>().is_err() => {
+            return Err(anyhow::anyhow!(
+                "SOURCE_DATE_EPOCH must be a number of seconds since 1970-01-01, got '{}'",
+                value
+            ))
         }
+        Some(_) => {}
+    }
+    Ok(())
+}
 
-        let name = Text::new("What is your project name?")
-            .with_default(
-                defaults
-                    .and_then(|d| d.name.as_deref())
-                    .unwrap_or("my-cpp-project"),
-            )
-            .with_help_message("The name of your project (will be used as directory name)")
-            .with_validator(|input: &str| match validate_project_name(input) {
-                Ok(()) => Ok(Validation::Valid),
-                Err(e) => Ok(Validation::Invalid(e.to_string().into())),
-            })
-            .prompt()?;
-
-        let description = Text::new("Project description:")
-            .with_default(
-                defaults
-                    .and_then(|d| d.description.as_deref())
-                    .unwrap_or(DEFAULT_DESCRIPTION),
-            )
-            .prompt()?;
-
-        let default_author = std::env::var("USER")
-            .or_else(|_| std::env::var("USERNAME")) // Try Windows username
-            .or_else(|_| Ok::<String, std::env::VarError>("Unknown".to_string()))
-            .unwrap();
-        let author = Text::new("Author:")
-            .with_default(
-                defaults
-                    .and_then(|d| d.author.as_deref())
-                    .unwrap_or(&default_author),
-            )
-            .prompt()?;
-
-        // Add validation for project path
-        let path = Text::new("Where do you want to create the project?")
-            .with_default(
-                defaults
-                    .map(|d| d.path.to_string_lossy().to_string())
-                    .as_deref()
-                    .unwrap_or("."),
-            )
-            .with_validator(|input: &str| {
-                let path = PathBuf::from(input);
-                match validate_project_path(&path) {
-                    Ok(()) => Ok(Validation::Valid),
-                    Err(e) => Ok(Validation::Invalid(e.to_string().into())),
-                }
-            })
-            .prompt()?;
-
-        let project_path = PathBuf::from(&path).join(&name);
+/// Checks that macOS bundles are built for CMake executables, with a
+/// reverse-DNS bundle identifier, and that universal binaries use CMake.
+pub(super) fn validate_macos_options(
+    macos_bundle: bool,
+    bundle_id: Option<&str>,
+    macos_universal: bool,
+    project_type: &ProjectType,
+    build_system: &BuildSystem,
+) -> Result<()> {
+    if macos_bundle && *project_type != ProjectType::Executable {
+        return Err(anyhow::anyhow!(
+            "macOS bundles are only available for executable projects"
+        ));
+    }
+    if macos_bundle && *build_system != BuildSystem::CMake {
+        return Err(anyhow::anyhow!(
+            "macOS bundles require the CMake build system"
+        ));
+    }
+    if macos_universal && *build_system != BuildSystem::CMake {
+        return Err(anyhow::anyhow!(
+            "Universal macOS binaries require the CMake build system"
+        ));
+    }
+    let Some(bundle_id) = bundle_id else {
+        return Ok(());
+    };
+    if !macos_bundle {
+        return Err(anyhow::anyhow!(
+            "A bundle identifier is only used for a macOS bundle"
+        ));
+    }
+    let pattern = regex::Regex::new(r"^[A-Za-z0-9-]+(\.[A-Za-z0-9-]+)+$").expect("valid regex");
+    if !pattern.is_match(bundle_id) {
+        return Err(anyhow::anyhow!(
+            "Invalid bundle identifier '{}': use reverse-DNS notation such as com.example.app",
+            bundle_id
+        ));
+    }
+    Ok(())
+}
 
-        // Check if project directory already exists
-        if project_path.exists() {
+/// Checks that compile definitions are only given for CMake projects and
+/// that each is `NAME` or `NAME=VALUE` with an upper-case macro name.
+pub(super) fn validate_compile_definitions(
+    definitions: &[String],
+    build_system: &BuildSystem,
+) -> Result<()> {
+    if definitions.is_empty() {
+        return Ok(());
+    }
+    if *build_system != BuildSystem::CMake {
+        return Err(anyhow::anyhow!(
+            "Compile definitions require the CMake build system"
+        ));
+    }
+    let pattern = regex::Regex::new(r"^[A-Z_][A-Z0-9_]*(=.*)?$").expect("valid regex");
+    for definition in definitions {
+        if !pattern.is_match(definition) || definition.chars().any(char::is_control) {
             return Err(anyhow::anyhow!(
-                "Project directory already exists: {}",
-                project_path.display()
+                "Invalid compile definition '{}': expected NAME or NAME=VALUE, with NAME in upper case",
+                definition
             ));
         }
+    }
+    Ok(())
+}
 
-        // Get project type
-        let project_type = Select::new(
-            "What type of project do you want to create?",
-            vec![
-                "Basic (Simple executable)",
-                "Library (Static/Dynamic library)",
-            ],
-        )
-        .prompt()?;
+/// Checks that a default vcpkg registry is only given for vcpkg projects,
+/// and that its baseline is a full commit hash of the registry.
+pub(super) fn validate_vcpkg_registry(
+    registry: Option<&str>,
+    baseline: Option<&str>,
+    package_manager: &PackageManager,
+) -> Result<()> {
+    if registry.is_none() && baseline.is_none() {
+        return Ok(());
+    }
+    if *package_manager != PackageManager::Vcpkg {
+        return Err(anyhow::anyhow!(
+            "A vcpkg registry can only be set with the vcpkg package manager"
+        ));
+    }
+    let Some(registry) = registry else {
+        return Err(anyhow::anyhow!(
+            "A vcpkg baseline requires a registry (--vcpkg-registry-url)"
+        ));
+    };
+    if registry.is_empty() || registry.contains(char::is_whitespace) {
+        return Err(anyhow::anyhow!(
+            "Invalid vcpkg registry URL: '{}'",
+            registry
+        ));
+    }
+    if let Some(baseline) = baseline {
+        if baseline.len() != 40 || !baseline.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(anyhow::anyhow!(
+                "Invalid vcpkg baseline '{}': expected the 40-character commit hash of the registry",
+                baseline
+            ));
+        }
+    }
+    Ok(())
+}
 
-        let project_type = match project_type {
-            "Basic (Simple executable)" => ProjectType::Executable,
-            "Library (Static/Dynamic library)" => ProjectType::Library,
-            _ => unreachable!(),
+/// Checks that vcpkg features are only given for vcpkg projects and that
+/// their names and descriptions are usable in `vcpkg.json`.
+pub(super) fn validate_vcpkg_features(
+    features: &HashMap<String, VcpkgFeature>,
+    package_manager: &PackageManager,
+) -> Result<()> {
+    if features.is_empty() {
+        return Ok(());
+    }
+    if *package_manager != PackageManager::Vcpkg {
+        return Err(anyhow::anyhow!(
+            "vcpkg features can only be set with the vcpkg package manager"
+        ));
+    }
+    for (name, feature) in features {
+        let valid_name = name.split('-').all(|part| {
+            !part.is_empty()
+                && part
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+        });
+        if !valid_name {
+            return Err(anyhow::anyhow!(
+                "Invalid vcpkg feature name '{}': use lowercase letters, digits and '-'",
+                name
+            ));
+        }
+        if feature.description.trim().is_empty() {
+            return Err(anyhow::anyhow!(
+                "vcpkg feature '{}' needs a description",
+                name
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Checks that the OpenAPI spec, if any, belongs to a library and can be
+/// parsed.
+pub(super) fn validate_openapi_spec(spec: Option<&Path>, project_type: &ProjectType) -> Result<()> {
+    let Some(spec) = spec else {
+        return Ok(());
+    };
+    if *project_type != ProjectType::Library {
+        return Err(anyhow::anyhow!(
+            "OpenAPI stubs are only generated for library projects"
+        ));
+    }
+    codegen::load_spec(spec, false)?;
+    Ok(())
+}
+
+/// Checks that `homepage` is an http(s) URL and drops a trailing slash, so
+/// paths can be appended to it.
+pub(super) fn normalize_homepage(homepage: &str) -> Result<String> {
+    let homepage = homepage.trim().trim_end_matches('/');
+    let is_url = ["https://", "http://"].iter().any(|scheme| {
+        homepage
+            .strip_prefix(scheme)
+            .is_some_and(|rest| !rest.is_empty())
+    });
+    if !is_url || homepage.contains(char::is_whitespace) {
+        return Err(anyhow::anyhow!(
+            "Homepage must be an http:// or https:// URL: {}",
+            homepage
+        ));
+    }
+    Ok(homepage.to_string())
+}
+
+pub(super) fn control_character_warning(field: &str, value: &str) -> Option<String> {
+    if value.chars().any(char::is_control) {
+        Some(format!(
+            "Warning: {} contains control characters which may not render correctly in generated files",
+            field
+        ))
+    } else {
+        None
+    }
+}
+
+/// Expands a leading `~` to the home directory and makes `path` absolute.
+///
+/// The longest part of the path that exists is canonicalized, resolving
+/// `.`, `..` and symlinks; the rest, which is yet to be created, is kept as
+/// given.
+pub(super) fn normalize_path(path: &Path) -> PathBuf {
+    let mut components = path.components();
+    let expanded = match (components.next(), dirs::home_dir()) {
+        (Some(Component::Normal(first)), Some(home)) if first == "~" => {
+            home.join(components.as_path())
+        }
+        _ => path.to_path_buf(),
+    };
+    let absolute = match std::env::current_dir() {
+        Ok(dir) if expanded.is_relative() => dir.join(expanded),
+        _ => expanded,
+    };
+
+    let mut existing = absolute.as_path();
+    let mut missing = Vec::new();
+    loop {
+        if let Ok(canonical) = existing.canonicalize().map(strip_verbatim_prefix) {
+            return missing
+                .iter()
+                .rev()
+                .fold(canonical, |path, name| path.join(name));
+        }
+        match (existing.parent(), existing.file_name()) {
+            (Some(parent), Some(name)) => {
+                missing.push(name);
+                existing = parent;
+            }
+            _ => return absolute,
+        }
+    }
+}
+
+/// Drops the `\\?\` prefix that canonicalizing adds to Windows drive
+/// paths; shells and many tools do not accept it.
+fn strip_verbatim_prefix(path: PathBuf) -> PathBuf {
+    match path.to_str().and_then(|path| path.strip_prefix(r"\\?\")) {
+        Some(rest) if rest.as_bytes().get(1) == Some(&b':') => PathBuf::from(rest),
+        _ => path,
+    }
+}
+
+pub(super) fn validate_project_path(path: &PathBuf) -> Result<()> {
+    if !path.exists() {
+        return Err(anyhow::anyhow!(
+            "Directory doesn't exist: {}",
+            path.display()
+        ));
+    }
+    if !path.is_dir() {
+        return Err(anyhow::anyhow!(
+            "Path is not a directory: {}",
+            path.display()
+        ));
+    }
+    // Check if we have write permissions
+    match fs::metadata(path) {
+        Ok(metadata) => {
+            if metadata.permissions().readonly() {
+                return Err(anyhow::anyhow!(
+                    "Directory is read-only: {}",
+                    path.display()
+                ));
+            }
+        }
+        Err(_) => {
+            return Err(anyhow::anyhow!(
+                "Cannot access directory: {}",
+                path.display()
+            ))
+        }
+    }
+    Ok(())
+}
+
+/// Validates the directory a missing `path` would be created in: its nearest
+/// existing ancestor.
+pub(super) fn validate_creatable_path(path: &Path) -> Result<()> {
+    let existing = path.ancestors().find(|dir| dir.exists()).unwrap_or(path);
+    validate_project_path(&existing.to_path_buf())
+}
+
+pub(super) fn default_author() -> String {
+    std::env::var("USER")
+        .or_else(|_| std::env::var("USERNAME")) // Try Windows username
+        .unwrap_or("Unknown".to_string())
+}
+
+/// Whether to create `docs/`: as given, otherwise for libraries and the
+/// Sphinx and Doxygen engines, which have nothing to configure without it.
+/// Sphinx renders a library's API reference, so it is rejected for other
+/// project types.
+fn resolve_docs_dir(
+    docs: Option<bool>,
+    project_type: &ProjectType,
+    doc_engine: &DocEngine,
+) -> Result<bool> {
+    let engine = match doc_engine {
+        DocEngine::Markdown => None,
+        DocEngine::Sphinx => Some("Sphinx"),
+        DocEngine::Doxygen => Some("Doxygen"),
+    };
+    if *doc_engine == DocEngine::Sphinx && *project_type != ProjectType::Library {
+        return Err(anyhow::anyhow!(
+            "The Sphinx documentation engine is only available for library projects"
+        ));
+    }
+    let generate = docs.unwrap_or(*project_type == ProjectType::Library || engine.is_some());
+    if let (Some(engine), false) = (engine, generate) {
+        return Err(anyhow::anyhow!(
+            "The {} documentation engine requires the docs/ directory",
+            engine
+        ));
+    }
+    Ok(generate)
+}
+
+/// Whether a prompt error was caused by the user cancelling (Esc) or
+/// interrupting (Ctrl-C) the interactive prompts.
+fn is_cancellation(error: &InquireError) -> bool {
+    matches!(
+        error,
+        InquireError::OperationCanceled | InquireError::OperationInterrupted
+    )
+}
+
+fn create_config_from_cli(cli: &Cli) -> Result<ProjectConfig> {
+    let name = cli.name.clone().ok_or_else(|| {
+        CppupError::Usage(anyhow::anyhow!(
+            "Project name is required in non-interactive mode"
+        ))
+    })?;
+    let project_type = cli
+        .project_type
+        .as_deref()
+        .ok_or_else(|| {
+            CppupError::Usage(anyhow::anyhow!(
+                "Project type is required in non-interactive mode"
+            ))
+        })?
+        .parse()?;
+
+    let mut builder = ProjectConfig::builder();
+    builder
+        .name(name)
+        .path(cli.path.clone().unwrap_or(PathBuf::from(".")))
+        .create_path(cli.create_path)
+        .project_type(project_type)
+        .quality_config(quality_config_from_cli(cli)?)
+        .code_formatter(CodeFormatter::new(
+            &cli.code_formatter
+                .iter()
+                .map(String::as_str)
+                .collect::<Vec<&str>>(),
+        ))
+        .ide(IdeConfig::new(
+            &cli.ide.iter().map(String::as_str).collect::<Vec<&str>>(),
+        ))
+        .snippets(parse_snippets(&cli.snippets)?)
+        .codeowners(cli.codeowners.clone())
+        .conan_options(cli.conan_options.clone())
+        .vcpkg_features(parse_vcpkg_features(&cli.vcpkg_features)?)
+        .compile_definitions(cli.defines.clone())
+        .extra_vars(cli.template_vars.iter().cloned().collect());
+
+    if let Some(description) = &cli.description {
+        builder.description(description);
+    }
+    if let Some(registry) = &cli.vcpkg_registry_url {
+        builder.vcpkg_default_registry(registry);
+    }
+    if let Some(baseline) = &cli.vcpkg_baseline {
+        builder.vcpkg_baseline(baseline);
+    }
+    if let Some(author) = &cli.author {
+        builder.author(author);
+    }
+    if let Some(value) = cli.build_system.as_deref() {
+        builder.build_system(value.parse()?);
+    }
+    if let Some(value) = cli.cmake_generator.as_deref() {
+        builder.cmake_generator(value.parse()?);
+    }
+    if let Some(value) = cli.cpp_standard.as_deref() {
+        builder.cpp_standard(value.parse()?);
+    }
+    if let Some(value) = cli.cpp_extension.as_deref() {
+        builder.cpp_extension(value.parse()?);
+    }
+    if let Some(value) = cli.hpp_extension.as_deref() {
+        builder.hpp_extension(value.parse()?);
+    }
+    if let Some(value) = cli.line_endings.as_deref() {
+        builder.line_endings(value.parse()?);
+    }
+    if let Some(value) = cli.sanitizer.as_deref() {
+        builder.sanitizer_preset(value.parse()?);
+    }
+    if let Some(value) = cli.test_framework.as_deref() {
+        builder.test_framework(value.parse()?);
+    }
+    if let Some(value) = cli.test_runner.as_deref() {
+        builder.test_runner(value.parse()?);
+    }
+    if let Some(value) = cli.package_manager.as_deref() {
+        builder.package_manager(value.parse()?);
+    }
+    if let Some(value) = cli.license.as_deref() {
+        builder.license(value.parse()?);
+    }
+    if let Some(use_git) = cli.git {
+        builder.use_git(use_git);
+    }
+    if let Some(git_commit) = cli.git_commit {
+        builder.git_commit(git_commit);
+    }
+    builder.force_git(cli.force_git);
+    if let Some(branch) = &cli.git_branch {
+        builder.git_branch(branch);
+    }
+    if let Some(remote) = &cli.git_remote {
+        builder.git_remote(remote);
+    }
+    if cli.create_github_repo {
+        builder.github_repo(github_visibility(cli));
+    }
+    if let Some(docs) = cli.docs {
+        builder.generate_docs_dir(docs);
+    }
+    if let Some(value) = cli.doc_engine.as_deref() {
+        builder.doc_engine(value.parse()?);
+    }
+    if let Some(spec) = &cli.openapi_spec {
+        builder.openapi_spec(spec);
+    }
+    if let Some(num_examples) = cli.num_examples {
+        builder.num_examples(num_examples);
+    }
+    if let Some(changelog) = cli.changelog {
+        builder.generate_changelog(changelog);
+    }
+    if let Some(community_files) = cli.community_files {
+        builder.generate_community_files(community_files);
+    }
+    if let Some(value) = cli.ci.as_deref() {
+        builder.ci(value.parse()?);
+    }
+    if let Some(dependabot) = cli.dependabot {
+        builder.enable_dependabot(dependabot);
+    }
+    if let Some(lto) = cli.lto {
+        builder.enable_lto(lto);
+    }
+    if let Some(value) = cli.coverage_service.as_deref() {
+        builder.coverage_service(value.parse()?);
+    }
+    if let Some(value) = cli.target.as_deref() {
+        builder.target_platform(value.parse()?);
+    }
+    if let Some(abi) = &cli.android_abi {
+        builder.android_abi(abi);
+    }
+    if let Some(api) = cli.android_api {
+        builder.android_api(api);
+    }
+    if let Some(macos_bundle) = cli.macos_bundle {
+        builder.macos_bundle(macos_bundle);
+    }
+    if let Some(bundle_id) = &cli.bundle_id {
+        builder.bundle_id(bundle_id);
+    }
+    if let Some(universal) = cli.universal {
+        builder.macos_universal(universal);
+    }
+    if let Some(homepage) = &cli.homepage {
+        builder.homepage(homepage);
+    }
+    if let Some(version) = &cli.project_version {
+        builder.version(version);
+    }
+
+    builder.build()
+}
+
+/// Builds a [`ProjectConfig`] in code, applying the same defaults and
+/// validation as non-interactive mode.
+///
+/// Only the name and the parent directory are required; the project is
+/// created in `path/name`. Everything else defaults to an executable built
+/// with CMake and C++17, MIT licensed, without tests, a package manager or
+/// quality tools.
+///
+/// # Examples
+///
+/// ```
+/// use cppup::project::{BuildSystem, ProjectType};
+/// use cppup::ProjectConfig;
+///
+/// let dir = tempfile::tempdir()?;
+/// let config = ProjectConfig::builder()
+///     .name("my-lib")
+///     .path(dir.path())
+///     .project_type(ProjectType::Library)
+///     .build_system(BuildSystem::Meson)
+///     .build()?;
+///
+/// assert_eq!(config.path, dir.path().join("my-lib"));
+/// assert!(config.generate_docs_dir);
+/// # Ok::<(), anyhow::Error>(())
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ProjectConfigBuilder {
+    name: Option<String>,
+    path: Option<PathBuf>,
+    create_path: bool,
+    description: Option<String>,
+    author: Option<String>,
+    project_type: Option<ProjectType>,
+    build_system: Option<BuildSystem>,
+    cmake_generator: Option<CmakeGenerator>,
+    cpp_standard: Option<CppStandard>,
+    cpp_extension: Option<CppExtension>,
+    hpp_extension: Option<HppExtension>,
+    line_endings: Option<LineEndings>,
+    sanitizer_preset: Option<SanitizerPreset>,
+    test_framework: Option<TestFramework>,
+    test_runner: Option<TestRunner>,
+    package_manager: Option<PackageManager>,
+    license: Option<License>,
+    use_git: Option<bool>,
+    git_commit: Option<bool>,
+    force_git: Option<bool>,
+    git_branch: Option<String>,
+    git_remote: Option<String>,
+    github_repo: Option<RepoVisibility>,
+    generate_docs_dir: Option<bool>,
+    doc_engine: Option<DocEngine>,
+    num_examples: Option<usize>,
+    openapi_spec: Option<PathBuf>,
+    generate_changelog: Option<bool>,
+    generate_community_files: Option<bool>,
+    ci: Option<CiProvider>,
+    enable_dependabot: Option<bool>,
+    homepage: Option<String>,
+    version: Option<String>,
+    quality_config: Option<QualityConfig>,
+    code_formatter: Option<CodeFormatter>,
+    ide: Option<IdeConfig>,
+    snippets: Vec<Snippet>,
+    codeowners: Vec<String>,
+    conan_options: Vec<String>,
+    vcpkg_features: HashMap<String, VcpkgFeature>,
+    vcpkg_default_registry: Option<String>,
+    vcpkg_baseline: Option<String>,
+    compile_definitions: Vec<String>,
+    enable_lto: Option<bool>,
+    coverage_service: Option<CoverageService>,
+    target_platform: Option<TargetPlatform>,
+    android_abi: Option<String>,
+    android_api: Option<u32>,
+    macos_bundle: Option<bool>,
+    bundle_id: Option<String>,
+    macos_universal: Option<bool>,
+    extra_vars: HashMap<String, String>,
+}
+
+impl ProjectConfigBuilder {
+    /// Sets the project name.
+    pub fn name(&mut self, name: impl Into<String>) -> &mut Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Sets the directory the project directory is created in.
+    pub fn path(&mut self, path: impl Into<PathBuf>) -> &mut Self {
+        self.path = Some(path.into());
+        self
+    }
+
+    /// Allows the directory set with [`path`](Self::path) not to exist yet;
+    /// it is created with the project. Defaults to `false`, so a mistyped
+    /// path is an error.
+    pub fn create_path(&mut self, create: bool) -> &mut Self {
+        self.create_path = create;
+        self
+    }
+
+    /// Sets the project description.
+    pub fn description(&mut self, description: impl Into<String>) -> &mut Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Sets the project author. Defaults to the current user.
+    pub fn author(&mut self, author: impl Into<String>) -> &mut Self {
+        self.author = Some(author.into());
+        self
+    }
+
+    /// Sets the project type.
+    pub fn project_type(&mut self, project_type: ProjectType) -> &mut Self {
+        self.project_type = Some(project_type);
+        self
+    }
+
+    /// Sets the build system.
+    pub fn build_system(&mut self, build_system: BuildSystem) -> &mut Self {
+        self.build_system = Some(build_system);
+        self
+    }
+
+    /// Sets the CMake generator. Requires the CMake build system.
+    pub fn cmake_generator(&mut self, cmake_generator: CmakeGenerator) -> &mut Self {
+        self.cmake_generator = Some(cmake_generator);
+        self
+    }
+
+    /// Sets the C++ standard.
+    pub fn cpp_standard(&mut self, cpp_standard: CppStandard) -> &mut Self {
+        self.cpp_standard = Some(cpp_standard);
+        self
+    }
+
+    /// Sets the extension of C++ source files.
+    pub fn cpp_extension(&mut self, cpp_extension: CppExtension) -> &mut Self {
+        self.cpp_extension = Some(cpp_extension);
+        self
+    }
+
+    /// Sets the extension of C++ header files.
+    pub fn hpp_extension(&mut self, hpp_extension: HppExtension) -> &mut Self {
+        self.hpp_extension = Some(hpp_extension);
+        self
+    }
+
+    /// Sets the line endings of the generated files.
+    pub fn line_endings(&mut self, line_endings: LineEndings) -> &mut Self {
+        self.line_endings = Some(line_endings);
+        self
+    }
+
+    /// Sets the sanitizer preset. Requires the CMake build system.
+    pub fn sanitizer_preset(&mut self, sanitizer_preset: SanitizerPreset) -> &mut Self {
+        self.sanitizer_preset = Some(sanitizer_preset);
+        self
+    }
+
+    /// Sets the testing framework.
+    pub fn test_framework(&mut self, test_framework: TestFramework) -> &mut Self {
+        self.test_framework = Some(test_framework);
+        self
+    }
+
+    /// Sets how the generated test executables are launched.
+    pub fn test_runner(&mut self, test_runner: TestRunner) -> &mut Self {
+        self.test_runner = Some(test_runner);
+        self
+    }
+
+    /// Sets the package manager.
+    pub fn package_manager(&mut self, package_manager: PackageManager) -> &mut Self {
+        self.package_manager = Some(package_manager);
+        self
+    }
+
+    /// Sets the license.
+    pub fn license(&mut self, license: License) -> &mut Self {
+        self.license = Some(license);
+        self
+    }
+
+    /// Sets whether to initialize a git repository. Defaults to `true`.
+    pub fn use_git(&mut self, use_git: bool) -> &mut Self {
+        self.use_git = Some(use_git);
+        self
+    }
+
+    /// Sets whether to commit the generated files when git is used.
+    /// Defaults to `true`.
+    pub fn git_commit(&mut self, git_commit: bool) -> &mut Self {
+        self.git_commit = Some(git_commit);
+        self
+    }
+
+    /// Sets whether to create a `docs/` directory. Defaults to `true` for
+    /// libraries and for the Sphinx engine.
+    pub fn generate_docs_dir(&mut self, generate_docs_dir: bool) -> &mut Self {
+        self.generate_docs_dir = Some(generate_docs_dir);
+        self
+    }
+
+    /// Sets the engine used for the `docs/` directory.
+    pub fn doc_engine(&mut self, doc_engine: DocEngine) -> &mut Self {
+        self.doc_engine = Some(doc_engine);
+        self
+    }
+
+    /// Sets whether to create a repository even when the project is inside
+    /// an existing one. Defaults to `false`.
+    pub fn force_git(&mut self, force_git: bool) -> &mut Self {
+        self.force_git = Some(force_git);
+        self
+    }
+
+    /// Sets the name of the initial git branch. Defaults to `main`.
+    pub fn git_branch(&mut self, git_branch: impl Into<String>) -> &mut Self {
+        self.git_branch = Some(git_branch.into());
+        self
+    }
+
+    /// Sets the URL added as the `origin` remote.
+    pub fn git_remote(&mut self, git_remote: impl Into<String>) -> &mut Self {
+        self.git_remote = Some(git_remote.into());
+        self
+    }
+
+    /// Requests a GitHub repository with the given visibility, created
+    /// with `gh` after generation.
+    pub fn github_repo(&mut self, visibility: RepoVisibility) -> &mut Self {
+        self.github_repo = Some(visibility);
+        self
+    }
+
+    /// Sets the number of example programs generated for a library.
+    /// Defaults to 1.
+    pub fn num_examples(&mut self, num_examples: usize) -> &mut Self {
+        self.num_examples = Some(num_examples);
+        self
+    }
+
+    /// Sets the OpenAPI spec that `include/api/` stubs are generated from.
+    pub fn openapi_spec(&mut self, spec: impl Into<PathBuf>) -> &mut Self {
+        self.openapi_spec = Some(spec.into());
+        self
+    }
+
+    /// Sets whether to create a `CHANGELOG.md`. Defaults to `false`.
+    pub fn generate_changelog(&mut self, generate_changelog: bool) -> &mut Self {
+        self.generate_changelog = Some(generate_changelog);
+        self
+    }
+
+    /// Sets whether to create `CONTRIBUTING.md` and `CODE_OF_CONDUCT.md`.
+    /// Defaults to `false`.
+    pub fn generate_community_files(&mut self, generate_community_files: bool) -> &mut Self {
+        self.generate_community_files = Some(generate_community_files);
+        self
+    }
+
+    /// Sets the CI pipeline generated with the project. Defaults to
+    /// [`CiProvider::None`].
+    pub fn ci(&mut self, ci: CiProvider) -> &mut Self {
+        self.ci = Some(ci);
+        self
+    }
+
+    /// Sets whether to create `.github/dependabot.yml`. It is only written
+    /// for projects with GitHub Actions CI. Defaults to `false`.
+    pub fn enable_dependabot(&mut self, enable_dependabot: bool) -> &mut Self {
+        self.enable_dependabot = Some(enable_dependabot);
+        self
+    }
+
+    /// Sets the project homepage URL.
+    pub fn homepage(&mut self, homepage: impl Into<String>) -> &mut Self {
+        self.homepage = Some(homepage.into());
+        self
+    }
+
+    /// Sets the project version, as `major[.minor[.patch[.tweak]]]`.
+    pub fn version(&mut self, version: impl Into<String>) -> &mut Self {
+        self.version = Some(version.into());
+        self
+    }
+
+    /// Sets the code quality tools.
+    pub fn quality_config(&mut self, quality_config: QualityConfig) -> &mut Self {
+        self.quality_config = Some(quality_config);
+        self
+    }
+
+    /// Sets the code formatters.
+    pub fn code_formatter(&mut self, code_formatter: CodeFormatter) -> &mut Self {
+        self.code_formatter = Some(code_formatter);
+        self
+    }
+
+    /// Sets the IDE project files.
+    pub fn ide(&mut self, ide: IdeConfig) -> &mut Self {
+        self.ide = Some(ide);
+        self
+    }
+
+    /// Sets the code snippets copied into `include/snippets/`.
+    pub fn snippets(&mut self, snippets: Vec<Snippet>) -> &mut Self {
+        self.snippets = snippets;
+        self
+    }
+
+    /// Sets the owners written to `CODEOWNERS`, e.g. `@org/cpp-team`.
+    /// Defaults to none, which writes no `CODEOWNERS` file.
+    pub fn codeowners(&mut self, owners: Vec<String>) -> &mut Self {
+        self.codeowners = owners;
+        self
+    }
+
+    /// Sets the Conan package options, each as `pkg:option=value`. Only
+    /// valid with the Conan package manager.
+    pub fn conan_options(&mut self, options: Vec<String>) -> &mut Self {
+        self.conan_options = options;
+        self
+    }
+
+    /// Sets the optional features of `vcpkg.json`, by name. Only valid
+    /// with the vcpkg package manager.
+    pub fn vcpkg_features(&mut self, features: HashMap<String, VcpkgFeature>) -> &mut Self {
+        self.vcpkg_features = features;
+        self
+    }
+
+    /// Sets the git repository of the default vcpkg registry. Only valid
+    /// with the vcpkg package manager.
+    pub fn vcpkg_default_registry(&mut self, registry: impl Into<String>) -> &mut Self {
+        self.vcpkg_default_registry = Some(registry.into());
+        self
+    }
+
+    /// Sets the commit of the default vcpkg registry to take the versions
+    /// from. Requires [`vcpkg_default_registry`](Self::vcpkg_default_registry).
+    pub fn vcpkg_baseline(&mut self, baseline: impl Into<String>) -> &mut Self {
+        self.vcpkg_baseline = Some(baseline.into());
+        self
+    }
+
+    /// Sets the preprocessor definitions, each `NAME` or `NAME=VALUE`.
+    /// Only valid with the CMake build system.
+    pub fn compile_definitions(&mut self, definitions: Vec<String>) -> &mut Self {
+        self.compile_definitions = definitions;
+        self
+    }
+
+    /// Sets whether release builds use Link Time Optimization. Defaults to
+    /// `false`.
+    pub fn enable_lto(&mut self, enable_lto: bool) -> &mut Self {
+        self.enable_lto = Some(enable_lto);
+        self
+    }
+
+    /// Sets the service the test coverage is uploaded to. Defaults to
+    /// [`CoverageService::None`].
+    pub fn coverage_service(&mut self, coverage_service: CoverageService) -> &mut Self {
+        self.coverage_service = Some(coverage_service);
+        self
+    }
+
+    /// Sets the platform the project is configured for. The Android target
+    /// requires a CMake library. Defaults to [`TargetPlatform::Host`].
+    pub fn target_platform(&mut self, target_platform: TargetPlatform) -> &mut Self {
+        self.target_platform = Some(target_platform);
+        self
+    }
+
+    /// Sets the ABI of the Android preset, e.g. `arm64-v8a` (the default).
+    pub fn android_abi(&mut self, android_abi: impl Into<String>) -> &mut Self {
+        self.android_abi = Some(android_abi.into());
+        self
+    }
+
+    /// Sets the Android API level of the Android preset. Defaults to 24.
+    pub fn android_api(&mut self, android_api: u32) -> &mut Self {
+        self.android_api = Some(android_api);
+        self
+    }
+
+    /// Sets whether the executable is built as a macOS application bundle.
+    /// Defaults to `false`.
+    pub fn macos_bundle(&mut self, macos_bundle: bool) -> &mut Self {
+        self.macos_bundle = Some(macos_bundle);
+        self
+    }
+
+    /// Sets the bundle identifier of the macOS bundle. Without one, it is
+    /// derived from the repository URL.
+    pub fn bundle_id(&mut self, bundle_id: impl Into<String>) -> &mut Self {
+        self.bundle_id = Some(bundle_id.into());
+        self
+    }
+
+    /// Sets whether a preset builds universal macOS binaries. Defaults to
+    /// `false`.
+    pub fn macos_universal(&mut self, macos_universal: bool) -> &mut Self {
+        self.macos_universal = Some(macos_universal);
+        self
+    }
+
+    /// Sets the extra variables passed to templates.
+    pub fn extra_vars(&mut self, extra_vars: HashMap<String, String>) -> &mut Self {
+        self.extra_vars = extra_vars;
+        self
+    }
+
+    /// Validates the settings and returns the configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The name or path is missing
+    /// - The name, description, author or version is invalid
+    /// - The path is not a writable directory, or with
+    ///   [`create_path`](Self::create_path) its nearest existing ancestor
+    ///   is not; a leading `~` is expanded and the path made absolute first
+    /// - A CMake-only option is combined with another build system
+    /// - The project directory already exists
+    pub fn build(&self) -> Result<ProjectConfig> {
+        let name = self.name.clone().context("Project name is required")?;
+        validate_project_name(&name).map_err(anyhow::Error::msg)?;
+
+        let base_path = normalize_path(self.path.as_deref().context("Project path is required")?);
+        if self.create_path {
+            validate_creatable_path(&base_path)?;
+        } else {
+            validate_project_path(&base_path)?;
+        }
+
+        let description = self
+            .description
+            .as_deref()
+            .unwrap_or(DEFAULT_DESCRIPTION)
+            .trim()
+            .to_string();
+        validate_description(&description)?;
+
+        let author = self
+            .author
+            .as_deref()
+            .map(|author| author.trim().to_string())
+            .unwrap_or_else(default_author);
+        validate_author(&author)?;
+
+        let version = self.version.as_deref().unwrap_or(DEFAULT_VERSION);
+        validate_version(version)?;
+
+        for (field, value) in [("Description", &description), ("Author", &author)] {
+            if let Some(warning) = control_character_warning(field, value) {
+                eprintln!("{}", warning);
+            }
+        }
+
+        let project_type = self.project_type.clone().unwrap_or_default();
+        let build_system = self.build_system.clone().unwrap_or_default();
+
+        let cmake_generator = self.cmake_generator.clone().unwrap_or_default();
+        if cmake_generator != CmakeGenerator::Default && build_system != BuildSystem::CMake {
+            return Err(anyhow::anyhow!(
+                "A CMake generator can only be selected with the CMake build system"
+            ));
+        }
+
+        let ide = self.ide.clone().unwrap_or_default();
+        if ide.enable_clion && build_system != BuildSystem::CMake {
+            return Err(anyhow::anyhow!(
+                "CLion project files require the CMake build system"
+            ));
+        }
+
+        let sanitizer_preset = self.sanitizer_preset.clone().unwrap_or_default();
+        if sanitizer_preset != SanitizerPreset::None && build_system != BuildSystem::CMake {
+            return Err(anyhow::anyhow!(
+                "Sanitizer presets require the CMake build system"
+            ));
+        }
+
+        let homepage = self
+            .homepage
+            .as_deref()
+            .map(normalize_homepage)
+            .transpose()?;
+
+        let doc_engine = self.doc_engine.clone().unwrap_or_default();
+        let generate_docs_dir =
+            resolve_docs_dir(self.generate_docs_dir, &project_type, &doc_engine)?;
+
+        let git_branch = self
+            .git_branch
+            .clone()
+            .unwrap_or(DEFAULT_GIT_BRANCH.to_string());
+        validate_branch_name(&git_branch)?;
+
+        let use_git = self.use_git.unwrap_or(true);
+        let git_remote = self
+            .git_remote
+            .as_deref()
+            .map(|remote| validate_git_remote(remote, use_git))
+            .transpose()?;
+
+        let git_commit = self.git_commit.unwrap_or(true);
+        validate_github_repo(
+            self.github_repo.as_ref(),
+            use_git,
+            git_commit,
+            git_remote.as_deref(),
+        )?;
+
+        let num_examples = self.num_examples.unwrap_or(1);
+        validate_num_examples(num_examples, &project_type)?;
+        validate_openapi_spec(self.openapi_spec.as_deref(), &project_type)?;
+        validate_codeowners(&self.codeowners)?;
+        let package_manager = self.package_manager.clone().unwrap_or_default();
+        validate_conan_options(&self.conan_options, &package_manager)?;
+        validate_vcpkg_features(&self.vcpkg_features, &package_manager)?;
+        validate_vcpkg_registry(
+            self.vcpkg_default_registry.as_deref(),
+            self.vcpkg_baseline.as_deref(),
+            &package_manager,
+        )?;
+        validate_compile_definitions(&self.compile_definitions, &build_system)?;
+        let quality_config = self.quality_config.clone().unwrap_or_default();
+        validate_clang_tidy_checks(&quality_config)?;
+        let target_platform = self.target_platform.clone().unwrap_or_default();
+        let android_abi = self
+            .android_abi
+            .clone()
+            .unwrap_or(DEFAULT_ANDROID_ABI.to_string());
+        let android_api = self.android_api.unwrap_or(DEFAULT_ANDROID_API);
+        validate_target_platform(
+            &target_platform,
+            &android_abi,
+            android_api,
+            &project_type,
+            &build_system,
+        )?;
+        let macos_bundle = self.macos_bundle.unwrap_or(false);
+        let macos_universal = self.macos_universal.unwrap_or(false);
+        validate_macos_options(
+            macos_bundle,
+            self.bundle_id.as_deref(),
+            macos_universal,
+            &project_type,
+            &build_system,
+        )?;
+
+        let path = base_path.join(&name);
+
+        // Check if project directory already exists
+        if path.exists() {
+            return Err(CppupError::Conflict(anyhow::anyhow!(
+                "Project directory already exists: {}",
+                path.display()
+            ))
+            .into());
+        }
+
+        Ok(ProjectConfig {
+            name,
+            project_type,
+            build_system,
+            cmake_generator,
+            cpp_standard: self.cpp_standard.clone().unwrap_or_default(),
+            cpp_extension: self.cpp_extension.clone().unwrap_or_default(),
+            hpp_extension: self.hpp_extension.clone().unwrap_or_default(),
+            line_endings: self.line_endings.unwrap_or_default(),
+            sanitizer_preset,
+            use_git,
+            git_commit,
+            force_git: self.force_git.unwrap_or(false),
+            git_branch,
+            git_remote,
+            github_repo: self.github_repo.clone(),
+            generate_docs_dir,
+            doc_engine,
+            num_examples,
+            openapi_spec: self.openapi_spec.clone(),
+            generate_changelog: self.generate_changelog.unwrap_or(false),
+            generate_community_files: self.generate_community_files.unwrap_or(false),
+            ci: self.ci.clone().unwrap_or_default(),
+            enable_dependabot: self.enable_dependabot.unwrap_or(false),
+            homepage,
+            path,
+            test_framework: self.test_framework.clone().unwrap_or_default(),
+            test_runner: self.test_runner.clone().unwrap_or_default(),
+            package_manager: self.package_manager.clone().unwrap_or_default(),
+            license: self.license.clone().unwrap_or_default(),
+            description,
+            author,
+            version: version.to_string(),
+            quality_config,
+            code_formatter: self.code_formatter.clone().unwrap_or_default(),
+            ide,
+            snippets: unique_snippets(&self.snippets),
+            codeowners: self.codeowners.clone(),
+            conan_options: self.conan_options.clone(),
+            vcpkg_features: self.vcpkg_features.clone(),
+            vcpkg_default_registry: self.vcpkg_default_registry.clone(),
+            vcpkg_baseline: self.vcpkg_baseline.clone(),
+            compile_definitions: self.compile_definitions.clone(),
+            enable_lto: self.enable_lto.unwrap_or(false),
+            coverage_service: self.coverage_service.clone().unwrap_or_default(),
+            target_platform,
+            android_abi,
+            android_api,
+            macos_bundle,
+            bundle_id: self.bundle_id.clone(),
+            macos_universal,
+            extra_vars: self.extra_vars.clone(),
+        })
+    }
+}
+
+/// Parses the `--snippet` values.
+fn parse_snippets(names: &[String]) -> Result<Vec<Snippet>> {
+    names.iter().map(|name| name.parse()).collect()
+}
+
+/// Parses the `--vcpkg-feature` values, each `name:description[:dep1,dep2]`.
+fn parse_vcpkg_features(values: &[String]) -> Result<HashMap<String, VcpkgFeature>> {
+    let mut features = HashMap::new();
+    for value in values {
+        let mut parts = value.splitn(3, ':');
+        let (Some(name), Some(description)) = (parts.next(), parts.next()) else {
+            return Err(anyhow::anyhow!(
+                "Invalid vcpkg feature '{}': expected name:description:dep1,dep2",
+                value
+            ));
+        };
+        let dependencies = parts
+            .next()
+            .unwrap_or_default()
+            .split(',')
+            .map(str::trim)
+            .filter(|dependency| !dependency.is_empty())
+            .map(String::from)
+            .collect();
+        let feature = VcpkgFeature {
+            description: description.trim().to_string(),
+            dependencies,
+        };
+        if features.insert(name.trim().to_string(), feature).is_some() {
+            return Err(anyhow::anyhow!("vcpkg feature '{}' is given twice", name));
+        }
+    }
+    Ok(features)
+}
+
+/// Drops repeated snippets, keeping the first occurrence of each.
+fn unique_snippets(snippets: &[Snippet]) -> Vec<Snippet> {
+    let mut unique = Vec::new();
+    for snippet in snippets {
+        if !unique.contains(snippet) {
+            unique.push(snippet.clone());
+        }
+    }
+    unique
+}
+
+/// Marks the error of a cancelled prompt in `result` as a
+/// [`CppupError::Cancelled`], so `main` reports it with its own exit code.
+pub(crate) fn categorize_cancelled<T>(result: Result<T>) -> Result<T> {
+    result.map_err(|error| {
+        if error
+            .downcast_ref::<InquireError>()
+            .is_some_and(is_cancellation)
+        {
+            CppupError::Cancelled(error).into()
+        } else {
+            error
+        }
+    })
+}
+
+impl std::fmt::Display for ProjectConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        for (index, (label, value)) in prompts::summary_rows(self).into_iter().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{:<16} {}", format!("{}:", label), value)?;
+        }
+        Ok(())
+    }
+}
+
+impl ProjectConfig {
+    /// Returns a builder for constructing a configuration in code.
+    ///
+    /// See [`ProjectConfigBuilder`] for the defaults and validation applied.
+    pub fn builder() -> ProjectConfigBuilder {
+        ProjectConfigBuilder::default()
+    }
+
+    /// Creates a new project configuration.
+    ///
+    /// This method can work in two modes:
+    /// - **Interactive mode**: Prompts the user for all configuration options
+    /// - **Non-interactive mode**: Uses CLI arguments to configure the project
+    ///
+    /// # Arguments
+    ///
+    /// * `defaults` - Optional CLI arguments. If `None`, uses interactive mode.
+    ///   If provided with `non_interactive` flag, uses CLI values without prompting.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the `ProjectConfig` or an error.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - Project name is invalid
+    /// - Project directory already exists
+    /// - Required CLI arguments are missing in non-interactive mode
+    /// - The user cancels the interactive prompts with Esc or Ctrl-C, in
+    ///   which case the error is a [`CppupError::Cancelled`]
+    ///
+    /// In non-interactive mode the `CPPUP_*` environment variables take the
+    /// place of the CLI arguments when they name the project; see
+    /// [`ProjectConfig::from_env_or_cli`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use cppup::ProjectConfig;
+    ///
+    /// // Interactive mode
+    /// // let config = ProjectConfig::new(None)?;
+    ///
+    /// // Non-interactive mode with CLI
+    /// // let cli = Cli::parse();
+    /// // let config = ProjectConfig::new(Some(&cli))?;
+    /// ```
+    pub fn new(defaults: Option<&Cli>) -> Result<Self> {
+        if defaults.is_some_and(|cli| cli.reproducible) {
+            check_source_date_epoch(std::env::var("SOURCE_DATE_EPOCH").ok().as_deref())?;
+        }
+        if let Some(default) = defaults {
+            if default.non_interactive {
+                return Self::from_env_or_cli(default);
+            }
+        }
+
+        // Answers remembered from the previous run fill in whatever was not
+        // given on the command line
+        let last_run_path = last_run::default_path();
+        let remembered = match (defaults, &last_run_path) {
+            (Some(cli), _) if cli.fresh => None,
+            (_, Some(path)) => last_run::load(path),
+            (_, None) => None,
+        };
+        let mut cli = defaults
+            .cloned()
+            .unwrap_or_else(|| Cli::parse_from(["cppup"]));
+
+        // A preset, given on the command line or chosen first, takes
+        // precedence over remembered answers
+        let preset = match cli.preset.as_deref() {
+            Some(name) => Preset::find(name),
+            None => categorize_cancelled(prompts::prompt_preset())?,
+        };
+        let preset_cli = preset.map(|preset| {
+            preset.apply(&mut cli);
+            preset.expand()
+        });
+        if let Some(remembered) = &remembered {
+            cli.fill_missing(remembered);
+        }
+
+        let initial = ProjectConfig {
+            name: cli.name.clone().unwrap_or("my-cpp-project".to_string()),
+            description: cli
+                .description
+                .as_deref()
+                .unwrap_or(DEFAULT_DESCRIPTION)
+                .trim()
+                .to_string(),
+            author: cli
+                .author
+                .as_deref()
+                .map(|author| author.trim().to_string())
+                .unwrap_or_else(default_author),
+            path: normalize_path(cli.path.as_deref().unwrap_or(Path::new("."))),
+            project_type: cli
+                .project_type
+                .as_deref()
+                .map(str::parse)
+                .transpose()?
+                .unwrap_or_default(),
+            build_system: cli
+                .build_system
+                .as_deref()
+                .map(str::parse)
+                .transpose()?
+                .unwrap_or_default(),
+            cmake_generator: cli
+                .cmake_generator
+                .as_deref()
+                .map(str::parse)
+                .transpose()?
+                .unwrap_or_default(),
+            cpp_standard: cli
+                .cpp_standard
+                .as_deref()
+                .map(str::parse)
+                .transpose()?
+                .unwrap_or(CppStandard::Cpp11),
+            cpp_extension: cli
+                .cpp_extension
+                .as_deref()
+                .map(str::parse)
+                .transpose()?
+                .unwrap_or_default(),
+            hpp_extension: cli
+                .hpp_extension
+                .as_deref()
+                .map(str::parse)
+                .transpose()?
+                .unwrap_or_default(),
+            line_endings: cli
+                .line_endings
+                .as_deref()
+                .map(str::parse)
+                .transpose()?
+                .unwrap_or_default(),
+            sanitizer_preset: cli
+                .sanitizer
+                .as_deref()
+                .map(str::parse)
+                .transpose()?
+                .unwrap_or_default(),
+            test_framework: cli
+                .test_framework
+                .as_deref()
+                .map(str::parse)
+                .transpose()?
+                .unwrap_or_default(),
+            test_runner: cli
+                .test_runner
+                .as_deref()
+                .map(str::parse)
+                .transpose()?
+                .unwrap_or_default(),
+            package_manager: cli
+                .package_manager
+                .as_deref()
+                .map(str::parse)
+                .transpose()?
+                .unwrap_or_default(),
+            license: cli
+                .license
+                .as_deref()
+                .map(str::parse)
+                .transpose()?
+                .unwrap_or_default(),
+            use_git: cli.git.unwrap_or(true),
+            git_commit: cli.git_commit.unwrap_or(true),
+            force_git: cli.force_git,
+            git_branch: match &cli.git_branch {
+                Some(branch) => {
+                    validate_branch_name(branch)?;
+                    branch.clone()
+                }
+                None => DEFAULT_GIT_BRANCH.to_string(),
+            },
+            git_remote: cli.git_remote.clone(),
+            github_repo: cli.create_github_repo.then(|| github_visibility(&cli)),
+            generate_docs_dir: false,
+            doc_engine: cli
+                .doc_engine
+                .as_deref()
+                .map(str::parse)
+                .transpose()?
+                .unwrap_or_default(),
+            num_examples: cli.num_examples.unwrap_or(1),
+            openapi_spec: cli.openapi_spec.clone(),
+            generate_changelog: cli.changelog.unwrap_or(false),
+            generate_community_files: cli.community_files.unwrap_or(false),
+            ci: cli
+                .ci
+                .as_deref()
+                .map(str::parse)
+                .transpose()?
+                .unwrap_or_default(),
+            enable_dependabot: cli.dependabot.unwrap_or(false),
+            homepage: cli
+                .homepage
+                .as_deref()
+                .map(normalize_homepage)
+                .transpose()?,
+            version: cli
+                .project_version
+                .clone()
+                .unwrap_or(DEFAULT_VERSION.to_string()),
+            quality_config: quality_config_from_cli(&cli)?,
+            code_formatter: CodeFormatter::new(
+                &cli.code_formatter
+                    .iter()
+                    .map(String::as_str)
+                    .collect::<Vec<&str>>(),
+            ),
+            ide: IdeConfig::new(&cli.ide.iter().map(String::as_str).collect::<Vec<&str>>()),
+            snippets: unique_snippets(&parse_snippets(&cli.snippets)?),
+            codeowners: cli.codeowners.clone(),
+            conan_options: cli.conan_options.clone(),
+            vcpkg_features: parse_vcpkg_features(&cli.vcpkg_features)?,
+            vcpkg_default_registry: cli.vcpkg_registry_url.clone(),
+            vcpkg_baseline: cli.vcpkg_baseline.clone(),
+            compile_definitions: cli.defines.clone(),
+            enable_lto: cli.lto.unwrap_or(false),
+            coverage_service: cli
+                .coverage_service
+                .as_deref()
+                .map(str::parse)
+                .transpose()?
+                .unwrap_or_default(),
+            target_platform: cli
+                .target
+                .as_deref()
+                .map(str::parse)
+                .transpose()?
+                .unwrap_or_default(),
+            android_abi: cli
+                .android_abi
+                .clone()
+                .unwrap_or(DEFAULT_ANDROID_ABI.to_string()),
+            android_api: cli.android_api.unwrap_or(DEFAULT_ANDROID_API),
+            macos_bundle: cli.macos_bundle.unwrap_or(false),
+            bundle_id: cli.bundle_id.clone(),
+            macos_universal: cli.universal.unwrap_or(false),
+            extra_vars: cli.template_vars.iter().cloned().collect(),
+        };
+
+        // Settings given explicitly on the command line are not asked again,
+        // unless --interactive-defaults asks for them with the given answers
+        let given = defaults.map(prompts::answered_settings).unwrap_or_default();
+        let answered = defaults.map(prompts::skipped_settings).unwrap_or_default();
+        let from_preset = preset_cli
+            .as_ref()
+            .map(prompts::answered_settings)
+            .unwrap_or_default();
+        let remembered = remembered
+            .as_ref()
+            .map(prompts::answered_settings)
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|setting| !from_preset.contains(setting) && !given.contains(setting))
+            .collect::<Vec<_>>();
+
+        let confirm = !defaults.is_some_and(|d| d.no_confirm);
+        let mut config =
+            categorize_cancelled(prompts::run(initial, &answered, &remembered, confirm))?;
+        config.generate_docs_dir =
+            resolve_docs_dir(cli.docs, &config.project_type, &config.doc_engine)?;
+        validate_num_examples(config.num_examples, &config.project_type)?;
+        validate_openapi_spec(config.openapi_spec.as_deref(), &config.project_type)?;
+        validate_codeowners(&config.codeowners)?;
+        validate_conan_options(&config.conan_options, &config.package_manager)?;
+        validate_vcpkg_features(&config.vcpkg_features, &config.package_manager)?;
+        validate_vcpkg_registry(
+            config.vcpkg_default_registry.as_deref(),
+            config.vcpkg_baseline.as_deref(),
+            &config.package_manager,
+        )?;
+        validate_compile_definitions(&config.compile_definitions, &config.build_system)?;
+        validate_clang_tidy_checks(&config.quality_config)?;
+        validate_target_platform(
+            &config.target_platform,
+            &config.android_abi,
+            config.android_api,
+            &config.project_type,
+            &config.build_system,
+        )?;
+        validate_version(&config.version)?;
+        validate_macos_options(
+            config.macos_bundle,
+            config.bundle_id.as_deref(),
+            config.macos_universal,
+            &config.project_type,
+            &config.build_system,
+        )?;
+        config.git_remote = config
+            .git_remote
+            .as_deref()
+            .map(|remote| validate_git_remote(remote, config.use_git))
+            .transpose()?;
+        validate_github_repo(
+            config.github_repo.as_ref(),
+            config.use_git,
+            config.git_commit,
+            config.git_remote.as_deref(),
+        )?;
+        if let Some(path) = &last_run_path {
+            if let Err(error) = last_run::save(path, &config) {
+                eprintln!("Warning: could not remember answers: {:#}", error);
+            }
+        }
+        config.path = config.path.join(&config.name);
+
+        // Check if project directory already exists
+        if config.path.exists() {
+            return Err(CppupError::Conflict(anyhow::anyhow!(
+                "Project directory already exists: {}",
+                config.path.display()
+            ))
+            .into());
+        }
+
+        Ok(config)
+    }
+
+    /// Creates a configuration from `CPPUP_*` environment variables, without
+    /// prompting.
+    ///
+    /// Every command-line option is read from the variable named after it:
+    /// `--build-system` from `CPPUP_BUILD_SYSTEM`, `--quality-tools` from
+    /// `CPPUP_QUALITY_TOOLS` (comma-separated), and so on. Switches such as
+    /// `--verify` take `true` or `false`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - `CPPUP_NAME` or `CPPUP_PROJECT_TYPE` is missing; all missing
+    ///   variables are named
+    /// - A variable holds a value its option does not accept
+    /// - The resulting configuration is invalid
+    ///
+    /// Missing variables and values that do not parse are reported as a
+    /// [`CppupError::Usage`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use cppup::ProjectConfig;
+    ///
+    /// // CPPUP_NAME=my-app CPPUP_PROJECT_TYPE=executable
+    /// let config = ProjectConfig::from_env()?;
+    /// # Ok::<(), anyhow::Error>(())
+    /// ```
+    pub fn from_env() -> Result<Self> {
+        let missing: Vec<&str> = REQUIRED_ENV_VARS
+            .into_iter()
+            .filter(|var| std::env::var_os(var).is_none_or(|value| value.is_empty()))
+            .collect();
+        if !missing.is_empty() {
+            return Err(CppupError::Usage(anyhow::anyhow!(
+                "Missing environment variables: {}",
+                missing.join(", ")
+            ))
+            .into());
+        }
+        create_config_without_prompts(&cli_from_env()?)
+    }
+
+    /// Creates a configuration from `CPPUP_*` environment variables when
+    /// they name the project, and from `cli` otherwise.
+    ///
+    /// The environment is used as a whole once `CPPUP_NAME` or
+    /// `CPPUP_PROJECT_TYPE` is set; the two sources are not mixed. Neither
+    /// source prompts.
+    ///
+    /// # Errors
+    ///
+    /// See [`ProjectConfig::from_env`]. With `cli`, returns an error if a
+    /// required option is missing or the configuration is invalid.
+    pub fn from_env_or_cli(cli: &Cli) -> Result<Self> {
+        if REQUIRED_ENV_VARS
+            .into_iter()
+            .any(|var| std::env::var_os(var).is_some())
+        {
+            Self::from_env()
+        } else {
+            create_config_without_prompts(cli)
+        }
+    }
+}
+
+/// Environment variables [`ProjectConfig::from_env`] cannot do without.
+const REQUIRED_ENV_VARS: [&str; 2] = ["CPPUP_NAME", "CPPUP_PROJECT_TYPE"];
+
+/// Returns the environment variable an option is read from, e.g.
+/// `CPPUP_BUILD_SYSTEM` for `build-system`.
+fn env_var_name(long: &str) -> String {
+    format!("CPPUP_{}", long.replace('-', "_").to_uppercase())
+}
+
+/// Parses the `CPPUP_*` environment variables as if they were given on the
+/// command line.
+fn cli_from_env() -> Result<Cli> {
+    let mut args = vec!["cppup".to_string()];
+    for arg in Cli::command().get_arguments() {
+        let Some(long) = arg.get_long() else {
+            continue;
+        };
+        let var = env_var_name(long);
+        let Ok(value) = std::env::var(&var) else {
+            continue;
         };
+        match arg.get_action() {
+            ArgAction::SetTrue => {
+                let enabled: bool = value.parse().map_err(|_| {
+                    CppupError::Usage(anyhow::anyhow!(
+                        "{} must be true or false, got `{}`",
+                        var,
+                        value
+                    ))
+                })?;
+                if enabled {
+                    args.push(format!("--{}", long));
+                }
+            }
+            ArgAction::Set | ArgAction::Append => args.push(format!("--{}={}", long, value)),
+            _ => {}
+        }
+    }
+    Cli::try_parse_from(args)
+        .context("Invalid CPPUP_* environment variable")
+        .map_err(|error| CppupError::Usage(error).into())
+}
+
+/// Creates a configuration from `cli` alone, after expanding its preset.
+fn create_config_without_prompts(cli: &Cli) -> Result<ProjectConfig> {
+    let mut cli = cli.clone();
+    if let Some(preset) = cli.preset.as_deref().and_then(Preset::find) {
+        preset.apply(&mut cli);
+    }
+    create_config_from_cli(&cli)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ENV_VARS: [&str; 6] = [
+        "CPPUP_NAME",
+        "CPPUP_PROJECT_TYPE",
+        "CPPUP_PATH",
+        "CPPUP_BUILD_SYSTEM",
+        "CPPUP_QUALITY_TOOLS",
+        "CPPUP_VERIFY",
+    ];
+
+    #[test]
+    fn test_from_env_lists_missing_variables() {
+        temp_env::with_vars_unset(ENV_VARS, || {
+            let error = ProjectConfig::from_env().unwrap_err();
+            assert_eq!(
+                error.to_string(),
+                "Missing environment variables: CPPUP_NAME, CPPUP_PROJECT_TYPE"
+            );
+            assert_eq!(
+                crate::error::exit_code(&error),
+                crate::error::USAGE_EXIT_CODE
+            );
+        });
+        temp_env::with_vars(
+            [
+                ("CPPUP_NAME", Some("env-app")),
+                ("CPPUP_PROJECT_TYPE", Some("")),
+            ],
+            || {
+                let error = ProjectConfig::from_env().unwrap_err().to_string();
+                assert_eq!(error, "Missing environment variables: CPPUP_PROJECT_TYPE");
+            },
+        );
+    }
+
+    #[test]
+    fn test_from_env() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().to_str().unwrap();
+        temp_env::with_vars(
+            [
+                ("CPPUP_NAME", Some("env-app")),
+                ("CPPUP_PROJECT_TYPE", Some("library")),
+                ("CPPUP_PATH", Some(path)),
+                ("CPPUP_BUILD_SYSTEM", Some("meson")),
+                ("CPPUP_QUALITY_TOOLS", Some("clang-tidy,cppcheck")),
+                ("CPPUP_VERIFY", Some("false")),
+            ],
+            || {
+                let config = ProjectConfig::from_env().unwrap();
+                assert_eq!(config.name, "env-app");
+                assert_eq!(config.project_type, ProjectType::Library);
+                assert_eq!(config.build_system, BuildSystem::Meson);
+                assert!(config.quality_config.enable_clang_tidy);
+                assert!(config.quality_config.enable_cppcheck);
+                assert_eq!(config.path, temp_dir.path().join("env-app"));
+            },
+        );
+    }
+
+    #[test]
+    fn test_from_env_rejects_invalid_values() {
+        temp_env::with_vars(
+            [
+                ("CPPUP_NAME", Some("env-app")),
+                ("CPPUP_PROJECT_TYPE", Some("executable")),
+                ("CPPUP_BUILD_SYSTEM", Some("bazel")),
+            ],
+            || {
+                let error = ProjectConfig::from_env().unwrap_err();
+                assert_eq!(
+                    crate::error::exit_code(&error),
+                    crate::error::USAGE_EXIT_CODE
+                );
+            },
+        );
+        temp_env::with_vars(
+            [
+                ("CPPUP_NAME", Some("env-app")),
+                ("CPPUP_PROJECT_TYPE", Some("executable")),
+                ("CPPUP_VERIFY", Some("yes")),
+            ],
+            || {
+                let error = ProjectConfig::from_env().unwrap_err().to_string();
+                assert_eq!(error, "CPPUP_VERIFY must be true or false, got `yes`");
+            },
+        );
+    }
+
+    #[test]
+    fn test_from_env_or_cli() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().to_str().unwrap();
+        let cli = Cli::parse_from([
+            "cppup",
+            "--name",
+            "cli-app",
+            "--project-type",
+            "executable",
+            "--path",
+            path,
+        ]);
+
+        temp_env::with_vars_unset(ENV_VARS, || {
+            let config = ProjectConfig::from_env_or_cli(&cli).unwrap();
+            assert_eq!(config.name, "cli-app");
+        });
+        temp_env::with_vars(
+            [
+                ("CPPUP_NAME", Some("env-app")),
+                ("CPPUP_PROJECT_TYPE", Some("library")),
+                ("CPPUP_PATH", Some(path)),
+            ],
+            || {
+                let config = ProjectConfig::from_env_or_cli(&cli).unwrap();
+                assert_eq!(config.name, "env-app");
+                assert_eq!(config.project_type, ProjectType::Library);
+            },
+        );
+    }
 
-        // Choose build system
-        let build_system = Select::new(
-            "Which build system do you want to use?",
-            vec!["CMake", "Make"],
-        )
-        .with_help_message("CMake is recommended for complex projects")
-        .prompt()?;
+    #[test]
+    fn test_normalize_path() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let root = temp_dir.path().canonicalize().unwrap();
+        fs::create_dir(root.join("a")).unwrap();
 
-        let build_system = match build_system {
-            "CMake" => BuildSystem::CMake,
-            "Make" => BuildSystem::Make,
-            _ => unreachable!(),
-        };
+        let home = dirs::home_dir().unwrap().canonicalize().unwrap();
+        assert_eq!(normalize_path(Path::new("~")), home);
+        assert_eq!(
+            normalize_path(Path::new("~/cppup-missing/app")),
+            home.join("cppup-missing").join("app")
+        );
 
-        // Choose C++ standard
-        let cpp_standard = Select::new(
-            "Which C++ standard do you want to use?",
-            vec!["C++11", "C++14", "C++17", "C++20", "C++23"],
-        )
-        .prompt()?;
-
-        let cpp_standard = match cpp_standard {
-            "C++11" => CppStandard::Cpp11,
-            "C++14" => CppStandard::Cpp14,
-            "C++17" => CppStandard::Cpp17,
-            "C++20" => CppStandard::Cpp20,
-            "C++23" => CppStandard::Cpp23,
-            _ => unreachable!(),
-        };
+        let current = std::env::current_dir().unwrap().canonicalize().unwrap();
+        assert_eq!(normalize_path(Path::new(".")), current);
+        assert_eq!(normalize_path(Path::new("..")), current.parent().unwrap());
+        assert_eq!(normalize_path(Path::new("new")), current.join("new"));
 
-        let package_manager = Select::new(
-            "Which package manager would you like to use?",
-            vec!["None", "Conan", "Vcpkg"],
-        )
-        .with_help_message("Package managers help manage external dependencies")
-        .prompt()?;
-
-        let package_manager = match package_manager {
-            "None" => PackageManager::None,
-            "Conan" => PackageManager::Conan,
-            "Vcpkg" => PackageManager::Vcpkg,
-            _ => unreachable!(),
-        };
+        assert_eq!(normalize_path(&root), root);
+        assert_eq!(
+            normalize_path(&root.join("a").join("..").join("a").join("new")),
+            root.join("a").join("new")
+        );
+    }
 
-        let test_framework = Select::new(
-            "Select testing framework:",
-            vec![
-                TestFramework::None,
-                TestFramework::Doctest,
-                TestFramework::GTest,
-                TestFramework::Catch2,
-                TestFramework::BoostTest,
-            ],
-        )
-        .prompt()?;
+    #[test]
+    fn test_builder_create_path() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let missing = temp_dir.path().join("src").join("sandbox");
+        let mut builder = ProjectConfig::builder();
+        builder.name("demo").path(&missing);
 
-        // Git initialization
-        let use_git = Confirm::new("Do you want to initialize git repository?")
-            .with_default(true)
-            .prompt()?;
+        let error = builder.build().unwrap_err();
+        assert!(error.to_string().starts_with("Directory doesn't exist"));
 
-        let license = Select::new(
-            "Which license do you want to use?",
-            vec!["MIT", "Apache-2.0", "GPL-3.0", "BSD-3-Clause"],
-        )
-        .prompt()?;
-
-        let license = match license {
-            "MIT" => License::MIT,
-            "Apache-2.0" => License::Apache2,
-            "GPL-3.0" => License::GPL3,
-            "BSD-3-Clause" => License::BSD3,
-            _ => unreachable!(),
-        };
+        let config = builder.create_path(true).build().unwrap();
+        assert_eq!(config.path, normalize_path(&missing.join("demo")));
+        assert!(!missing.exists());
 
-        let quality_config = if Confirm::new("Do you want to set up code quality tools?")
-            .with_default(true)
-            .prompt()?
-        {
-            let tools = MultiSelect::new(
-                "Which code quality tools would you like to use?",
-                vec![
-                    "clang-tidy (Static analysis)",
-                    "cppcheck (Static analysis)",
-                    "include-what-you-use (Static analysis)",
-                ],
-            )
-            .with_help_message("Use space to select/deselect, enter to confirm")
-            .with_default(&[0])
-            .prompt()?;
-
-            let selected_tools: Vec<&str> = tools
-                .iter()
-                .map(|t| match *t {
-                    "clang-tidy (Static analysis)" => "clang-tidy",
-                    "cppcheck (Static analysis)" => "cppcheck",
-                    "include-what-you-use (Static analysis)" => "include-what-you-use",
-                    _ => unreachable!(),
-                })
-                .collect();
-            QualityConfig::new(&selected_tools)
-        } else {
-            QualityConfig::new(&[])
-        };
+        fs::write(temp_dir.path().join("file"), "").unwrap();
+        builder.path(temp_dir.path().join("file").join("sub"));
+        let error = builder.build().unwrap_err();
+        assert!(error.to_string().starts_with("Path is not a directory"));
+    }
 
-        let code_formatter = if Confirm::new("Do you want to set up code formatter?")
-            .with_default(true)
-            .prompt()?
-        {
-            let tools = MultiSelect::new(
-                "Which code formatter would you like to use?",
-                vec![
-                    "clang-format (Code formatting)",
-                    "cmake-format (Code formatting)",
-                ],
-            )
-            .with_help_message("Use space to select/deselect, enter to confirm")
-            .with_default(&[0])
-            .prompt()?;
-
-            let selected_tools: Vec<&str> = tools
-                .iter()
-                .map(|t| match *t {
-                    "clang-format (Code formatting)" => "clang-format",
-                    "cmake-format (Code formatting)" => "cmake-format",
-                    _ => unreachable!(),
-                })
-                .collect();
-            CodeFormatter::new(&selected_tools)
-        } else {
-            CodeFormatter::new(&[])
-        };
+    #[test]
+    fn test_validate_version() {
+        for version in ["1", "0.1.0", "2.10", "1.2.3.4"] {
+            assert!(validate_version(version).is_ok(), "{}", version);
+        }
+        for version in ["", "v1.0", "1.2.3.4.5", "1.0-beta", "1..2"] {
+            assert!(validate_version(version).is_err(), "{}", version);
+        }
+    }
 
-        Ok(ProjectConfig {
-            name,
-            project_type,
-            build_system,
-            cpp_standard,
-            use_git,
-            path: project_path,
-            package_manager,
-            license,
-            author,
-            description,
-            version: DEFAULT_VERSION.to_string(),
-            quality_config,
-            code_formatter,
-            test_framework,
-        })
+    #[test]
+    fn test_check_source_date_epoch() {
+        assert!(check_source_date_epoch(None).is_ok());
+        assert!(check_source_date_epoch(Some("1700000000")).is_ok());
+        assert!(check_source_date_epoch(Some("last week"))
+            .unwrap_err()
+            .to_string()
+            .contains("SOURCE_DATE_EPOCH"));
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[test]
+    fn test_strip_verbatim_prefix() {
+        assert_eq!(
+            strip_verbatim_prefix(PathBuf::from(r"\\?\C:\dev\demo")),
+            PathBuf::from(r"C:\dev\demo")
+        );
+        assert_eq!(
+            strip_verbatim_prefix(PathBuf::from(r"\\?\UNC\server\share")),
+            PathBuf::from(r"\\?\UNC\server\share")
+        );
+        assert_eq!(
+            strip_verbatim_prefix(PathBuf::from("/home/me")),
+            PathBuf::from("/home/me")
+        );
+    }
+
+    #[test]
+    fn test_validate_project_path_unicode() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("プロジェクト").join("Проекты");
+        fs::create_dir_all(&path).unwrap();
+
+        assert!(validate_project_path(&path).is_ok());
+        assert!(validate_project_path(&path.join("нет")).is_err());
+    }
 
     #[test]
-    fn test_validate_project_name_valid() {
-        assert!(validate_project_name("my-project").is_ok());
-        assert!(validate_project_name("my_project").is_ok());
-        assert!(validate_project_name("MyProject123").is_ok());
-        assert!(validate_project_name("a").is_ok());
+    fn test_validate_description_valid() {
+        assert!(validate_description("A C++ project").is_ok());
+        assert!(validate_description("").is_ok());
+        assert!(validate_description(&"a".repeat(500)).is_ok());
     }
 
     #[test]
-    fn test_validate_project_name_empty() {
-        let result = validate_project_name("");
-        assert!(result.is_err());
+    fn test_validate_description_too_long() {
+        let result = validate_description(&"a".repeat(501));
         assert_eq!(
             result.unwrap_err().to_string(),
-            "Project name cannot be empty"
+            "Description is too long (maximum 500 characters)"
         );
     }
 
     #[test]
-    fn test_validate_project_name_starts_with_number() {
-        let result = validate_project_name("123project");
-        assert!(result.is_err());
+    fn test_validate_description_line_breaks() {
+        let result = validate_description("first line\nsecond line");
         assert_eq!(
             result.unwrap_err().to_string(),
-            "Project name cannot start with a number"
+            "Description cannot contain line breaks"
         );
+        assert!(validate_description("carriage\rreturn").is_err());
     }
 
     #[test]
-    fn test_validate_project_name_invalid_characters() {
-        let result = validate_project_name("my project!");
-        assert!(result.is_err());
+    fn test_validate_author() {
+        assert!(validate_author("Test Author").is_ok());
         assert_eq!(
-            result.unwrap_err().to_string(),
-            "Project name can only contain alphanumeric characters, '-' and '_'"
+            validate_author("   ").unwrap_err().to_string(),
+            "Author cannot be empty"
         );
+        assert!(validate_author("").is_err());
+    }
+
+    #[test]
+    fn test_control_character_warning() {
+        assert!(control_character_warning("Description", "A plain description").is_none());
+        assert!(control_character_warning("Description", "Unicode 🚀 ünïcode").is_none());
+        assert!(control_character_warning("Description", "Bell \u{7} char").is_some());
+        assert!(control_character_warning("Author", "Tab\there")
+            .unwrap()
+            .starts_with("Warning: Author"));
     }
 
     #[test]
-    fn test_validate_project_name_too_long() {
-        let long_name = "a".repeat(101);
-        let result = validate_project_name(&long_name);
-        assert!(result.is_err());
-        assert_eq!(result.unwrap_err().to_string(), "Project name is too long");
+    fn test_categorize_cancelled() {
+        use crate::error::{exit_code, CANCELLED_EXIT_CODE};
+
+        let cancelled: Result<()> = Err(InquireError::OperationInterrupted.into());
+        let error = categorize_cancelled(cancelled).unwrap_err();
+        assert_eq!(exit_code(&error), CANCELLED_EXIT_CODE);
+
+        let failed: Result<()> = Err(InquireError::NotTTY.into());
+        let error = categorize_cancelled(failed).unwrap_err();
+        assert_eq!(exit_code(&error), crate::error::FAILURE_EXIT_CODE);
+        assert!(categorize_cancelled(Ok(1)).is_ok());
     }
 
     #[test]
-    fn test_validate_project_name_exactly_100_chars() {
-        let name = "a".repeat(100);
-        assert!(validate_project_name(&name).is_ok());
+    fn test_is_cancellation() {
+        assert!(is_cancellation(&InquireError::OperationCanceled));
+        assert!(is_cancellation(&InquireError::OperationInterrupted));
+        assert!(!is_cancellation(&InquireError::NotTTY));
+        assert!(!is_cancellation(&InquireError::InvalidConfiguration(
+            "bad".to_string()
+        )));
     }
 
     #[test]
@@ -612,4 +2571,640 @@ mod tests {
         assert_eq!(ProjectType::Executable.to_string(), "executable");
         assert_eq!(ProjectType::Library.to_string(), "library");
     }
+
+    #[test]
+    fn test_builder_defaults() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let config = ProjectConfig::builder()
+            .name("app")
+            .path(temp_dir.path())
+            .build()
+            .unwrap();
+
+        assert_eq!(config.path, temp_dir.path().join("app"));
+        assert_eq!(config.project_type, ProjectType::Executable);
+        assert_eq!(config.build_system, BuildSystem::CMake);
+        assert_eq!(config.description, DEFAULT_DESCRIPTION);
+        assert_eq!(config.version, DEFAULT_VERSION);
+        assert!(config.use_git);
+        assert!(!config.generate_docs_dir);
+    }
+
+    #[test]
+    fn test_builder_requires_name_and_path() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+
+        let result = ProjectConfig::builder().path(temp_dir.path()).build();
+        assert_eq!(result.unwrap_err().to_string(), "Project name is required");
+
+        let result = ProjectConfig::builder().name("app").build();
+        assert_eq!(result.unwrap_err().to_string(), "Project path is required");
+    }
+
+    #[test]
+    fn test_builder_rejects_invalid_name() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let result = ProjectConfig::builder()
+            .name("123app")
+            .path(temp_dir.path())
+            .build();
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Project name cannot start with a number"
+        );
+    }
+
+    #[test]
+    fn test_builder_rejects_missing_path() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let missing = temp_dir.path().join("missing");
+        let result = ProjectConfig::builder().name("app").path(&missing).build();
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            format!("Directory doesn't exist: {}", missing.display())
+        );
+    }
+
+    #[test]
+    fn test_builder_rejects_existing_directory() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        fs::create_dir(temp_dir.path().join("app")).unwrap();
+        let result = ProjectConfig::builder()
+            .name("app")
+            .path(temp_dir.path())
+            .build();
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .starts_with("Project directory already exists"));
+    }
+
+    #[test]
+    fn test_validate_branch_name() {
+        for valid in ["main", "trunk", "develop", "release/1.0", "feature-x_y"] {
+            assert!(validate_branch_name(valid).is_ok(), "{} rejected", valid);
+        }
+        for invalid in [
+            "",
+            "-main",
+            "@",
+            "a b",
+            "a..b",
+            "a~1",
+            "a^",
+            "a:b",
+            "a?",
+            "a*",
+            "a[b",
+            "a\\b",
+            "main.",
+            "main.lock",
+            "/main",
+            "main/",
+            "a//b",
+            "a/.hidden",
+            "a@{1}",
+            "a\tb",
+        ] {
+            assert!(
+                validate_branch_name(invalid).is_err(),
+                "{:?} accepted",
+                invalid
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate_git_remote() {
+        for valid in [
+            "git@github.com:me/proj.git",
+            "https://github.com/me/proj.git",
+            "ssh://git@gitlab.com/me/proj.git",
+            "https://git.example.com/team/proj",
+        ] {
+            assert!(
+                validate_git_remote(valid, true).is_ok(),
+                "{} rejected",
+                valid
+            );
+        }
+        for invalid in [
+            "proj",
+            "github.com:me/proj",
+            "https://github.com",
+            "git@github.com:",
+            "https://a b/c",
+        ] {
+            assert!(
+                validate_git_remote(invalid, true).is_err(),
+                "{} accepted",
+                invalid
+            );
+        }
+        assert_eq!(
+            validate_git_remote("git@github.com:me/proj.git", false)
+                .unwrap_err()
+                .to_string(),
+            "A git remote requires git to be enabled"
+        );
+    }
+
+    #[test]
+    fn test_cpp_standard_helpers() {
+        assert_eq!(CppStandard::oldest(), CppStandard::Cpp11);
+        assert_eq!(CppStandard::latest(), CppStandard::Cpp23);
+
+        let standards = [
+            CppStandard::Cpp11,
+            CppStandard::Cpp14,
+            CppStandard::Cpp17,
+            CppStandard::Cpp20,
+            CppStandard::Cpp23,
+        ];
+        let years: Vec<u16> = standards.iter().map(CppStandard::year).collect();
+        assert_eq!(years, [2011, 2014, 2017, 2020, 2023]);
+        for standard in &standards {
+            assert_eq!(
+                standard.year() % 100,
+                standard.to_string().parse::<u16>().unwrap()
+            );
+        }
+
+        let versions: Vec<&str> = standards
+            .iter()
+            .map(CppStandard::cmake_min_version)
+            .collect();
+        assert_eq!(versions, ["3.1", "3.1", "3.8", "3.12", "3.20"]);
+    }
+
+    #[test]
+    fn test_option_defaults_match_cli_defaults() {
+        assert_eq!(BuildSystem::default(), BuildSystem::CMake);
+        assert_eq!(PackageManager::default(), PackageManager::None);
+        assert_eq!(TestFramework::default(), TestFramework::None);
+        assert_eq!(License::default(), License::MIT);
+        assert_eq!(CppStandard::default(), CppStandard::Cpp17);
+        assert_eq!(ProjectType::default(), ProjectType::Executable);
+        assert_eq!(CmakeGenerator::default(), CmakeGenerator::Default);
+        assert_eq!(TestRunner::default(), TestRunner::Direct);
+        assert_eq!(SanitizerPreset::default(), SanitizerPreset::None);
+        assert_eq!(DocEngine::default(), DocEngine::Markdown);
+        assert_eq!(QualityConfig::default(), QualityConfig::new(&[]));
+        assert_eq!(CodeFormatter::default(), CodeFormatter::new(&[]));
+        assert_eq!(IdeConfig::default(), IdeConfig::new(&[]));
+    }
+
+    #[test]
+    fn test_project_config_default_matches_builder() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let built = ProjectConfig::builder()
+            .name("foo")
+            .path(temp_dir.path())
+            .build()
+            .unwrap();
+        let config = ProjectConfig {
+            name: "foo".into(),
+            path: temp_dir.path().join("foo"),
+            ..Default::default()
+        };
+
+        // Compare through serde, as ProjectConfig has no PartialEq
+        assert_eq!(
+            serde_json::to_value(&config).unwrap(),
+            serde_json::to_value(&built).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_builder_num_examples() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let mut builder = ProjectConfig::builder();
+        builder.name("examples").path(temp_dir.path());
+        assert_eq!(builder.build().unwrap().num_examples, 1);
+
+        builder.num_examples(3);
+        assert_eq!(
+            builder.build().unwrap_err().to_string(),
+            "Examples are only generated for library projects"
+        );
+
+        builder.project_type(ProjectType::Library);
+        assert_eq!(builder.build().unwrap().num_examples, 3);
+
+        for invalid in [0, 100] {
+            builder.num_examples(invalid);
+            assert!(builder.build().is_err());
+        }
+    }
+
+    #[test]
+    fn test_normalize_homepage() {
+        assert_eq!(
+            normalize_homepage("https://github.com/acme/app/").unwrap(),
+            "https://github.com/acme/app"
+        );
+        assert!(normalize_homepage("http://example.com").is_ok());
+        for invalid in [
+            "github.com/acme/app",
+            "https://",
+            "ftp://example.com",
+            "https://a b",
+        ] {
+            assert!(normalize_homepage(invalid).is_err(), "{} accepted", invalid);
+        }
+    }
+
+    #[test]
+    fn test_builder_sphinx_requires_docs_dir() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let mut builder = ProjectConfig::builder();
+        builder
+            .name("app")
+            .path(temp_dir.path())
+            .doc_engine(DocEngine::Sphinx);
+
+        let result = builder.build();
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "The Sphinx documentation engine is only available for library projects"
+        );
+
+        let config = builder.project_type(ProjectType::Library).build().unwrap();
+        assert!(config.generate_docs_dir);
+
+        let result = builder.generate_docs_dir(false).build();
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "The Sphinx documentation engine requires the docs/ directory"
+        );
+
+        let result = builder.doc_engine(DocEngine::Doxygen).build();
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "The Doxygen documentation engine requires the docs/ directory"
+        );
+    }
+
+    #[test]
+    fn test_builder_rejects_cmake_options_without_cmake() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let mut builder = ProjectConfig::builder();
+        builder
+            .name("app")
+            .path(temp_dir.path())
+            .build_system(BuildSystem::Make);
+
+        let result = builder
+            .clone()
+            .cmake_generator(CmakeGenerator::Ninja)
+            .build();
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "A CMake generator can only be selected with the CMake build system"
+        );
+
+        let result = builder
+            .clone()
+            .sanitizer_preset(SanitizerPreset::Address)
+            .build();
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Sanitizer presets require the CMake build system"
+        );
+
+        let result = builder.ide(IdeConfig::new(&["clion"])).build();
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "CLion project files require the CMake build system"
+        );
+    }
+
+    #[test]
+    fn test_parse_vcpkg_features() {
+        let features = parse_vcpkg_features(&[
+            "compression:Compression support:zlib, bzip2".to_string(),
+            "docs:Documentation".to_string(),
+        ])
+        .unwrap();
+        assert_eq!(features["compression"].description, "Compression support");
+        assert_eq!(features["compression"].dependencies, vec!["zlib", "bzip2"]);
+        assert!(features["docs"].dependencies.is_empty());
+
+        assert!(parse_vcpkg_features(&["compression".to_string()]).is_err());
+        let error =
+            parse_vcpkg_features(&["docs:Docs".to_string(), "docs:Again".to_string()]).unwrap_err();
+        assert_eq!(error.to_string(), "vcpkg feature 'docs' is given twice");
+    }
+
+    #[test]
+    fn test_validate_vcpkg_features() {
+        let feature = |description: &str| VcpkgFeature {
+            description: description.to_string(),
+            dependencies: vec!["zlib".to_string()],
+        };
+        let features = |name: &str, description: &str| {
+            HashMap::from([(name.to_string(), feature(description))])
+        };
+        assert!(validate_vcpkg_features(&HashMap::new(), &PackageManager::None).is_ok());
+        assert!(
+            validate_vcpkg_features(&features("zlib-2", "Zlib"), &PackageManager::Vcpkg).is_ok()
+        );
+        assert!(
+            validate_vcpkg_features(&features("zlib", "Zlib"), &PackageManager::Conan).is_err()
+        );
+        for invalid in ["Zlib", "zlib_support", "-zlib", ""] {
+            assert!(
+                validate_vcpkg_features(&features(invalid, "Zlib"), &PackageManager::Vcpkg)
+                    .is_err(),
+                "{}",
+                invalid
+            );
+        }
+        assert!(validate_vcpkg_features(&features("zlib", " "), &PackageManager::Vcpkg).is_err());
+    }
+
+    #[test]
+    fn test_builder_android_target() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let mut builder = ProjectConfig::builder();
+        builder
+            .name("native-lib")
+            .path(temp_dir.path())
+            .target_platform(TargetPlatform::Android);
+
+        let error = builder.clone().build().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "The Android target is only available for library projects, which are loaded through JNI"
+        );
+
+        builder.project_type(ProjectType::Library);
+        let config = builder.clone().build().unwrap();
+        assert_eq!(config.target_platform, TargetPlatform::Android);
+        assert_eq!(config.android_abi, "arm64-v8a");
+        assert_eq!(config.android_api, 24);
+
+        let error = builder.clone().android_abi("mips").build().unwrap_err();
+        assert!(error.to_string().starts_with("Unknown Android ABI 'mips'"));
+        let error = builder.clone().android_api(19).build().unwrap_err();
+        assert!(error
+            .to_string()
+            .starts_with("Android API level 19 is too old"));
+        let error = builder
+            .build_system(BuildSystem::Meson)
+            .build()
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "The Android target requires the CMake build system"
+        );
+
+        let error = builder
+            .target_platform(TargetPlatform::WindowsMingw)
+            .build()
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "The Windows MinGW target requires the CMake or Make build system"
+        );
+        assert!(builder.build_system(BuildSystem::Make).build().is_ok());
+    }
+
+    #[test]
+    fn test_validate_macos_options() {
+        let executable = ProjectType::Executable;
+        let cmake = BuildSystem::CMake;
+        assert!(
+            validate_macos_options(true, Some("com.example.app"), true, &executable, &cmake)
+                .is_ok()
+        );
+        assert!(validate_macos_options(false, None, true, &ProjectType::Library, &cmake).is_ok());
+
+        let error = |bundle, id, universal, project_type, build_system| {
+            validate_macos_options(bundle, id, universal, project_type, build_system)
+                .unwrap_err()
+                .to_string()
+        };
+        assert_eq!(
+            error(true, None, false, &ProjectType::Library, &cmake),
+            "macOS bundles are only available for executable projects"
+        );
+        assert_eq!(
+            error(true, None, false, &executable, &BuildSystem::Make),
+            "macOS bundles require the CMake build system"
+        );
+        assert_eq!(
+            error(false, None, true, &executable, &BuildSystem::Meson),
+            "Universal macOS binaries require the CMake build system"
+        );
+        assert_eq!(
+            error(false, Some("com.example.app"), false, &executable, &cmake),
+            "A bundle identifier is only used for a macOS bundle"
+        );
+        for invalid in ["app", "com.example.my_app", "com..app", ".com.app"] {
+            assert!(error(true, Some(invalid), false, &executable, &cmake)
+                .starts_with("Invalid bundle identifier"));
+        }
+    }
+
+    #[test]
+    fn test_validate_compile_definitions() {
+        let definitions = |definitions: &[&str]| {
+            definitions
+                .iter()
+                .map(|d| d.to_string())
+                .collect::<Vec<_>>()
+        };
+        assert!(validate_compile_definitions(&[], &BuildSystem::Make).is_ok());
+        assert!(validate_compile_definitions(
+            &definitions(&["USE_SIMD", "_LOG_LEVEL=2", "GREETING=\"hello world\""]),
+            &BuildSystem::CMake
+        )
+        .is_ok());
+        assert_eq!(
+            validate_compile_definitions(&definitions(&["USE_SIMD"]), &BuildSystem::Meson)
+                .unwrap_err()
+                .to_string(),
+            "Compile definitions require the CMake build system"
+        );
+        for invalid in [
+            "use_simd",
+            "2FAST",
+            "-DUSE_SIMD",
+            "USE SIMD",
+            "=1",
+            "NAME=a\nb",
+        ] {
+            let error = validate_compile_definitions(&definitions(&[invalid]), &BuildSystem::CMake)
+                .unwrap_err();
+            assert!(error.to_string().contains(&format!("'{}'", invalid)));
+        }
+    }
+
+    #[test]
+    fn test_validate_vcpkg_registry() {
+        let registry = Some("https://github.com/org/vcpkg-registry");
+        let baseline = Some("3f2c6e8d1a4b5c6d7e8f9a0b1c2d3e4f5a6b7c8d");
+        assert!(validate_vcpkg_registry(None, None, &PackageManager::None).is_ok());
+        assert!(validate_vcpkg_registry(registry, baseline, &PackageManager::Vcpkg).is_ok());
+        assert!(validate_vcpkg_registry(registry, None, &PackageManager::Vcpkg).is_ok());
+        assert!(validate_vcpkg_registry(registry, baseline, &PackageManager::Conan).is_err());
+        assert!(validate_vcpkg_registry(None, baseline, &PackageManager::Vcpkg).is_err());
+        assert!(
+            validate_vcpkg_registry(Some("my registry"), None, &PackageManager::Vcpkg).is_err()
+        );
+        for invalid in [
+            "main",
+            "3f2c6e8d",
+            "zf2c6e8d1a4b5c6d7e8f9a0b1c2d3e4f5a6b7c8d",
+        ] {
+            let error = validate_vcpkg_registry(registry, Some(invalid), &PackageManager::Vcpkg)
+                .unwrap_err();
+            assert!(error.to_string().contains(&format!("'{}'", invalid)));
+        }
+    }
+
+    #[test]
+    fn test_validate_conan_options() {
+        let options = |options: &[&str]| options.iter().map(|o| o.to_string()).collect::<Vec<_>>();
+        assert!(validate_conan_options(&[], &PackageManager::None).is_ok());
+        assert!(validate_conan_options(
+            &options(&["fmt:shared=True", "boost/*:header_only=True"]),
+            &PackageManager::Conan
+        )
+        .is_ok());
+        assert!(
+            validate_conan_options(&options(&["fmt:shared=True"]), &PackageManager::Vcpkg).is_err()
+        );
+        for invalid in [
+            "fmt",
+            "fmt:shared",
+            "fmt=True",
+            ":shared=True",
+            "fmt:shared=",
+        ] {
+            let error =
+                validate_conan_options(&options(&[invalid]), &PackageManager::Conan).unwrap_err();
+            assert!(error.to_string().contains(&format!("'{}'", invalid)));
+        }
+    }
+
+    #[test]
+    fn test_validate_codeowners() {
+        let owners = |owners: &[&str]| owners.iter().map(|o| o.to_string()).collect::<Vec<_>>();
+        assert!(validate_codeowners(&owners(&["@org/cpp-team", "@jane"])).is_ok());
+        for invalid in ["org/cpp-team", "@", "@org team", ""] {
+            let error = validate_codeowners(&owners(&["@jane", invalid])).unwrap_err();
+            assert!(error.to_string().contains(&format!("'{}'", invalid)));
+        }
+    }
+
+    #[test]
+    fn test_builder_drops_repeated_snippets() {
+        let config = ProjectConfig::builder()
+            .name("snippets")
+            .path(".")
+            .snippets(vec![Snippet::Logger, Snippet::ThreadPool, Snippet::Logger])
+            .build()
+            .unwrap();
+        assert_eq!(config.snippets, vec![Snippet::Logger, Snippet::ThreadPool]);
+    }
+
+    #[test]
+    fn test_project_type_and_cpp_standard_serde() {
+        for (project_type, value) in [
+            (ProjectType::Executable, "executable"),
+            (ProjectType::Library, "library"),
+        ] {
+            let json = serde_json::to_string(&project_type).unwrap();
+            assert_eq!(json, format!("\"{}\"", value));
+            assert_eq!(
+                serde_json::from_str::<ProjectType>(&json).unwrap(),
+                project_type
+            );
+            assert_eq!(value.parse::<ProjectType>().unwrap(), project_type);
+        }
+
+        for (standard, value) in [
+            (CppStandard::Cpp11, "11"),
+            (CppStandard::Cpp14, "14"),
+            (CppStandard::Cpp17, "17"),
+            (CppStandard::Cpp20, "20"),
+            (CppStandard::Cpp23, "23"),
+        ] {
+            let json = serde_json::to_string(&standard).unwrap();
+            assert_eq!(json, format!("\"{}\"", value));
+            assert_eq!(
+                serde_json::from_str::<CppStandard>(&json).unwrap(),
+                standard
+            );
+            assert_eq!(value.parse::<CppStandard>().unwrap(), standard);
+        }
+
+        assert_eq!(
+            "98".parse::<CppStandard>().unwrap_err().to_string(),
+            "Unknown C++ standard: 98"
+        );
+    }
+
+    #[test]
+    fn test_project_config_serde_round_trip() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let config = ProjectConfig::builder()
+            .name("app")
+            .path(temp_dir.path())
+            .project_type(ProjectType::Library)
+            .test_framework(TestFramework::Catch2)
+            .quality_config(QualityConfig::new(&["cppcheck"]))
+            .build()
+            .unwrap();
+
+        let json = serde_json::to_value(&config).unwrap();
+        assert_eq!(json["project_type"], "library");
+        assert_eq!(json["test_framework"], "catch2");
+        assert_eq!(json["quality_config"], serde_json::json!(["cppcheck"]));
+
+        let parsed: ProjectConfig = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(serde_json::to_value(&parsed).unwrap(), json);
+    }
+
+    #[test]
+    fn test_project_config_display() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let config = ProjectConfig::builder()
+            .name("app")
+            .description("A demo")
+            .author("Jane")
+            .path(temp_dir.path())
+            .project_type(ProjectType::Library)
+            .cpp_standard(CppStandard::Cpp20)
+            .test_framework(TestFramework::Catch2)
+            .quality_config(QualityConfig::new(&["cppcheck"]))
+            .use_git(false)
+            .build()
+            .unwrap();
+
+        let output = config.to_string();
+        for (label, value) in [
+            ("Name", "app".to_string()),
+            ("Description", "A demo".to_string()),
+            ("Author", "Jane".to_string()),
+            ("Path", config.path.display().to_string()),
+            ("Project type", ProjectType::Library.to_string()),
+            ("Build system", BuildSystem::CMake.to_string()),
+            ("CMake generator", CmakeGenerator::Default.to_string()),
+            ("C++ standard", "C++20".to_string()),
+            ("Package manager", PackageManager::None.to_string()),
+            ("Test framework", TestFramework::Catch2.to_string()),
+            ("Test runner", TestRunner::Direct.to_string()),
+            ("License", License::MIT.to_string()),
+            ("Quality tools", "cppcheck".to_string()),
+            ("Formatters", "none".to_string()),
+            ("IDE files", "none".to_string()),
+            ("Git", "no".to_string()),
+        ] {
+            let row = format!("{:<16} {}", format!("{}:", label), value);
+            assert!(output.lines().any(|line| line == row), "missing {:?}", row);
+        }
+    }
 }
@@ -0,0 +1,170 @@
+//! Creating the project's GitHub repository with the `gh` CLI.
+//!
+//! Every `gh` invocation goes through a [`CommandRunner`], so the argument
+//! construction can be tested without the network.
+
+use super::builder::repository_web_url;
+use super::tool::{CommandRunner, ToolCommand};
+use super::{ProjectConfig, RepoVisibility};
+use anyhow::{Context, Result};
+use std::path::Path;
+use std::time::Duration;
+
+/// How long a `gh` call, including the push of the initial commit, may take
+/// before it is stopped.
+const GH_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// Runs `gh` with `args` in `dir` and returns its standard output.
+fn run_gh<I, S>(runner: &dyn CommandRunner, args: I, dir: &Path) -> Result<String>
+where
+    I: IntoIterator<Item = S>,
+    S: Into<String>,
+{
+    let command = ToolCommand::new("gh")
+        .args(args)
+        .current_dir(dir)
+        .timeout(GH_TIMEOUT);
+    Ok(runner.run_checked(&command)?.stdout)
+}
+
+/// Returns `owner/name` for a github.com remote URL.
+pub(super) fn repository_slug(remote: &str) -> Option<String> {
+    repository_web_url(remote)?
+        .strip_prefix("https://github.com/")
+        .map(str::to_string)
+}
+
+/// Arguments for `gh repo create`. The repository is named after the
+/// GitHub remote if one is set, otherwise after the project under the
+/// authenticated account.
+pub(super) fn repo_create_args(config: &ProjectConfig, visibility: &RepoVisibility) -> Vec<String> {
+    let name = config
+        .git_remote
+        .as_deref()
+        .and_then(repository_slug)
+        .unwrap_or_else(|| config.name.clone());
+    let mut args = vec!["repo".to_string(), "create".to_string(), name];
+    args.push(format!("--{}", visibility));
+    if !config.description.is_empty() {
+        args.extend(["--description".to_string(), config.description.clone()]);
+    }
+    if let Some(homepage) = &config.homepage {
+        args.extend(["--homepage".to_string(), homepage.clone()]);
+    }
+    args.extend(["--source", ".", "--push"].map(String::from));
+    args
+}
+
+/// Checks that `gh` is logged in, so generation fails before any file is
+/// written.
+pub(super) fn check_authenticated(runner: &dyn CommandRunner, dir: &Path) -> Result<()> {
+    run_gh(runner, ["auth", "status"], dir)
+        .map(|_| ())
+        .context("gh is not authenticated; run `gh auth login` first")
+}
+
+/// Creates the repository and pushes the initial commit. Returns the URL
+/// printed by `gh`.
+pub(super) fn create_repository(
+    runner: &dyn CommandRunner,
+    config: &ProjectConfig,
+    visibility: &RepoVisibility,
+) -> Result<String> {
+    let output = run_gh(runner, repo_create_args(config, visibility), &config.path)?;
+    Ok(output.trim().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::project::tool::{CommandOutput, MockRunner};
+    use std::path::PathBuf;
+    use std::sync::Mutex;
+
+    fn config() -> ProjectConfig {
+        ProjectConfig {
+            name: "widget".to_string(),
+            description: "A widget".to_string(),
+            path: PathBuf::from("/tmp/widget"),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_repo_create_args_public_and_private() {
+        let config = config();
+        assert_eq!(
+            repo_create_args(&config, &RepoVisibility::Public),
+            [
+                "repo",
+                "create",
+                "widget",
+                "--public",
+                "--description",
+                "A widget",
+                "--source",
+                ".",
+                "--push"
+            ]
+        );
+        let args = repo_create_args(&config, &RepoVisibility::Private);
+        assert!(args.contains(&"--private".to_string()));
+        assert!(!args.contains(&"--public".to_string()));
+    }
+
+    #[test]
+    fn test_repo_create_args_follow_remote() {
+        let mut config = config();
+        config.git_remote = Some("git@github.com:acme/widget-cpp.git".to_string());
+        config.homepage = Some("https://acme.dev".to_string());
+        let args = repo_create_args(&config, &RepoVisibility::Public);
+        assert_eq!(args[2], "acme/widget-cpp");
+        assert!(args
+            .windows(2)
+            .any(|pair| pair == ["--homepage", "https://acme.dev"]));
+    }
+
+    #[test]
+    fn test_repository_slug() {
+        assert_eq!(
+            repository_slug("https://github.com/acme/widget.git").as_deref(),
+            Some("acme/widget")
+        );
+        assert_eq!(repository_slug("git@gitlab.com:acme/widget.git"), None);
+    }
+
+    #[test]
+    fn test_create_repository_runs_in_project() {
+        let dirs = Mutex::new(Vec::new());
+        let runner = MockRunner::new(|command: &ToolCommand| {
+            dirs.lock().unwrap().push(command.dir.clone());
+            Ok(CommandOutput::ok("https://github.com/me/widget\n"))
+        });
+        let url = create_repository(&runner, &config(), &RepoVisibility::Private).unwrap();
+        assert_eq!(url, "https://github.com/me/widget");
+        let calls = runner.calls();
+        assert_eq!(calls.len(), 1);
+        assert!(calls[0].starts_with("gh repo create widget --private"));
+        assert_eq!(*dirs.lock().unwrap(), [Some(PathBuf::from("/tmp/widget"))]);
+    }
+
+    #[test]
+    fn test_check_authenticated() {
+        let runner = MockRunner::new(|_: &ToolCommand| Ok(CommandOutput::ok("Logged in")));
+        assert!(check_authenticated(&runner, Path::new(".")).is_ok());
+        assert_eq!(runner.calls(), ["gh auth status"]);
+
+        let runner = MockRunner::new(|_: &ToolCommand| {
+            Ok(CommandOutput::failed(
+                1,
+                "You are not logged into any GitHub hosts",
+            ))
+        });
+        let error = check_authenticated(&runner, Path::new(".")).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "gh is not authenticated; run `gh auth login` first"
+        );
+        assert!(format!("{:#}", error).contains("not logged into any GitHub hosts"));
+    }
+}
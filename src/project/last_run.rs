@@ -0,0 +1,297 @@
+//! Answers remembered from the previous interactive run.
+//!
+//! After an interactive run the chosen settings (except the project name and
+//! location) are stored as command-line arguments in the cppup config
+//! directory. The next interactive run uses them as prompt defaults.
+
+use super::config::ProjectConfig;
+use super::TestFramework;
+use crate::cli::Cli;
+use anyhow::{Context, Result};
+use clap::Parser;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Oldest cppup version whose remembered answers can still be read.
+const MIN_COMPATIBLE_VERSION: &str = "0.1.0";
+
+#[derive(Debug, Serialize, Deserialize)]
+struct LastRun {
+    /// cppup version that wrote the file
+    version: String,
+    /// Remembered answers as command-line arguments
+    args: Vec<String>,
+}
+
+/// Location of the remembered answers file, if a config directory exists.
+pub(super) fn default_path() -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join("cppup").join("last-run.json"))
+}
+
+/// Loads the remembered answers as a parsed [`Cli`].
+///
+/// Returns `None` when the file is missing, unreadable, written by an
+/// incompatible cppup version, or contains arguments this version rejects.
+pub(super) fn load(path: &Path) -> Option<Cli> {
+    let content = fs::read_to_string(path).ok()?;
+    let last_run: LastRun = serde_json::from_str(&content).ok()?;
+    if !is_compatible(&last_run.version) {
+        return None;
+    }
+
+    Cli::try_parse_from(std::iter::once("cppup".to_string()).chain(last_run.args)).ok()
+}
+
+/// Stores the answers of an interactive run, except name and location.
+pub(super) fn save(path: &Path, config: &ProjectConfig) -> Result<()> {
+    let last_run = LastRun {
+        version: env!("CARGO_PKG_VERSION").to_string(),
+        args: remembered_args(config),
+    };
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create {}", parent.display()))?;
+    }
+    let content =
+        serde_json::to_string_pretty(&last_run).context("Failed to serialize last run")?;
+    fs::write(path, content).with_context(|| format!("Failed to write {}", path.display()))
+}
+
+fn remembered_args(config: &ProjectConfig) -> Vec<String> {
+    let test_framework = match config.test_framework {
+        TestFramework::BoostTest => "boosttest".to_string(),
+        ref framework => framework.to_string(),
+    };
+
+    let mut args = vec![
+        ("--description", config.description.clone()),
+        ("--author", config.author.clone()),
+        ("--project-type", config.project_type.to_string()),
+        ("--build-system", config.build_system.to_string()),
+        ("--cmake-generator", config.cmake_generator.to_string()),
+        ("--cpp-standard", config.cpp_standard.to_string()),
+        ("--package-manager", config.package_manager.to_string()),
+        ("--test-framework", test_framework),
+        ("--test-runner", config.test_runner.to_string()),
+        ("--license", config.license.to_string()),
+        ("--git", config.use_git.to_string()),
+        ("--git-commit", config.git_commit.to_string()),
+        ("--git-branch", config.git_branch.clone()),
+        ("--num-examples", config.num_examples.to_string()),
+    ];
+    for (flag, names) in [
+        ("--quality-tools", config.quality_config.to_string()),
+        (
+            "--clang-tidy-checks",
+            config
+                .quality_config
+                .clang_tidy_checks
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", "),
+        ),
+        ("--code-formatter", config.code_formatter.to_string()),
+        ("--ide", config.ide.to_string()),
+    ] {
+        if !names.is_empty() {
+            args.push((flag, names.replace(", ", ",")));
+        }
+    }
+
+    // `--flag=value` keeps values starting with '-' from being read as flags
+    args.into_iter()
+        .map(|(flag, value)| format!("{}={}", flag, value))
+        .collect()
+}
+
+/// Returns whether answers written by `version` can be read by this version.
+fn is_compatible(version: &str) -> bool {
+    match (
+        parse_version(version),
+        parse_version(MIN_COMPATIBLE_VERSION),
+    ) {
+        (Some(version), Some(minimum)) => version >= minimum,
+        _ => false,
+    }
+}
+
+fn parse_version(version: &str) -> Option<(u64, u64, u64)> {
+    let mut parts = version.split('.').map(|part| part.parse::<u64>().ok());
+    let version = (parts.next()??, parts.next()??, parts.next()??);
+    parts.next().is_none().then_some(version)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::project::config::{CppStandard, ProjectType};
+    use crate::project::{
+        BuildSystem, CiProvider, CmakeGenerator, CodeFormatter, CoverageService, CppExtension,
+        DocEngine, HppExtension, IdeConfig, License, LineEndings, PackageManager, QualityConfig,
+        SanitizerPreset, TargetPlatform, TestRunner,
+    };
+    use std::collections::HashMap;
+    use tempfile::TempDir;
+
+    fn create_test_config() -> ProjectConfig {
+        ProjectConfig {
+            name: "test-project".to_string(),
+            description: "A test project".to_string(),
+            project_type: ProjectType::Library,
+            build_system: BuildSystem::CMake,
+            cmake_generator: CmakeGenerator::Default,
+            cpp_standard: CppStandard::Cpp20,
+            test_framework: TestFramework::BoostTest,
+            test_runner: TestRunner::Asan,
+            package_manager: PackageManager::Conan,
+            license: License::Apache2,
+            use_git: false,
+            git_commit: false,
+            force_git: false,
+            git_branch: "main".to_string(),
+            git_remote: None,
+            github_repo: None,
+            cpp_extension: CppExtension::Cpp,
+            hpp_extension: HppExtension::Hpp,
+            line_endings: LineEndings::Lf,
+            sanitizer_preset: SanitizerPreset::None,
+            generate_docs_dir: false,
+            doc_engine: DocEngine::Markdown,
+            num_examples: 1,
+            openapi_spec: None,
+            generate_changelog: false,
+            generate_community_files: false,
+            ci: CiProvider::None,
+            enable_dependabot: false,
+            homepage: None,
+            path: PathBuf::from("/tmp"),
+            author: "Test Author".to_string(),
+            version: "0.1.0".to_string(),
+            quality_config: QualityConfig::new(&["clang-tidy", "cppcheck"]),
+            code_formatter: CodeFormatter::new(&[]),
+            ide: IdeConfig::new(&["clion"]),
+            snippets: Vec::new(),
+            codeowners: Vec::new(),
+            conan_options: Vec::new(),
+            vcpkg_features: HashMap::new(),
+            vcpkg_default_registry: None,
+            vcpkg_baseline: None,
+            compile_definitions: Vec::new(),
+            enable_lto: false,
+            coverage_service: CoverageService::None,
+            target_platform: TargetPlatform::Host,
+            android_abi: "arm64-v8a".to_string(),
+            android_api: 24,
+            macos_bundle: false,
+            bundle_id: None,
+            macos_universal: false,
+            extra_vars: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_save_and_load_round_trip() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("cppup/last-run.json");
+
+        save(&path, &create_test_config()).unwrap();
+        let cli = load(&path).unwrap();
+
+        assert_eq!(cli.name, None);
+        assert_eq!(cli.path, None);
+        assert_eq!(cli.description.as_deref(), Some("A test project"));
+        assert_eq!(cli.project_type.as_deref(), Some("library"));
+        assert_eq!(cli.cpp_standard.as_deref(), Some("20"));
+        assert_eq!(cli.test_framework.as_deref(), Some("boosttest"));
+        assert_eq!(cli.test_runner.as_deref(), Some("asan"));
+        assert_eq!(cli.package_manager.as_deref(), Some("conan"));
+        assert_eq!(cli.license.as_deref(), Some("Apache-2.0"));
+        assert_eq!(cli.git, Some(false));
+        assert_eq!(cli.quality_tools, vec!["clang-tidy", "cppcheck"]);
+        assert!(cli.code_formatter.is_empty());
+        assert_eq!(cli.ide, vec!["clion"]);
+    }
+
+    #[test]
+    fn test_round_trip_keeps_values_starting_with_dash() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("last-run.json");
+        let mut config = create_test_config();
+        config.description = "--not a flag".to_string();
+
+        save(&path, &config).unwrap();
+        assert_eq!(
+            load(&path).unwrap().description.as_deref(),
+            Some("--not a flag")
+        );
+    }
+
+    #[test]
+    fn test_load_ignores_missing_or_invalid_files() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("last-run.json");
+        assert!(load(&path).is_none());
+
+        fs::write(&path, "not json").unwrap();
+        assert!(load(&path).is_none());
+
+        fs::write(
+            &path,
+            r#"{"version": "0.1.0", "args": ["--build-system", "bazel"]}"#,
+        )
+        .unwrap();
+        assert!(load(&path).is_none());
+    }
+
+    #[test]
+    fn test_load_ignores_incompatible_versions() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("last-run.json");
+
+        fs::write(
+            &path,
+            r#"{"version": "0.0.9", "args": ["--cpp-standard", "20"]}"#,
+        )
+        .unwrap();
+        assert!(load(&path).is_none());
+
+        fs::write(
+            &path,
+            r#"{"version": "0.1.0", "args": ["--cpp-standard", "20"]}"#,
+        )
+        .unwrap();
+        assert_eq!(load(&path).unwrap().cpp_standard.as_deref(), Some("20"));
+    }
+
+    #[test]
+    fn test_is_compatible() {
+        assert!(is_compatible("0.1.0"));
+        assert!(is_compatible("1.2.3"));
+        assert!(!is_compatible("0.0.9"));
+        assert!(!is_compatible("not-a-version"));
+        assert!(!is_compatible("0.1"));
+    }
+
+    #[test]
+    fn test_fill_missing_prefers_explicit_values() {
+        let mut cli = Cli::try_parse_from(["cppup", "--cpp-standard", "17"]).unwrap();
+        let remembered = Cli::try_parse_from([
+            "cppup",
+            "--cpp-standard",
+            "20",
+            "--test-framework",
+            "gtest",
+            "--ide",
+            "emacs",
+        ])
+        .unwrap();
+
+        cli.fill_missing(&remembered);
+        assert_eq!(cli.cpp_standard.as_deref(), Some("17"));
+        assert_eq!(cli.test_framework.as_deref(), Some("gtest"));
+        assert_eq!(cli.ide, vec!["emacs"]);
+    }
+}
@@ -3,14 +3,48 @@
 //! This module provides the core functionality for creating and configuring
 //! C++ projects, including validation, building, and template rendering.
 
+mod artifact;
 mod builder;
+mod codegen;
 mod config;
+mod github;
+mod last_run;
+mod overwrite;
+mod presets;
+mod prompts;
+mod tool;
 mod validator;
 
-pub use builder::ProjectBuilder;
+pub use artifact::generate_from_cli;
+pub use builder::{BuildReport, ProjectBuilder};
 pub use config::ProjectConfig;
 pub use validator::ProjectValidator;
 
+// Library API only; the binary, which declares these modules itself, does
+// not use them by these paths
+#[allow(unused_imports)]
+pub use artifact::{generate_artifact, Artifact, ArtifactTemplateData};
+#[allow(unused_imports)]
+pub use builder::{CommandSummary, VerifyOutcome};
+#[allow(unused_imports)]
+pub use codegen::{load_spec, ApiGroup, ApiOperation, ApiParameter, ApiSpec};
+#[allow(unused_imports)]
+pub use config::{CppStandard, ProjectConfigBuilder, ProjectType};
+#[allow(unused_imports)]
+pub use overwrite::{prompt_decision, review, ChangeSummary, Decision, FileChange};
+#[allow(unused_imports)]
+pub use tool::{CommandOutput, CommandRunner, SystemRunner, ToolCommand};
+#[allow(unused_imports)]
+pub use validator::CompatibilityChecker;
+
+pub(crate) use config::categorize_cancelled;
+#[cfg(test)]
+pub(crate) use tool::MockRunner;
+
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::str::FromStr;
+
 /// Build system options for the generated project.
 ///
 /// # Examples
@@ -21,12 +55,18 @@ pub use validator::ProjectValidator;
 /// let system = BuildSystem::CMake;
 /// assert_eq!(system.to_string(), "cmake");
 /// ```
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub enum BuildSystem {
     /// CMake build system (recommended for complex projects)
+    #[serde(rename = "cmake")]
+    #[default]
     CMake,
     /// GNU Make build system
+    #[serde(rename = "make")]
     Make,
+    /// Meson build system with the Ninja backend
+    #[serde(rename = "meson")]
+    Meson,
 }
 
 impl std::fmt::Display for BuildSystem {
@@ -34,6 +74,267 @@ impl std::fmt::Display for BuildSystem {
         match self {
             BuildSystem::CMake => write!(f, "cmake"),
             BuildSystem::Make => write!(f, "make"),
+            BuildSystem::Meson => write!(f, "meson"),
+        }
+    }
+}
+
+impl FromStr for BuildSystem {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "cmake" => Ok(BuildSystem::CMake),
+            "make" => Ok(BuildSystem::Make),
+            "meson" => Ok(BuildSystem::Meson),
+            _ => Err(anyhow::anyhow!("Unknown build system: {}", value)),
+        }
+    }
+}
+
+/// CMake generator used by the generated `CMakePresets.json`.
+///
+/// # Examples
+///
+/// ```
+/// use cppup::project::CmakeGenerator;
+///
+/// let generator = CmakeGenerator::NinjaMultiConfig;
+/// assert_eq!(generator.to_string(), "ninja-multi-config");
+/// assert_eq!(generator.cmake_name(), Some("Ninja Multi-Config"));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub enum CmakeGenerator {
+    /// Let CMake pick its platform default; no presets file is generated
+    #[serde(rename = "default")]
+    #[default]
+    Default,
+    /// Single-configuration Ninja
+    #[serde(rename = "ninja")]
+    Ninja,
+    /// Ninja Multi-Config, building Debug and Release from one configure step
+    #[serde(rename = "ninja-multi-config")]
+    NinjaMultiConfig,
+    /// Unix Makefiles
+    #[serde(rename = "unix-makefiles")]
+    UnixMakefiles,
+    /// Visual Studio 17 2022
+    #[serde(rename = "vs2022")]
+    VisualStudio2022,
+}
+
+impl CmakeGenerator {
+    /// Returns the generator name understood by `cmake -G`, or `None` for
+    /// the platform default.
+    pub fn cmake_name(&self) -> Option<&'static str> {
+        match self {
+            CmakeGenerator::Default => None,
+            CmakeGenerator::Ninja => Some("Ninja"),
+            CmakeGenerator::NinjaMultiConfig => Some("Ninja Multi-Config"),
+            CmakeGenerator::UnixMakefiles => Some("Unix Makefiles"),
+            CmakeGenerator::VisualStudio2022 => Some("Visual Studio 17 2022"),
+        }
+    }
+
+    /// Returns whether the generator builds several configurations from one
+    /// build directory.
+    pub fn is_multi_config(&self) -> bool {
+        matches!(
+            self,
+            CmakeGenerator::NinjaMultiConfig | CmakeGenerator::VisualStudio2022
+        )
+    }
+}
+
+impl std::fmt::Display for CmakeGenerator {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            CmakeGenerator::Default => write!(f, "default"),
+            CmakeGenerator::Ninja => write!(f, "ninja"),
+            CmakeGenerator::NinjaMultiConfig => write!(f, "ninja-multi-config"),
+            CmakeGenerator::UnixMakefiles => write!(f, "unix-makefiles"),
+            CmakeGenerator::VisualStudio2022 => write!(f, "vs2022"),
+        }
+    }
+}
+
+impl FromStr for CmakeGenerator {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "default" => Ok(CmakeGenerator::Default),
+            "ninja" => Ok(CmakeGenerator::Ninja),
+            "ninja-multi-config" => Ok(CmakeGenerator::NinjaMultiConfig),
+            "unix-makefiles" => Ok(CmakeGenerator::UnixMakefiles),
+            "vs2022" => Ok(CmakeGenerator::VisualStudio2022),
+            _ => Err(anyhow::anyhow!("Unknown CMake generator: {}", value)),
+        }
+    }
+}
+
+/// File extension for C++ source files.
+///
+/// # Examples
+///
+/// ```
+/// use cppup::project::CppExtension;
+///
+/// assert_eq!(CppExtension::Cxx.to_string(), "cxx");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub enum CppExtension {
+    /// `.cpp`
+    #[serde(rename = "cpp")]
+    #[default]
+    Cpp,
+    /// `.cxx`
+    #[serde(rename = "cxx")]
+    Cxx,
+    /// `.cc`
+    #[serde(rename = "cc")]
+    Cc,
+}
+
+impl std::fmt::Display for CppExtension {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            CppExtension::Cpp => write!(f, "cpp"),
+            CppExtension::Cxx => write!(f, "cxx"),
+            CppExtension::Cc => write!(f, "cc"),
+        }
+    }
+}
+
+impl FromStr for CppExtension {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "cpp" => Ok(CppExtension::Cpp),
+            "cxx" => Ok(CppExtension::Cxx),
+            "cc" => Ok(CppExtension::Cc),
+            _ => Err(anyhow::anyhow!("Unknown source file extension: {}", value)),
+        }
+    }
+}
+
+/// File extension for C++ header files.
+///
+/// # Examples
+///
+/// ```
+/// use cppup::project::HppExtension;
+///
+/// assert_eq!(HppExtension::H.to_string(), "h");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub enum HppExtension {
+    /// `.hpp`
+    #[serde(rename = "hpp")]
+    #[default]
+    Hpp,
+    /// `.h`
+    #[serde(rename = "h")]
+    H,
+    /// `.hxx`
+    #[serde(rename = "hxx")]
+    Hxx,
+}
+
+impl std::fmt::Display for HppExtension {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            HppExtension::Hpp => write!(f, "hpp"),
+            HppExtension::H => write!(f, "h"),
+            HppExtension::Hxx => write!(f, "hxx"),
+        }
+    }
+}
+
+impl FromStr for HppExtension {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "hpp" => Ok(HppExtension::Hpp),
+            "h" => Ok(HppExtension::H),
+            "hxx" => Ok(HppExtension::Hxx),
+            _ => Err(anyhow::anyhow!("Unknown header file extension: {}", value)),
+        }
+    }
+}
+
+/// Line endings of the generated files.
+///
+/// Windows batch and PowerShell scripts always get CRLF line endings, and
+/// shell scripts and other files starting with a shebang always get LF, as
+/// a `\r` would end up in their interpreter's name.
+///
+/// # Examples
+///
+/// ```
+/// use cppup::project::LineEndings;
+/// use std::path::Path;
+///
+/// assert!(!LineEndings::Lf.uses_crlf(Path::new("CMakeLists.txt"), ""));
+/// assert!(LineEndings::Lf.uses_crlf(Path::new("scripts/build.bat"), ""));
+/// assert!(!LineEndings::Crlf.uses_crlf(Path::new("scripts/run"), "#!/bin/sh\n"));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub enum LineEndings {
+    /// `\n`, as the templates are written
+    #[serde(rename = "lf")]
+    #[default]
+    Lf,
+    /// `\r\n`
+    #[serde(rename = "crlf")]
+    Crlf,
+    /// CRLF on Windows, LF elsewhere
+    #[serde(rename = "native")]
+    Native,
+}
+
+impl LineEndings {
+    /// Whether the file at `path` with `contents` is written with CRLF line
+    /// endings.
+    pub fn uses_crlf(self, path: &Path, contents: &str) -> bool {
+        let has_extension = |names: &[&str]| {
+            path.extension().is_some_and(|extension| {
+                names
+                    .iter()
+                    .any(|name| extension.eq_ignore_ascii_case(name))
+            })
+        };
+        match self {
+            _ if has_extension(&["bat", "ps1"]) => true,
+            _ if has_extension(&["sh"]) || contents.starts_with("#!") => false,
+            LineEndings::Lf => false,
+            LineEndings::Crlf => true,
+            LineEndings::Native => cfg!(windows),
+        }
+    }
+}
+
+impl std::fmt::Display for LineEndings {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            LineEndings::Lf => write!(f, "lf"),
+            LineEndings::Crlf => write!(f, "crlf"),
+            LineEndings::Native => write!(f, "native"),
+        }
+    }
+}
+
+impl FromStr for LineEndings {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "lf" => Ok(LineEndings::Lf),
+            "crlf" => Ok(LineEndings::Crlf),
+            "native" => Ok(LineEndings::Native),
+            _ => Err(anyhow::anyhow!("Unknown line endings: {}", value)),
         }
     }
 }
@@ -52,16 +353,25 @@ impl std::fmt::Display for BuildSystem {
 /// assert_eq!(license.to_string(), "MIT");
 /// ```
 #[allow(clippy::upper_case_acronyms)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub enum License {
     /// MIT License - Permissive license with minimal restrictions
+    #[serde(rename = "MIT")]
+    #[default]
     MIT,
     /// Apache License 2.0 - Permissive license with patent grant
+    #[serde(rename = "Apache-2.0")]
     Apache2,
     /// GNU General Public License v3.0 - Copyleft license
+    #[serde(rename = "GPL-3.0")]
     GPL3,
     /// BSD 3-Clause License - Permissive license
+    #[serde(rename = "BSD-3-Clause")]
     BSD3,
+    /// Boost Software License 1.0 - Permissive license without attribution
+    /// requirements for binaries
+    #[serde(rename = "BSL-1.0")]
+    BSL1,
 }
 
 impl std::fmt::Display for License {
@@ -71,6 +381,22 @@ impl std::fmt::Display for License {
             License::Apache2 => write!(f, "Apache-2.0"),
             License::GPL3 => write!(f, "GPL-3.0"),
             License::BSD3 => write!(f, "BSD-3-Clause"),
+            License::BSL1 => write!(f, "BSL-1.0"),
+        }
+    }
+}
+
+impl FromStr for License {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "MIT" => Ok(License::MIT),
+            "Apache-2.0" => Ok(License::Apache2),
+            "GPL-3.0" => Ok(License::GPL3),
+            "BSD-3-Clause" => Ok(License::BSD3),
+            "BSL-1.0" => Ok(License::BSL1),
+            _ => Err(anyhow::anyhow!("Unknown license: {}", value)),
         }
     }
 }
@@ -85,13 +411,17 @@ impl std::fmt::Display for License {
 /// let pm = PackageManager::Conan;
 /// assert_eq!(pm.to_string(), "conan");
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub enum PackageManager {
     /// Conan package manager (<https://conan.io/>)
+    #[serde(rename = "conan")]
     Conan,
     /// Vcpkg package manager (<https://vcpkg.io/>)
+    #[serde(rename = "vcpkg")]
     Vcpkg,
     /// No package manager
+    #[serde(rename = "none")]
+    #[default]
     None,
 }
 
@@ -105,6 +435,19 @@ impl std::fmt::Display for PackageManager {
     }
 }
 
+impl FromStr for PackageManager {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "conan" => Ok(PackageManager::Conan),
+            "vcpkg" => Ok(PackageManager::Vcpkg),
+            "none" => Ok(PackageManager::None),
+            _ => Err(anyhow::anyhow!("Unknown package manager: {}", value)),
+        }
+    }
+}
+
 /// Configuration for code quality and static analysis tools.
 ///
 /// Allows enabling multiple static analysis tools for the generated project.
@@ -119,7 +462,8 @@ impl std::fmt::Display for PackageManager {
 /// assert!(config.enable_cppcheck);
 /// assert!(!config.enable_include_what_you_use);
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "QualityConfigRepr", into = "QualityConfigRepr")]
 pub struct QualityConfig {
     /// Enable clang-tidy static analyzer
     pub enable_clang_tidy: bool,
@@ -127,6 +471,8 @@ pub struct QualityConfig {
     pub enable_cppcheck: bool,
     /// Enable include-what-you-use tool
     pub enable_include_what_you_use: bool,
+    /// clang-tidy check categories; empty keeps the default check set
+    pub clang_tidy_checks: Vec<ClangTidyCategory>,
 }
 
 impl QualityConfig {
@@ -149,12 +495,39 @@ impl QualityConfig {
             enable_clang_tidy: tools.contains(&"clang-tidy"),
             enable_cppcheck: tools.contains(&"cppcheck"),
             enable_include_what_you_use: tools.contains(&"include-what-you-use"),
+            clang_tidy_checks: Vec::new(),
         }
     }
-}
 
-impl std::fmt::Display for QualityConfig {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    /// The `Checks:` globs of the selected clang-tidy categories.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cppup::project::{ClangTidyCategory, QualityConfig};
+    ///
+    /// let mut config = QualityConfig::new(&["clang-tidy"]);
+    /// config.clang_tidy_checks = vec![ClangTidyCategory::Modernize, ClangTidyCategory::Bugprone];
+    /// assert_eq!(config.clang_tidy_check_globs(), vec!["modernize-*", "bugprone-*"]);
+    /// ```
+    pub fn clang_tidy_check_globs(&self) -> Vec<String> {
+        self.clang_tidy_checks
+            .iter()
+            .map(|category| format!("{}-*", category))
+            .collect()
+    }
+
+    /// Names of the enabled tools, in a fixed order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cppup::project::QualityConfig;
+    ///
+    /// let config = QualityConfig::new(&["cppcheck", "clang-tidy"]);
+    /// assert_eq!(config.names(), vec!["clang-tidy", "cppcheck"]);
+    /// ```
+    pub fn names(&self) -> Vec<&'static str> {
         let mut tools = Vec::new();
 
         if self.enable_clang_tidy {
@@ -167,7 +540,149 @@ impl std::fmt::Display for QualityConfig {
             tools.push("include-what-you-use");
         }
 
-        write!(f, "{}", tools.join(", "))
+        tools
+    }
+}
+
+impl std::fmt::Display for QualityConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.names().join(", "))
+    }
+}
+
+impl TryFrom<Vec<String>> for QualityConfig {
+    type Error = anyhow::Error;
+
+    fn try_from(names: Vec<String>) -> Result<Self, Self::Error> {
+        let names: Vec<&str> = names.iter().map(String::as_str).collect();
+        if let Some(unknown) = names
+            .iter()
+            .find(|name| QualityConfig::new(&[name]).names().is_empty())
+        {
+            return Err(anyhow::anyhow!("Unknown quality tool: {}", unknown));
+        }
+        Ok(QualityConfig::new(&names))
+    }
+}
+
+impl From<QualityConfig> for Vec<String> {
+    fn from(config: QualityConfig) -> Self {
+        config.names().into_iter().map(String::from).collect()
+    }
+}
+
+/// Serialized form of [`QualityConfig`]: the tool names, or the tool names
+/// and the clang-tidy check categories once categories are selected.
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum QualityConfigRepr {
+    Tools(Vec<String>),
+    WithChecks {
+        tools: Vec<String>,
+        clang_tidy_checks: Vec<ClangTidyCategory>,
+    },
+}
+
+impl TryFrom<QualityConfigRepr> for QualityConfig {
+    type Error = anyhow::Error;
+
+    fn try_from(repr: QualityConfigRepr) -> Result<Self, Self::Error> {
+        match repr {
+            QualityConfigRepr::Tools(tools) => QualityConfig::try_from(tools),
+            QualityConfigRepr::WithChecks {
+                tools,
+                clang_tidy_checks,
+            } => Ok(QualityConfig {
+                clang_tidy_checks,
+                ..QualityConfig::try_from(tools)?
+            }),
+        }
+    }
+}
+
+impl From<QualityConfig> for QualityConfigRepr {
+    fn from(config: QualityConfig) -> Self {
+        if config.clang_tidy_checks.is_empty() {
+            return QualityConfigRepr::Tools(config.into());
+        }
+        QualityConfigRepr::WithChecks {
+            clang_tidy_checks: config.clang_tidy_checks.clone(),
+            tools: config.into(),
+        }
+    }
+}
+
+/// Category of clang-tidy checks, enabled in `.clang-tidy` as `<category>-*`.
+///
+/// # Examples
+///
+/// ```
+/// use cppup::project::ClangTidyCategory;
+///
+/// let category: ClangTidyCategory = "cppcoreguidelines".parse().unwrap();
+/// assert_eq!(category, ClangTidyCategory::CppCoreGuidelines);
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ClangTidyCategory {
+    /// Checks that suggest newer C++ constructs
+    #[serde(rename = "modernize")]
+    Modernize,
+    /// Checks for bug-prone code
+    #[serde(rename = "bugprone")]
+    Bugprone,
+    /// Checks for performance problems
+    #[serde(rename = "performance")]
+    Performance,
+    /// Checks for readability problems
+    #[serde(rename = "readability")]
+    Readability,
+    /// Checks for the C++ Core Guidelines
+    #[serde(rename = "cppcoreguidelines")]
+    CppCoreGuidelines,
+    /// Checks for the CERT secure coding guidelines
+    #[serde(rename = "cert")]
+    Cert,
+    /// Checks for the High Integrity C++ coding standard
+    #[serde(rename = "hicpp")]
+    Hicpp,
+    /// Checks for portability problems
+    #[serde(rename = "portability")]
+    Portability,
+}
+
+impl std::fmt::Display for ClangTidyCategory {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            ClangTidyCategory::Modernize => write!(f, "modernize"),
+            ClangTidyCategory::Bugprone => write!(f, "bugprone"),
+            ClangTidyCategory::Performance => write!(f, "performance"),
+            ClangTidyCategory::Readability => write!(f, "readability"),
+            ClangTidyCategory::CppCoreGuidelines => write!(f, "cppcoreguidelines"),
+            ClangTidyCategory::Cert => write!(f, "cert"),
+            ClangTidyCategory::Hicpp => write!(f, "hicpp"),
+            ClangTidyCategory::Portability => write!(f, "portability"),
+        }
+    }
+}
+
+impl FromStr for ClangTidyCategory {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "modernize" => Ok(ClangTidyCategory::Modernize),
+            "bugprone" => Ok(ClangTidyCategory::Bugprone),
+            "performance" => Ok(ClangTidyCategory::Performance),
+            "readability" => Ok(ClangTidyCategory::Readability),
+            "cppcoreguidelines" => Ok(ClangTidyCategory::CppCoreGuidelines),
+            "cert" => Ok(ClangTidyCategory::Cert),
+            "hicpp" => Ok(ClangTidyCategory::Hicpp),
+            "portability" => Ok(ClangTidyCategory::Portability),
+            _ => Err(anyhow::anyhow!(
+                "Unknown clang-tidy check category: {}",
+                value
+            )),
+        }
     }
 }
 
@@ -184,12 +699,15 @@ impl std::fmt::Display for QualityConfig {
 /// assert!(formatter.enable_clang_format);
 /// assert!(formatter.enable_cmake_format);
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "Vec<String>", into = "Vec<String>")]
 pub struct CodeFormatter {
     /// Enable clang-format for C++ code
     pub enable_clang_format: bool,
     /// Enable cmake-format for CMake files
     pub enable_cmake_format: bool,
+    /// Enable cmake-lint for CMake files
+    pub enable_cmake_lint: bool,
 }
 
 impl CodeFormatter {
@@ -197,7 +715,7 @@ impl CodeFormatter {
     ///
     /// # Arguments
     ///
-    /// * `tools` - Slice of formatter names ("clang-format", "cmake-format")
+    /// * `tools` - Slice of formatter names ("clang-format", "cmake-format", "cmake-lint")
     ///
     /// # Examples
     ///
@@ -212,12 +730,21 @@ impl CodeFormatter {
         Self {
             enable_clang_format: tools.contains(&"clang-format"),
             enable_cmake_format: tools.contains(&"cmake-format"),
+            enable_cmake_lint: tools.contains(&"cmake-lint"),
         }
     }
-}
 
-impl std::fmt::Display for CodeFormatter {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    /// Names of the enabled formatters, in a fixed order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cppup::project::CodeFormatter;
+    ///
+    /// let formatter = CodeFormatter::new(&["cmake-format", "clang-format"]);
+    /// assert_eq!(formatter.names(), vec!["clang-format", "cmake-format"]);
+    /// ```
+    pub fn names(&self) -> Vec<&'static str> {
         let mut tools = Vec::new();
 
         if self.enable_clang_format {
@@ -226,8 +753,144 @@ impl std::fmt::Display for CodeFormatter {
         if self.enable_cmake_format {
             tools.push("cmake-format");
         }
+        if self.enable_cmake_lint {
+            tools.push("cmake-lint");
+        }
+
+        tools
+    }
+}
 
-        write!(f, "{}", tools.join(", "))
+impl std::fmt::Display for CodeFormatter {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.names().join(", "))
+    }
+}
+
+impl TryFrom<Vec<String>> for CodeFormatter {
+    type Error = anyhow::Error;
+
+    fn try_from(names: Vec<String>) -> Result<Self, Self::Error> {
+        let names: Vec<&str> = names.iter().map(String::as_str).collect();
+        if let Some(unknown) = names
+            .iter()
+            .find(|name| CodeFormatter::new(&[name]).names().is_empty())
+        {
+            return Err(anyhow::anyhow!("Unknown code formatter: {}", unknown));
+        }
+        Ok(CodeFormatter::new(&names))
+    }
+}
+
+impl From<CodeFormatter> for Vec<String> {
+    fn from(config: CodeFormatter) -> Self {
+        config.names().into_iter().map(String::from).collect()
+    }
+}
+
+/// Configuration for IDE project files.
+///
+/// Allows generating project files for multiple IDEs at once.
+///
+/// # Examples
+///
+/// ```
+/// use cppup::project::IdeConfig;
+///
+/// let ide = IdeConfig::new(&["clion"]);
+/// assert!(ide.enable_clion);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "Vec<String>", into = "Vec<String>")]
+pub struct IdeConfig {
+    /// Generate JetBrains CLion `.idea/` CMake profiles and run configuration
+    pub enable_clion: bool,
+    /// Generate KDevelop `.kdev4` project files
+    pub enable_kdevelop: bool,
+    /// Generate Emacs `.dir-locals.el` clangd configuration
+    pub enable_emacs: bool,
+    /// Generate a Sublime Text `.sublime-project` file
+    pub enable_sublime: bool,
+}
+
+impl IdeConfig {
+    /// Creates a new IdeConfig from a list of IDE names.
+    ///
+    /// # Arguments
+    ///
+    /// * `ides` - Slice of IDE names ("clion", "kdevelop", "emacs", "sublime")
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cppup::project::IdeConfig;
+    ///
+    /// let ide = IdeConfig::new(&[]);
+    /// assert!(!ide.enable_clion);
+    /// ```
+    pub fn new(ides: &[&str]) -> Self {
+        Self {
+            enable_clion: ides.contains(&"clion"),
+            enable_kdevelop: ides.contains(&"kdevelop"),
+            enable_emacs: ides.contains(&"emacs"),
+            enable_sublime: ides.contains(&"sublime"),
+        }
+    }
+
+    /// Returns the names of the enabled IDEs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cppup::project::IdeConfig;
+    ///
+    /// let ide = IdeConfig::new(&["emacs", "clion"]);
+    /// assert_eq!(ide.names(), vec!["clion", "emacs"]);
+    /// ```
+    pub fn names(&self) -> Vec<&'static str> {
+        let mut ides = Vec::new();
+
+        if self.enable_clion {
+            ides.push("clion");
+        }
+        if self.enable_kdevelop {
+            ides.push("kdevelop");
+        }
+        if self.enable_emacs {
+            ides.push("emacs");
+        }
+        if self.enable_sublime {
+            ides.push("sublime");
+        }
+
+        ides
+    }
+}
+
+impl std::fmt::Display for IdeConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.names().join(", "))
+    }
+}
+
+impl TryFrom<Vec<String>> for IdeConfig {
+    type Error = anyhow::Error;
+
+    fn try_from(names: Vec<String>) -> Result<Self, Self::Error> {
+        let names: Vec<&str> = names.iter().map(String::as_str).collect();
+        if let Some(unknown) = names
+            .iter()
+            .find(|name| IdeConfig::new(&[name]).names().is_empty())
+        {
+            return Err(anyhow::anyhow!("Unknown IDE: {}", unknown));
+        }
+        Ok(IdeConfig::new(&names))
+    }
+}
+
+impl From<IdeConfig> for Vec<String> {
+    fn from(config: IdeConfig) -> Self {
+        config.names().into_iter().map(String::from).collect()
     }
 }
 
@@ -241,17 +904,23 @@ impl std::fmt::Display for CodeFormatter {
 /// let framework = TestFramework::Doctest;
 /// assert_eq!(framework.to_string(), "doctest");
 /// ```
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub enum TestFramework {
     /// doctest - Fast, header-only testing framework
+    #[serde(rename = "doctest")]
     Doctest,
     /// Google Test - Google's C++ testing framework
+    #[serde(rename = "gtest")]
     GTest,
     /// Catch2 - Modern, header-only testing framework
+    #[serde(rename = "catch2")]
     Catch2,
     /// Boost.Test - Part of the Boost library collection
+    #[serde(rename = "boosttest")]
     BoostTest,
     /// No testing framework
+    #[serde(rename = "none")]
+    #[default]
     None,
 }
 
@@ -267,14 +936,617 @@ impl std::fmt::Display for TestFramework {
     }
 }
 
+impl FromStr for TestFramework {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "doctest" => Ok(TestFramework::Doctest),
+            "gtest" => Ok(TestFramework::GTest),
+            "catch2" => Ok(TestFramework::Catch2),
+            "boosttest" | "boost" => Ok(TestFramework::BoostTest),
+            "none" => Ok(TestFramework::None),
+            _ => Err(anyhow::anyhow!("Unknown test framework: {}", value)),
+        }
+    }
+}
+
+/// Strategy used to launch the generated test executables.
+///
+/// # Examples
+///
+/// ```
+/// use cppup::project::TestRunner;
+///
+/// let runner = TestRunner::Valgrind;
+/// assert_eq!(runner.to_string(), "valgrind");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub enum TestRunner {
+    /// Run test executables directly (framework test discovery where available)
+    #[serde(rename = "direct")]
+    #[default]
+    Direct,
+    /// Run test executables under valgrind's memcheck
+    #[serde(rename = "valgrind")]
+    Valgrind,
+    /// Build test executables with AddressSanitizer and run them directly
+    #[serde(rename = "asan")]
+    Asan,
+    /// Wrap test invocations in `ctest --build-and-test`
+    #[serde(rename = "ctest")]
+    CTestWrapper,
+}
+
+impl std::fmt::Display for TestRunner {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            TestRunner::Direct => write!(f, "direct"),
+            TestRunner::Valgrind => write!(f, "valgrind"),
+            TestRunner::Asan => write!(f, "asan"),
+            TestRunner::CTestWrapper => write!(f, "ctest"),
+        }
+    }
+}
+
+impl FromStr for TestRunner {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "direct" => Ok(TestRunner::Direct),
+            "valgrind" => Ok(TestRunner::Valgrind),
+            "asan" => Ok(TestRunner::Asan),
+            "ctest" => Ok(TestRunner::CTestWrapper),
+            _ => Err(anyhow::anyhow!("Unknown test runner: {}", value)),
+        }
+    }
+}
+
+/// Named set of sanitizers applied by the generated `cmake/sanitizers.cmake`.
+///
+/// # Examples
+///
+/// ```
+/// use cppup::project::SanitizerPreset;
+///
+/// let preset = SanitizerPreset::AddressAndUndefined;
+/// assert_eq!(preset.to_string(), "address-undefined");
+/// assert_eq!(preset.sanitizers(), ["address", "undefined"]);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub enum SanitizerPreset {
+    /// No sanitizers
+    #[serde(rename = "none")]
+    #[default]
+    None,
+    /// AddressSanitizer
+    #[serde(rename = "address")]
+    Address,
+    /// ThreadSanitizer
+    #[serde(rename = "thread")]
+    Thread,
+    /// MemorySanitizer (Clang only)
+    #[serde(rename = "memory")]
+    Memory,
+    /// UndefinedBehaviorSanitizer
+    #[serde(rename = "undefined")]
+    Undefined,
+    /// AddressSanitizer and UndefinedBehaviorSanitizer
+    #[serde(rename = "address-undefined")]
+    AddressAndUndefined,
+    /// MemorySanitizer and UndefinedBehaviorSanitizer (Clang only). ASan and
+    /// TSan cannot be combined with MSan.
+    #[serde(rename = "full")]
+    Full,
+}
+
+impl SanitizerPreset {
+    /// Names passed to `-fsanitize=`.
+    pub fn sanitizers(&self) -> &'static [&'static str] {
+        match self {
+            SanitizerPreset::None => &[],
+            SanitizerPreset::Address => &["address"],
+            SanitizerPreset::Thread => &["thread"],
+            SanitizerPreset::Memory => &["memory"],
+            SanitizerPreset::Undefined => &["undefined"],
+            SanitizerPreset::AddressAndUndefined => &["address", "undefined"],
+            SanitizerPreset::Full => &["memory", "undefined"],
+        }
+    }
+
+    /// Whether the preset includes MemorySanitizer, which GCC lacks.
+    pub fn requires_clang(&self) -> bool {
+        self.sanitizers().contains(&"memory")
+    }
+}
+
+impl std::fmt::Display for SanitizerPreset {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            SanitizerPreset::None => write!(f, "none"),
+            SanitizerPreset::Address => write!(f, "address"),
+            SanitizerPreset::Thread => write!(f, "thread"),
+            SanitizerPreset::Memory => write!(f, "memory"),
+            SanitizerPreset::Undefined => write!(f, "undefined"),
+            SanitizerPreset::AddressAndUndefined => write!(f, "address-undefined"),
+            SanitizerPreset::Full => write!(f, "full"),
+        }
+    }
+}
+
+impl FromStr for SanitizerPreset {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "none" => Ok(SanitizerPreset::None),
+            "address" => Ok(SanitizerPreset::Address),
+            "thread" => Ok(SanitizerPreset::Thread),
+            "memory" => Ok(SanitizerPreset::Memory),
+            "undefined" => Ok(SanitizerPreset::Undefined),
+            "address-undefined" => Ok(SanitizerPreset::AddressAndUndefined),
+            "full" => Ok(SanitizerPreset::Full),
+            _ => Err(anyhow::anyhow!("Unknown sanitizer preset: {}", value)),
+        }
+    }
+}
+
+/// Engine used for the generated `docs/` directory.
+///
+/// # Examples
+///
+/// ```
+/// use cppup::project::DocEngine;
+///
+/// let engine = DocEngine::Sphinx;
+/// assert_eq!(engine.to_string(), "sphinx");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub enum DocEngine {
+    /// Plain Markdown placeholder pages
+    #[serde(rename = "markdown")]
+    #[default]
+    Markdown,
+    /// Sphinx with Breathe, rendering the API reference from Doxygen XML
+    #[serde(rename = "sphinx")]
+    Sphinx,
+    /// Doxygen HTML pages generated from the source comments
+    #[serde(rename = "doxygen")]
+    Doxygen,
+}
+
+impl std::fmt::Display for DocEngine {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            DocEngine::Markdown => write!(f, "markdown"),
+            DocEngine::Sphinx => write!(f, "sphinx"),
+            DocEngine::Doxygen => write!(f, "doxygen"),
+        }
+    }
+}
+
+impl FromStr for DocEngine {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "markdown" => Ok(DocEngine::Markdown),
+            "sphinx" => Ok(DocEngine::Sphinx),
+            "doxygen" => Ok(DocEngine::Doxygen),
+            _ => Err(anyhow::anyhow!("Unknown documentation engine: {}", value)),
+        }
+    }
+}
+
+/// CI pipeline generated with the project.
+///
+/// # Examples
+///
+/// ```
+/// use cppup::project::CiProvider;
+///
+/// let ci: CiProvider = "github".parse().unwrap();
+/// assert_eq!(ci, CiProvider::GitHub);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub enum CiProvider {
+    /// No pipeline
+    #[serde(rename = "none")]
+    #[default]
+    None,
+    /// A GitHub Actions workflow in `.github/workflows/ci.yml`
+    #[serde(rename = "github")]
+    GitHub,
+}
+
+impl std::fmt::Display for CiProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            CiProvider::None => write!(f, "none"),
+            CiProvider::GitHub => write!(f, "github"),
+        }
+    }
+}
+
+impl FromStr for CiProvider {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "none" => Ok(CiProvider::None),
+            "github" => Ok(CiProvider::GitHub),
+            _ => Err(anyhow::anyhow!("Unknown CI provider: {}", value)),
+        }
+    }
+}
+
+/// Service the test coverage is uploaded to.
+///
+/// # Examples
+///
+/// ```
+/// use cppup::project::CoverageService;
+///
+/// let service: CoverageService = "codecov".parse().unwrap();
+/// assert_eq!(service, CoverageService::Codecov);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub enum CoverageService {
+    /// No coverage service
+    #[serde(rename = "none")]
+    #[default]
+    None,
+    /// Codecov, configured with a `codecov.yml`
+    #[serde(rename = "codecov")]
+    Codecov,
+    /// Coveralls
+    #[serde(rename = "coveralls")]
+    Coveralls,
+}
+
+impl std::fmt::Display for CoverageService {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            CoverageService::None => write!(f, "none"),
+            CoverageService::Codecov => write!(f, "codecov"),
+            CoverageService::Coveralls => write!(f, "coveralls"),
+        }
+    }
+}
+
+/// Platform the project is configured for.
+///
+/// # Examples
+///
+/// ```
+/// use cppup::project::TargetPlatform;
+///
+/// let target: TargetPlatform = "android".parse().unwrap();
+/// assert_eq!(target, TargetPlatform::Android);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub enum TargetPlatform {
+    /// The machine the project is built on
+    #[serde(rename = "host")]
+    #[default]
+    Host,
+    /// Android, cross-compiled with the NDK into a shared library for JNI
+    #[serde(rename = "android")]
+    Android,
+    /// 64-bit Windows, cross-compiled with MinGW-w64
+    #[serde(rename = "windows-mingw")]
+    WindowsMingw,
+}
+
+impl std::fmt::Display for TargetPlatform {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            TargetPlatform::Host => write!(f, "host"),
+            TargetPlatform::Android => write!(f, "android"),
+            TargetPlatform::WindowsMingw => write!(f, "windows-mingw"),
+        }
+    }
+}
+
+impl FromStr for TargetPlatform {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "host" => Ok(TargetPlatform::Host),
+            "android" => Ok(TargetPlatform::Android),
+            "windows-mingw" => Ok(TargetPlatform::WindowsMingw),
+            _ => Err(anyhow::anyhow!("Unknown target platform: {}", value)),
+        }
+    }
+}
+
+impl FromStr for CoverageService {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "none" => Ok(CoverageService::None),
+            "codecov" => Ok(CoverageService::Codecov),
+            "coveralls" => Ok(CoverageService::Coveralls),
+            _ => Err(anyhow::anyhow!("Unknown coverage service: {}", value)),
+        }
+    }
+}
+
+/// Visibility of a repository created on GitHub.
+///
+/// # Examples
+///
+/// ```
+/// use cppup::project::RepoVisibility;
+///
+/// let visibility: RepoVisibility = "private".parse().unwrap();
+/// assert_eq!(visibility.to_string(), "private");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub enum RepoVisibility {
+    /// Visible to everyone
+    #[serde(rename = "public")]
+    #[default]
+    Public,
+    /// Visible to the owner and collaborators only
+    #[serde(rename = "private")]
+    Private,
+}
+
+impl std::fmt::Display for RepoVisibility {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            RepoVisibility::Public => write!(f, "public"),
+            RepoVisibility::Private => write!(f, "private"),
+        }
+    }
+}
+
+impl FromStr for RepoVisibility {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "public" => Ok(RepoVisibility::Public),
+            "private" => Ok(RepoVisibility::Private),
+            _ => Err(anyhow::anyhow!("Unknown repository visibility: {}", value)),
+        }
+    }
+}
+
+/// Optional header-only code snippet copied into `include/snippets/`.
+///
+/// # Examples
+///
+/// ```
+/// use cppup::project::Snippet;
+///
+/// let snippet: Snippet = "thread-pool".parse().unwrap();
+/// assert_eq!(snippet.file_stem(), "thread_pool");
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Snippet {
+    /// Fixed-size pool of worker threads returning `std::future`s
+    #[serde(rename = "thread-pool")]
+    ThreadPool,
+    /// Leveled, timestamped logger writing to a `std::ostream`
+    #[serde(rename = "logger")]
+    Logger,
+    /// Parser for `--flag` and `--option value` command-line arguments
+    #[serde(rename = "argument-parser")]
+    ArgumentParser,
+    /// Plain HTTP/1.0 GET client over POSIX sockets
+    #[serde(rename = "http-client")]
+    HttpClient,
+}
+
+impl Snippet {
+    /// Name of the generated header, without the extension.
+    pub fn file_stem(&self) -> &'static str {
+        match self {
+            Snippet::ThreadPool => "thread_pool",
+            Snippet::Logger => "logger",
+            Snippet::ArgumentParser => "argument_parser",
+            Snippet::HttpClient => "http_client",
+        }
+    }
+}
+
+impl std::fmt::Display for Snippet {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Snippet::ThreadPool => write!(f, "thread-pool"),
+            Snippet::Logger => write!(f, "logger"),
+            Snippet::ArgumentParser => write!(f, "argument-parser"),
+            Snippet::HttpClient => write!(f, "http-client"),
+        }
+    }
+}
+
+impl FromStr for Snippet {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "thread-pool" => Ok(Snippet::ThreadPool),
+            "logger" => Ok(Snippet::Logger),
+            "argument-parser" => Ok(Snippet::ArgumentParser),
+            "http-client" => Ok(Snippet::HttpClient),
+            _ => Err(anyhow::anyhow!("Unknown snippet: {}", value)),
+        }
+    }
+}
+
+/// Optional feature of the `vcpkg.json` manifest, installed only when
+/// requested through `VCPKG_MANIFEST_FEATURES`.
+///
+/// # Examples
+///
+/// ```
+/// use cppup::project::VcpkgFeature;
+///
+/// let feature = VcpkgFeature {
+///     description: "Compression support".to_string(),
+///     dependencies: vec!["zlib".to_string()],
+/// };
+/// assert_eq!(feature.dependencies, ["zlib"]);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct VcpkgFeature {
+    /// Description shown by `vcpkg search`
+    pub description: String,
+    /// vcpkg ports the feature depends on
+    pub dependencies: Vec<String>,
+}
+
+/// A Meson wrap file placed in `subprojects/`.
+///
+/// Serialized as the template data of the `wrap` template.
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct WrapFile {
+    /// Subproject name, also the wrap file name
+    #[serde(rename = "wrap_name")]
+    pub name: &'static str,
+    /// Git tag checked out
+    #[serde(rename = "wrap_version")]
+    pub version: &'static str,
+    /// Git repository URL
+    #[serde(rename = "wrap_url")]
+    pub url: &'static str,
+    /// Dependency names provided by the subproject, comma-separated
+    #[serde(rename = "wrap_dependencies")]
+    pub dependencies: &'static str,
+    /// Build method of a subproject without a `meson.build`, e.g. "cmake"
+    #[serde(rename = "wrap_method")]
+    pub method: Option<&'static str>,
+    /// Dependency names mapped to the variables of a CMake subproject,
+    /// used instead of `dependencies` when `method` is "cmake"
+    #[serde(rename = "wrap_variables")]
+    pub variables: &'static [(&'static str, &'static str)],
+}
+
+/// Meson-specific configuration.
+///
+/// # Examples
+///
+/// ```
+/// use cppup::project::{MesonConfig, TestFramework};
+///
+/// let meson = MesonConfig::new(&TestFramework::Doctest);
+/// assert_eq!(meson.wrap_files[0].name, "doctest");
+/// ```
+#[derive(Debug, Clone)]
+pub struct MesonConfig {
+    /// Wrap files to generate in `subprojects/`
+    pub wrap_files: Vec<WrapFile>,
+}
+
+impl MesonConfig {
+    /// Creates the Meson configuration for the given test framework.
+    ///
+    /// Boost is found through Meson's built-in dependency lookup only. Google
+    /// Test has no `meson.build` upstream, so its wrap builds it as a CMake
+    /// subproject. The `gtest` fallback resolves to `gtest_main`, matching
+    /// what `dependency('gtest', main: true)` finds on the system.
+    pub fn new(test_framework: &TestFramework) -> Self {
+        let wrap_files = match test_framework {
+            TestFramework::Doctest => vec![WrapFile {
+                name: "doctest",
+                version: "v2.4.11",
+                url: "https://github.com/doctest/doctest.git",
+                dependencies: "doctest",
+                method: None,
+                variables: &[],
+            }],
+            TestFramework::Catch2 => vec![WrapFile {
+                name: "catch2",
+                version: "v3.5.2",
+                url: "https://github.com/catchorg/Catch2.git",
+                dependencies: "catch2, catch2-with-main",
+                method: None,
+                variables: &[],
+            }],
+            TestFramework::GTest => vec![WrapFile {
+                name: "gtest",
+                version: "v1.17.0",
+                url: "https://github.com/google/googletest.git",
+                dependencies: "gtest",
+                method: Some("cmake"),
+                variables: &[("gtest", "gtest_main_dep")],
+            }],
+            TestFramework::BoostTest | TestFramework::None => Vec::new(),
+        };
+        Self { wrap_files }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use serde::de::DeserializeOwned;
+    use std::fmt::Debug;
+
+    /// Checks that `value` serializes to `expected` and parses back from it.
+    fn assert_round_trip<T>(value: T, expected: &str)
+    where
+        T: Serialize + DeserializeOwned + FromStr<Err = anyhow::Error> + PartialEq + Debug,
+    {
+        let json = serde_json::to_string(&value).unwrap();
+        assert_eq!(json, format!("\"{}\"", expected));
+        assert_eq!(serde_json::from_str::<T>(&json).unwrap(), value);
+        assert_eq!(expected.parse::<T>().unwrap(), value);
+    }
 
     #[test]
     fn test_build_system_display() {
         assert_eq!(BuildSystem::CMake.to_string(), "cmake");
         assert_eq!(BuildSystem::Make.to_string(), "make");
+        assert_eq!(BuildSystem::Meson.to_string(), "meson");
+    }
+
+    #[test]
+    fn test_cmake_generator_display() {
+        assert_eq!(CmakeGenerator::Default.to_string(), "default");
+        assert_eq!(CmakeGenerator::Ninja.to_string(), "ninja");
+        assert_eq!(
+            CmakeGenerator::NinjaMultiConfig.to_string(),
+            "ninja-multi-config"
+        );
+        assert_eq!(CmakeGenerator::UnixMakefiles.to_string(), "unix-makefiles");
+        assert_eq!(CmakeGenerator::VisualStudio2022.to_string(), "vs2022");
+    }
+
+    #[test]
+    fn test_cmake_generator_cmake_name() {
+        assert_eq!(CmakeGenerator::Default.cmake_name(), None);
+        assert_eq!(
+            CmakeGenerator::VisualStudio2022.cmake_name(),
+            Some("Visual Studio 17 2022")
+        );
+        assert_eq!(
+            CmakeGenerator::NinjaMultiConfig.cmake_name(),
+            Some("Ninja Multi-Config")
+        );
+    }
+
+    #[test]
+    fn test_line_endings_uses_crlf() {
+        assert!(!LineEndings::Lf.uses_crlf(Path::new("src/main.cpp"), ""));
+        assert!(LineEndings::Crlf.uses_crlf(Path::new("src/main.cpp"), ""));
+        assert_eq!(
+            LineEndings::Native.uses_crlf(Path::new("src/main.cpp"), ""),
+            cfg!(windows)
+        );
+        for line_endings in [LineEndings::Lf, LineEndings::Crlf, LineEndings::Native] {
+            assert!(line_endings.uses_crlf(Path::new("scripts/build.bat"), ""));
+            assert!(line_endings.uses_crlf(Path::new("scripts/Setup.PS1"), ""));
+            assert!(!line_endings.uses_crlf(Path::new("scripts/setup.sh"), ""));
+            assert!(!line_endings.uses_crlf(Path::new("scripts/SETUP.SH"), ""));
+            assert!(!line_endings.uses_crlf(Path::new("tools/run"), "#!/usr/bin/env python3\n"));
+        }
     }
 
     #[test]
@@ -283,6 +1555,7 @@ mod tests {
         assert_eq!(License::Apache2.to_string(), "Apache-2.0");
         assert_eq!(License::GPL3.to_string(), "GPL-3.0");
         assert_eq!(License::BSD3.to_string(), "BSD-3-Clause");
+        assert_eq!(License::BSL1.to_string(), "BSL-1.0");
     }
 
     #[test]
@@ -322,6 +1595,32 @@ mod tests {
         assert_eq!(single_config.to_string(), "cppcheck");
     }
 
+    #[test]
+    fn test_quality_config_serde_keeps_clang_tidy_checks() {
+        let config = QualityConfig::new(&["clang-tidy"]);
+        assert_eq!(
+            serde_json::to_value(&config).unwrap(),
+            serde_json::json!(["clang-tidy"])
+        );
+
+        let config = QualityConfig {
+            clang_tidy_checks: vec![ClangTidyCategory::Cert, ClangTidyCategory::Performance],
+            ..config
+        };
+        let json = serde_json::to_value(&config).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "tools": ["clang-tidy"],
+                "clang_tidy_checks": ["cert", "performance"]
+            })
+        );
+        assert_eq!(
+            serde_json::from_value::<QualityConfig>(json).unwrap(),
+            config
+        );
+    }
+
     #[test]
     fn test_code_formatter_new() {
         let formatter = CodeFormatter::new(&["clang-format"]);
@@ -347,6 +1646,45 @@ mod tests {
 
         let single_formatter = CodeFormatter::new(&["cmake-format"]);
         assert_eq!(single_formatter.to_string(), "cmake-format");
+
+        let lint_formatter = CodeFormatter::new(&["cmake-lint", "clang-format"]);
+        assert_eq!(lint_formatter.to_string(), "clang-format, cmake-lint");
+    }
+
+    #[test]
+    fn test_ide_config_new() {
+        let ide = IdeConfig::new(&["clion"]);
+        assert!(ide.enable_clion);
+        assert!(!ide.enable_kdevelop);
+
+        let empty_ide = IdeConfig::new(&[]);
+        assert!(!empty_ide.enable_clion);
+        assert!(!empty_ide.enable_kdevelop);
+
+        let all_ide = IdeConfig::new(&["clion", "kdevelop", "emacs", "sublime"]);
+        assert!(all_ide.enable_clion);
+        assert!(all_ide.enable_kdevelop);
+        assert!(all_ide.enable_emacs);
+        assert!(all_ide.enable_sublime);
+    }
+
+    #[test]
+    fn test_ide_config_names() {
+        assert!(IdeConfig::new(&[]).names().is_empty());
+        assert_eq!(
+            IdeConfig::new(&["emacs", "kdevelop", "clion"]).names(),
+            vec!["clion", "kdevelop", "emacs"]
+        );
+    }
+
+    #[test]
+    fn test_ide_config_display() {
+        assert_eq!(IdeConfig::new(&["clion"]).to_string(), "clion");
+        assert_eq!(
+            IdeConfig::new(&["clion", "kdevelop"]).to_string(),
+            "clion, kdevelop"
+        );
+        assert_eq!(IdeConfig::new(&[]).to_string(), "");
     }
 
     #[test]
@@ -357,4 +1695,168 @@ mod tests {
         assert_eq!(TestFramework::BoostTest.to_string(), "boost");
         assert_eq!(TestFramework::None.to_string(), "none");
     }
+
+    #[test]
+    fn test_test_runner_display() {
+        assert_eq!(TestRunner::Direct.to_string(), "direct");
+        assert_eq!(TestRunner::Valgrind.to_string(), "valgrind");
+        assert_eq!(TestRunner::Asan.to_string(), "asan");
+        assert_eq!(TestRunner::CTestWrapper.to_string(), "ctest");
+    }
+
+    #[test]
+    fn test_sanitizer_preset() {
+        assert!(SanitizerPreset::None.sanitizers().is_empty());
+        assert_eq!(SanitizerPreset::Thread.sanitizers(), ["thread"]);
+        assert_eq!(SanitizerPreset::Full.to_string(), "full");
+
+        assert!(SanitizerPreset::Full.requires_clang());
+        assert!(SanitizerPreset::Memory.requires_clang());
+        assert!(!SanitizerPreset::AddressAndUndefined.requires_clang());
+    }
+
+    #[test]
+    fn test_meson_config_wrap_files() {
+        let doctest = MesonConfig::new(&TestFramework::Doctest);
+        assert_eq!(doctest.wrap_files.len(), 1);
+        assert_eq!(doctest.wrap_files[0].name, "doctest");
+        assert_eq!(doctest.wrap_files[0].dependencies, "doctest");
+
+        let catch2 = MesonConfig::new(&TestFramework::Catch2);
+        assert_eq!(catch2.wrap_files[0].name, "catch2");
+
+        let gtest = MesonConfig::new(&TestFramework::GTest);
+        assert_eq!(gtest.wrap_files[0].method, Some("cmake"));
+        assert_eq!(
+            gtest.wrap_files[0].variables,
+            &[("gtest", "gtest_main_dep")]
+        );
+
+        assert!(MesonConfig::new(&TestFramework::BoostTest)
+            .wrap_files
+            .is_empty());
+        assert!(MesonConfig::new(&TestFramework::None).wrap_files.is_empty());
+    }
+
+    #[test]
+    fn test_enum_serde_round_trip() {
+        assert_round_trip(BuildSystem::CMake, "cmake");
+        assert_round_trip(BuildSystem::Make, "make");
+        assert_round_trip(BuildSystem::Meson, "meson");
+
+        assert_round_trip(CmakeGenerator::Default, "default");
+        assert_round_trip(CmakeGenerator::Ninja, "ninja");
+        assert_round_trip(CmakeGenerator::NinjaMultiConfig, "ninja-multi-config");
+        assert_round_trip(CmakeGenerator::UnixMakefiles, "unix-makefiles");
+        assert_round_trip(CmakeGenerator::VisualStudio2022, "vs2022");
+
+        assert_round_trip(CppExtension::Cpp, "cpp");
+        assert_round_trip(CppExtension::Cxx, "cxx");
+        assert_round_trip(CppExtension::Cc, "cc");
+
+        assert_round_trip(HppExtension::Hpp, "hpp");
+        assert_round_trip(HppExtension::H, "h");
+        assert_round_trip(HppExtension::Hxx, "hxx");
+
+        assert_round_trip(LineEndings::Lf, "lf");
+        assert_round_trip(LineEndings::Crlf, "crlf");
+        assert_round_trip(LineEndings::Native, "native");
+
+        assert_round_trip(License::MIT, "MIT");
+        assert_round_trip(License::Apache2, "Apache-2.0");
+        assert_round_trip(License::GPL3, "GPL-3.0");
+        assert_round_trip(License::BSD3, "BSD-3-Clause");
+        assert_round_trip(License::BSL1, "BSL-1.0");
+
+        assert_round_trip(PackageManager::Conan, "conan");
+        assert_round_trip(PackageManager::Vcpkg, "vcpkg");
+        assert_round_trip(PackageManager::None, "none");
+
+        assert_round_trip(TestFramework::Doctest, "doctest");
+        assert_round_trip(TestFramework::GTest, "gtest");
+        assert_round_trip(TestFramework::Catch2, "catch2");
+        assert_round_trip(TestFramework::BoostTest, "boosttest");
+        assert_round_trip(TestFramework::None, "none");
+
+        assert_round_trip(TestRunner::Direct, "direct");
+        assert_round_trip(TestRunner::Valgrind, "valgrind");
+        assert_round_trip(TestRunner::Asan, "asan");
+        assert_round_trip(TestRunner::CTestWrapper, "ctest");
+
+        assert_round_trip(SanitizerPreset::None, "none");
+        assert_round_trip(SanitizerPreset::Address, "address");
+        assert_round_trip(SanitizerPreset::Thread, "thread");
+        assert_round_trip(SanitizerPreset::Memory, "memory");
+        assert_round_trip(SanitizerPreset::Undefined, "undefined");
+        assert_round_trip(SanitizerPreset::AddressAndUndefined, "address-undefined");
+        assert_round_trip(SanitizerPreset::Full, "full");
+
+        assert_round_trip(DocEngine::Markdown, "markdown");
+        assert_round_trip(DocEngine::Sphinx, "sphinx");
+        assert_round_trip(DocEngine::Doxygen, "doxygen");
+
+        assert_round_trip(CiProvider::None, "none");
+        assert_round_trip(CiProvider::GitHub, "github");
+
+        assert_round_trip(CoverageService::None, "none");
+        assert_round_trip(CoverageService::Codecov, "codecov");
+        assert_round_trip(CoverageService::Coveralls, "coveralls");
+        assert_round_trip(ClangTidyCategory::Modernize, "modernize");
+        assert_round_trip(ClangTidyCategory::CppCoreGuidelines, "cppcoreguidelines");
+        assert_round_trip(ClangTidyCategory::Hicpp, "hicpp");
+        assert!("google".parse::<ClangTidyCategory>().is_err());
+
+        assert_round_trip(TargetPlatform::Host, "host");
+        assert_round_trip(TargetPlatform::Android, "android");
+        assert_round_trip(TargetPlatform::WindowsMingw, "windows-mingw");
+
+        assert_round_trip(Snippet::ThreadPool, "thread-pool");
+        assert_round_trip(Snippet::Logger, "logger");
+        assert_round_trip(Snippet::ArgumentParser, "argument-parser");
+        assert_round_trip(Snippet::HttpClient, "http-client");
+    }
+
+    #[test]
+    fn test_enum_from_str_errors() {
+        assert_eq!(
+            "ant".parse::<BuildSystem>().unwrap_err().to_string(),
+            "Unknown build system: ant"
+        );
+        assert_eq!(
+            "mit".parse::<License>().unwrap_err().to_string(),
+            "Unknown license: mit"
+        );
+        assert!(serde_json::from_str::<PackageManager>("\"nuget\"").is_err());
+        // The display name of Boost.Test is accepted as well
+        assert_eq!(
+            "boost".parse::<TestFramework>().unwrap(),
+            TestFramework::BoostTest
+        );
+    }
+
+    #[test]
+    fn test_tool_config_serde_round_trip() {
+        let quality = QualityConfig::new(&["cppcheck", "clang-tidy"]);
+        let json = serde_json::to_string(&quality).unwrap();
+        assert_eq!(json, r#"["clang-tidy","cppcheck"]"#);
+        assert_eq!(
+            serde_json::from_str::<QualityConfig>(&json).unwrap(),
+            quality
+        );
+
+        let formatter = CodeFormatter::new(&["clang-format", "cmake-lint"]);
+        let json = serde_json::to_string(&formatter).unwrap();
+        assert_eq!(json, r#"["clang-format","cmake-lint"]"#);
+        assert_eq!(
+            serde_json::from_str::<CodeFormatter>(&json).unwrap(),
+            formatter
+        );
+
+        let ide = IdeConfig::new(&["sublime"]);
+        let json = serde_json::to_string(&ide).unwrap();
+        assert_eq!(serde_json::from_str::<IdeConfig>(&json).unwrap(), ide);
+
+        let error = serde_json::from_str::<QualityConfig>(r#"["clang-tidy","lint"]"#).unwrap_err();
+        assert!(error.to_string().contains("Unknown quality tool: lint"));
+    }
 }
@@ -0,0 +1,268 @@
+//! Reviewing rendered files that would replace existing ones: their diffs,
+//! whether to overwrite each one, and a summary of what changed.
+
+use crate::color;
+use anyhow::Result;
+use inquire::Select;
+use std::path::PathBuf;
+
+/// Lines of context around each change in a diff.
+const DIFF_CONTEXT: usize = 3;
+
+/// A rendered file and the contents of the file it would replace.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileChange {
+    /// Path of the file
+    pub path: PathBuf,
+    /// Current contents, `None` if the file does not exist yet
+    pub existing: Option<String>,
+    /// Newly rendered contents
+    pub rendered: String,
+}
+
+impl FileChange {
+    /// Whether writing the rendered contents would leave the file as it is.
+    pub fn is_unchanged(&self) -> bool {
+        self.existing.as_deref() == Some(self.rendered.as_str())
+    }
+
+    /// Returns a unified diff from the current to the rendered contents,
+    /// colored when colors are on. Empty if the file is unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cppup::project::FileChange;
+    ///
+    /// let change = FileChange {
+    ///     path: ".clang-format".into(),
+    ///     existing: Some("BasedOnStyle: LLVM\n".to_string()),
+    ///     rendered: "BasedOnStyle: Google\n".to_string(),
+    /// };
+    /// assert!(change.diff().contains("-BasedOnStyle: LLVM\n+BasedOnStyle: Google"));
+    /// ```
+    pub fn diff(&self) -> String {
+        if self.is_unchanged() {
+            return String::new();
+        }
+        let existing: Vec<&str> = self
+            .existing
+            .as_deref()
+            .unwrap_or_default()
+            .lines()
+            .collect();
+        let rendered: Vec<&str> = self.rendered.lines().collect();
+        let name = self.path.display().to_string();
+        let lines = difflib::unified_diff(
+            &existing,
+            &rendered,
+            &name,
+            &name,
+            "current",
+            "rendered",
+            DIFF_CONTEXT,
+        );
+        if lines.is_empty() {
+            return format!("{}: only line endings or the final newline differ", name);
+        }
+        lines
+            .iter()
+            .map(|line| {
+                let line = line.trim_end_matches('\n');
+                if line.starts_with("+++") || line.starts_with("---") {
+                    line.to_string()
+                } else if line.starts_with('+') {
+                    color::insertion(line)
+                } else if line.starts_with('-') {
+                    color::deletion(line)
+                } else {
+                    line.to_string()
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// Whether to replace an existing file that has changed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Decision {
+    /// Write the rendered contents
+    Overwrite,
+    /// Keep the current file
+    Skip,
+}
+
+/// What became of the reviewed files.
+#[derive(Debug, Default, PartialEq)]
+pub struct ChangeSummary {
+    /// Files that did not exist yet
+    pub created: Vec<PathBuf>,
+    /// Existing files that are overwritten with different contents
+    pub changed: Vec<PathBuf>,
+    /// Existing files whose contents are already the rendered ones
+    pub unchanged: Vec<PathBuf>,
+    /// Changed files the user chose to keep
+    pub skipped: Vec<PathBuf>,
+}
+
+impl std::fmt::Display for ChangeSummary {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let counts = [
+            (self.created.len(), "created"),
+            (self.changed.len(), "changed"),
+            (self.unchanged.len(), "unchanged"),
+            (self.skipped.len(), "skipped"),
+        ];
+        let parts: Vec<String> = counts
+            .iter()
+            .filter(|(count, _)| *count > 0)
+            .map(|(count, label)| format!("{} {}", count, label))
+            .collect();
+        if parts.is_empty() {
+            write!(f, "no files")
+        } else {
+            write!(f, "{}", parts.join(", "))
+        }
+    }
+}
+
+/// Sorts `changes` into the files to write and a summary. New files are
+/// always written and unchanged ones never; `decide` is asked about every
+/// file that would change.
+///
+/// # Errors
+///
+/// Returns the first error of `decide`.
+pub fn review(
+    changes: Vec<FileChange>,
+    mut decide: impl FnMut(&FileChange) -> Result<Decision>,
+) -> Result<(Vec<FileChange>, ChangeSummary)> {
+    let mut writes = Vec::new();
+    let mut summary = ChangeSummary::default();
+    for change in changes {
+        if change.existing.is_none() {
+            summary.created.push(change.path.clone());
+        } else if change.is_unchanged() {
+            summary.unchanged.push(change.path);
+            continue;
+        } else if decide(&change)? == Decision::Overwrite {
+            summary.changed.push(change.path.clone());
+        } else {
+            summary.skipped.push(change.path);
+            continue;
+        }
+        writes.push(change);
+    }
+    Ok((writes, summary))
+}
+
+/// Asks whether to overwrite `change`, showing its diff on request.
+///
+/// # Errors
+///
+/// Returns an error if the prompt fails or is cancelled.
+pub fn prompt_decision(change: &FileChange) -> Result<Decision> {
+    loop {
+        let answer = Select::new(
+            &format!("{} has changed. Overwrite it?", change.path.display()),
+            vec!["Overwrite", "Skip", "Show diff"],
+        )
+        .prompt()?;
+        match answer {
+            "Overwrite" => return Ok(Decision::Overwrite),
+            "Skip" => return Ok(Decision::Skip),
+            _ => println!("{}", change.diff()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn change(path: &str, existing: Option<&str>, rendered: &str) -> FileChange {
+        FileChange {
+            path: PathBuf::from(path),
+            existing: existing.map(String::from),
+            rendered: rendered.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_diff() {
+        let edited = change("a.txt", Some("one\ntwo\nthree\n"), "one\n2\nthree\nfour\n");
+        assert_eq!(
+            edited.diff(),
+            "--- a.txt\tcurrent\n+++ a.txt\trendered\n@@ -1,3 +1,4 @@\n one\n-two\n+2\n three\n+four"
+        );
+
+        assert_eq!(change("a.txt", Some("same\n"), "same\n").diff(), "");
+        assert!(change("a.txt", Some("one\r\n"), "one\n")
+            .diff()
+            .contains("only line endings or the final newline differ"));
+        assert!(change("new.txt", None, "one\n").diff().contains("+one"));
+    }
+
+    #[test]
+    fn test_review_asks_only_about_changed_files() {
+        let changes = vec![
+            change("new.txt", None, "new\n"),
+            change("same.txt", Some("same\n"), "same\n"),
+            change("keep.txt", Some("old\n"), "new\n"),
+            change("edit.txt", Some("old\n"), "new\n"),
+        ];
+        let mut asked = Vec::new();
+
+        let (writes, summary) = review(changes, |change| {
+            asked.push(change.path.clone());
+            Ok(if change.path.ends_with("keep.txt") {
+                Decision::Skip
+            } else {
+                Decision::Overwrite
+            })
+        })
+        .unwrap();
+
+        assert_eq!(
+            asked,
+            [PathBuf::from("keep.txt"), PathBuf::from("edit.txt")]
+        );
+        let written: Vec<_> = writes.iter().map(|change| &change.path).collect();
+        assert_eq!(
+            written,
+            [&PathBuf::from("new.txt"), &PathBuf::from("edit.txt")]
+        );
+        assert_eq!(
+            summary,
+            ChangeSummary {
+                created: vec![PathBuf::from("new.txt")],
+                changed: vec![PathBuf::from("edit.txt")],
+                unchanged: vec![PathBuf::from("same.txt")],
+                skipped: vec![PathBuf::from("keep.txt")],
+            }
+        );
+        assert_eq!(
+            summary.to_string(),
+            "1 created, 1 changed, 1 unchanged, 1 skipped"
+        );
+    }
+
+    #[test]
+    fn test_review_stops_at_the_first_error() {
+        let changes = vec![
+            change("a.txt", Some("old\n"), "new\n"),
+            change("b.txt", Some("old\n"), "new\n"),
+        ];
+        let mut asked = 0;
+
+        let result = review(changes, |_| {
+            asked += 1;
+            anyhow::bail!("cancelled")
+        });
+
+        assert!(result.is_err());
+        assert_eq!(asked, 1);
+        assert_eq!(ChangeSummary::default().to_string(), "no files");
+    }
+}
@@ -0,0 +1,211 @@
+//! Named bundles of options selectable with `--preset`.
+//!
+//! A preset is expanded into command-line arguments before the rest of the
+//! configuration is resolved, so any explicitly given flag still wins.
+
+use crate::cli::Cli;
+use clap::Parser;
+
+/// A named bundle of options.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(super) struct Preset {
+    /// Name accepted by `--preset`
+    pub(super) name: &'static str,
+    /// Short explanation shown in interactive mode
+    pub(super) description: &'static str,
+    /// Options the preset expands into, as command-line arguments
+    args: &'static [&'static str],
+}
+
+/// All presets, in the order they are offered.
+pub(super) const PRESETS: [Preset; 4] = [
+    Preset {
+        name: "minimal",
+        description: "Bare project without tests, tools or git",
+        args: &[
+            "--project-type=executable",
+            "--test-framework=none",
+            "--git=false",
+        ],
+    },
+    Preset {
+        name: "recommended",
+        description: "doctest, clang-format, clang-tidy and CMake presets",
+        args: &[
+            "--project-type=executable",
+            "--test-framework=doctest",
+            "--code-formatter=clang-format",
+            "--quality-tools=clang-tidy",
+            "--cmake-generator=ninja",
+        ],
+    },
+    Preset {
+        name: "full",
+        description: "Every test, quality, formatting, docs and CI option enabled",
+        args: &[
+            "--project-type=executable",
+            "--cpp-standard=20",
+            "--test-framework=gtest",
+            "--test-runner=ctest",
+            "--package-manager=vcpkg",
+            "--code-formatter=clang-format,cmake-format,cmake-lint",
+            "--quality-tools=clang-tidy,cppcheck,include-what-you-use",
+            "--cmake-generator=ninja-multi-config",
+            "--docs=true",
+            "--doc-engine=doxygen",
+            "--changelog=true",
+            "--community-files=true",
+            "--ci=github",
+            "--dependabot=true",
+            "--coverage-service=codecov",
+        ],
+    },
+    Preset {
+        name: "opensource",
+        description: "recommended with the MIT license, git and community files",
+        args: &[
+            "--project-type=executable",
+            "--test-framework=doctest",
+            "--code-formatter=clang-format",
+            "--quality-tools=clang-tidy",
+            "--cmake-generator=ninja",
+            "--license=MIT",
+            "--git=true",
+            "--community-files=true",
+        ],
+    },
+];
+
+impl Preset {
+    /// Looks up a preset by name.
+    pub(super) fn find(name: &str) -> Option<Preset> {
+        PRESETS.into_iter().find(|preset| preset.name == name)
+    }
+
+    /// Expands the preset into the options it stands for.
+    pub(super) fn expand(&self) -> Cli {
+        Cli::try_parse_from(std::iter::once("cppup").chain(self.args.iter().copied()))
+            .expect("preset arguments are valid")
+    }
+
+    /// Fills every setting missing from `cli` with the preset's value.
+    ///
+    /// CMake-only options are dropped when `cli` explicitly selects another
+    /// build system, so a preset never makes an explicit choice invalid.
+    pub(super) fn apply(&self, cli: &mut Cli) {
+        let mut expanded = self.expand();
+        if cli.build_system.as_deref().is_some_and(|b| b != "cmake") {
+            expanded.cmake_generator = None;
+            expanded
+                .code_formatter
+                .retain(|f| f != "cmake-format" && f != "cmake-lint");
+        }
+        cli.fill_missing(&expanded);
+    }
+}
+
+impl std::fmt::Display for Preset {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{} ({})", self.name, self.description)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_every_preset_expands() {
+        for preset in PRESETS {
+            let cli = preset.expand();
+            assert_eq!(cli.project_type.as_deref(), Some("executable"));
+            assert!(cli.name.is_none());
+            assert!(cli.path.is_none());
+        }
+    }
+
+    #[test]
+    fn test_find_preset() {
+        assert_eq!(Preset::find("full").unwrap().name, "full");
+        assert!(Preset::find("unknown").is_none());
+    }
+
+    #[test]
+    fn test_minimal_expansion() {
+        let cli = Preset::find("minimal").unwrap().expand();
+        assert_eq!(cli.test_framework.as_deref(), Some("none"));
+        assert_eq!(cli.git, Some(false));
+        assert!(cli.quality_tools.is_empty());
+        assert!(cli.code_formatter.is_empty());
+    }
+
+    #[test]
+    fn test_recommended_expansion() {
+        let cli = Preset::find("recommended").unwrap().expand();
+        assert_eq!(cli.test_framework.as_deref(), Some("doctest"));
+        assert_eq!(cli.code_formatter, vec!["clang-format"]);
+        assert_eq!(cli.quality_tools, vec!["clang-tidy"]);
+        assert_eq!(cli.cmake_generator.as_deref(), Some("ninja"));
+    }
+
+    #[test]
+    fn test_full_expansion() {
+        let cli = Preset::find("full").unwrap().expand();
+        assert_eq!(cli.test_framework.as_deref(), Some("gtest"));
+        assert_eq!(
+            cli.quality_tools,
+            vec!["clang-tidy", "cppcheck", "include-what-you-use"]
+        );
+        assert_eq!(cli.docs, Some(true));
+        assert_eq!(cli.doc_engine.as_deref(), Some("doxygen"));
+        assert_eq!(cli.changelog, Some(true));
+        assert_eq!(cli.community_files, Some(true));
+        assert_eq!(cli.ci.as_deref(), Some("github"));
+        assert_eq!(cli.dependabot, Some(true));
+        assert_eq!(cli.coverage_service.as_deref(), Some("codecov"));
+    }
+
+    #[test]
+    fn test_opensource_extends_recommended() {
+        let recommended = Preset::find("recommended").unwrap().expand();
+        let opensource = Preset::find("opensource").unwrap().expand();
+        assert_eq!(opensource.test_framework, recommended.test_framework);
+        assert_eq!(opensource.code_formatter, recommended.code_formatter);
+        assert_eq!(opensource.quality_tools, recommended.quality_tools);
+        assert_eq!(opensource.license.as_deref(), Some("MIT"));
+        assert_eq!(opensource.community_files, Some(true));
+    }
+
+    #[test]
+    fn test_explicit_flags_override_preset() {
+        let mut cli = Cli::try_parse_from([
+            "cppup",
+            "--preset",
+            "full",
+            "--test-framework",
+            "catch2",
+            "--quality-tools",
+            "cppcheck",
+        ])
+        .unwrap();
+        Preset::find("full").unwrap().apply(&mut cli);
+
+        assert_eq!(cli.test_framework.as_deref(), Some("catch2"));
+        assert_eq!(cli.quality_tools, vec!["cppcheck"]);
+        assert_eq!(cli.cpp_standard.as_deref(), Some("20"));
+        assert_eq!(cli.package_manager.as_deref(), Some("vcpkg"));
+        assert_eq!(cli.ci.as_deref(), Some("github"));
+    }
+
+    #[test]
+    fn test_apply_drops_cmake_options_for_make() {
+        let mut cli = Cli::try_parse_from(["cppup", "--build-system", "make"]).unwrap();
+        Preset::find("full").unwrap().apply(&mut cli);
+
+        assert_eq!(cli.build_system.as_deref(), Some("make"));
+        assert!(cli.cmake_generator.is_none());
+        assert_eq!(cli.code_formatter, vec!["clang-format"]);
+        assert_eq!(cli.test_framework.as_deref(), Some("gtest"));
+        assert_eq!(cli.doc_engine.as_deref(), Some("doxygen"));
+    }
+}
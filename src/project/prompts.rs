@@ -0,0 +1,1142 @@
+//! Interactive prompts for configuring a project.
+//!
+//! Every setting has its own prompt function so that individual answers can
+//! be revisited from the confirmation summary shown before generation.
+
+use super::builder::enclosing_git_repository;
+use super::config::{
+    control_character_warning, normalize_path, validate_author, validate_creatable_path,
+    validate_description, validate_project_path, CppStandard, ProjectConfig, ProjectType,
+};
+use super::presets::{Preset, PRESETS};
+use super::{
+    BuildSystem, CmakeGenerator, CodeFormatter, IdeConfig, License, PackageManager, QualityConfig,
+    SanitizerPreset, TestFramework, TestRunner,
+};
+use crate::cli::Cli;
+use crate::validation::validate_project_name;
+use anyhow::Result;
+use inquire::validator::Validation;
+use inquire::{Confirm, MultiSelect, Select, Text};
+use std::path::{Path, PathBuf};
+
+/// A single setting that can be asked for in interactive mode.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(super) enum Setting {
+    Name,
+    Description,
+    Author,
+    Path,
+    ProjectType,
+    BuildSystem,
+    CmakeGenerator,
+    CppStandard,
+    PackageManager,
+    TestFramework,
+    TestRunner,
+    Git,
+    License,
+    QualityTools,
+    CodeFormatter,
+    Ide,
+}
+
+impl Setting {
+    /// All settings in the order they are asked for.
+    pub(super) const ALL: [Setting; 16] = [
+        Setting::Name,
+        Setting::Description,
+        Setting::Author,
+        Setting::Path,
+        Setting::ProjectType,
+        Setting::BuildSystem,
+        Setting::CmakeGenerator,
+        Setting::CppStandard,
+        Setting::PackageManager,
+        Setting::TestFramework,
+        Setting::TestRunner,
+        Setting::Git,
+        Setting::License,
+        Setting::QualityTools,
+        Setting::CodeFormatter,
+        Setting::Ide,
+    ];
+
+    /// Returns whether the setting is meaningful for the current configuration.
+    fn is_applicable(&self, config: &ProjectConfig) -> bool {
+        match self {
+            Setting::CmakeGenerator => config.build_system == BuildSystem::CMake,
+            Setting::TestRunner => config.test_framework != TestFramework::None,
+            _ => true,
+        }
+    }
+}
+
+impl std::fmt::Display for Setting {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Setting::Name => write!(f, "Name"),
+            Setting::Description => write!(f, "Description"),
+            Setting::Author => write!(f, "Author"),
+            Setting::Path => write!(f, "Location"),
+            Setting::ProjectType => write!(f, "Project type"),
+            Setting::BuildSystem => write!(f, "Build system"),
+            Setting::CmakeGenerator => write!(f, "CMake generator"),
+            Setting::CppStandard => write!(f, "C++ standard"),
+            Setting::PackageManager => write!(f, "Package manager"),
+            Setting::TestFramework => write!(f, "Test framework"),
+            Setting::TestRunner => write!(f, "Test runner"),
+            Setting::Git => write!(f, "Git"),
+            Setting::License => write!(f, "License"),
+            Setting::QualityTools => write!(f, "Code quality tools"),
+            Setting::CodeFormatter => write!(f, "Code formatters"),
+            Setting::Ide => write!(f, "IDE project files"),
+        }
+    }
+}
+
+/// Asks every applicable setting that was not already answered, then shows a
+/// summary and lets the user revise individual answers until they confirm.
+/// Prompts for `remembered` settings note that their default comes from the
+/// previous run.
+///
+/// While prompting, `config.path` holds the parent directory the project will
+/// be created in; the caller joins the project name onto it afterwards.
+pub(super) fn run(
+    mut config: ProjectConfig,
+    answered: &[Setting],
+    remembered: &[Setting],
+    confirm: bool,
+) -> Result<ProjectConfig> {
+    for setting in Setting::ALL {
+        if !answered.contains(&setting) && setting.is_applicable(&config) {
+            prompt_setting(&mut config, setting, remembered.contains(&setting))?;
+        }
+    }
+
+    if confirm {
+        loop {
+            println!("\n{}", summary(&config));
+
+            if Confirm::new("Create project with these settings?")
+                .with_default(true)
+                .prompt()?
+            {
+                break;
+            }
+
+            let setting = Select::new(
+                "Which setting do you want to change?",
+                applicable_settings(&config),
+            )
+            .prompt()?;
+            prompt_setting(&mut config, setting, false)?;
+        }
+    }
+
+    Ok(config)
+}
+
+/// Returns the settings explicitly given on the command line with a usable
+/// value. These are accepted as-is and not prompted for again.
+pub(super) fn answered_settings(cli: &Cli) -> Vec<Setting> {
+    Setting::ALL
+        .into_iter()
+        .filter(|setting| match setting {
+            Setting::Name => cli
+                .name
+                .as_deref()
+                .is_some_and(|name| validate_project_name(name).is_ok()),
+            Setting::Description => cli
+                .description
+                .as_deref()
+                .is_some_and(|description| validate_description(description.trim()).is_ok()),
+            Setting::Author => cli
+                .author
+                .as_deref()
+                .is_some_and(|author| validate_author(author).is_ok()),
+            Setting::Path => cli.path.as_ref().is_some_and(|path| {
+                let path = normalize_path(path);
+                if cli.create_path {
+                    validate_creatable_path(&path).is_ok()
+                } else {
+                    validate_project_path(&path).is_ok()
+                }
+            }),
+            Setting::ProjectType => cli.project_type.is_some(),
+            Setting::BuildSystem => cli.build_system.is_some(),
+            Setting::CmakeGenerator => cli.cmake_generator.is_some(),
+            Setting::CppStandard => cli.cpp_standard.is_some(),
+            Setting::PackageManager => cli.package_manager.is_some(),
+            Setting::TestFramework => cli.test_framework.is_some(),
+            Setting::TestRunner => cli.test_runner.is_some(),
+            Setting::Git => cli.git.is_some() || cli.force_git,
+            Setting::License => cli.license.is_some(),
+            Setting::QualityTools => !cli.quality_tools.is_empty(),
+            Setting::CodeFormatter => !cli.code_formatter.is_empty(),
+            Setting::Ide => !cli.ide.is_empty(),
+        })
+        .collect()
+}
+
+/// Returns the settings given on the command line that are not prompted
+/// for: none with `--interactive-defaults`, where they only pre-fill the
+/// prompts.
+pub(super) fn skipped_settings(cli: &Cli) -> Vec<Setting> {
+    if cli.interactive_defaults {
+        Vec::new()
+    } else {
+        answered_settings(cli)
+    }
+}
+
+/// Returns the settings that can be changed from the summary.
+pub(super) fn applicable_settings(config: &ProjectConfig) -> Vec<Setting> {
+    Setting::ALL
+        .into_iter()
+        .filter(|setting| setting.is_applicable(config))
+        .collect()
+}
+
+/// One option offered by a prompt: the CLI value it stands for and the label
+/// shown to the user.
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct Choice {
+    pub(super) value: &'static str,
+    pub(super) label: String,
+}
+
+impl Choice {
+    fn new(value: &'static str, label: impl Into<String>) -> Self {
+        Self {
+            value,
+            label: label.into(),
+        }
+    }
+}
+
+impl std::fmt::Display for Choice {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.label)
+    }
+}
+
+/// Package managers offered for the chosen build system. Conan and vcpkg
+/// only integrate with the generated CMake files, so they are annotated
+/// for the other build systems.
+pub(super) fn package_manager_choices(config: &ProjectConfig) -> Vec<Choice> {
+    let suffix = match config.build_system {
+        BuildSystem::CMake => "",
+        BuildSystem::Make => " (manifest only, not wired into the Makefile)",
+        BuildSystem::Meson => " (manifest only, not wired into meson.build)",
+    };
+
+    vec![
+        Choice::new("none", "None"),
+        Choice::new("conan", format!("Conan{}", suffix)),
+        Choice::new("vcpkg", format!("Vcpkg{}", suffix)),
+    ]
+}
+
+/// Test frameworks, noting whether the chosen package manager installs them.
+pub(super) fn test_framework_choices(config: &ProjectConfig) -> Vec<Choice> {
+    let note = match config.package_manager {
+        PackageManager::None => " (must be installed on the system)".to_string(),
+        ref package_manager => format!(" (installed by {})", package_manager),
+    };
+
+    vec![
+        Choice::new("none", "none"),
+        Choice::new("doctest", format!("doctest{}", note)),
+        Choice::new("gtest", format!("gtest{}", note)),
+        Choice::new("catch2", format!("catch2{}", note)),
+        Choice::new("boosttest", format!("boost{}", note)),
+    ]
+}
+
+/// Code formatters that make sense for the chosen build system.
+pub(super) fn code_formatter_choices(config: &ProjectConfig) -> Vec<Choice> {
+    let mut choices = vec![Choice::new(
+        "clang-format",
+        "clang-format (Code formatting)",
+    )];
+    if config.build_system == BuildSystem::CMake {
+        choices.push(Choice::new(
+            "cmake-format",
+            "cmake-format (Code formatting)",
+        ));
+        choices.push(Choice::new("cmake-lint", "cmake-lint (CMake linting)"));
+    }
+    choices
+}
+
+/// IDE project files that can be generated for the chosen build system.
+pub(super) fn ide_choices(config: &ProjectConfig) -> Vec<Choice> {
+    let mut choices = Vec::new();
+    if config.build_system == BuildSystem::CMake {
+        choices.push(Choice::new("clion", "CLion (.idea/ CMake profiles)"));
+    }
+    choices.extend([
+        Choice::new("kdevelop", "KDevelop (.kdev4 project)"),
+        Choice::new("emacs", "Emacs (.dir-locals.el for clangd)"),
+        Choice::new("sublime", "Sublime Text (.sublime-project)"),
+    ]);
+    choices
+}
+
+fn git_summary(config: &ProjectConfig) -> String {
+    if !config.use_git {
+        return "no".to_string();
+    }
+    match enclosing_git_repository(&config.path) {
+        Some(root) if !config.force_git => format!("no, inside {}", root.display()),
+        Some(_) => "yes, nested".to_string(),
+        None => "yes".to_string(),
+    }
+}
+
+/// Returns the label and displayed value of each chosen setting, skipping
+/// settings that do not apply to the project.
+pub(super) fn summary_rows(config: &ProjectConfig) -> Vec<(&'static str, String)> {
+    let none_if_empty = |value: String| {
+        if value.is_empty() {
+            "none".to_string()
+        } else {
+            value
+        }
+    };
+
+    let mut rows = vec![
+        ("Name", config.name.clone()),
+        ("Description", config.description.clone()),
+        ("Author", config.author.clone()),
+        ("Path", config.path.display().to_string()),
+        ("Project type", config.project_type.to_string()),
+        ("Build system", config.build_system.to_string()),
+    ];
+    if Setting::CmakeGenerator.is_applicable(config) {
+        rows.push(("CMake generator", config.cmake_generator.to_string()));
+    }
+    rows.extend([
+        ("C++ standard", format!("C++{}", config.cpp_standard)),
+        ("Package manager", config.package_manager.to_string()),
+        ("Test framework", config.test_framework.to_string()),
+    ]);
+    if Setting::TestRunner.is_applicable(config) {
+        rows.push(("Test runner", config.test_runner.to_string()));
+    }
+    rows.extend([
+        ("License", config.license.to_string()),
+        (
+            "Quality tools",
+            none_if_empty(config.quality_config.to_string()),
+        ),
+        (
+            "Formatters",
+            none_if_empty(config.code_formatter.to_string()),
+        ),
+        ("IDE files", none_if_empty(config.ide.to_string())),
+        ("Git", git_summary(config)),
+    ]);
+    rows
+}
+
+/// Formats the chosen settings as an aligned summary table.
+///
+/// While prompting, `config.path` is still the parent directory, so the
+/// summary shows the project directory inside it.
+pub(super) fn summary(config: &ProjectConfig) -> String {
+    let mut project = config.clone();
+    project.path = config.path.join(&config.name);
+
+    let mut output = String::from("Project summary:");
+    for line in project.to_string().lines() {
+        output.push_str(&format!("\n  {}", line));
+    }
+    output
+}
+
+/// Keeps settings that depend on earlier answers consistent.
+pub(super) fn normalize(config: &mut ProjectConfig) {
+    if config.test_framework == TestFramework::None {
+        config.test_runner = TestRunner::Direct;
+    }
+    if config.build_system != BuildSystem::CMake {
+        config.cmake_generator = CmakeGenerator::Default;
+        config.ide.enable_clion = false;
+        config.code_formatter.enable_cmake_format = false;
+        config.code_formatter.enable_cmake_lint = false;
+        config.sanitizer_preset = SanitizerPreset::None;
+    }
+}
+
+fn prompt_setting(config: &mut ProjectConfig, setting: Setting, remembered: bool) -> Result<()> {
+    match setting {
+        Setting::Name => config.name = prompt_name(&config.name)?,
+        Setting::Description => {
+            config.description = prompt_description(&config.description, remembered)?
+        }
+        Setting::Author => config.author = prompt_author(&config.author, remembered)?,
+        Setting::Path => config.path = prompt_path(&config.path)?,
+        Setting::ProjectType => {
+            config.project_type = prompt_project_type(&config.project_type, remembered)?
+        }
+        Setting::BuildSystem => {
+            config.build_system = prompt_build_system(&config.build_system, remembered)?
+        }
+        Setting::CmakeGenerator => {
+            config.cmake_generator = prompt_cmake_generator(&config.cmake_generator, remembered)?
+        }
+        Setting::CppStandard => {
+            config.cpp_standard = prompt_cpp_standard(&config.cpp_standard, remembered)?
+        }
+        Setting::PackageManager => {
+            config.package_manager = prompt_package_manager(config, remembered)?
+        }
+        Setting::TestFramework => {
+            config.test_framework = prompt_test_framework(config, remembered)?
+        }
+        Setting::TestRunner => {
+            config.test_runner = prompt_test_runner(&config.test_runner, remembered)?
+        }
+        Setting::Git => match enclosing_git_repository(&config.path) {
+            Some(root) => {
+                config.force_git = prompt_nested_git(&root, config.force_git)?;
+                config.use_git |= config.force_git;
+            }
+            None => config.use_git = prompt_git(config.use_git, remembered)?,
+        },
+        Setting::License => config.license = prompt_license(&config.license, remembered)?,
+        Setting::QualityTools => {
+            config.quality_config = prompt_quality_tools(&config.quality_config, remembered)?
+        }
+        Setting::CodeFormatter => {
+            config.code_formatter = prompt_code_formatter(config, remembered)?
+        }
+        Setting::Ide => config.ide = prompt_ide(config, remembered)?,
+    }
+
+    normalize(config);
+    Ok(())
+}
+
+fn prompt_name(current: &str) -> Result<String> {
+    Ok(Text::new("What is your project name?")
+        .with_default(current)
+        .with_help_message("The name of your project (will be used as directory name)")
+        .with_validator(|input: &str| match validate_project_name(input) {
+            Ok(()) => Ok(Validation::Valid),
+            Err(e) => Ok(Validation::Invalid(e.into())),
+        })
+        .prompt()?)
+}
+
+fn prompt_description(current: &str, remembered: bool) -> Result<String> {
+    let description = Text::new("Project description:")
+        .with_default(current)
+        .with_help_message(&help_message(
+            "Used in the README and package manifests",
+            remembered,
+        ))
+        .with_validator(|input: &str| match validate_description(input.trim()) {
+            Ok(()) => Ok(Validation::Valid),
+            Err(e) => Ok(Validation::Invalid(e.to_string().into())),
+        })
+        .prompt()?
+        .trim()
+        .to_string();
+
+    if let Some(warning) = control_character_warning("Description", &description) {
+        eprintln!("{}", warning);
+    }
+    Ok(description)
+}
+
+fn prompt_author(current: &str, remembered: bool) -> Result<String> {
+    let author = Text::new("Author:")
+        .with_default(current)
+        .with_help_message(&help_message(
+            "Used in the license and package manifests",
+            remembered,
+        ))
+        .with_validator(|input: &str| match validate_author(input) {
+            Ok(()) => Ok(Validation::Valid),
+            Err(e) => Ok(Validation::Invalid(e.to_string().into())),
+        })
+        .prompt()?
+        .trim()
+        .to_string();
+
+    if let Some(warning) = control_character_warning("Author", &author) {
+        eprintln!("{}", warning);
+    }
+    Ok(author)
+}
+
+/// Asks for the parent directory, offering to create it if it is missing.
+fn prompt_path(current: &Path) -> Result<PathBuf> {
+    loop {
+        let path = Text::new("Where do you want to create the project?")
+            .with_default(&current.to_string_lossy())
+            .with_validator(|input: &str| {
+                match validate_creatable_path(&normalize_path(Path::new(input))) {
+                    Ok(()) => Ok(Validation::Valid),
+                    Err(e) => Ok(Validation::Invalid(e.to_string().into())),
+                }
+            })
+            .prompt()?;
+
+        let path = normalize_path(Path::new(&path));
+        if path.is_dir()
+            || Confirm::new(&format!("{} doesn't exist. Create it?", path.display()))
+                .with_default(true)
+                .prompt()?
+        {
+            return Ok(path);
+        }
+    }
+}
+
+fn prompt_project_type(current: &ProjectType, remembered: bool) -> Result<ProjectType> {
+    let cursor = match current {
+        ProjectType::Executable => 0,
+        ProjectType::Library => 1,
+    };
+    let project_type = Select::new(
+        "What type of project do you want to create?",
+        vec![
+            "Basic (Simple executable)",
+            "Library (Static/Dynamic library)",
+        ],
+    )
+    .with_help_message(&help_message(
+        "Libraries also get an example executable",
+        remembered,
+    ))
+    .with_starting_cursor(cursor)
+    .prompt()?;
+
+    Ok(match project_type {
+        "Basic (Simple executable)" => ProjectType::Executable,
+        "Library (Static/Dynamic library)" => ProjectType::Library,
+        _ => unreachable!(),
+    })
+}
+
+fn prompt_build_system(current: &BuildSystem, remembered: bool) -> Result<BuildSystem> {
+    let cursor = match current {
+        BuildSystem::CMake => 0,
+        BuildSystem::Make => 1,
+        BuildSystem::Meson => 2,
+    };
+    let build_system = Select::new(
+        "Which build system do you want to use?",
+        vec!["CMake", "Make", "Meson"],
+    )
+    .with_help_message(&help_message(
+        "CMake is recommended for complex projects",
+        remembered,
+    ))
+    .with_starting_cursor(cursor)
+    .prompt()?;
+
+    Ok(match build_system {
+        "CMake" => BuildSystem::CMake,
+        "Make" => BuildSystem::Make,
+        "Meson" => BuildSystem::Meson,
+        _ => unreachable!(),
+    })
+}
+
+fn prompt_cmake_generator(current: &CmakeGenerator, remembered: bool) -> Result<CmakeGenerator> {
+    let cursor = match current {
+        CmakeGenerator::Default => 0,
+        CmakeGenerator::Ninja => 1,
+        CmakeGenerator::NinjaMultiConfig => 2,
+        CmakeGenerator::UnixMakefiles => 3,
+        CmakeGenerator::VisualStudio2022 => 4,
+    };
+    let generator = Select::new(
+        "Which CMake generator should the presets use?",
+        vec![
+            "Default",
+            "Ninja",
+            "Ninja Multi-Config",
+            "Unix Makefiles",
+            "Visual Studio 17 2022",
+        ],
+    )
+    .with_help_message(&help_message(
+        "Anything but Default also generates CMakePresets.json",
+        remembered,
+    ))
+    .with_starting_cursor(cursor)
+    .prompt()?;
+
+    Ok(match generator {
+        "Default" => CmakeGenerator::Default,
+        "Ninja" => CmakeGenerator::Ninja,
+        "Ninja Multi-Config" => CmakeGenerator::NinjaMultiConfig,
+        "Unix Makefiles" => CmakeGenerator::UnixMakefiles,
+        "Visual Studio 17 2022" => CmakeGenerator::VisualStudio2022,
+        _ => unreachable!(),
+    })
+}
+
+fn prompt_cpp_standard(current: &CppStandard, remembered: bool) -> Result<CppStandard> {
+    let cursor = match current {
+        CppStandard::Cpp11 => 0,
+        CppStandard::Cpp14 => 1,
+        CppStandard::Cpp17 => 2,
+        CppStandard::Cpp20 => 3,
+        CppStandard::Cpp23 => 4,
+    };
+    let cpp_standard = Select::new(
+        "Which C++ standard do you want to use?",
+        vec!["C++11", "C++14", "C++17", "C++20", "C++23"],
+    )
+    .with_help_message(&help_message(
+        "Newer standards require a more recent compiler",
+        remembered,
+    ))
+    .with_starting_cursor(cursor)
+    .prompt()?;
+
+    Ok(match cpp_standard {
+        "C++11" => CppStandard::Cpp11,
+        "C++14" => CppStandard::Cpp14,
+        "C++17" => CppStandard::Cpp17,
+        "C++20" => CppStandard::Cpp20,
+        "C++23" => CppStandard::Cpp23,
+        _ => unreachable!(),
+    })
+}
+
+fn prompt_package_manager(config: &ProjectConfig, remembered: bool) -> Result<PackageManager> {
+    let choices = package_manager_choices(config);
+    let current = config.package_manager.to_string();
+    let cursor = choices
+        .iter()
+        .position(|choice| choice.value == current)
+        .unwrap_or(0);
+
+    let package_manager = Select::new("Which package manager would you like to use?", choices)
+        .with_help_message(&help_message(
+            "Package managers help manage external dependencies",
+            remembered,
+        ))
+        .with_starting_cursor(cursor)
+        .prompt()?;
+
+    package_manager.value.parse()
+}
+
+fn prompt_test_framework(config: &ProjectConfig, remembered: bool) -> Result<TestFramework> {
+    let choices = test_framework_choices(config);
+    let cursor = choices
+        .iter()
+        .position(|choice| {
+            choice
+                .value
+                .parse::<TestFramework>()
+                .is_ok_and(|framework| framework == config.test_framework)
+        })
+        .unwrap_or(0);
+
+    let test_framework = Select::new("Select testing framework:", choices)
+        .with_help_message(&help_message(
+            "Tests are generated under tests/",
+            remembered,
+        ))
+        .with_starting_cursor(cursor)
+        .prompt()?;
+
+    test_framework.value.parse()
+}
+
+fn prompt_test_runner(current: &TestRunner, remembered: bool) -> Result<TestRunner> {
+    let cursor = match current {
+        TestRunner::Direct => 0,
+        TestRunner::Valgrind => 1,
+        TestRunner::Asan => 2,
+        TestRunner::CTestWrapper => 3,
+    };
+    let test_runner = Select::new(
+        "How should the tests be run?",
+        vec!["Direct", "Valgrind", "AddressSanitizer", "CTest wrapper"],
+    )
+    .with_help_message(&help_message(
+        "Valgrind and AddressSanitizer help catch memory errors",
+        remembered,
+    ))
+    .with_starting_cursor(cursor)
+    .prompt()?;
+
+    Ok(match test_runner {
+        "Direct" => TestRunner::Direct,
+        "Valgrind" => TestRunner::Valgrind,
+        "AddressSanitizer" => TestRunner::Asan,
+        "CTest wrapper" => TestRunner::CTestWrapper,
+        _ => unreachable!(),
+    })
+}
+
+fn prompt_git(current: bool, remembered: bool) -> Result<bool> {
+    Ok(Confirm::new("Do you want to initialize git repository?")
+        .with_default(current)
+        .with_help_message(&help_message(
+            "Creates a repository in the project directory",
+            remembered,
+        ))
+        .prompt()?)
+}
+
+fn prompt_nested_git(root: &Path, current: bool) -> Result<bool> {
+    Ok(Confirm::new(&format!(
+        "The project is inside the git repository at {}. Create a nested repository anyway?",
+        root.display()
+    ))
+    .with_default(current)
+    .with_help_message("Otherwise only the .gitignore is written and the outer repository is used")
+    .prompt()?)
+}
+
+fn prompt_license(current: &License, remembered: bool) -> Result<License> {
+    let cursor = match current {
+        License::MIT => 0,
+        License::Apache2 => 1,
+        License::GPL3 => 2,
+        License::BSD3 => 3,
+        License::BSL1 => 4,
+    };
+    let license = Select::new(
+        "Which license do you want to use?",
+        vec!["MIT", "Apache-2.0", "GPL-3.0", "BSD-3-Clause", "BSL-1.0"],
+    )
+    .with_help_message(&help_message("Written to the LICENSE file", remembered))
+    .with_starting_cursor(cursor)
+    .prompt()?;
+
+    Ok(match license {
+        "MIT" => License::MIT,
+        "Apache-2.0" => License::Apache2,
+        "GPL-3.0" => License::GPL3,
+        "BSD-3-Clause" => License::BSD3,
+        "BSL-1.0" => License::BSL1,
+        _ => unreachable!(),
+    })
+}
+
+fn prompt_quality_tools(current: &QualityConfig, remembered: bool) -> Result<QualityConfig> {
+    if !Confirm::new("Do you want to set up code quality tools?")
+        .with_default(true)
+        .prompt()?
+    {
+        return Ok(QualityConfig::new(&[]));
+    }
+
+    let enabled = [
+        current.enable_clang_tidy,
+        current.enable_cppcheck,
+        current.enable_include_what_you_use,
+    ];
+    let defaults = selected_indices(&enabled);
+
+    let tools = MultiSelect::new(
+        "Which code quality tools would you like to use?",
+        vec![
+            "clang-tidy (Static analysis)",
+            "cppcheck (Static analysis)",
+            "include-what-you-use (Static analysis)",
+        ],
+    )
+    .with_help_message(&help_message(SELECT_HELP, remembered))
+    .with_default(&defaults)
+    .prompt()?;
+
+    let selected_tools: Vec<&str> = tools
+        .iter()
+        .map(|t| match *t {
+            "clang-tidy (Static analysis)" => "clang-tidy",
+            "cppcheck (Static analysis)" => "cppcheck",
+            "include-what-you-use (Static analysis)" => "include-what-you-use",
+            _ => unreachable!(),
+        })
+        .collect();
+    let mut quality_config = QualityConfig::new(&selected_tools);
+    if quality_config.enable_clang_tidy {
+        quality_config.clang_tidy_checks = current.clang_tidy_checks.clone();
+    }
+    Ok(quality_config)
+}
+
+fn prompt_code_formatter(config: &ProjectConfig, remembered: bool) -> Result<CodeFormatter> {
+    if !Confirm::new("Do you want to set up code formatter?")
+        .with_default(true)
+        .prompt()?
+    {
+        return Ok(CodeFormatter::new(&[]));
+    }
+
+    let choices = code_formatter_choices(config);
+    let enabled: Vec<bool> = choices
+        .iter()
+        .map(|choice| match choice.value {
+            "clang-format" => config.code_formatter.enable_clang_format,
+            "cmake-format" => config.code_formatter.enable_cmake_format,
+            "cmake-lint" => config.code_formatter.enable_cmake_lint,
+            _ => unreachable!(),
+        })
+        .collect();
+    let defaults = selected_indices(&enabled);
+
+    let tools = MultiSelect::new("Which code formatter would you like to use?", choices)
+        .with_help_message(&help_message(SELECT_HELP, remembered))
+        .with_default(&defaults)
+        .prompt()?;
+
+    let selected_tools: Vec<&str> = tools.iter().map(|choice| choice.value).collect();
+    Ok(CodeFormatter::new(&selected_tools))
+}
+
+fn prompt_ide(config: &ProjectConfig, remembered: bool) -> Result<IdeConfig> {
+    let choices = ide_choices(config);
+    let current = config.ide.names();
+    let enabled: Vec<bool> = choices
+        .iter()
+        .map(|choice| current.contains(&choice.value))
+        .collect();
+
+    let ides = MultiSelect::new(
+        "Which IDE project files would you like to generate?",
+        choices,
+    )
+    .with_help_message(&help_message(SELECT_HELP, remembered))
+    .with_default(&enabled_indices(&enabled))
+    .prompt()?;
+
+    let selected_ides: Vec<&str> = ides.iter().map(|choice| choice.value).collect();
+    Ok(IdeConfig::new(&selected_ides))
+}
+
+const SELECT_HELP: &str = "Use space to select/deselect, enter to confirm";
+
+/// Asks for a preset to start from before the individual settings.
+pub(super) fn prompt_preset() -> Result<Option<Preset>> {
+    let mut options = vec!["None (choose every setting)".to_string()];
+    options.extend(PRESETS.iter().map(Preset::to_string));
+
+    let choice = Select::new("Start from a preset?", options)
+        .with_help_message("A preset pre-fills the defaults of the following questions")
+        .raw_prompt()?;
+
+    Ok(choice.index.checked_sub(1).map(|index| PRESETS[index]))
+}
+
+/// Appends a note to the help message when the default was remembered from
+/// the previous run.
+fn help_message(base: &str, remembered: bool) -> String {
+    if remembered {
+        format!("{} (default from last run)", base)
+    } else {
+        base.to_string()
+    }
+}
+
+/// Indices of the enabled options, pre-selecting the first option when
+/// nothing has been chosen yet.
+fn selected_indices(enabled: &[bool]) -> Vec<usize> {
+    let indices = enabled_indices(enabled);
+    if indices.is_empty() {
+        vec![0]
+    } else {
+        indices
+    }
+}
+
+fn enabled_indices(enabled: &[bool]) -> Vec<usize> {
+    enabled
+        .iter()
+        .enumerate()
+        .filter(|(_, enabled)| **enabled)
+        .map(|(index, _)| index)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::project::{
+        CiProvider, CoverageService, CppExtension, DocEngine, HppExtension, LineEndings,
+        TargetPlatform,
+    };
+    use clap::Parser;
+    use std::collections::HashMap;
+
+    fn create_test_config() -> ProjectConfig {
+        ProjectConfig {
+            name: "test-project".to_string(),
+            description: "A test project".to_string(),
+            project_type: ProjectType::Executable,
+            build_system: BuildSystem::CMake,
+            cmake_generator: CmakeGenerator::Default,
+            cpp_standard: CppStandard::Cpp17,
+            test_framework: TestFramework::Doctest,
+            test_runner: TestRunner::Valgrind,
+            package_manager: PackageManager::Conan,
+            license: License::MIT,
+            use_git: true,
+            git_commit: false,
+            force_git: false,
+            git_branch: "main".to_string(),
+            git_remote: None,
+            github_repo: None,
+            cpp_extension: CppExtension::Cpp,
+            hpp_extension: HppExtension::Hpp,
+            line_endings: LineEndings::Lf,
+            sanitizer_preset: SanitizerPreset::None,
+            generate_docs_dir: false,
+            doc_engine: DocEngine::Markdown,
+            num_examples: 1,
+            openapi_spec: None,
+            generate_changelog: false,
+            generate_community_files: false,
+            ci: CiProvider::None,
+            enable_dependabot: false,
+            homepage: None,
+            path: PathBuf::from("/tmp"),
+            author: "Test Author".to_string(),
+            version: "1.0.0".to_string(),
+            quality_config: QualityConfig::new(&["clang-tidy", "cppcheck"]),
+            code_formatter: CodeFormatter::new(&[]),
+            ide: IdeConfig::new(&["clion"]),
+            snippets: Vec::new(),
+            codeowners: Vec::new(),
+            conan_options: Vec::new(),
+            vcpkg_features: HashMap::new(),
+            vcpkg_default_registry: None,
+            vcpkg_baseline: None,
+            compile_definitions: Vec::new(),
+            enable_lto: false,
+            coverage_service: CoverageService::None,
+            target_platform: TargetPlatform::Host,
+            android_abi: "arm64-v8a".to_string(),
+            android_api: 24,
+            macos_bundle: false,
+            bundle_id: None,
+            macos_universal: false,
+            extra_vars: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_summary_lists_every_setting() {
+        let config = create_test_config();
+        let summary = summary(&config);
+
+        assert!(summary.contains("Name:            test-project"));
+        assert!(summary.contains(&config.path.join("test-project").display().to_string()));
+        assert!(summary.contains("Project type:    executable"));
+        assert!(summary.contains("Build system:    cmake"));
+        assert!(summary.contains("CMake generator: default"));
+        assert!(summary.contains("C++ standard:    C++17"));
+        assert!(summary.contains("Package manager: conan"));
+        assert!(summary.contains("Test framework:  doctest"));
+        assert!(summary.contains("Test runner:     valgrind"));
+        assert!(summary.contains("License:         MIT"));
+        assert!(summary.contains("Quality tools:   clang-tidy, cppcheck"));
+        assert!(summary.contains("Formatters:      none"));
+        assert!(summary.contains("IDE files:       clion"));
+        assert!(summary.contains("Git:             yes"));
+    }
+
+    #[test]
+    fn test_summary_hides_test_runner_without_tests() {
+        let mut config = create_test_config();
+        config.test_framework = TestFramework::None;
+
+        assert!(!summary(&config).contains("Test runner"));
+    }
+
+    #[test]
+    fn test_applicable_settings() {
+        let mut config = create_test_config();
+        assert_eq!(applicable_settings(&config).len(), Setting::ALL.len());
+
+        config.test_framework = TestFramework::None;
+        let settings = applicable_settings(&config);
+        assert!(!settings.contains(&Setting::TestRunner));
+        assert_eq!(settings.len(), Setting::ALL.len() - 1);
+    }
+
+    #[test]
+    fn test_normalize_resets_dependent_settings() {
+        let mut config = create_test_config();
+        config.cmake_generator = CmakeGenerator::Ninja;
+        config.test_framework = TestFramework::None;
+        config.build_system = BuildSystem::Make;
+        normalize(&mut config);
+
+        assert_eq!(config.test_runner, TestRunner::Direct);
+        assert_eq!(config.cmake_generator, CmakeGenerator::Default);
+        assert!(!config.ide.enable_clion);
+
+        config.code_formatter = CodeFormatter::new(&["clang-format", "cmake-format"]);
+        normalize(&mut config);
+        assert!(config.code_formatter.enable_clang_format);
+        assert!(!config.code_formatter.enable_cmake_format);
+    }
+
+    #[test]
+    fn test_package_manager_choices_annotated_for_make() {
+        let mut config = create_test_config();
+        let labels = |config: &ProjectConfig| {
+            package_manager_choices(config)
+                .into_iter()
+                .map(|choice| choice.label)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(labels(&config), vec!["None", "Conan", "Vcpkg"]);
+
+        config.build_system = BuildSystem::Make;
+        let make_labels = labels(&config);
+        assert_eq!(make_labels[0], "None");
+        assert!(make_labels[1].starts_with("Conan (manifest only"));
+        assert!(make_labels[2].starts_with("Vcpkg (manifest only"));
+    }
+
+    #[test]
+    fn test_test_framework_choices_note_package_manager() {
+        let mut config = create_test_config();
+        let choices = test_framework_choices(&config);
+        assert_eq!(choices[0].label, "none");
+        assert_eq!(choices[2].label, "gtest (installed by conan)");
+        assert_eq!(choices[4].value, "boosttest");
+
+        config.package_manager = PackageManager::None;
+        let choices = test_framework_choices(&config);
+        assert_eq!(
+            choices[1].label,
+            "doctest (must be installed on the system)"
+        );
+    }
+
+    #[test]
+    fn test_code_formatter_choices_filtered_by_build_system() {
+        let mut config = create_test_config();
+        let values = |config: &ProjectConfig| {
+            code_formatter_choices(config)
+                .into_iter()
+                .map(|choice| choice.value)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            values(&config),
+            vec!["clang-format", "cmake-format", "cmake-lint"]
+        );
+
+        config.build_system = BuildSystem::Make;
+        assert_eq!(values(&config), vec!["clang-format"]);
+    }
+
+    #[test]
+    fn test_ide_choices_filtered_by_build_system() {
+        let mut config = create_test_config();
+        assert_eq!(ide_choices(&config)[0].value, "clion");
+
+        config.build_system = BuildSystem::Make;
+        assert!(ide_choices(&config)
+            .iter()
+            .all(|choice| choice.value != "clion"));
+    }
+
+    #[test]
+    fn test_help_message_marks_remembered_defaults() {
+        assert_eq!(help_message("Pick one", false), "Pick one");
+        assert_eq!(
+            help_message("Pick one", true),
+            "Pick one (default from last run)"
+        );
+    }
+
+    #[test]
+    fn test_selected_indices() {
+        assert_eq!(selected_indices(&[false, false]), vec![0]);
+        assert_eq!(selected_indices(&[false, true, true]), vec![1, 2]);
+        assert!(enabled_indices(&[false, false]).is_empty());
+    }
+
+    #[test]
+    fn test_answered_settings_from_cli() {
+        let cli = Cli::try_parse_from([
+            "cppup",
+            "--name",
+            "my-project",
+            "--test-framework",
+            "gtest",
+            "--license",
+            "Apache-2.0",
+            "--git",
+            "false",
+            "--ide",
+            "clion,emacs",
+        ])
+        .unwrap();
+
+        assert_eq!(
+            answered_settings(&cli),
+            vec![
+                Setting::Name,
+                Setting::TestFramework,
+                Setting::Git,
+                Setting::License,
+                Setting::Ide
+            ]
+        );
+    }
+
+    #[test]
+    fn test_skipped_settings_with_interactive_defaults() {
+        let args = ["cppup", "--name", "my-project", "--build-system", "meson"];
+        let cli = Cli::try_parse_from(args).unwrap();
+        assert_eq!(
+            skipped_settings(&cli),
+            vec![Setting::Name, Setting::BuildSystem]
+        );
+
+        let cli = Cli::try_parse_from(args.iter().chain(&["--interactive-defaults"])).unwrap();
+        assert!(skipped_settings(&cli).is_empty());
+        assert_eq!(
+            answered_settings(&cli),
+            vec![Setting::Name, Setting::BuildSystem]
+        );
+        assert!(
+            Cli::try_parse_from(["cppup", "--interactive-defaults", "--non-interactive"]).is_err()
+        );
+    }
+
+    #[test]
+    fn test_answered_settings_ignores_invalid_values() {
+        let cli = Cli::try_parse_from([
+            "cppup",
+            "--name",
+            "1invalid",
+            "--author",
+            "   ",
+            "--path",
+            "/nonexistent/directory/path",
+        ])
+        .unwrap();
+
+        assert!(answered_settings(&cli).is_empty());
+    }
+
+    #[test]
+    fn test_answered_settings_without_flags() {
+        let cli = Cli::try_parse_from(["cppup"]).unwrap();
+        assert!(answered_settings(&cli).is_empty());
+
+        let cli = Cli::try_parse_from(["cppup", "--git"]).unwrap();
+        assert_eq!(answered_settings(&cli), vec![Setting::Git]);
+    }
+}
@@ -0,0 +1,490 @@
+//! Running external tools.
+//!
+//! Every external program cppup starts, such as git, g++, cmake or conan,
+//! goes through a [`CommandRunner`], so the flows around them can be tested
+//! without the tools installed. Tools translate their messages, and some
+//! their version banners, under non-English locales; commands whose output
+//! cppup reads therefore run in the C locale.
+
+use anyhow::{Context, Result};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// An external command to run.
+///
+/// # Examples
+///
+/// ```
+/// use cppup::project::ToolCommand;
+///
+/// let command = ToolCommand::new("git").args(["init", "-b", "main"]);
+/// assert_eq!(command.to_string(), "git init -b main");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ToolCommand {
+    /// Program name, e.g. `git`
+    pub program: String,
+    /// Arguments passed to the program
+    pub args: Vec<String>,
+    /// Working directory, the current one if `None`
+    pub dir: Option<PathBuf>,
+    /// Environment variables set in addition to the inherited ones
+    pub envs: Vec<(String, String)>,
+    /// Whether the command runs in the C locale
+    pub c_locale: bool,
+    /// Whether the output goes to the terminal instead of being captured
+    pub inherit_output: bool,
+    /// How long the command may run before it is stopped
+    pub timeout: Option<Duration>,
+}
+
+impl ToolCommand {
+    /// Creates a command for `program` that runs in the C locale with its
+    /// output captured.
+    pub fn new(program: &str) -> Self {
+        Self {
+            program: program.to_string(),
+            args: Vec::new(),
+            dir: None,
+            envs: Vec::new(),
+            c_locale: true,
+            inherit_output: false,
+            timeout: None,
+        }
+    }
+
+    /// Adds arguments.
+    pub fn args<I, S>(mut self, args: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.args.extend(args.into_iter().map(Into::into));
+        self
+    }
+
+    /// Sets the working directory.
+    pub fn current_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.dir = Some(dir.into());
+        self
+    }
+
+    /// Sets an environment variable.
+    pub fn env(mut self, key: &str, value: impl Into<String>) -> Self {
+        self.envs.push((key.to_string(), value.into()));
+        self
+    }
+
+    /// Runs the command in the user's locale, for commands whose output
+    /// is meant for the user rather than for cppup.
+    pub fn user_locale(mut self) -> Self {
+        self.c_locale = false;
+        self
+    }
+
+    /// Lets the command print to the terminal; its output is not captured.
+    pub fn inherit_output(mut self) -> Self {
+        self.inherit_output = true;
+        self
+    }
+
+    /// Stops the command after `timeout`.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+}
+
+impl std::fmt::Display for ToolCommand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.program)?;
+        for arg in &self.args {
+            write!(f, " {}", arg)?;
+        }
+        Ok(())
+    }
+}
+
+/// What a finished command did.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CommandOutput {
+    /// Exit code, `None` if the command was stopped by a signal or timeout
+    pub exit_code: Option<i32>,
+    /// Whether the command was stopped after its timeout
+    pub timed_out: bool,
+    /// Captured standard output, empty if it was not captured
+    pub stdout: String,
+    /// Captured standard error, empty if it was not captured
+    pub stderr: String,
+    /// Time the command took
+    pub duration: Duration,
+}
+
+impl CommandOutput {
+    /// Whether the command exited with code 0.
+    pub fn success(&self) -> bool {
+        self.exit_code == Some(0)
+    }
+
+    /// Describes how the command ended, e.g. `exit code 2`.
+    pub fn status(&self) -> String {
+        match self.exit_code {
+            Some(code) => format!("exit code {}", code),
+            None if self.timed_out => format!(
+                "a timeout after {} seconds",
+                self.duration.as_secs_f32().round()
+            ),
+            None => "a signal".to_string(),
+        }
+    }
+
+    /// Returns the output if `command` succeeded.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error with the exit status and standard error otherwise.
+    pub fn check(self, command: &ToolCommand) -> Result<Self> {
+        if self.success() {
+            return Ok(self);
+        }
+        let mut message = format!("`{}` failed with {}", command, self.status());
+        if !self.stderr.trim().is_empty() {
+            message.push_str(&format!(": {}", self.stderr.trim()));
+        }
+        Err(anyhow::anyhow!(message))
+    }
+}
+
+/// Runs external commands.
+pub trait CommandRunner: Send + Sync {
+    /// Runs `command` and waits for it to finish. A non-zero exit code is
+    /// not an error; see [`CommandOutput::check`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the command cannot be started.
+    fn run(&self, command: &ToolCommand) -> Result<CommandOutput>;
+
+    /// Runs `command` and returns its output if it exits with code 0.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the command cannot be started or fails.
+    fn run_checked(&self, command: &ToolCommand) -> Result<CommandOutput> {
+        self.run(command)?.check(command)
+    }
+
+    /// Runs `command` and returns its standard output, or `None` if it
+    /// cannot be started or fails.
+    fn stdout(&self, command: &ToolCommand) -> Option<String> {
+        self.run_checked(command).ok().map(|output| output.stdout)
+    }
+
+    /// Returns whether `program` can be found on the `PATH`.
+    fn is_installed(&self, program: &str) -> bool;
+}
+
+/// Runs commands as child processes, logging each one at debug level.
+pub struct SystemRunner;
+
+impl CommandRunner for SystemRunner {
+    fn run(&self, command: &ToolCommand) -> Result<CommandOutput> {
+        let mut process = Command::new(&command.program);
+        process.args(&command.args);
+        if let Some(dir) = &command.dir {
+            process.current_dir(dir);
+        }
+        if command.c_locale {
+            process.env("LC_ALL", "C").env("LANG", "C");
+        }
+        process.envs(command.envs.iter().map(|(key, value)| (key, value)));
+        log::debug!(
+            "running `{}` in {}",
+            command,
+            command.dir.as_deref().unwrap_or(Path::new(".")).display()
+        );
+
+        let started = Instant::now();
+        let output = if command.inherit_output {
+            let status = process
+                .status()
+                .with_context(|| format!("Failed to run `{}`", command))?;
+            CommandOutput {
+                exit_code: status.code(),
+                duration: started.elapsed(),
+                ..CommandOutput::default()
+            }
+        } else {
+            wait_captured(&mut process, command, started)?
+        };
+
+        log::debug!(
+            "`{}` finished with {} in {:.2?}",
+            command,
+            output.status(),
+            output.duration
+        );
+        if !output.stderr.trim().is_empty() {
+            log::debug!("stderr of `{}`: {}", command, output.stderr.trim());
+        }
+        Ok(output)
+    }
+
+    fn is_installed(&self, program: &str) -> bool {
+        which::which(program).is_ok()
+    }
+}
+
+/// Runs `process` with its output captured, stopping it after the timeout
+/// of `command`.
+///
+/// After a timeout the output read so far is returned without waiting for
+/// the pipes to close: a grandchild, like the `git-remote-https` of a `git
+/// clone`, can keep them open after the command itself was killed.
+fn wait_captured(
+    process: &mut Command,
+    command: &ToolCommand,
+    started: Instant,
+) -> Result<CommandOutput> {
+    let mut child = process
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .with_context(|| format!("Failed to run `{}`", command))?;
+
+    // Read both pipes while waiting so a chatty tool cannot fill them
+    let read = |pipe: Option<Box<dyn Read + Send>>| {
+        let bytes = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&bytes);
+        let reader = thread::spawn(move || {
+            let Some(mut pipe) = pipe else {
+                return;
+            };
+            let mut chunk = [0; 8192];
+            while let Ok(read @ 1..) = pipe.read(&mut chunk) {
+                sink.lock().unwrap().extend_from_slice(&chunk[..read]);
+            }
+        });
+        (reader, bytes)
+    };
+    let stdout = read(child.stdout.take().map(|pipe| Box::new(pipe) as _));
+    let stderr = read(child.stderr.take().map(|pipe| Box::new(pipe) as _));
+
+    let status = match command.timeout {
+        None => Some(child.wait()?),
+        Some(timeout) => loop {
+            if let Some(status) = child.try_wait()? {
+                break Some(status);
+            }
+            if started.elapsed() >= timeout {
+                let _ = child.kill();
+                let _ = child.wait();
+                break None;
+            }
+            thread::sleep(Duration::from_millis(100));
+        },
+    };
+
+    let collect = |(reader, bytes): (thread::JoinHandle<()>, Arc<Mutex<Vec<u8>>>)| {
+        if status.is_some() {
+            let _ = reader.join();
+        }
+        let bytes = bytes.lock().unwrap();
+        String::from_utf8_lossy(&bytes).into_owned()
+    };
+    Ok(CommandOutput {
+        exit_code: status.and_then(|status| status.code()),
+        timed_out: status.is_none(),
+        stdout: collect(stdout),
+        stderr: collect(stderr),
+        duration: started.elapsed(),
+    })
+}
+
+/// A [`CommandRunner`] that answers from a function and records the
+/// commands it was asked to run. Every program counts as installed unless
+/// it is marked [`missing`](Self::missing).
+#[cfg(test)]
+pub(crate) struct MockRunner<F> {
+    answer: F,
+    calls: std::sync::Mutex<Vec<ToolCommand>>,
+    missing: Vec<String>,
+}
+
+#[cfg(test)]
+impl<F: Fn(&ToolCommand) -> Result<CommandOutput> + Send + Sync> MockRunner<F> {
+    pub(crate) fn new(answer: F) -> Self {
+        Self {
+            answer,
+            calls: std::sync::Mutex::new(Vec::new()),
+            missing: Vec::new(),
+        }
+    }
+
+    /// Reports `programs` as not installed.
+    pub(crate) fn missing<'a>(mut self, programs: impl IntoIterator<Item = &'a str>) -> Self {
+        self.missing.extend(programs.into_iter().map(String::from));
+        self
+    }
+
+    /// The commands run so far, formatted like `git init -b main`.
+    pub(crate) fn calls(&self) -> Vec<String> {
+        self.calls
+            .lock()
+            .unwrap()
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+impl<F: Fn(&ToolCommand) -> Result<CommandOutput> + Send + Sync> CommandRunner for MockRunner<F> {
+    fn run(&self, command: &ToolCommand) -> Result<CommandOutput> {
+        self.calls.lock().unwrap().push(command.clone());
+        (self.answer)(command)
+    }
+
+    fn is_installed(&self, program: &str) -> bool {
+        !self.missing.iter().any(|missing| missing == program)
+    }
+}
+
+#[cfg(test)]
+impl CommandOutput {
+    /// A successful run that printed `stdout`.
+    pub(crate) fn ok(stdout: &str) -> Self {
+        Self {
+            exit_code: Some(0),
+            stdout: stdout.to_string(),
+            ..Self::default()
+        }
+    }
+
+    /// A run that exited with `code` after printing `stderr`.
+    pub(crate) fn failed(code: i32, stderr: &str) -> Self {
+        Self {
+            exit_code: Some(code),
+            stderr: stderr.to_string(),
+            ..Self::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        let command = ToolCommand::new("git").args(["init"]);
+        assert!(CommandOutput::ok("").check(&command).is_ok());
+
+        let error = CommandOutput::failed(128, "fatal: not permitted\n")
+            .check(&command)
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "`git init` failed with exit code 128: fatal: not permitted"
+        );
+
+        let stopped = CommandOutput {
+            timed_out: true,
+            duration: Duration::from_secs(300),
+            ..CommandOutput::default()
+        };
+        assert_eq!(
+            stopped.check(&command).unwrap_err().to_string(),
+            "`git init` failed with a timeout after 300 seconds"
+        );
+    }
+
+    #[test]
+    fn test_mock_runner_records_calls() {
+        let runner = MockRunner::new(|command| {
+            if command.program == "g++" {
+                Ok(CommandOutput::ok("13.2.0\n"))
+            } else {
+                Err(anyhow::anyhow!("not installed"))
+            }
+        });
+
+        assert_eq!(
+            runner
+                .stdout(&ToolCommand::new("g++").args(["-dumpfullversion"]))
+                .as_deref(),
+            Some("13.2.0\n")
+        );
+        assert_eq!(runner.stdout(&ToolCommand::new("cmake")), None);
+        assert_eq!(runner.calls(), ["g++ -dumpfullversion", "cmake"]);
+
+        let runner = runner.missing(["cmake"]);
+        assert!(runner.is_installed("g++"));
+        assert!(!runner.is_installed("cmake"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_system_runner_uses_the_c_locale() {
+        let command = ToolCommand::new("sh").args(["-c", "echo $LC_ALL $LANG; echo oops >&2"]);
+        let output = SystemRunner.run(&command).unwrap();
+        assert!(output.success());
+        assert_eq!(output.stdout, "C C\n");
+        assert_eq!(output.stderr, "oops\n");
+
+        let output = SystemRunner
+            .run(
+                &command
+                    .user_locale()
+                    .env("LC_ALL", "de_DE.UTF-8")
+                    .env("LANG", "de_DE.UTF-8"),
+            )
+            .unwrap();
+        assert_eq!(output.stdout, "de_DE.UTF-8 de_DE.UTF-8\n");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_system_runner_reports_failures_and_timeouts() {
+        let failing = ToolCommand::new("sh").args(["-c", "exit 3"]);
+        let output = SystemRunner.run(&failing).unwrap();
+        assert_eq!(output.exit_code, Some(3));
+        assert!(SystemRunner.run_checked(&failing).is_err());
+
+        let slow = ToolCommand::new("sleep")
+            .args(["5"])
+            .timeout(Duration::from_millis(200));
+        let output = SystemRunner.run(&slow).unwrap();
+        assert!(output.timed_out);
+        assert!(!output.success());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_system_runner_timeout_ignores_grandchildren_holding_the_pipes() {
+        let command = ToolCommand::new("sh")
+            .args(["-c", "sleep 30 & echo started; wait"])
+            .timeout(Duration::from_millis(500));
+        let started = Instant::now();
+        let output = SystemRunner.run(&command).unwrap();
+        assert!(output.timed_out);
+        assert_eq!(output.stdout, "started\n");
+        assert!(started.elapsed() < Duration::from_secs(10));
+    }
+
+    #[test]
+    fn test_system_runner_missing_tool() {
+        let command = ToolCommand::new("cppup-definitely-missing-tool");
+        let error = SystemRunner.run(&command).unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("Failed to run `cppup-definitely-missing-tool`"));
+        assert_eq!(SystemRunner.stdout(&command), None);
+        assert!(!SystemRunner.is_installed("cppup-definitely-missing-tool"));
+    }
+}
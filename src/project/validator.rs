@@ -1,7 +1,168 @@
+use super::builder::{create_template_data, ProjectBuilder};
 use super::config::{CppStandard, ProjectConfig};
-use super::{BuildSystem, PackageManager};
+use super::github;
+use super::tool::{CommandRunner, SystemRunner, ToolCommand};
+use super::{
+    BuildSystem, CmakeGenerator, CoverageService, DocEngine, PackageManager, TargetPlatform,
+    TestFramework,
+};
+use crate::color;
+use crate::templates::shadowed_template_vars;
 use anyhow::{Context, Result};
-use std::process::Command;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// Captures the version in the first line of `g++ --version`.
+const GCC_VERSION_PATTERN: &str = r"g\+\+ .* (\d+\.\d+)";
+/// Captures the version printed by `g++ -dumpfullversion`.
+const GCC_DUMPED_VERSION_PATTERN: &str = r"^(\d+\.\d+)";
+/// Captures the version in the output of `cmake --version`.
+const CMAKE_VERSION_PATTERN: &str = r"cmake version (\d+\.\d+)";
+/// The longest path Windows accepts without long path support: `MAX_PATH`
+/// less the terminating NUL.
+const WINDOWS_MAX_PATH: usize = 259;
+/// Where Windows records whether long paths are enabled.
+const LONG_PATHS_KEY: &str = r"HKLM\SYSTEM\CurrentControlSet\Control\FileSystem";
+
+/// Inspects the directory a project is generated in.
+pub(super) trait OutputDirProbe {
+    /// Creates and removes a file in `dir`, failing if it cannot be written.
+    fn probe_write(&self, dir: &Path) -> std::io::Result<()>;
+
+    /// Returns the bytes free on the filesystem holding `dir`, if known.
+    fn available_space(&self, dir: &Path) -> Option<u64>;
+
+    /// Returns the longest path the system accepts, or `None` if paths are
+    /// not limited.
+    fn max_path_length(&self) -> Option<usize>;
+}
+
+/// Probes the real filesystem, asking `df` and `reg` through `runner`.
+struct SystemProbe<'a> {
+    runner: &'a dyn CommandRunner,
+}
+
+impl OutputDirProbe for SystemProbe<'_> {
+    fn probe_write(&self, dir: &Path) -> std::io::Result<()> {
+        // Permission bits miss ACLs and read-only mounts, so actually write
+        let probe = dir.join(format!(".cppup-probe-{}", std::process::id()));
+        fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&probe)?;
+        fs::remove_file(&probe)
+    }
+
+    fn available_space(&self, dir: &Path) -> Option<u64> {
+        let output = self.runner.stdout(
+            &ToolCommand::new("df").args(["-Pk".to_string(), dir.to_string_lossy().into_owned()]),
+        )?;
+        parse_df_available(&output)
+    }
+
+    fn max_path_length(&self) -> Option<usize> {
+        if !cfg!(windows) {
+            return None;
+        }
+        let long_paths_enabled = self
+            .runner
+            .stdout(&ToolCommand::new("reg").args([
+                "query",
+                LONG_PATHS_KEY,
+                "/v",
+                "LongPathsEnabled",
+            ]))
+            .is_some_and(|output| output.split_whitespace().any(|word| word == "0x1"));
+        (!long_paths_enabled).then_some(WINDOWS_MAX_PATH)
+    }
+}
+
+/// Reads the available space from the output of `df -Pk`, in bytes.
+fn parse_df_available(output: &str) -> Option<u64> {
+    let kilobytes: u64 = output
+        .lines()
+        .nth(1)?
+        .split_whitespace()
+        .nth(3)?
+        .parse()
+        .ok()?;
+    Some(kilobytes * 1024)
+}
+
+/// Returns the longest of `files` once placed under `root`, with its length
+/// in characters.
+fn longest_output_path<'a>(
+    root: &Path,
+    files: impl IntoIterator<Item = &'a Path>,
+) -> Option<(PathBuf, usize)> {
+    files
+        .into_iter()
+        .map(|file| {
+            let path = root.join(file);
+            let length = path.to_string_lossy().chars().count();
+            (path, length)
+        })
+        .max_by_key(|(_, length)| *length)
+}
+
+/// Checks that the selected options work together.
+///
+/// # Examples
+///
+/// ```
+/// use cppup::project::{CompatibilityChecker, CppStandard, TestFramework};
+///
+/// assert!(CompatibilityChecker::check_framework_standard_compatibility(
+///     &TestFramework::Catch2,
+///     &CppStandard::Cpp11,
+/// )
+/// .is_err());
+/// ```
+pub struct CompatibilityChecker;
+
+impl CompatibilityChecker {
+    /// Checks that the test framework supports the C++ standard.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the framework's current release cannot be built
+    /// with the standard: Catch2 v3 and Google Test 1.14 require C++14.
+    pub fn check_framework_standard_compatibility(
+        framework: &TestFramework,
+        standard: &CppStandard,
+    ) -> Result<()> {
+        let minimum = match framework {
+            TestFramework::Catch2 => Some(("Catch2 v3", CppStandard::Cpp14)),
+            TestFramework::GTest => Some(("Google Test 1.14", CppStandard::Cpp14)),
+            TestFramework::Doctest | TestFramework::BoostTest | TestFramework::None => None,
+        };
+
+        match minimum {
+            Some((name, minimum)) if standard.year() < minimum.year() => Err(anyhow::anyhow!(
+                "{} requires C++{} or newer, but C++{} was selected",
+                name,
+                minimum,
+                standard
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns warnings for combinations that work with some, but not all,
+    /// releases of the framework.
+    pub fn framework_standard_warnings(
+        framework: &TestFramework,
+        standard: &CppStandard,
+    ) -> Vec<String> {
+        match (framework, standard) {
+            (TestFramework::BoostTest, CppStandard::Cpp23) => vec![
+                "Warning: not all Boost versions fully support C++23; Boost.Test may need a recent release".to_string(),
+            ],
+            _ => Vec::new(),
+        }
+    }
+}
 
 /// Validates system prerequisites for project generation.
 ///
@@ -19,6 +180,7 @@ use std::process::Command;
 /// ```
 pub struct ProjectValidator {
     config: ProjectConfig,
+    runner: Arc<dyn CommandRunner>,
 }
 
 impl ProjectValidator {
@@ -37,17 +199,41 @@ impl ProjectValidator {
     /// // let validator = ProjectValidator::new(config);
     /// ```
     pub fn new(config: ProjectConfig) -> Self {
-        Self { config }
+        Self {
+            config,
+            runner: Arc::new(SystemRunner),
+        }
+    }
+
+    /// Replaces how the tools are found and run when checking the
+    /// prerequisites, e.g. with a fake in tests.
+    #[allow(dead_code)]
+    pub fn with_command_runner(mut self, runner: Arc<dyn CommandRunner>) -> Self {
+        self.runner = runner;
+        self
     }
 
     /// Checks that all required tools are installed and compatible.
     ///
     /// Validates:
+    /// - Test framework support for the selected C++ standard
     /// - Build system tools (CMake/Make, g++)
     /// - Package manager tools (Conan/Vcpkg if selected)
     /// - Quality tools (clang-tidy, cppcheck, etc. if enabled)
-    /// - Code formatters (clang-format, cmake-format if enabled)
+    /// - Code formatters (clang-format, cmake-format, cmake-lint if enabled)
+    /// - Doxygen and Sphinx for the Sphinx documentation engine
+    /// - An authenticated `gh` CLI when a GitHub repository is requested
+    /// - Clang for sanitizer presets that include MemorySanitizer
+    /// - An Android NDK in `ANDROID_NDK_HOME` for the Android target
+    /// - The MinGW-w64 cross compiler for the Windows MinGW target
     /// - Compiler version compatibility with C++ standard
+    /// - The output directory: generated paths within the Windows path
+    ///   length limit unless long paths are enabled, a directory that can
+    ///   actually be written to, and enough free space for the files
+    ///
+    /// Extra template variables that shadow built-in template data, and a
+    /// missing Doxygen for the Doxygen documentation engine, are reported as
+    /// warnings.
     ///
     /// # Returns
     ///
@@ -59,18 +245,203 @@ impl ProjectValidator {
     /// Returns an error if:
     /// - A required tool is not installed
     /// - The compiler version is too old for the selected C++ standard
+    /// - The project cannot be written to its output directory
     pub fn check_prerequisites(&self) -> Result<()> {
+        CompatibilityChecker::check_framework_standard_compatibility(
+            &self.config.test_framework,
+            &self.config.cpp_standard,
+        )?;
+        for warning in CompatibilityChecker::framework_standard_warnings(
+            &self.config.test_framework,
+            &self.config.cpp_standard,
+        ) {
+            eprintln!("{}", warning);
+        }
         self.check_required_tools()?;
+        self.check_sanitizer_compiler()?;
+        self.check_android_ndk(std::env::var_os("ANDROID_NDK_HOME").as_deref())?;
+        self.check_cmake_version()?;
+        self.check_github()?;
         self.check_compiler_version()?;
+        self.check_output_location(&SystemProbe {
+            runner: self.runner.as_ref(),
+        })?;
+        for warning in self.template_var_warnings() {
+            eprintln!("{}", warning);
+        }
+        if let Some(warning) = self.dependabot_warning() {
+            eprintln!("{}", warning);
+        }
+        if let Some(warning) = self.vcpkg_baseline_warning() {
+            eprintln!("{}", warning);
+        }
+        if let Some(warning) = self.lto_sanitizer_warning() {
+            eprintln!("{}", warning);
+        }
+        if let Some(warning) = self.coverage_upload_warning() {
+            eprintln!("{}", warning);
+        }
+        if self.uses_sphinx() && !self.is_python_module_installed("breathe") {
+            eprintln!(
+                "Warning: the breathe Python package was not found; install it with `pip install -r docs/requirements.txt` to build the documentation"
+            );
+        }
+        if self.uses_doxygen() && !self.runner.is_installed("doxygen") {
+            eprintln!("Warning: doxygen was not found; install it to build the documentation");
+        }
+        Ok(())
+    }
+
+    /// Checks that the generated files fit in the output directory, before
+    /// anything is written.
+    fn check_output_location(&self, probe: &dyn OutputDirProbe) -> Result<()> {
+        let files = ProjectBuilder::new(self.config.clone()).render_files()?;
+        let root = &self.config.path;
+
+        if let Some(limit) = probe.max_path_length() {
+            let longest = longest_output_path(root, files.iter().map(|(path, _)| path.as_path()));
+            if let Some((path, length)) = longest.filter(|(_, length)| *length > limit) {
+                return Err(anyhow::anyhow!(
+                    "{} would be {} characters long, over the Windows limit of {}; choose a shorter --path or project name, or enable long paths by setting LongPathsEnabled to 1 under {}",
+                    path.display(),
+                    length,
+                    limit,
+                    LONG_PATHS_KEY
+                ));
+            }
+        }
+
+        // The project directory, and with --create-path its parents, may
+        // not exist yet
+        let dir = root.ancestors().find(|dir| dir.exists()).unwrap_or(root);
+        probe.probe_write(dir).map_err(|error| {
+            anyhow::anyhow!(
+                "Cannot write to {}: {}; check its permissions or choose another --path",
+                dir.display(),
+                error
+            )
+        })?;
+
+        let needed: u64 = files.iter().map(|(_, content)| content.len() as u64).sum();
+        if let Some(available) = probe
+            .available_space(dir)
+            .filter(|available| *available < needed)
+        {
+            return Err(anyhow::anyhow!(
+                "Not enough disk space in {}: the project needs about {} KiB but only {} KiB are free",
+                dir.display(),
+                needed.div_ceil(1024),
+                available / 1024
+            ));
+        }
         Ok(())
     }
 
+    /// Checks that `gh` is logged in when a GitHub repository is requested.
+    fn check_github(&self) -> Result<()> {
+        if self.config.github_repo.is_none() {
+            return Ok(());
+        }
+        github::check_authenticated(self.runner.as_ref(), Path::new("."))
+    }
+
+    fn uses_sphinx(&self) -> bool {
+        self.config.generate_docs_dir && self.config.doc_engine == DocEngine::Sphinx
+    }
+
+    fn uses_doxygen(&self) -> bool {
+        self.config.generate_docs_dir && self.config.doc_engine == DocEngine::Doxygen
+    }
+
+    /// Returns whether `python3` can import `module`.
+    fn is_python_module_installed(&self, module: &str) -> bool {
+        self.runner
+            .stdout(
+                &ToolCommand::new("python3").args(["-c".to_string(), format!("import {}", module)]),
+            )
+            .is_some()
+    }
+
+    fn dependabot_warning(&self) -> Option<String> {
+        (self.config.enable_dependabot && !create_template_data(&self.config).has_github_actions)
+            .then(|| {
+                "Warning: Dependabot needs a GitHub Actions pipeline (--ci github); .github/dependabot.yml is not generated".to_string()
+            })
+    }
+
+    fn coverage_upload_warning(&self) -> Option<String> {
+        let service = &self.config.coverage_service;
+        if *service == CoverageService::None {
+            None
+        } else if !create_template_data(&self.config).has_github_actions {
+            Some(format!(
+                "Warning: coverage is uploaded to {} from CI, but no CI pipeline is generated (--ci github); add the upload step to your CI",
+                service
+            ))
+        } else if self.config.test_framework == TestFramework::None {
+            Some(format!(
+                "Warning: coverage is uploaded to {} from CI, but the project has no tests to measure; no upload step is generated",
+                service
+            ))
+        } else {
+            None
+        }
+    }
+
+    fn lto_sanitizer_warning(&self) -> Option<String> {
+        let sanitizers = self.config.sanitizer_preset.sanitizers();
+        (self.config.enable_lto
+            && sanitizers
+                .iter()
+                .any(|sanitizer| matches!(*sanitizer, "address" | "memory")))
+        .then(|| {
+            format!(
+                "Warning: Link Time Optimization with the {} sanitizer preset can miss errors or fail to link; consider disabling one of them for sanitizer builds",
+                self.config.sanitizer_preset
+            )
+        })
+    }
+
+    fn vcpkg_baseline_warning(&self) -> Option<String> {
+        (self.config.vcpkg_default_registry.is_some() && self.config.vcpkg_baseline.is_none())
+            .then(|| {
+                "Warning: the vcpkg registry has no baseline; vcpkg requires one, so set it with --vcpkg-baseline or add it to vcpkg-configuration.json".to_string()
+            })
+    }
+
+    fn template_var_warnings(&self) -> Vec<String> {
+        let data = create_template_data(&self.config);
+        shadowed_template_vars(&data, &self.config.extra_vars)
+            .into_iter()
+            .map(|key| {
+                format!(
+                    "Warning: template variable '{}' overrides a built-in template value",
+                    key
+                )
+            })
+            .collect()
+    }
+
     fn check_required_tools(&self) -> Result<()> {
         let mut tools = match self.config.build_system {
             BuildSystem::CMake => vec!["cmake", "g++"],
             BuildSystem::Make => vec!["make", "g++"],
+            BuildSystem::Meson => vec!["meson", "ninja", "g++"],
         };
 
+        if matches!(
+            self.config.cmake_generator,
+            CmakeGenerator::Ninja | CmakeGenerator::NinjaMultiConfig
+        ) || (self.config.build_system == BuildSystem::CMake
+            && self.config.target_platform != TargetPlatform::Host)
+            || self.config.macos_universal
+        {
+            tools.push("ninja");
+        }
+        if self.config.target_platform == TargetPlatform::WindowsMingw {
+            tools.push("x86_64-w64-mingw32-g++");
+        }
+
         match self.config.package_manager {
             PackageManager::Conan => {
                 tools.push("conan");
@@ -98,8 +469,17 @@ impl ProjectValidator {
         if code_formatter.enable_cmake_format {
             tools.push("cmake-format");
         }
+        if code_formatter.enable_cmake_lint {
+            tools.push("cmake-lint");
+        }
+        if self.uses_sphinx() {
+            tools.extend(["doxygen", "sphinx-build"]);
+        }
+        if self.config.github_repo.is_some() {
+            tools.push("gh");
+        }
         for tool in tools {
-            if !Self::is_tool_installed(tool) {
+            if !self.runner.is_installed(tool) {
                 return Err(anyhow::anyhow!("{} is not installed", tool));
             }
         }
@@ -107,55 +487,198 @@ impl ProjectValidator {
         Ok(())
     }
 
+    fn check_sanitizer_compiler(&self) -> Result<()> {
+        let preset = &self.config.sanitizer_preset;
+        if preset.requires_clang() && !self.runner.is_installed("clang++") {
+            return Err(anyhow::anyhow!(
+                "The {} sanitizer preset uses MemorySanitizer, which requires Clang (clang++ is not installed); GCC does not support it",
+                preset
+            ));
+        }
+        Ok(())
+    }
+
+    /// Checks that `ndk_home`, the value of `ANDROID_NDK_HOME`, points at an
+    /// NDK with the CMake toolchain file the Android preset uses.
+    fn check_android_ndk(&self, ndk_home: Option<&std::ffi::OsStr>) -> Result<()> {
+        if self.config.target_platform != TargetPlatform::Android {
+            return Ok(());
+        }
+        let ndk_home = ndk_home.filter(|value| !value.is_empty()).ok_or_else(|| {
+            anyhow::anyhow!(
+                "The Android target requires ANDROID_NDK_HOME to point at an Android NDK"
+            )
+        })?;
+        let toolchain = Path::new(ndk_home).join("build/cmake/android.toolchain.cmake");
+        if !toolchain.is_file() {
+            return Err(anyhow::anyhow!(
+                "ANDROID_NDK_HOME does not point at an Android NDK: {} was not found",
+                toolchain.display()
+            ));
+        }
+        Ok(())
+    }
+
+    fn check_cmake_version(&self) -> Result<()> {
+        // Ninja Multi-Config was introduced in CMake 3.17
+        if self.config.cmake_generator != CmakeGenerator::NinjaMultiConfig {
+            return Ok(());
+        }
+
+        let version = Self::cmake_version(self.runner.as_ref());
+        if !version.is_some_and(|version| Self::version_at_least(&version, (3, 17))) {
+            return Err(anyhow::anyhow!(
+                "The Ninja Multi-Config generator requires CMake version >= 3.17."
+            ));
+        }
+
+        Ok(())
+    }
+
     fn check_compiler_version(&self) -> Result<()> {
-        let compiler_version = Self::get_compiler_version()?;
-        println!("Found compiler: {}", compiler_version);
+        let version = Self::get_compiler_version(self.runner.as_ref())
+            .context("Failed to get the g++ version")?;
+        println!("{} g++ {}", color::success("Found compiler:"), version);
 
         // Check if compiler supports the selected C++ standard
         let required_version = match self.config.cpp_standard {
-            CppStandard::Cpp11 => 4.8,
-            CppStandard::Cpp14 => 5.0,
-            CppStandard::Cpp17 => 7.0,
-            CppStandard::Cpp20 => 10.0,
-            CppStandard::Cpp23 => 12.0,
+            CppStandard::Cpp11 => (4, 8),
+            CppStandard::Cpp14 => (5, 0),
+            CppStandard::Cpp17 => (7, 0),
+            CppStandard::Cpp20 => (10, 0),
+            CppStandard::Cpp23 => (12, 0),
         };
 
-        if let Some(version) = Self::extract_gcc_version(&compiler_version) {
-            if version < required_version {
-                return Err(anyhow::anyhow!(
-                    "G++ version {} is too old for C++{}. Version >= {} required.",
-                    version,
-                    self.config.cpp_standard,
-                    required_version
-                ));
-            }
+        if !Self::version_at_least(&version, required_version) {
+            return Err(anyhow::anyhow!(
+                "G++ version {} is too old for C++{}. Version >= {}.{} required.",
+                version,
+                self.config.cpp_standard,
+                required_version.0,
+                required_version.1
+            ));
         }
 
         Ok(())
     }
 
-    fn is_tool_installed(tool: &str) -> bool {
-        which::which(tool).is_ok()
+    /// Returns whether `runner` finds `tool` on the `PATH`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use cppup::project::SystemRunner;
+    /// use cppup::ProjectValidator;
+    ///
+    /// if !ProjectValidator::is_tool_installed(&SystemRunner, "cmake") {
+    ///     eprintln!("cmake is missing");
+    /// }
+    /// ```
+    #[allow(dead_code)]
+    pub fn is_tool_installed(runner: &dyn CommandRunner, tool: &str) -> bool {
+        runner.is_installed(tool)
+    }
+
+    /// Returns whether `tool` reports a version of at least `min`, given as
+    /// `(major, minor)`.
+    ///
+    /// The tool is run through `runner` with `args` in the C locale and `version_regex` is
+    /// matched against its standard output; the first capture group must hold the version as
+    /// `major.minor`.
+    ///
+    /// Returns `false` if the tool cannot be run or no version is found.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use cppup::project::SystemRunner;
+    /// use cppup::ProjectValidator;
+    ///
+    /// let ok = ProjectValidator::is_tool_version_sufficient(
+    ///     &SystemRunner,
+    ///     "cmake",
+    ///     &["--version"],
+    ///     r"cmake version (\d+\.\d+)",
+    ///     (3, 17),
+    /// );
+    /// ```
+    #[allow(dead_code)]
+    pub fn is_tool_version_sufficient(
+        runner: &dyn CommandRunner,
+        tool: &str,
+        args: &[&str],
+        version_regex: &str,
+        min: (u32, u32),
+    ) -> bool {
+        runner
+            .stdout(&ToolCommand::new(tool).args(args.iter().copied()))
+            .and_then(|output| Self::extract_version(&output, version_regex))
+            .is_some_and(|version| Self::version_at_least(&version, min))
+    }
+
+    /// Returns the `major.minor` version of g++. `-dumpfullversion` (GCC 7
+    /// and later) is asked first; the `--version` banner is only parsed if
+    /// that fails.
+    fn get_compiler_version(runner: &dyn CommandRunner) -> Result<String> {
+        let gcc = |arg: &str| ToolCommand::new("g++").args([arg]);
+        if let Some(version) = runner
+            .stdout(&gcc("-dumpfullversion"))
+            .and_then(|output| Self::extract_version(output.trim(), GCC_DUMPED_VERSION_PATTERN))
+        {
+            return Ok(version);
+        }
+        let banner = runner.run_checked(&gcc("--version"))?.stdout;
+        Self::extract_version(&banner, GCC_VERSION_PATTERN).ok_or_else(|| {
+            anyhow::anyhow!(
+                "no version found in `g++ --version`: {}",
+                banner.lines().next().unwrap_or_default()
+            )
+        })
     }
 
-    fn get_compiler_version() -> Result<String> {
-        let output = Command::new("g++")
-            .arg("--version")
-            .output()
-            .context("Failed to get g++ version")?;
+    /// Returns the `major.minor` version of CMake: from the JSON of `cmake -E
+    /// capabilities` (CMake 3.7 and later), or else from the `cmake
+    /// --version` banner.
+    fn cmake_version(runner: &dyn CommandRunner) -> Option<String> {
+        let cmake = |args: &[&str]| ToolCommand::new("cmake").args(args.iter().copied());
+        let from_capabilities = runner
+            .stdout(&cmake(&["-E", "capabilities"]))
+            .and_then(|output| {
+                let capabilities: serde_json::Value = serde_json::from_str(&output).ok()?;
+                let version = &capabilities["version"];
+                Some(format!(
+                    "{}.{}",
+                    version["major"].as_u64()?,
+                    version["minor"].as_u64()?
+                ))
+            });
+        from_capabilities.or_else(|| {
+            Self::extract_version(
+                &runner.stdout(&cmake(&["--version"]))?,
+                CMAKE_VERSION_PATTERN,
+            )
+        })
+    }
 
-        let version = String::from_utf8_lossy(&output.stdout);
-        Ok(version.lines().next().unwrap_or("unknown").to_string())
+    fn extract_version(version_string: &str, version_regex: &str) -> Option<String> {
+        let version_regex = regex::Regex::new(version_regex).ok()?;
+        Some(
+            version_regex
+                .captures(version_string)?
+                .get(1)?
+                .as_str()
+                .to_string(),
+        )
     }
 
-    fn extract_gcc_version(version_string: &str) -> Option<f32> {
-        let version_regex = regex::Regex::new(r"g\+\+ .* (\d+\.\d+)").ok()?;
-        version_regex
-            .captures(version_string)?
-            .get(1)?
-            .as_str()
-            .parse()
-            .ok()
+    /// Compares the `major.minor` components of `version` numerically with
+    /// `min`, so `3.9 < 3.17`.
+    fn version_at_least(version: &str, min: (u32, u32)) -> bool {
+        let (major, minor) = version.split_once('.').unwrap_or((version, "0"));
+        match (major.parse::<u32>(), minor.parse::<u32>()) {
+            (Ok(major), Ok(minor)) => (major, minor) >= min,
+            _ => false,
+        }
     }
 }
 
@@ -163,7 +686,12 @@ impl ProjectValidator {
 mod tests {
     use super::*;
     use crate::project::config::{CppStandard, ProjectType};
-    use crate::project::{CodeFormatter, License, QualityConfig, TestFramework};
+    use crate::project::tool::{CommandOutput, MockRunner};
+    use crate::project::{
+        CiProvider, CodeFormatter, CppExtension, DocEngine, HppExtension, IdeConfig, License,
+        LineEndings, QualityConfig, SanitizerPreset, TestFramework, TestRunner,
+    };
+    use std::collections::HashMap;
     use std::path::PathBuf;
 
     fn create_test_config() -> ProjectConfig {
@@ -172,47 +700,275 @@ mod tests {
             description: "Test project".to_string(),
             project_type: ProjectType::Executable,
             build_system: BuildSystem::CMake,
+            cmake_generator: CmakeGenerator::Default,
             cpp_standard: CppStandard::Cpp17,
             test_framework: TestFramework::None,
+            test_runner: TestRunner::Direct,
             package_manager: PackageManager::None,
             license: License::MIT,
             use_git: false,
+            git_commit: false,
+            force_git: false,
+            git_branch: "main".to_string(),
+            git_remote: None,
+            github_repo: None,
+            cpp_extension: CppExtension::Cpp,
+            hpp_extension: HppExtension::Hpp,
+            line_endings: LineEndings::Lf,
+            sanitizer_preset: SanitizerPreset::None,
+            generate_docs_dir: false,
+            doc_engine: DocEngine::Markdown,
+            num_examples: 1,
+            openapi_spec: None,
+            generate_changelog: false,
+            generate_community_files: false,
+            ci: CiProvider::None,
+            enable_dependabot: false,
+            homepage: None,
             path: PathBuf::from("/tmp/test-project"),
             author: "Test Author".to_string(),
             version: "0.1.0".to_string(),
             quality_config: QualityConfig::new(&[]),
+            ide: IdeConfig::new(&[]),
+            snippets: Vec::new(),
+            codeowners: Vec::new(),
+            conan_options: Vec::new(),
+            vcpkg_features: HashMap::new(),
+            vcpkg_default_registry: None,
+            vcpkg_baseline: None,
+            compile_definitions: Vec::new(),
+            enable_lto: false,
+            coverage_service: CoverageService::None,
+            target_platform: TargetPlatform::Host,
+            android_abi: "arm64-v8a".to_string(),
+            android_api: 24,
+            macos_bundle: false,
+            bundle_id: None,
+            macos_universal: false,
             code_formatter: CodeFormatter::new(&[]),
+            extra_vars: HashMap::new(),
         }
     }
 
     #[test]
     fn test_extract_gcc_version_valid() {
         let version_string = "g++ (Ubuntu 11.4.0-1ubuntu1~22.04) 11.4.0";
-        let version = ProjectValidator::extract_gcc_version(version_string);
-        assert_eq!(version, Some(11.4));
+        let version = ProjectValidator::extract_version(version_string, GCC_VERSION_PATTERN);
+        assert_eq!(version.as_deref(), Some("11.4"));
     }
 
     #[test]
     fn test_extract_gcc_version_different_format() {
         let version_string = "g++ (GCC) 12.2.0";
-        let version = ProjectValidator::extract_gcc_version(version_string);
-        assert_eq!(version, Some(12.2));
+        let version = ProjectValidator::extract_version(version_string, GCC_VERSION_PATTERN);
+        assert_eq!(version.as_deref(), Some("12.2"));
     }
 
     #[test]
     fn test_extract_gcc_version_invalid() {
         let version_string = "invalid version string";
-        let version = ProjectValidator::extract_gcc_version(version_string);
+        let version = ProjectValidator::extract_version(version_string, GCC_VERSION_PATTERN);
         assert_eq!(version, None);
     }
 
     #[test]
     fn test_extract_gcc_version_no_number() {
         let version_string = "g++ version unknown";
-        let version = ProjectValidator::extract_gcc_version(version_string);
+        let version = ProjectValidator::extract_version(version_string, GCC_VERSION_PATTERN);
         assert_eq!(version, None);
     }
 
+    #[test]
+    fn test_extract_cmake_version() {
+        assert_eq!(
+            ProjectValidator::extract_version(
+                "cmake version 3.28.1\n\nCMake suite",
+                CMAKE_VERSION_PATTERN
+            )
+            .as_deref(),
+            Some("3.28")
+        );
+        assert_eq!(
+            ProjectValidator::extract_version("cmake version 3.16.3", CMAKE_VERSION_PATTERN)
+                .as_deref(),
+            Some("3.16")
+        );
+        assert_eq!(
+            ProjectValidator::extract_version("unknown", CMAKE_VERSION_PATTERN),
+            None
+        );
+    }
+
+    #[test]
+    fn test_extract_version_invalid_regex() {
+        assert_eq!(
+            ProjectValidator::extract_version("cmake version 3.28", r"(\d+"),
+            None
+        );
+    }
+
+    /// Returns a runner that answers each argument list with the given
+    /// output, and fails to start anything else.
+    fn fake_tool(
+        answers: &'static [(&'static [&'static str], &'static str)],
+    ) -> MockRunner<impl Fn(&ToolCommand) -> Result<CommandOutput> + Send + Sync> {
+        MockRunner::new(move |command: &ToolCommand| {
+            answers
+                .iter()
+                .find(|(args, _)| *args == command.args.as_slice())
+                .map(|(_, output)| CommandOutput::ok(output))
+                .ok_or_else(|| anyhow::anyhow!("Failed to run `{}`", command))
+        })
+    }
+
+    #[test]
+    fn test_get_compiler_version_prefers_dumpfullversion() {
+        let run = fake_tool(&[
+            (&["-dumpfullversion"], "13.2.0\n"),
+            (&["--version"], "g++ (GCC) 9.1.0"),
+        ]);
+        assert_eq!(
+            ProjectValidator::get_compiler_version(&run).unwrap(),
+            "13.2"
+        );
+    }
+
+    #[test]
+    fn test_get_compiler_version_falls_back_to_the_banner() {
+        // GCC before 7 does not know -dumpfullversion
+        let run = fake_tool(&[(
+            &["--version"],
+            "g++ (Ubuntu 5.4.0-6ubuntu1) 5.4.0 20160609\n",
+        )]);
+        assert_eq!(ProjectValidator::get_compiler_version(&run).unwrap(), "5.4");
+
+        let run = fake_tool(&[
+            (&["-dumpfullversion"], "\n"),
+            (&["--version"], "g++ (GCC) 12.2.0"),
+        ]);
+        assert_eq!(
+            ProjectValidator::get_compiler_version(&run).unwrap(),
+            "12.2"
+        );
+    }
+
+    #[test]
+    fn test_get_compiler_version_errors() {
+        let error = ProjectValidator::get_compiler_version(&fake_tool(&[])).unwrap_err();
+        assert_eq!(error.to_string(), "Failed to run `g++ --version`");
+
+        // A banner without a version number
+        let run = fake_tool(&[(&["--version"], "g++ (GCC) version inconnue\nCopyright")]);
+        let error = ProjectValidator::get_compiler_version(&run).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "no version found in `g++ --version`: g++ (GCC) version inconnue"
+        );
+    }
+
+    #[test]
+    fn test_cmake_version() {
+        let run = fake_tool(&[
+            (
+                &["-E", "capabilities"],
+                r#"{"fileApi":{},"version":{"isDirty":false,"major":3,"minor":28,"patch":1,"string":"3.28.1","suffix":""}}"#,
+            ),
+            (&["--version"], "cmake version 3.9.0"),
+        ]);
+        assert_eq!(
+            ProjectValidator::cmake_version(&run).as_deref(),
+            Some("3.28")
+        );
+
+        // CMake before 3.7 has no capabilities
+        let run = fake_tool(&[(&["--version"], "cmake version 3.5.1\n\nCMake suite")]);
+        assert_eq!(
+            ProjectValidator::cmake_version(&run).as_deref(),
+            Some("3.5")
+        );
+
+        let run = fake_tool(&[
+            (&["-E", "capabilities"], "not json"),
+            (&["--version"], "cmake version 3.16.3"),
+        ]);
+        assert_eq!(
+            ProjectValidator::cmake_version(&run).as_deref(),
+            Some("3.16")
+        );
+        assert_eq!(ProjectValidator::cmake_version(&fake_tool(&[])), None);
+    }
+
+    #[test]
+    fn test_version_at_least() {
+        assert!(ProjectValidator::version_at_least("11.4", (10, 0)));
+        assert!(ProjectValidator::version_at_least("10.0", (10, 0)));
+        assert!(!ProjectValidator::version_at_least("9.4", (10, 0)));
+        assert!(ProjectValidator::version_at_least("4.8", (4, 8)));
+        assert!(!ProjectValidator::version_at_least("4.7", (4, 8)));
+    }
+
+    #[test]
+    fn test_version_at_least_compares_minor_numerically() {
+        assert!(ProjectValidator::version_at_least("3.28", (3, 17)));
+        assert!(ProjectValidator::version_at_least("3.17", (3, 17)));
+        assert!(!ProjectValidator::version_at_least("3.9", (3, 17)));
+        assert!(!ProjectValidator::version_at_least("3.16", (3, 17)));
+        assert!(ProjectValidator::version_at_least("4.0", (3, 17)));
+        assert!(!ProjectValidator::version_at_least("3.2", (3, 10)));
+        assert!(ProjectValidator::version_at_least("3.20", (3, 10)));
+        assert!(!ProjectValidator::version_at_least("3.19", (3, 20)));
+    }
+
+    #[test]
+    fn test_version_at_least_rejects_garbage() {
+        assert!(!ProjectValidator::version_at_least("unknown", (3, 17)));
+        assert!(!ProjectValidator::version_at_least("", (1, 0)));
+    }
+
+    #[test]
+    fn test_is_tool_installed_missing_tool() {
+        assert!(!ProjectValidator::is_tool_installed(
+            &SystemRunner,
+            "cppup-definitely-missing-tool"
+        ));
+        let runner =
+            MockRunner::new(|_: &ToolCommand| Ok(CommandOutput::ok(""))).missing(["cmake"]);
+        assert!(!ProjectValidator::is_tool_installed(&runner, "cmake"));
+        assert!(ProjectValidator::is_tool_installed(&runner, "make"));
+    }
+
+    #[test]
+    fn test_is_tool_version_sufficient_missing_tool() {
+        assert!(!ProjectValidator::is_tool_version_sufficient(
+            &SystemRunner,
+            "cppup-definitely-missing-tool",
+            &["--version"],
+            CMAKE_VERSION_PATTERN,
+            (3, 17)
+        ));
+    }
+
+    #[test]
+    fn test_is_tool_version_sufficient() {
+        let runner =
+            MockRunner::new(|_: &ToolCommand| Ok(CommandOutput::ok("cmake version 3.28.3\n")));
+        assert!(ProjectValidator::is_tool_version_sufficient(
+            &runner,
+            "cmake",
+            &["--version"],
+            CMAKE_VERSION_PATTERN,
+            (3, 17)
+        ));
+        assert_eq!(runner.calls(), ["cmake --version"]);
+        assert!(!ProjectValidator::is_tool_version_sufficient(
+            &runner,
+            "cmake",
+            &["--version"],
+            CMAKE_VERSION_PATTERN,
+            (4, 0)
+        ));
+    }
+
     #[test]
     fn test_validator_creation() {
         let config = create_test_config();
@@ -220,6 +976,253 @@ mod tests {
         assert_eq!(validator.config.name, "test-project");
     }
 
+    #[test]
+    fn test_check_android_ndk() {
+        let mut config = create_test_config();
+        assert!(ProjectValidator::new(config.clone())
+            .check_android_ndk(None)
+            .is_ok());
+
+        config.target_platform = TargetPlatform::Android;
+        let validator = ProjectValidator::new(config);
+        let error = validator.check_android_ndk(None).unwrap_err();
+        assert!(error.to_string().contains("ANDROID_NDK_HOME"));
+
+        let ndk = tempfile::TempDir::new().unwrap();
+        assert!(validator
+            .check_android_ndk(Some(ndk.path().as_os_str()))
+            .unwrap_err()
+            .to_string()
+            .contains("android.toolchain.cmake was not found"));
+
+        let cmake_dir = ndk.path().join("build/cmake");
+        std::fs::create_dir_all(&cmake_dir).unwrap();
+        std::fs::write(cmake_dir.join("android.toolchain.cmake"), "").unwrap();
+        assert!(validator
+            .check_android_ndk(Some(ndk.path().as_os_str()))
+            .is_ok());
+    }
+
+    struct FakeProbe {
+        writable: bool,
+        available_space: Option<u64>,
+        max_path_length: Option<usize>,
+    }
+
+    impl OutputDirProbe for FakeProbe {
+        fn probe_write(&self, _dir: &Path) -> std::io::Result<()> {
+            if self.writable {
+                Ok(())
+            } else {
+                Err(std::io::Error::from(std::io::ErrorKind::PermissionDenied))
+            }
+        }
+
+        fn available_space(&self, _dir: &Path) -> Option<u64> {
+            self.available_space
+        }
+
+        fn max_path_length(&self) -> Option<usize> {
+            self.max_path_length
+        }
+    }
+
+    #[test]
+    fn test_longest_output_path() {
+        let root = Path::new("/projects/demo");
+        let files = [
+            Path::new("CMakeLists.txt"),
+            Path::new("tests/CMakeLists.txt"),
+            Path::new("src/main.cpp"),
+        ];
+
+        let (path, length) = longest_output_path(root, files).unwrap();
+        assert_eq!(path, root.join("tests/CMakeLists.txt"));
+        assert_eq!(length, "/projects/demo/tests/CMakeLists.txt".len());
+        assert_eq!(
+            longest_output_path(Path::new("/ü"), [Path::new("ä")])
+                .unwrap()
+                .1,
+            4
+        );
+        assert!(longest_output_path(root, []).is_none());
+    }
+
+    #[test]
+    fn test_parse_df_available() {
+        let output = "Filesystem     1024-blocks      Used Available Capacity Mounted on\n\
+                      /dev/sda1        102400000  51200000  51200000      50% /\n";
+        assert_eq!(parse_df_available(output), Some(51_200_000 * 1024));
+        assert_eq!(parse_df_available("Filesystem\n"), None);
+    }
+
+    #[test]
+    fn test_check_output_location() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let mut config = create_test_config();
+        config.path = temp_dir.path().join("missing/test-project");
+        let validator = ProjectValidator::new(config);
+        let probe = FakeProbe {
+            writable: true,
+            available_space: None,
+            max_path_length: None,
+        };
+        assert!(validator.check_output_location(&probe).is_ok());
+
+        let error = validator
+            .check_output_location(&FakeProbe {
+                writable: false,
+                ..probe
+            })
+            .unwrap_err();
+        assert!(error
+            .to_string()
+            .starts_with(&format!("Cannot write to {}:", temp_dir.path().display())));
+
+        let error = validator
+            .check_output_location(&FakeProbe {
+                available_space: Some(1024),
+                ..probe
+            })
+            .unwrap_err();
+        assert!(error.to_string().starts_with("Not enough disk space"));
+
+        let error = validator
+            .check_output_location(&FakeProbe {
+                max_path_length: Some(20),
+                ..probe
+            })
+            .unwrap_err();
+        assert!(error.to_string().contains("over the Windows limit of 20"));
+        assert!(error.to_string().contains("LongPathsEnabled"));
+    }
+
+    #[test]
+    fn test_system_probe_writes_and_removes_a_file() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let probe = SystemProbe {
+            runner: &SystemRunner,
+        };
+        probe.probe_write(temp_dir.path()).unwrap();
+        assert_eq!(fs::read_dir(temp_dir.path()).unwrap().count(), 0);
+        assert!(probe.probe_write(&temp_dir.path().join("missing")).is_err());
+    }
+
+    #[test]
+    fn test_system_probe_asks_df_through_the_runner() {
+        let runner = MockRunner::new(|_: &ToolCommand| {
+            Ok(CommandOutput::ok(
+                "Filesystem 1024-blocks Used Available Capacity Mounted on\n/dev/sda1 100 60 40 60% /\n",
+            ))
+        });
+        let probe = SystemProbe { runner: &runner };
+        assert_eq!(
+            probe.available_space(Path::new("/project")),
+            Some(40 * 1024)
+        );
+        assert_eq!(runner.calls(), ["df -Pk /project"]);
+    }
+
+    #[test]
+    fn test_dependabot_warning() {
+        let mut config = create_test_config();
+        assert_eq!(
+            ProjectValidator::new(config.clone()).dependabot_warning(),
+            None
+        );
+
+        config.enable_dependabot = true;
+        assert_eq!(
+            ProjectValidator::new(config.clone()).dependabot_warning().unwrap(),
+            "Warning: Dependabot needs a GitHub Actions pipeline (--ci github); .github/dependabot.yml is not generated"
+        );
+
+        config.ci = CiProvider::GitHub;
+        assert_eq!(ProjectValidator::new(config).dependabot_warning(), None);
+    }
+
+    #[test]
+    fn test_coverage_upload_warning() {
+        let mut config = create_test_config();
+        assert_eq!(
+            ProjectValidator::new(config.clone()).coverage_upload_warning(),
+            None
+        );
+
+        config.coverage_service = CoverageService::Coveralls;
+        assert!(ProjectValidator::new(config.clone())
+            .coverage_upload_warning()
+            .unwrap()
+            .contains("no CI pipeline is generated (--ci github)"));
+
+        config.ci = CiProvider::GitHub;
+        config.test_framework = TestFramework::None;
+        assert!(ProjectValidator::new(config.clone())
+            .coverage_upload_warning()
+            .unwrap()
+            .contains("the project has no tests to measure"));
+
+        config.test_framework = TestFramework::Doctest;
+        assert_eq!(
+            ProjectValidator::new(config).coverage_upload_warning(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_lto_sanitizer_warning() {
+        let mut config = create_test_config();
+        config.enable_lto = true;
+        config.sanitizer_preset = SanitizerPreset::Thread;
+        assert_eq!(
+            ProjectValidator::new(config.clone()).lto_sanitizer_warning(),
+            None
+        );
+
+        for preset in [SanitizerPreset::AddressAndUndefined, SanitizerPreset::Full] {
+            config.sanitizer_preset = preset;
+            assert!(ProjectValidator::new(config.clone())
+                .lto_sanitizer_warning()
+                .unwrap()
+                .starts_with("Warning: Link Time Optimization with the"));
+        }
+
+        config.enable_lto = false;
+        assert_eq!(ProjectValidator::new(config).lto_sanitizer_warning(), None);
+    }
+
+    #[test]
+    fn test_vcpkg_baseline_warning() {
+        let mut config = create_test_config();
+        config.package_manager = PackageManager::Vcpkg;
+        config.vcpkg_default_registry = Some("https://github.com/org/registry".to_string());
+        assert!(ProjectValidator::new(config.clone())
+            .vcpkg_baseline_warning()
+            .unwrap()
+            .contains("--vcpkg-baseline"));
+
+        config.vcpkg_baseline = Some("3f2c6e8d1a4b5c6d7e8f9a0b1c2d3e4f5a6b7c8d".to_string());
+        assert_eq!(ProjectValidator::new(config).vcpkg_baseline_warning(), None);
+    }
+
+    #[test]
+    fn test_template_var_warnings() {
+        let mut config = create_test_config();
+        assert!(ProjectValidator::new(config.clone())
+            .template_var_warnings()
+            .is_empty());
+
+        config.extra_vars = HashMap::from([
+            ("name".to_string(), "other".to_string()),
+            ("company".to_string(), "Acme".to_string()),
+        ]);
+        let warnings = ProjectValidator::new(config).template_var_warnings();
+        assert_eq!(
+            warnings,
+            vec!["Warning: template variable 'name' overrides a built-in template value"]
+        );
+    }
+
     #[test]
     fn test_cpp_standard_version_requirements() {
         // Test that we can access the required version logic through the type
@@ -243,4 +1246,71 @@ mod tests {
             CppStandard::Cpp23
         ));
     }
+
+    #[test]
+    fn test_framework_standard_compatibility_matrix() {
+        use CppStandard::*;
+        use TestFramework::*;
+
+        // (framework, standard, compatible, warns)
+        let matrix = [
+            (Doctest, Cpp11, true, false),
+            (Doctest, Cpp14, true, false),
+            (Doctest, Cpp17, true, false),
+            (Doctest, Cpp20, true, false),
+            (Doctest, Cpp23, true, false),
+            (GTest, Cpp11, false, false),
+            (GTest, Cpp14, true, false),
+            (GTest, Cpp17, true, false),
+            (GTest, Cpp20, true, false),
+            (GTest, Cpp23, true, false),
+            (Catch2, Cpp11, false, false),
+            (Catch2, Cpp14, true, false),
+            (Catch2, Cpp17, true, false),
+            (Catch2, Cpp20, true, false),
+            (Catch2, Cpp23, true, false),
+            (BoostTest, Cpp11, true, false),
+            (BoostTest, Cpp14, true, false),
+            (BoostTest, Cpp17, true, false),
+            (BoostTest, Cpp20, true, false),
+            (BoostTest, Cpp23, true, true),
+            (None, Cpp11, true, false),
+            (None, Cpp14, true, false),
+            (None, Cpp17, true, false),
+            (None, Cpp20, true, false),
+            (None, Cpp23, true, false),
+        ];
+
+        for (framework, standard, compatible, warns) in matrix {
+            assert_eq!(
+                CompatibilityChecker::check_framework_standard_compatibility(&framework, &standard)
+                    .is_ok(),
+                compatible,
+                "{} with C++{}",
+                framework,
+                standard
+            );
+            assert_eq!(
+                !CompatibilityChecker::framework_standard_warnings(&framework, &standard)
+                    .is_empty(),
+                warns,
+                "{} with C++{}",
+                framework,
+                standard
+            );
+        }
+    }
+
+    #[test]
+    fn test_framework_standard_error_message() {
+        let error = CompatibilityChecker::check_framework_standard_compatibility(
+            &TestFramework::Catch2,
+            &CppStandard::Cpp11,
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Catch2 v3 requires C++14 or newer, but C++11 was selected"
+        );
+    }
 }
@@ -6,43 +6,217 @@
 use anyhow::{Context, Result};
 use handlebars::Handlebars;
 use serde::Serialize;
+use std::collections::HashMap;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// Version of the embedded template set, recorded in `.cppup.json` so a
+/// project can be regenerated with the templates it was created from.
+pub const TEMPLATES_VERSION: &str = "1";
+
+/// Built-in templates that render scripts, written with mode 0755 on Unix.
+const EXECUTABLE_TEMPLATES: &[&str] = &["update-changelog.sh"];
 
 /// Data structure containing all template variables for project generation.
 ///
 /// This struct is serialized and passed to Handlebars templates to render
 /// project-specific content.
-#[derive(Serialize)]
+#[derive(Serialize, Debug, Clone)]
 pub struct ProjectTemplateData {
     /// Project name
     pub name: String,
     /// C++ standard version (11, 14, 17, 20, 23)
     pub cpp_standard: String,
+    /// C++ standard version as a number, for comparisons with `gte`/`lt`
+    pub cpp_standard_num: u32,
+    /// Version passed to `cmake_minimum_required`
+    pub cmake_min_version: String,
+    /// Extension of C++ source files, without the dot
+    pub cpp_extension: String,
+    /// Extension of C++ header files, without the dot
+    pub hpp_extension: String,
     /// Whether this is a library project
     pub is_library: bool,
-    /// C++ namespace (project name with hyphens replaced by underscores)
+    /// C++ namespace (hyphens replaced by underscores, dots by `::`)
     pub namespace: String,
+    /// Components of the C++ namespace, outermost first
+    pub namespace_parts: Vec<String>,
+    /// CMake project and target name (dots replaced by underscores)
+    pub target_name: String,
+    /// Path of the public header relative to `include/`
+    pub header_path: String,
     /// Build system name
     pub build_system: String,
+    /// Whether Meson is the build system
+    pub is_meson: bool,
+    /// CMake generator name for `CMakePresets.json` (empty for the platform default)
+    pub cmake_generator: String,
     /// Project description
     pub description: String,
     /// Author name
     pub author: String,
+    /// Email address from an author given as `Name <email>` (empty otherwise)
+    pub author_email: String,
     /// Project version
     pub version: String,
+    /// Base names of the example programs, without extension
+    pub example_names: Vec<String>,
     /// Current year for copyright notices
     pub year: String,
+    /// Current date as `YYYY-MM-DD`, for the first changelog release
+    pub release_date: String,
+    /// Project homepage URL (empty if not set)
+    pub homepage: String,
+    /// Whether a `CHANGELOG.md` is generated
+    pub generate_changelog: bool,
+    /// Whether `CONTRIBUTING.md` and `CODE_OF_CONDUCT.md` are generated
+    pub generate_community_files: bool,
+    /// Name of the initial git branch
+    pub git_branch: String,
+    /// URL of the `origin` remote (empty if not set)
+    pub git_remote: String,
+    /// Web page of the repository, for GitHub and GitLab remotes (empty
+    /// otherwise)
+    pub repository_url: String,
+    /// URL of the changelog on the homepage (empty without a homepage)
+    pub changelog_url: String,
     /// Whether tests are enabled
     pub enable_tests: bool,
     /// Test framework name
     pub test_framework: String,
+    /// Test runner name (direct, valgrind, asan, ctest)
+    pub test_runner: String,
     /// Package manager name
     pub package_manager: String,
-    /// Quality tools configuration string
+    /// SPDX identifier of the license
+    pub license: String,
+    /// Quality tools joined for display ("clang-tidy, cppcheck").
+    /// Deprecated for conditionals: use the `enable_*` flags instead.
     pub quality_config: String,
-    /// Code formatter configuration string
+    /// Code formatters joined for display.
+    /// Deprecated for conditionals: use the `enable_*` flags instead.
     pub code_formatter: String,
+    /// Names of the enabled quality tools, for iterating with `#each`
+    pub quality_tools: Vec<String>,
+    /// Whether clang-tidy is enabled
+    pub enable_clang_tidy: bool,
+    /// clang-tidy `Checks:` globs of the selected categories, e.g. `modernize-*`
+    /// (empty for the default check set)
+    pub clang_tidy_checks: Vec<String>,
+    /// Whether cppcheck is enabled
+    pub enable_cppcheck: bool,
+    /// Whether include-what-you-use is enabled
+    pub enable_iwyu: bool,
+    /// Whether clang-format is enabled
+    pub enable_clang_format: bool,
+    /// Whether cmake-format is enabled
+    pub enable_cmake_format: bool,
+    /// Whether cmake-lint is enabled
+    pub enable_cmake_lint: bool,
+    /// Sanitizer preset name ("none", "address", "full", ...)
+    pub sanitizer_preset: String,
+    /// Sanitizers of the preset, comma-separated for `-fsanitize=`
+    pub sanitizer_flags: String,
+    /// Engine of the `docs/` directory ("markdown", "sphinx" or "doxygen")
+    pub doc_engine: String,
+    /// Path of the runnable binary relative to the project root
+    pub binary_path: String,
+    /// Names of the IDEs project files are generated for
+    pub ide: Vec<String>,
+    /// File names, without extension, of the headers in `include/snippets/`
+    pub snippets: Vec<String>,
+    /// Commands that build the project, run from the project root
+    pub build_steps: Vec<String>,
+    /// Commands that run the tests after building (empty without tests)
+    pub test_steps: Vec<String>,
+    /// Conan generators for the build system, e.g. `CMakeDeps`
+    pub conan_generators: Vec<String>,
+    /// Conan package options as `pkg/*:option=value`
+    pub conan_options: Vec<String>,
+    /// The `features` object of `vcpkg.json` (`null` without features)
+    pub vcpkg_features: serde_json::Value,
+    /// Whether a `vcpkg-configuration.json` with a default registry is generated
+    pub vcpkg_has_config: bool,
+    /// Git repository of the default vcpkg registry (empty if not set)
+    pub vcpkg_registry_url: String,
+    /// Commit of the default vcpkg registry (empty if not set)
+    pub vcpkg_baseline: String,
+    /// Preprocessor definitions as `NAME` or `NAME=VALUE`
+    pub compile_definitions: Vec<String>,
+    /// Whether release builds use Link Time Optimization
+    pub enable_lto: bool,
+    /// Coverage service name ("none", "codecov" or "coveralls")
+    pub coverage_service: String,
+    /// Whether the project is configured for Android
+    pub android: bool,
+    /// Android ABI of the Android preset, e.g. `arm64-v8a`
+    pub android_abi: String,
+    /// Android API level of the Android preset
+    pub android_api: u32,
+    /// Whether the project is cross-compiled for Windows with MinGW-w64
+    pub mingw: bool,
+    /// Whether the executable is built as a macOS application bundle
+    pub macos_bundle: bool,
+    /// Bundle identifier of the macOS bundle
+    pub bundle_id: String,
+    /// Whether a preset builds universal macOS binaries
+    pub macos_universal: bool,
+    /// Switches for templates that depend on several options, like `.gitignore`
+    pub features: TemplateFeatures,
+    /// The GitHub or GitLab repository behind the `origin` remote, if any
+    pub repository: Option<TemplateRepository>,
+    /// CI provider whose pipeline the project ships (`github`; empty
+    /// without CI)
+    pub ci: String,
+    /// Whether the CI runs on GitHub Actions
+    pub has_github_actions: bool,
+    /// Ubuntu packages the CI installs before building
+    pub ci_packages: Vec<String>,
+    /// Whether the CI builds with coverage instrumentation and uploads the
+    /// report to `coverage_service`
+    pub ci_coverage: bool,
+}
+
+/// A hosted repository, passed to templates as `repository.<name>`.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct TemplateRepository {
+    /// Hosting service: `github` or `gitlab`
+    pub provider: String,
+    /// Owner and project path, e.g. `me/project`
+    pub slug: String,
+    /// Web page of the repository
+    pub url: String,
+}
+
+/// Feature switches passed to templates as `features.<name>`.
+///
+/// Templates such as `.gitignore` combine the build system, package manager
+/// and IDE choices; these booleans save them from comparing strings.
+#[derive(Serialize, Debug, Clone, Default, PartialEq)]
+pub struct TemplateFeatures {
+    /// CMake is the build system
+    pub cmake: bool,
+    /// Make is the build system
+    pub make: bool,
+    /// Meson is the build system
+    pub meson: bool,
+    /// Conan manages the dependencies
+    pub conan: bool,
+    /// vcpkg manages the dependencies
+    pub vcpkg: bool,
+    /// CLion `.idea/` files are generated
+    pub clion: bool,
+    /// KDevelop project files are generated
+    pub kdevelop: bool,
+    /// Emacs `.dir-locals.el` is generated
+    pub emacs: bool,
+    /// A Sublime Text project is generated
+    pub sublime: bool,
+    /// The build exports `compile_commands.json`, so clangd keeps an index in `.cache/`
+    pub clangd: bool,
+    /// The docs are built with Sphinx
+    pub sphinx: bool,
 }
 
 /// Template renderer using Handlebars.
@@ -50,18 +224,31 @@ pub struct ProjectTemplateData {
 /// This renderer loads all embedded templates and provides methods to render
 /// them with project-specific data.
 ///
+/// # Thread safety
+///
+/// `TemplateRenderer` is `Send + Sync` and cheap to clone: clones share one
+/// template registry behind an [`Arc`], so a single renderer can be handed to
+/// several threads that render concurrently. Registering templates or
+/// partials on a clone copies the registry first and does not affect the
+/// other clones.
+///
 /// # Examples
 ///
 /// ```no_run
 /// use cppup::TemplateRenderer;
+/// use std::collections::HashMap;
 /// use std::path::Path;
 ///
 /// let renderer = TemplateRenderer::new();
 /// // let data = ...; // ProjectTemplateData
-/// // renderer.render("main.cpp", &data, Path::new("src/main.cpp"))?;
+/// // renderer.render("main.cpp", &data, &HashMap::new(), Path::new("src/main.cpp"))?;
 /// ```
+#[derive(Clone)]
 pub struct TemplateRenderer {
-    registry: Handlebars<'static>,
+    registry: Arc<Handlebars<'static>>,
+    overridden: Vec<String>,
+    version: String,
+    executables: Vec<String>,
 }
 
 impl TemplateRenderer {
@@ -75,16 +262,216 @@ impl TemplateRenderer {
     /// let renderer = TemplateRenderer::new();
     /// ```
     pub fn new() -> Self {
+        let (registry, overridden) =
+            create_template_registry(None).expect("embedded templates are valid");
         Self {
-            registry: create_template_registry(),
+            registry: Arc::new(registry),
+            overridden,
+            version: TEMPLATES_VERSION.to_string(),
+            executables: Vec::new(),
+        }
+    }
+
+    /// Creates a TemplateRenderer that prefers templates found in `dir` over
+    /// the embedded ones. Overrides use the same relative path as the
+    /// embedded template, e.g. `cmake/CMakeLists.txt.hbs`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error naming the file if a user template cannot be read or
+    /// is not a valid Handlebars template.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use cppup::templates::{user_template_dir, TemplateRenderer};
+    ///
+    /// if let Some(dir) = user_template_dir() {
+    ///     let renderer = TemplateRenderer::with_user_templates(&dir)?;
+    /// }
+    /// # Ok::<(), anyhow::Error>(())
+    /// ```
+    pub fn with_user_templates(dir: &Path) -> Result<Self> {
+        let (registry, overridden) = create_template_registry(Some(dir))?;
+        Ok(Self {
+            registry: Arc::new(registry),
+            overridden,
+            version: TEMPLATES_VERSION.to_string(),
+            executables: Vec::new(),
+        })
+    }
+
+    /// Creates a TemplateRenderer for the given template set version.
+    ///
+    /// The current version uses the embedded templates. Other versions are
+    /// loaded from `<config dir>/cppup/templates/<version>/`, where they must
+    /// have been downloaded beforehand.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the version is not installed or one of its
+    /// templates is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cppup::templates::{TemplateRenderer, TEMPLATES_VERSION};
+    ///
+    /// let renderer = TemplateRenderer::new_versioned(TEMPLATES_VERSION)?;
+    /// assert_eq!(renderer.version(), TEMPLATES_VERSION);
+    /// # Ok::<(), anyhow::Error>(())
+    /// ```
+    pub fn new_versioned(version: &str) -> Result<Self> {
+        Self::load_versioned(version, user_template_dir().as_deref())
+    }
+
+    fn load_versioned(version: &str, templates_root: Option<&Path>) -> Result<Self> {
+        if version == TEMPLATES_VERSION {
+            return Ok(Self::new());
+        }
+        if version.is_empty()
+            || !version
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '.' || c == '-')
+            || version.starts_with('.')
+        {
+            return Err(anyhow::anyhow!("Invalid template version: {}", version));
+        }
+
+        let dir = templates_root
+            .map(|root| root.join(version))
+            .filter(|dir| dir.is_dir())
+            .with_context(|| {
+                format!(
+                    "Template version {} is not installed; download it with `cppup templates download {}`",
+                    version, version
+                )
+            })?;
+        let (registry, overridden) = create_template_registry(Some(&dir))?;
+        Ok(Self {
+            registry: Arc::new(registry),
+            overridden,
+            version: version.to_string(),
+            executables: Vec::new(),
+        })
+    }
+
+    /// Version of the template set this renderer uses.
+    pub fn version(&self) -> &str {
+        &self.version
+    }
+
+    /// Registers an additional template, replacing one of the same name.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `content` is not a valid Handlebars template.
+    pub fn register_template(&mut self, name: &str, content: &str) -> Result<()> {
+        Arc::make_mut(&mut self.registry)
+            .register_template_string(name, content)
+            .with_context(|| format!("Failed to register template {}", name))
+    }
+
+    /// Registers every `*.hbs` file in `dir` as a partial named after the file
+    /// stem, so `copyright.hbs` becomes `{{> copyright}}`.
+    ///
+    /// All files are attempted; valid partials stay registered even when
+    /// others fail.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `dir` cannot be read or any partial is malformed.
+    /// Only the first malformed partial is returned, the rest are printed as
+    /// warnings.
+    #[allow(dead_code)]
+    pub fn register_partials_from_dir(&mut self, dir: &Path) -> Result<()> {
+        let mut paths = Vec::new();
+        for entry in fs::read_dir(dir)
+            .with_context(|| format!("Failed to read partials directory {}", dir.display()))?
+        {
+            let path = entry?.path();
+            if path.is_file() && path.extension().is_some_and(|ext| ext == "hbs") {
+                paths.push(path);
+            }
+        }
+        paths.sort();
+
+        let mut errors: Vec<(PathBuf, handlebars::TemplateError)> = Vec::new();
+        for path in paths {
+            let Some(name) = path.file_stem().and_then(|stem| stem.to_str()) else {
+                continue;
+            };
+            let content = fs::read_to_string(&path)
+                .with_context(|| format!("Failed to read partial {}", path.display()))?;
+            if let Err(error) = Arc::make_mut(&mut self.registry).register_partial(name, content) {
+                errors.push((path, error));
+            }
+        }
+
+        let mut errors = errors.into_iter();
+        let Some((path, first)) = errors.next() else {
+            return Ok(());
+        };
+        for (path, error) in errors {
+            eprintln!("Warning: skipping partial {}: {}", path.display(), error);
+        }
+        Err(first).with_context(|| format!("Failed to register partial {}", path.display()))
+    }
+
+    /// Returns whether a template with the given name is registered.
+    pub fn has_template(&self, name: &str) -> bool {
+        self.registry.has_template(name)
+    }
+
+    /// Marks a registered template as rendering a script, so its output is
+    /// made executable like that of the built-in scripts.
+    pub fn mark_executable(&mut self, name: &str) {
+        if !self.is_executable(name) {
+            self.executables.push(name.to_string());
         }
     }
-    /// Renders a template with the given data and writes it to a file.
+
+    /// Returns whether the output of the named template is made executable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cppup::TemplateRenderer;
+    ///
+    /// let renderer = TemplateRenderer::new();
+    /// assert!(renderer.is_executable("update-changelog.sh"));
+    /// assert!(!renderer.is_executable("README.md"));
+    /// ```
+    pub fn is_executable(&self, name: &str) -> bool {
+        EXECUTABLE_TEMPLATES.contains(&name) || self.executables.iter().any(|script| script == name)
+    }
+
+    /// Names of all registered templates, sorted.
+    #[allow(dead_code)]
+    pub fn template_names(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = self
+            .registry
+            .get_templates()
+            .keys()
+            .map(String::as_str)
+            .collect();
+        names.sort_unstable();
+        names
+    }
+
+    /// Relative paths of the templates replaced by user templates.
+    pub fn overridden_templates(&self) -> &[String] {
+        &self.overridden
+    }
+
+    /// Renders a template with the given data and writes it to a file, which
+    /// is made executable on Unix if the template renders a script.
     ///
     /// # Arguments
     ///
     /// * `template_name` - Name of the template to render (without .hbs extension)
     /// * `data` - Data to pass to the template
+    /// * `extra` - Additional variables merged into `data` (see [`merge_template_data`])
     /// * `output_path` - Path where the rendered file will be written
     ///
     /// # Returns
@@ -101,29 +488,50 @@ impl TemplateRenderer {
     ///
     /// ```no_run
     /// use cppup::TemplateRenderer;
+    /// use std::collections::HashMap;
     /// use std::path::Path;
     ///
     /// let renderer = TemplateRenderer::new();
     /// // let data = ...; // ProjectTemplateData
-    /// // renderer.render("main.cpp", &data, Path::new("src/main.cpp"))?;
+    /// // renderer.render("main.cpp", &data, &HashMap::new(), Path::new("src/main.cpp"))?;
     /// ```
+    #[allow(dead_code)]
     pub fn render<T: Serialize>(
         &self,
         template_name: &str,
         data: &T,
+        extra: &HashMap<String, String>,
         output_path: &Path,
     ) -> Result<()> {
-        let rendered = self
-            .registry
-            .render(template_name, &data)
-            .with_context(|| format!("Failed to render template {}", template_name))?;
+        let rendered = self.render_with_extra(template_name, data, extra)?;
 
         fs::write(output_path, rendered)
             .with_context(|| format!("Failed to write file {}", output_path.display()))?;
+        if self.is_executable(template_name) {
+            make_executable(output_path)?;
+        }
 
         Ok(())
     }
 
+    /// Renders a template with `extra` merged into `data` (see
+    /// [`merge_template_data`]) and returns the output.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the data cannot be merged or rendering fails.
+    pub fn render_with_extra<T: Serialize>(
+        &self,
+        template_name: &str,
+        data: &T,
+        extra: &HashMap<String, String>,
+    ) -> Result<String> {
+        let data = merge_template_data(data, extra)?;
+        self.registry
+            .render(template_name, &data)
+            .with_context(|| format!("Failed to render template {}", template_name))
+    }
+
     #[allow(dead_code)]
     pub fn render_to_string<T: Serialize>(&self, template_name: &str, data: &T) -> Result<String> {
         self.registry
@@ -138,6 +546,91 @@ impl Default for TemplateRenderer {
     }
 }
 
+/// Converts the line endings of `contents` to CRLF if `crlf` is set, and to
+/// LF otherwise.
+///
+/// # Examples
+///
+/// ```
+/// use cppup::templates::normalize_line_endings;
+///
+/// assert_eq!(normalize_line_endings("a\nb\r\n", true), "a\r\nb\r\n");
+/// assert_eq!(normalize_line_endings("a\nb\r\n", false), "a\nb\n");
+/// ```
+pub fn normalize_line_endings(contents: &str, crlf: bool) -> String {
+    let lf = contents.replace("\r\n", "\n");
+    if crlf {
+        lf.replace('\n', "\r\n")
+    } else {
+        lf
+    }
+}
+
+/// Sets the executable bits on a generated script.
+#[cfg(unix)]
+pub(crate) fn make_executable(path: &Path) -> Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+
+    fs::set_permissions(path, fs::Permissions::from_mode(0o755))
+        .with_context(|| format!("Failed to make {} executable", path.display()))
+}
+
+#[cfg(not(unix))]
+pub(crate) fn make_executable(_path: &Path) -> Result<()> {
+    Ok(())
+}
+
+/// Serializes `data` to a JSON object and inserts the extra variables into
+/// it. Extra variables take precedence over fields of the same name.
+///
+/// # Errors
+///
+/// Returns an error if `data` does not serialize to a JSON object.
+///
+/// # Examples
+///
+/// ```
+/// use cppup::templates::merge_template_data;
+/// use std::collections::HashMap;
+///
+/// let data = serde_json::json!({ "name": "demo" });
+/// let extra = HashMap::from([("company".to_string(), "Acme".to_string())]);
+/// let merged = merge_template_data(&data, &extra).unwrap();
+/// assert_eq!(merged["name"], "demo");
+/// assert_eq!(merged["company"], "Acme");
+/// ```
+pub fn merge_template_data<T: Serialize>(
+    data: &T,
+    extra: &HashMap<String, String>,
+) -> Result<serde_json::Value> {
+    let mut map = match serde_json::to_value(data).context("Failed to serialize template data")? {
+        serde_json::Value::Object(map) => map,
+        _ => return Err(anyhow::anyhow!("Template data must be an object")),
+    };
+    for (key, value) in extra {
+        map.insert(key.clone(), serde_json::Value::String(value.clone()));
+    }
+    Ok(serde_json::Value::Object(map))
+}
+
+/// Returns the extra variable names that shadow fields of `data`, sorted.
+pub fn shadowed_template_vars<T: Serialize>(
+    data: &T,
+    extra: &HashMap<String, String>,
+) -> Vec<String> {
+    let fields = match serde_json::to_value(data) {
+        Ok(serde_json::Value::Object(map)) => map,
+        _ => return Vec::new(),
+    };
+    let mut shadowed: Vec<String> = extra
+        .keys()
+        .filter(|key| fields.contains_key(*key))
+        .cloned()
+        .collect();
+    shadowed.sort();
+    shadowed
+}
+
 fn contains_helper(
     h: &handlebars::Helper,
     _: &Handlebars,
@@ -161,104 +654,358 @@ fn contains_helper(
     Ok(())
 }
 
-fn create_template_registry() -> Handlebars<'static> {
+fn json_escape_helper(
+    h: &handlebars::Helper,
+    _: &Handlebars,
+    _: &handlebars::Context,
+    _: &mut handlebars::RenderContext,
+    out: &mut dyn handlebars::Output,
+) -> handlebars::HelperResult {
+    let value = h.param(0).and_then(|p| p.value().as_str()).unwrap_or("");
+
+    // serde_json produces a quoted JSON string; strip the surrounding quotes
+    // so the helper can be used inside a template's own string literal
+    let escaped = serde_json::to_string(value)
+        .map_err(|e| handlebars::RenderErrorReason::Other(e.to_string()))?;
+    out.write(&escaped[1..escaped.len() - 1])?;
+
+    Ok(())
+}
+
+/// Splits an identifier into lowercase words at separators and at
+/// lowercase-to-uppercase boundaries, e.g. `myProject-name` becomes
+/// `["my", "project", "name"]`.
+fn identifier_words(value: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut current = String::new();
+    let mut previous_lowercase = false;
+
+    for c in value.chars() {
+        if !c.is_alphanumeric() {
+            if !current.is_empty() {
+                words.push(std::mem::take(&mut current));
+            }
+            previous_lowercase = false;
+            continue;
+        }
+        if c.is_uppercase() && previous_lowercase && !current.is_empty() {
+            words.push(std::mem::take(&mut current));
+        }
+        previous_lowercase = c.is_lowercase() || c.is_numeric();
+        current.extend(c.to_lowercase());
+    }
+    if !current.is_empty() {
+        words.push(current);
+    }
+    words
+}
+
+pub(crate) fn snake_case(value: &str) -> String {
+    identifier_words(value).join("_")
+}
+
+fn pascal_case(value: &str) -> String {
+    identifier_words(value)
+        .iter()
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+/// Writes the first parameter, as a string, transformed by `transform`.
+fn write_transformed(
+    h: &handlebars::Helper,
+    out: &mut dyn handlebars::Output,
+    transform: impl Fn(&str) -> String,
+) -> handlebars::HelperResult {
+    let value = h.param(0).and_then(|p| p.value().as_str()).unwrap_or("");
+    out.write(&transform(value))?;
+    Ok(())
+}
+
+fn snake_case_helper(
+    h: &handlebars::Helper,
+    _: &Handlebars,
+    _: &handlebars::Context,
+    _: &mut handlebars::RenderContext,
+    out: &mut dyn handlebars::Output,
+) -> handlebars::HelperResult {
+    write_transformed(h, out, snake_case)
+}
+
+fn pascal_case_helper(
+    h: &handlebars::Helper,
+    _: &Handlebars,
+    _: &handlebars::Context,
+    _: &mut handlebars::RenderContext,
+    out: &mut dyn handlebars::Output,
+) -> handlebars::HelperResult {
+    write_transformed(h, out, pascal_case)
+}
+
+fn upper_helper(
+    h: &handlebars::Helper,
+    _: &Handlebars,
+    _: &handlebars::Context,
+    _: &mut handlebars::RenderContext,
+    out: &mut dyn handlebars::Output,
+) -> handlebars::HelperResult {
+    write_transformed(h, out, str::to_uppercase)
+}
+
+fn lower_helper(
+    h: &handlebars::Helper,
+    _: &Handlebars,
+    _: &handlebars::Context,
+    _: &mut handlebars::RenderContext,
+    out: &mut dyn handlebars::Output,
+) -> handlebars::HelperResult {
+    write_transformed(h, out, str::to_lowercase)
+}
+
+/// Writes an reStructuredText title underline as long as the parameter.
+fn rst_underline_helper(
+    h: &handlebars::Helper,
+    _: &Handlebars,
+    _: &handlebars::Context,
+    _: &mut handlebars::RenderContext,
+    out: &mut dyn handlebars::Output,
+) -> handlebars::HelperResult {
+    write_transformed(h, out, |value| "=".repeat(value.chars().count()))
+}
+
+/// Escapes the parameter for the text of a shields.io static badge, where
+/// `-` and `_` separate the label, message and color.
+fn shields_text_helper(
+    h: &handlebars::Helper,
+    _: &Handlebars,
+    _: &handlebars::Context,
+    _: &mut handlebars::RenderContext,
+    out: &mut dyn handlebars::Output,
+) -> handlebars::HelperResult {
+    write_transformed(h, out, |value| {
+        value
+            .replace('-', "--")
+            .replace('_', "__")
+            .replace(' ', "%20")
+            .replace('+', "%2B")
+    })
+}
+
+/// Writes the parameter as pretty-printed JSON. Lines after the first are
+/// indented by the `indent` hash parameter, so the block lines up with the
+/// surrounding template.
+fn json_helper(
+    h: &handlebars::Helper,
+    _: &Handlebars,
+    _: &handlebars::Context,
+    _: &mut handlebars::RenderContext,
+    out: &mut dyn handlebars::Output,
+) -> handlebars::HelperResult {
+    let value = h.param(0).map(|p| p.value().clone()).unwrap_or_default();
+    let indent = h
+        .hash_get("indent")
+        .and_then(|indent| indent.value().as_u64())
+        .unwrap_or(0) as usize;
+    let json = serde_json::to_string_pretty(&value)
+        .map_err(|e| handlebars::RenderErrorReason::Other(e.to_string()))?;
+    out.write(&json.replace('\n', &format!("\n{}", " ".repeat(indent))))?;
+    Ok(())
+}
+
+/// Reads parameter `index` as a number. Numeric strings such as
+/// `cpp_standard` are accepted too.
+fn number_param(h: &handlebars::Helper, index: usize) -> Result<f64, handlebars::RenderError> {
+    let value = h.param(index).map(|p| p.value());
+    value
+        .and_then(|v| v.as_f64().or_else(|| v.as_str()?.trim().parse().ok()))
+        .ok_or_else(|| {
+            handlebars::RenderErrorReason::Other(format!(
+                "{} expects two numbers, got {:?}",
+                h.name(),
+                value
+            ))
+            .into()
+        })
+}
+
+/// Writes "true" when `compare` holds for the two numeric parameters.
+fn write_comparison(
+    h: &handlebars::Helper,
+    out: &mut dyn handlebars::Output,
+    compare: impl Fn(f64, f64) -> bool,
+) -> handlebars::HelperResult {
+    let (left, right) = (number_param(h, 0)?, number_param(h, 1)?);
+
+    // Empty string is falsy in Handlebars
+    out.write(if compare(left, right) { "true" } else { "" })?;
+    Ok(())
+}
+
+fn gte_helper(
+    h: &handlebars::Helper,
+    _: &Handlebars,
+    _: &handlebars::Context,
+    _: &mut handlebars::RenderContext,
+    out: &mut dyn handlebars::Output,
+) -> handlebars::HelperResult {
+    write_comparison(h, out, |left, right| left >= right)
+}
+
+fn lt_helper(
+    h: &handlebars::Helper,
+    _: &Handlebars,
+    _: &handlebars::Context,
+    _: &mut handlebars::RenderContext,
+    out: &mut dyn handlebars::Output,
+) -> handlebars::HelperResult {
+    write_comparison(h, out, |left, right| left < right)
+}
+
+/// Pairs a template name with its path below `templates/` and its embedded
+/// content.
+macro_rules! embedded {
+    ($name:literal, $path:literal) => {
+        ($name, $path, include_str!(concat!("../templates/", $path)))
+    };
+}
+
+/// All built-in templates as `(name, relative path, content)`.
+const EMBEDDED_TEMPLATES: &[(&str, &str, &str)] = &[
+    embedded!("main.cpp", "main.cpp.hbs"),
+    embedded!("CMakeLists.txt", "cmake/CMakeLists.txt.hbs"),
+    embedded!("CMakePresets.json", "cmake/CMakePresets.json.hbs"),
+    embedded!("options.cmake", "cmake/options.cmake.hbs"),
+    embedded!(
+        "compilation-flags.cmake",
+        "cmake/compilation-flags.cmake.hbs"
+    ),
+    embedded!("source.cmake", "cmake/source.cmake.hbs"),
+    embedded!("mingw-toolchain", "cmake/x86_64-w64-mingw32.cmake.hbs"),
+    embedded!("Info.plist.in", "cmake/Info.plist.in.hbs"),
+    embedded!("sanitizers.cmake", "cmake/sanitizers.cmake.hbs"),
+    embedded!("Makefile", "Makefile.hbs"),
+    embedded!("meson.build", "meson/meson.build.hbs"),
+    embedded!("wrap", "meson/wrap.hbs"),
+    embedded!("tests.meson", "meson/test.hbs"),
+    embedded!("header.hpp", "header.hpp.hbs"),
+    embedded!("library.cpp", "library.cpp.hbs"),
+    embedded!("example.cpp", "example.cpp.hbs"),
+    embedded!("example.cmake", "cmake/example.cmake.hbs"),
+    embedded!("gitignore", "gitignore.hbs"),
+    embedded!("editorconfig", "editorconfig.hbs"),
+    embedded!("gitattributes", "gitattributes.hbs"),
+    embedded!("README.md", "README.md.hbs"),
+    embedded!("docs-index.md", "docs/index.md.hbs"),
+    embedded!("docs-api.md", "docs/api.md.hbs"),
+    embedded!("docs-conf.py", "docs/conf.py.hbs"),
+    embedded!("docs-index.rst", "docs/index.rst.hbs"),
+    embedded!("docs-Makefile", "docs/Makefile.hbs"),
+    embedded!("docs.cmake", "cmake/docs.cmake.hbs"),
+    embedded!("docs-Doxyfile", "docs/Doxyfile.hbs"),
+    embedded!("docs-requirements.txt", "docs/requirements.txt.hbs"),
+    embedded!("docs-CMakeLists.txt", "docs/CMakeLists.txt.hbs"),
+    embedded!("CHANGELOG.md", "CHANGELOG.md.hbs"),
+    embedded!("CONTRIBUTING.md", "CONTRIBUTING.md.hbs"),
+    embedded!("CODE_OF_CONDUCT.md", "CODE_OF_CONDUCT.md.hbs"),
+    embedded!("CODEOWNERS", "CODEOWNERS.hbs"),
+    embedded!("github-ci.yml", "ci/github-actions.yml.hbs"),
+    embedded!("dependabot.yml", "ci/dependabot.yml.hbs"),
+    embedded!("codecov.yml", "ci/codecov.yml.hbs"),
+    embedded!("code-quality", "partials/code-quality.md.hbs"),
+    embedded!("update-changelog.sh", "scripts/update-changelog.sh.hbs"),
+    embedded!("conanfile.txt", "package-managers/conanfile.txt.hbs"),
+    embedded!("vcpkg.json", "package-managers/vcpkg.json.hbs"),
+    embedded!(
+        "vcpkg-configuration.json",
+        "package-managers/vcpkg-configuration.json.hbs"
+    ),
+    embedded!("MIT", "licenses/MIT.hbs"),
+    embedded!("GPL-3.0", "licenses/GPL-3.0.hbs"),
+    embedded!("BSD-3-Clause", "licenses/BSD-3-Clause.hbs"),
+    embedded!("BSL-1.0", "licenses/BSL-1.0.hbs"),
+    embedded!("Apache-2.0", "licenses/Apache-2.0.hbs"),
+    embedded!("clang-format", "formatters/clang-format.hbs"),
+    embedded!("cmake-format", "formatters/cmake-format.yaml.hbs"),
+    embedded!("cmake-lint", "formatters/cmake-lint.yml.hbs"),
+    embedded!("clang-tidy", "static-analyzers/clang-tidy.hbs"),
+    embedded!(
+        "cppcheck-suppressions.xml",
+        "static-analyzers/cppcheck-suppressions.xml.hbs"
+    ),
+    embedded!("clion-cmake.xml", "ide/clion/cmake.xml.hbs"),
+    embedded!("clion-run.xml", "ide/clion/runConfigurations/run.xml.hbs"),
+    embedded!("kdevelop-project.kdev4", "ide/kdevelop/project.kdev4.hbs"),
+    embedded!("kdevelop-builddir.kdev4", "ide/kdevelop/builddir.kdev4.hbs"),
+    embedded!("dir-locals.el", "ide/emacs/dir-locals.el.hbs"),
+    embedded!("sublime-project", "ide/sublime/project.sublime-project.hbs"),
+    embedded!("tests.cmake", "tests/tests.cmake.hbs"),
+    embedded!("api-header", "api/header.hpp.hbs"),
+    embedded!("snippet-thread-pool", "snippets/thread_pool.hpp.hbs"),
+    embedded!("snippet-logger", "snippets/logger.hpp.hbs"),
+    embedded!(
+        "snippet-argument-parser",
+        "snippets/argument_parser.hpp.hbs"
+    ),
+    embedded!("snippet-http-client", "snippets/http_client.hpp.hbs"),
+    embedded!("boost_test_main.cpp", "tests/boost_test_main.cpp.hbs"),
+    embedded!("catch2_main.cpp", "tests/catch2_main.cpp.hbs"),
+    embedded!("gtest_main.cpp", "tests/gtest_main.cpp.hbs"),
+    embedded!("doctest_main.cpp", "tests/doctest_main.cpp.hbs"),
+];
+
+/// Directory searched for user template overrides, if a config directory
+/// exists.
+pub fn user_template_dir() -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join("cppup").join("templates"))
+}
+
+/// Builds the template registry. Templates found in `user_dir` under the
+/// same relative path as an embedded template replace it.
+///
+/// Returns the registry and the relative paths of the overridden templates.
+fn create_template_registry(user_dir: Option<&Path>) -> Result<(Handlebars<'static>, Vec<String>)> {
     let mut handlebars = Handlebars::new();
 
     // Register helper functions
     handlebars.register_helper("contains", Box::new(contains_helper));
+    handlebars.register_helper("json-escape", Box::new(json_escape_helper));
+    handlebars.register_helper("json", Box::new(json_helper));
+    handlebars.register_helper("snake_case", Box::new(snake_case_helper));
+    handlebars.register_helper("pascal_case", Box::new(pascal_case_helper));
+    handlebars.register_helper("upper", Box::new(upper_helper));
+    handlebars.register_helper("lower", Box::new(lower_helper));
+    handlebars.register_helper("rst_underline", Box::new(rst_underline_helper));
+    handlebars.register_helper("shields_text", Box::new(shields_text_helper));
+    handlebars.register_helper("gte", Box::new(gte_helper));
+    handlebars.register_helper("lt", Box::new(lt_helper));
 
-    // Register all templates with proper error handling
-    let templates = vec![
-        ("main.cpp", include_str!("../templates/main.cpp.hbs")),
-        (
-            "CMakeLists.txt",
-            include_str!("../templates/cmake/CMakeLists.txt.hbs"),
-        ),
-        (
-            "options.cmake",
-            include_str!("../templates/cmake/options.cmake.hbs"),
-        ),
-        (
-            "compilation-flags.cmake",
-            include_str!("../templates/cmake/compilation-flags.cmake.hbs"),
-        ),
-        (
-            "source.cmake",
-            include_str!("../templates/cmake/source.cmake.hbs"),
-        ),
-        ("Makefile", include_str!("../templates/Makefile.hbs")),
-        ("header.hpp", include_str!("../templates/header.hpp.hbs")),
-        ("library.cpp", include_str!("../templates/library.cpp.hbs")),
-        ("example.cpp", include_str!("../templates/example.cpp.hbs")),
-        (
-            "example.cmake",
-            include_str!("../templates/cmake/example.cmake.hbs"),
-        ),
-        ("gitignore", include_str!("../templates/gitignore.hbs")),
-        ("README.md", include_str!("../templates/README.md.hbs")),
-        (
-            "conanfile.txt",
-            include_str!("../templates/package-managers/conanfile.txt.hbs"),
-        ),
-        (
-            "vcpkg.json",
-            include_str!("../templates/package-managers/vcpkg.json.hbs"),
-        ),
-        ("MIT", include_str!("../templates/licenses/MIT.hbs")),
-        ("GPL-3.0", include_str!("../templates/licenses/GPL-3.0.hbs")),
-        (
-            "BSD-3-Clause",
-            include_str!("../templates/licenses/BSD-3-Clause.hbs"),
-        ),
-        (
-            "Apache-2.0",
-            include_str!("../templates/licenses/Apache-2.0.hbs"),
-        ),
-        (
-            "clang-format",
-            include_str!("../templates/formatters/clang-format.hbs"),
-        ),
-        (
-            "cmake-format",
-            include_str!("../templates/formatters/cmake-format.yaml.hbs"),
-        ),
-        (
-            "clang-tidy",
-            include_str!("../templates/static-analyzers/clang-tidy.hbs"),
-        ),
-        (
-            "cppcheck-suppressions.xml",
-            include_str!("../templates/static-analyzers/cppcheck-suppressions.xml.hbs"),
-        ),
-        (
-            "tests.cmake",
-            include_str!("../templates/tests/tests.cmake.hbs"),
-        ),
-        (
-            "boost_test_main.cpp",
-            include_str!("../templates/tests/boost_test_main.cpp.hbs"),
-        ),
-        (
-            "catch2_main.cpp",
-            include_str!("../templates/tests/catch2_main.cpp.hbs"),
-        ),
-        (
-            "gtest_main.cpp",
-            include_str!("../templates/tests/gtest_main.cpp.hbs"),
-        ),
-        (
-            "doctest_main.cpp",
-            include_str!("../templates/tests/doctest_main.cpp.hbs"),
-        ),
-    ];
-
-    for (name, content) in templates {
-        handlebars
-            .register_template_string(name, content)
-            .unwrap_or_else(|e| panic!("Failed to register template {}: {}", name, e));
+    let mut overridden = Vec::new();
+    for (name, path, content) in EMBEDDED_TEMPLATES {
+        let user_path = user_dir
+            .map(|dir| dir.join(path))
+            .filter(|path| path.is_file());
+        match user_path {
+            Some(user_path) => {
+                let content = fs::read_to_string(&user_path)
+                    .with_context(|| format!("Failed to read template {}", user_path.display()))?;
+                handlebars
+                    .register_template_string(name, content)
+                    .with_context(|| format!("Invalid template {}", user_path.display()))?;
+                overridden.push(path.to_string());
+            }
+            None => handlebars
+                .register_template_string(name, content)
+                .with_context(|| format!("Failed to register template {}", name))?,
+        }
     }
 
-    handlebars
+    Ok((handlebars, overridden))
 }
 
 #[cfg(test)]
@@ -270,18 +1017,80 @@ mod tests {
         ProjectTemplateData {
             name: "test-project".to_string(),
             cpp_standard: "17".to_string(),
+            cpp_standard_num: 17,
+            cmake_min_version: "3.27".to_string(),
+            cpp_extension: "cpp".to_string(),
+            hpp_extension: "hpp".to_string(),
             is_library: false,
             namespace: "test_project".to_string(),
+            namespace_parts: vec!["test_project".to_string()],
+            target_name: "test-project".to_string(),
+            header_path: "test-project.hpp".to_string(),
             build_system: "cmake".to_string(),
+            is_meson: false,
+            cmake_generator: String::new(),
             description: "A test project".to_string(),
             author: "Test Author".to_string(),
+            author_email: String::new(),
             version: "0.1.0".to_string(),
+            example_names: vec!["example".to_string()],
             year: "2024".to_string(),
+            release_date: "2024-01-01".to_string(),
+            homepage: String::new(),
+            generate_changelog: false,
+            generate_community_files: false,
+            git_branch: "main".to_string(),
+            git_remote: String::new(),
+            repository_url: String::new(),
+            changelog_url: String::new(),
             enable_tests: true,
             test_framework: "doctest".to_string(),
+            test_runner: "direct".to_string(),
             package_manager: "none".to_string(),
+            license: "MIT".to_string(),
             quality_config: "none".to_string(),
             code_formatter: "none".to_string(),
+            quality_tools: Vec::new(),
+            enable_clang_tidy: false,
+            clang_tidy_checks: Vec::new(),
+            enable_cppcheck: false,
+            enable_iwyu: false,
+            enable_clang_format: false,
+            enable_cmake_format: false,
+            enable_cmake_lint: false,
+            sanitizer_preset: "none".to_string(),
+            sanitizer_flags: String::new(),
+            doc_engine: "markdown".to_string(),
+            binary_path: "build/Debug/bin/test-project".to_string(),
+            ide: Vec::new(),
+            snippets: Vec::new(),
+            build_steps: vec![
+                "cmake -S . -B build".to_string(),
+                "cmake --build build".to_string(),
+            ],
+            test_steps: vec!["ctest --test-dir build".to_string()],
+            conan_generators: vec!["CMakeDeps".to_string(), "CMakeToolchain".to_string()],
+            conan_options: Vec::new(),
+            vcpkg_features: serde_json::Value::Null,
+            vcpkg_has_config: false,
+            vcpkg_registry_url: String::new(),
+            vcpkg_baseline: String::new(),
+            compile_definitions: Vec::new(),
+            enable_lto: false,
+            coverage_service: "none".to_string(),
+            android: false,
+            android_abi: "arm64-v8a".to_string(),
+            android_api: 24,
+            mingw: false,
+            macos_bundle: false,
+            bundle_id: "com.example.test-project".to_string(),
+            macos_universal: false,
+            features: TemplateFeatures::default(),
+            repository: None,
+            ci: String::new(),
+            has_github_actions: false,
+            ci_packages: Vec::new(),
+            ci_coverage: false,
         }
     }
 
@@ -292,30 +1101,87 @@ mod tests {
     }
 
     #[test]
-    fn test_render_main_cpp() {
+    fn test_renderer_is_shared_across_threads() {
+        fn assert_send_sync_clone<T: Send + Sync + Clone>() {}
+        assert_send_sync_clone::<TemplateRenderer>();
+
         let renderer = TemplateRenderer::new();
-        let data = create_test_data();
+        let handles: Vec<_> = ["main.cpp", "README.md"]
+            .into_iter()
+            .map(|name| {
+                let renderer = renderer.clone();
+                std::thread::spawn(move || renderer.render_to_string(name, &create_test_data()))
+            })
+            .collect();
+        for handle in handles {
+            assert!(handle.join().unwrap().unwrap().contains("test-project"));
+        }
+    }
 
-        let result = renderer.render_to_string("main.cpp", &data);
-        assert!(result.is_ok());
+    #[test]
+    fn test_registering_on_a_clone_leaves_the_original_untouched() {
+        let original = TemplateRenderer::new();
+        let mut clone = original.clone();
+        clone.register_template("extra", "{{name}}").unwrap();
 
-        let content = result.unwrap();
-        assert!(content.contains("#include"));
+        assert!(clone.has_template("extra"));
+        assert!(!original.has_template("extra"));
+        assert!(original.has_template("main.cpp"));
+        assert!(clone.template_names().contains(&"extra"));
+        assert!(!original.template_names().contains(&"extra"));
     }
 
     #[test]
-    fn test_render_cmake() {
+    fn test_template_names_are_sorted() {
         let renderer = TemplateRenderer::new();
-        let data = create_test_data();
+        let names = renderer.template_names();
+        assert!(names.is_sorted());
+        assert!(names.contains(&"README.md"));
+        assert!(names.contains(&"mingw-toolchain"));
+    }
+
+    #[test]
+    fn test_render_main_cpp() {
+        let renderer = TemplateRenderer::new();
+        let data = create_test_data();
+
+        let result = renderer.render_to_string("main.cpp", &data);
+        assert!(result.is_ok());
+
+        let content = result.unwrap();
+        assert!(content.contains("#include"));
+    }
+
+    #[test]
+    fn test_render_cmake() {
+        let renderer = TemplateRenderer::new();
+        let data = create_test_data();
 
         let result = renderer.render_to_string("CMakeLists.txt", &data);
         assert!(result.is_ok());
 
         let content = result.unwrap();
-        assert!(content.contains("cmake_minimum_required"));
+        assert!(content.contains("cmake_minimum_required(VERSION 3.27)"));
         assert!(content.contains("test-project"));
     }
 
+    #[test]
+    fn test_render_conanfile() {
+        let renderer = TemplateRenderer::new();
+        let mut data = create_test_data();
+
+        let conanfile = renderer.render_to_string("conanfile.txt", &data).unwrap();
+        assert!(conanfile.contains("[generators]\nCMakeDeps\nCMakeToolchain\n"));
+        assert!(conanfile.contains("[options]\n# Specify package options here"));
+        assert!(conanfile.ends_with("[layout]\ncmake_layout\n"));
+
+        data.conan_generators = vec!["MakeDeps".to_string()];
+        data.conan_options = vec!["fmt/*:shared=True".to_string()];
+        let conanfile = renderer.render_to_string("conanfile.txt", &data).unwrap();
+        assert!(conanfile.contains("[generators]\nMakeDeps\n\n"));
+        assert!(conanfile.contains("[options]\nfmt/*:shared=True\n\n"));
+    }
+
     #[test]
     fn test_render_to_file() {
         let renderer = TemplateRenderer::new();
@@ -323,7 +1189,7 @@ mod tests {
         let temp_dir = TempDir::new().unwrap();
         let output_path = temp_dir.path().join("test.cpp");
 
-        let result = renderer.render("main.cpp", &data, &output_path);
+        let result = renderer.render("main.cpp", &data, &HashMap::new(), &output_path);
         assert!(result.is_ok());
         assert!(output_path.exists());
 
@@ -331,6 +1197,283 @@ mod tests {
         assert!(content.contains("#include"));
     }
 
+    #[cfg(unix)]
+    #[test]
+    fn test_render_makes_scripts_executable() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let renderer = TemplateRenderer::new();
+        let data = create_test_data();
+        let temp_dir = TempDir::new().unwrap();
+        let script = temp_dir.path().join("update-changelog.sh");
+        let readme = temp_dir.path().join("README.md");
+
+        renderer
+            .render("update-changelog.sh", &data, &HashMap::new(), &script)
+            .unwrap();
+        renderer
+            .render("README.md", &data, &HashMap::new(), &readme)
+            .unwrap();
+
+        let mode = |path: &Path| fs::metadata(path).unwrap().permissions().mode() & 0o777;
+        assert_eq!(mode(&script), 0o755);
+        assert_eq!(mode(&readme) & 0o111, 0);
+    }
+
+    #[test]
+    fn test_normalize_line_endings() {
+        let mixed = "one\r\ntwo\nthree";
+        assert_eq!(normalize_line_endings(mixed, false), "one\ntwo\nthree");
+        assert_eq!(normalize_line_endings(mixed, true), "one\r\ntwo\r\nthree");
+        assert_eq!(
+            normalize_line_endings(&normalize_line_endings(mixed, true), true),
+            "one\r\ntwo\r\nthree"
+        );
+        assert_eq!(normalize_line_endings("", true), "");
+    }
+
+    #[test]
+    fn test_mark_executable() {
+        let mut renderer = TemplateRenderer::new();
+        renderer
+            .register_template("bootstrap", "#!/bin/sh\n")
+            .unwrap();
+        assert!(!renderer.is_executable("bootstrap"));
+
+        let clone = renderer.clone();
+        renderer.mark_executable("bootstrap");
+        assert!(renderer.is_executable("bootstrap"));
+        assert!(!clone.is_executable("bootstrap"));
+    }
+
+    #[test]
+    fn test_render_dir_locals() {
+        let renderer = TemplateRenderer::new();
+        let mut data = create_test_data();
+
+        let content = renderer.render_to_string("dir-locals.el", &data).unwrap();
+        assert!(content.contains("\"--compile-commands-dir=build\""));
+        assert!(!content.contains("clang-format-buffer"));
+
+        data.enable_clang_format = true;
+        let content = renderer.render_to_string("dir-locals.el", &data).unwrap();
+        assert!(content.contains("clang-format-buffer"));
+    }
+
+    #[test]
+    fn test_merge_template_data_adds_extra_vars() {
+        let data = create_test_data();
+        let extra = HashMap::from([("company".to_string(), "Acme".to_string())]);
+
+        let merged = merge_template_data(&data, &extra).unwrap();
+        assert_eq!(merged["company"], "Acme");
+        assert_eq!(merged["name"], "test-project");
+        assert_eq!(merged["is_library"], false);
+    }
+
+    #[test]
+    fn test_merge_template_data_extra_overrides_fields() {
+        let data = create_test_data();
+        let extra = HashMap::from([("author".to_string(), "Someone Else".to_string())]);
+
+        let merged = merge_template_data(&data, &extra).unwrap();
+        assert_eq!(merged["author"], "Someone Else");
+    }
+
+    #[test]
+    fn test_merge_template_data_rejects_non_objects() {
+        assert!(merge_template_data(&"plain string", &HashMap::new()).is_err());
+    }
+
+    #[test]
+    fn test_shadowed_template_vars() {
+        let data = create_test_data();
+        let extra = HashMap::from([
+            ("version".to_string(), "2.0.0".to_string()),
+            ("company".to_string(), "Acme".to_string()),
+            ("author".to_string(), "Someone Else".to_string()),
+        ]);
+
+        assert_eq!(
+            shadowed_template_vars(&data, &extra),
+            vec!["author", "version"]
+        );
+        assert!(shadowed_template_vars(&data, &HashMap::new()).is_empty());
+    }
+
+    #[test]
+    fn test_render_extra_vars() {
+        let renderer = TemplateRenderer::new();
+        let data = create_test_data();
+        let temp_dir = TempDir::new().unwrap();
+        let output_path = temp_dir.path().join("README.md");
+        let extra = HashMap::from([("name".to_string(), "overridden".to_string())]);
+
+        renderer
+            .render("README.md", &data, &extra, &output_path)
+            .unwrap();
+        let content = fs::read_to_string(&output_path).unwrap();
+        assert!(content.starts_with("# overridden"));
+    }
+
+    #[test]
+    fn test_render_nested_namespace() {
+        let renderer = TemplateRenderer::new();
+        let mut data = create_test_data();
+
+        let content = renderer.render_to_string("header.hpp", &data).unwrap();
+        assert!(content.contains("namespace test_project {\n"));
+        assert!(content.contains("} // namespace test_project"));
+
+        data.namespace = "acme::networking::http".to_string();
+        data.namespace_parts = vec![
+            "acme".to_string(),
+            "networking".to_string(),
+            "http".to_string(),
+        ];
+        let content = renderer.render_to_string("header.hpp", &data).unwrap();
+        assert!(content.contains("namespace acme { namespace networking { namespace http {\n"));
+        assert!(content.contains("}}} // namespace acme::networking::http"));
+    }
+
+    #[test]
+    fn test_render_cmake_presets() {
+        let renderer = TemplateRenderer::new();
+        let mut data = create_test_data();
+
+        data.cmake_generator = "Ninja Multi-Config".to_string();
+        let content = renderer
+            .render_to_string("CMakePresets.json", &data)
+            .unwrap();
+        let presets: serde_json::Value = serde_json::from_str(&content).unwrap();
+        assert_eq!(
+            presets["configurePresets"][0]["generator"],
+            "Ninja Multi-Config"
+        );
+        assert_eq!(presets["configurePresets"].as_array().unwrap().len(), 1);
+        assert_eq!(presets["buildPresets"][0]["configuration"], "Debug");
+        assert_eq!(presets["buildPresets"][1]["configuration"], "Release");
+
+        data.cmake_generator = "Ninja".to_string();
+        let content = renderer
+            .render_to_string("CMakePresets.json", &data)
+            .unwrap();
+        let presets: serde_json::Value = serde_json::from_str(&content).unwrap();
+        assert_eq!(presets["configurePresets"].as_array().unwrap().len(), 2);
+        assert_eq!(
+            presets["configurePresets"][1]["cacheVariables"]["CMAKE_BUILD_TYPE"],
+            "Release"
+        );
+        assert_eq!(presets["buildPresets"][1]["name"], "Release");
+    }
+
+    #[test]
+    fn test_render_macos_bundle_and_universal_preset() {
+        let renderer = TemplateRenderer::new();
+        let mut data = create_test_data();
+        data.macos_bundle = true;
+        data.macos_universal = true;
+        data.version = "1.2.0".to_string();
+
+        let plist = renderer.render_to_string("Info.plist.in", &data).unwrap();
+        for placeholder in [
+            "<string>${MACOSX_BUNDLE_EXECUTABLE_NAME}</string>",
+            "<string>${MACOSX_BUNDLE_GUI_IDENTIFIER}</string>",
+            "<string>${MACOSX_BUNDLE_SHORT_VERSION_STRING}</string>",
+            "<string>${MACOSX_BUNDLE_BUNDLE_VERSION}</string>",
+        ] {
+            assert!(plist.contains(placeholder), "{}", placeholder);
+        }
+
+        let source = renderer.render_to_string("source.cmake", &data).unwrap();
+        assert!(source.contains("add_executable(${PROJECT_NAME} MACOSX_BUNDLE main.cpp)"));
+        assert!(source.contains("MACOSX_BUNDLE_GUI_IDENTIFIER \"com.example.test-project\""));
+        assert!(source.contains("MACOSX_BUNDLE_SHORT_VERSION_STRING \"1.2.0\""));
+
+        let content = renderer
+            .render_to_string("CMakePresets.json", &data)
+            .unwrap();
+        let presets: serde_json::Value = serde_json::from_str(&content).unwrap();
+        let universal = &presets["configurePresets"][0];
+        assert_eq!(universal["name"], "macos-universal");
+        assert_eq!(
+            universal["cacheVariables"]["CMAKE_OSX_ARCHITECTURES"],
+            "arm64;x86_64"
+        );
+
+        data.cmake_generator = "Ninja".to_string();
+        data.mingw = true;
+        let content = renderer
+            .render_to_string("CMakePresets.json", &data)
+            .unwrap();
+        let presets: serde_json::Value = serde_json::from_str(&content).unwrap();
+        assert_eq!(presets["configurePresets"].as_array().unwrap().len(), 4);
+        assert_eq!(presets["buildPresets"][3]["name"], "macos-universal");
+    }
+
+    #[test]
+    fn test_render_android_cmake_preset() {
+        let renderer = TemplateRenderer::new();
+        let mut data = create_test_data();
+        data.is_library = true;
+        data.android = true;
+        data.android_abi = "x86_64".to_string();
+        data.android_api = 26;
+
+        let content = renderer
+            .render_to_string("CMakePresets.json", &data)
+            .unwrap();
+        let presets: serde_json::Value = serde_json::from_str(&content).unwrap();
+        let android = &presets["configurePresets"][0];
+        assert_eq!(presets["configurePresets"].as_array().unwrap().len(), 1);
+        assert_eq!(android["name"], "android-x86_64");
+        assert_eq!(android["generator"], "Ninja");
+        let cache = &android["cacheVariables"];
+        assert_eq!(
+            cache["CMAKE_TOOLCHAIN_FILE"],
+            "$env{ANDROID_NDK_HOME}/build/cmake/android.toolchain.cmake"
+        );
+        assert_eq!(cache["ANDROID_ABI"], "x86_64");
+        assert_eq!(cache["ANDROID_PLATFORM"], "android-26");
+        assert_eq!(cache["BUILD_SHARED_LIBS"], "ON");
+        assert_eq!(
+            presets["buildPresets"][0]["configurePreset"],
+            "android-x86_64"
+        );
+
+        data.cmake_generator = "Ninja".to_string();
+        let content = renderer
+            .render_to_string("CMakePresets.json", &data)
+            .unwrap();
+        let presets: serde_json::Value = serde_json::from_str(&content).unwrap();
+        assert_eq!(presets["configurePresets"].as_array().unwrap().len(), 3);
+        assert_eq!(presets["configurePresets"][2]["name"], "android-x86_64");
+        assert_eq!(presets["buildPresets"].as_array().unwrap().len(), 3);
+
+        let source = renderer.render_to_string("source.cmake", &data).unwrap();
+        assert!(source.contains("add_library(${PROJECT_NAME}\n"));
+    }
+
+    #[test]
+    fn test_render_sublime_project() {
+        let renderer = TemplateRenderer::new();
+        let mut data = create_test_data();
+
+        let content = renderer.render_to_string("sublime-project", &data).unwrap();
+        let project: serde_json::Value = serde_json::from_str(&content).unwrap();
+        assert_eq!(project["folders"][0]["path"], ".");
+        assert!(project["build_systems"][0]["shell_cmd"]
+            .as_str()
+            .unwrap()
+            .contains("cmake --build build"));
+        assert!(project["settings"].is_object());
+
+        data.build_system = "make".to_string();
+        let content = renderer.render_to_string("sublime-project", &data).unwrap();
+        let project: serde_json::Value = serde_json::from_str(&content).unwrap();
+        assert_eq!(project["build_systems"][0]["shell_cmd"], "make");
+    }
+
     #[test]
     fn test_invalid_template() {
         let renderer = TemplateRenderer::new();
@@ -345,18 +1488,80 @@ mod tests {
         let data = ProjectTemplateData {
             name: "test-project".to_string(),
             cpp_standard: "17".to_string(),
+            cpp_standard_num: 17,
+            cmake_min_version: "3.27".to_string(),
+            cpp_extension: "cpp".to_string(),
+            hpp_extension: "hpp".to_string(),
             is_library: false,
             namespace: "test_project".to_string(),
+            namespace_parts: vec!["test_project".to_string()],
+            target_name: "test-project".to_string(),
+            header_path: "test-project.hpp".to_string(),
             build_system: "cmake".to_string(),
+            is_meson: false,
+            cmake_generator: String::new(),
             description: "A test project".to_string(),
             author: "Test Author".to_string(),
+            author_email: String::new(),
             version: "0.1.0".to_string(),
+            example_names: vec!["example".to_string()],
             year: "2024".to_string(),
+            release_date: "2024-01-01".to_string(),
+            homepage: String::new(),
+            generate_changelog: false,
+            generate_community_files: false,
+            git_branch: "main".to_string(),
+            git_remote: String::new(),
+            repository_url: String::new(),
+            changelog_url: String::new(),
             enable_tests: true,
             test_framework: "doctest".to_string(),
+            test_runner: "direct".to_string(),
             package_manager: "none".to_string(),
+            license: "MIT".to_string(),
             quality_config: "clang-tidy,cppcheck".to_string(),
             code_formatter: "clang-format".to_string(),
+            quality_tools: vec!["clang-tidy".to_string(), "cppcheck".to_string()],
+            enable_clang_tidy: true,
+            clang_tidy_checks: Vec::new(),
+            enable_cppcheck: true,
+            enable_iwyu: false,
+            enable_clang_format: true,
+            enable_cmake_format: false,
+            enable_cmake_lint: false,
+            sanitizer_preset: "none".to_string(),
+            sanitizer_flags: String::new(),
+            doc_engine: "markdown".to_string(),
+            binary_path: "build/Debug/bin/test-project".to_string(),
+            ide: Vec::new(),
+            snippets: Vec::new(),
+            build_steps: vec![
+                "cmake -S . -B build".to_string(),
+                "cmake --build build".to_string(),
+            ],
+            test_steps: vec!["ctest --test-dir build".to_string()],
+            conan_generators: vec!["CMakeDeps".to_string(), "CMakeToolchain".to_string()],
+            conan_options: Vec::new(),
+            vcpkg_features: serde_json::Value::Null,
+            vcpkg_has_config: false,
+            vcpkg_registry_url: String::new(),
+            vcpkg_baseline: String::new(),
+            compile_definitions: Vec::new(),
+            enable_lto: false,
+            coverage_service: "none".to_string(),
+            android: false,
+            android_abi: "arm64-v8a".to_string(),
+            android_api: 24,
+            mingw: false,
+            macos_bundle: false,
+            bundle_id: "com.example.test-project".to_string(),
+            macos_universal: false,
+            features: TemplateFeatures::default(),
+            repository: None,
+            ci: String::new(),
+            has_github_actions: false,
+            ci_packages: Vec::new(),
+            ci_coverage: false,
         };
 
         // Test template that uses the contains helper
@@ -366,6 +1571,7 @@ mod tests {
             .register_template_string("test_contains", template)
             .unwrap();
         handlebars.register_helper("contains", Box::new(contains_helper));
+        handlebars.register_helper("json-escape", Box::new(json_escape_helper));
 
         let result = handlebars.render("test_contains", &data).unwrap();
         assert_eq!(result, "clang-tidy enabled");
@@ -379,4 +1585,915 @@ mod tests {
         let result2 = handlebars.render("test_contains2", &data).unwrap();
         assert_eq!(result2, "");
     }
+
+    #[test]
+    fn test_json_escape_helper() {
+        let mut handlebars = Handlebars::new();
+        handlebars.register_helper("json-escape", Box::new(json_escape_helper));
+        handlebars
+            .register_template_string("test_escape", "\"{{json-escape value}}\"")
+            .unwrap();
+
+        let data = serde_json::json!({ "value": "say \"hi\"\\ \n" });
+        let result = handlebars.render("test_escape", &data).unwrap();
+        assert_eq!(result, r#""say \"hi\"\\ \n""#);
+    }
+
+    #[test]
+    fn test_case_helpers() {
+        let mut handlebars = Handlebars::new();
+        handlebars.register_helper("snake_case", Box::new(snake_case_helper));
+        handlebars.register_helper("pascal_case", Box::new(pascal_case_helper));
+        handlebars.register_helper("upper", Box::new(upper_helper));
+        handlebars.register_helper("lower", Box::new(lower_helper));
+        handlebars.register_helper("gte", Box::new(gte_helper));
+        handlebars.register_helper("lt", Box::new(lt_helper));
+        handlebars
+            .register_template_string(
+                "test_case",
+                "{{snake_case value}} {{pascal_case value}} {{upper value}} {{lower value}}",
+            )
+            .unwrap();
+
+        let data = serde_json::json!({ "value": "my-Project.v2" });
+        let result = handlebars.render("test_case", &data).unwrap();
+        assert_eq!(
+            result,
+            "my_project_v2 MyProjectV2 MY-PROJECT.V2 my-project.v2"
+        );
+    }
+
+    #[test]
+    fn test_snake_and_pascal_case() {
+        assert_eq!(snake_case("test-project"), "test_project");
+        assert_eq!(snake_case("myProjectName"), "my_project_name");
+        assert_eq!(snake_case("acme.networking.http"), "acme_networking_http");
+        assert_eq!(snake_case("HTTPServer"), "httpserver");
+        assert_eq!(snake_case(""), "");
+        assert_eq!(pascal_case("test_project"), "TestProject");
+        assert_eq!(pascal_case("my-project"), "MyProject");
+        assert_eq!(pascal_case("acme.networking"), "AcmeNetworking");
+    }
+
+    #[test]
+    fn test_render_sphinx_docs() {
+        let renderer = TemplateRenderer::new();
+        let mut data = create_test_data();
+
+        let cmake = renderer.render_to_string("CMakeLists.txt", &data).unwrap();
+        assert!(!cmake.contains("docs.cmake"));
+
+        data.doc_engine = "sphinx".to_string();
+        let cmake = renderer.render_to_string("CMakeLists.txt", &data).unwrap();
+        assert!(cmake.contains("include(cmake/docs.cmake)"));
+
+        let conf = renderer.render_to_string("docs-conf.py", &data).unwrap();
+        assert!(conf.contains(r#"project = "test-project""#));
+        assert!(conf.contains(r#"extensions = ["breathe"]"#));
+        assert!(conf.contains(r#"breathe_default_project = "test-project""#));
+
+        let index = renderer.render_to_string("docs-index.rst", &data).unwrap();
+        assert!(index.starts_with("test-project\n============\n"));
+
+        let docs_cmake = renderer.render_to_string("docs.cmake", &data).unwrap();
+        assert!(docs_cmake.contains(
+            "add_custom_target(test-project_doxygen\n  COMMAND ${DOXYGEN_EXECUTABLE} docs/Doxyfile"
+        ));
+        assert!(docs_cmake.contains("add_custom_target(docs"));
+        assert!(docs_cmake.contains("DEPENDS test-project_doxygen"));
+
+        data.features.sphinx = true;
+        let doxyfile = renderer.render_to_string("docs-Doxyfile", &data).unwrap();
+        assert!(doxyfile.contains("GENERATE_XML           = YES"));
+        assert!(doxyfile.contains("GENERATE_HTML          = NO"));
+        assert!(doxyfile.contains("OUTPUT_DIRECTORY       = docs/_build/doxygen\n"));
+
+        let requirements = renderer
+            .render_to_string("docs-requirements.txt", &data)
+            .unwrap();
+        assert!(requirements.contains("sphinx>=7\nbreathe>=4.35\n"));
+    }
+
+    #[test]
+    fn test_render_doxygen_docs() {
+        let renderer = TemplateRenderer::new();
+        let mut data = create_test_data();
+        data.description = r#"A "fast" parser"#.to_string();
+
+        let cmake = renderer.render_to_string("CMakeLists.txt", &data).unwrap();
+        assert!(!cmake.contains("BUILD_DOCS"));
+
+        data.doc_engine = "doxygen".to_string();
+        let cmake = renderer.render_to_string("CMakeLists.txt", &data).unwrap();
+        assert!(cmake.contains(r#"option(BUILD_DOCS "Add the docs target that runs Doxygen" ON)"#));
+        assert!(cmake.contains("if(BUILD_DOCS)\n  add_subdirectory(docs)\nendif()"));
+
+        let doxyfile = renderer.render_to_string("docs-Doxyfile", &data).unwrap();
+        assert!(doxyfile.contains(r#"PROJECT_BRIEF          = "A \"fast\" parser""#));
+        assert!(doxyfile.contains("PROJECT_NUMBER         = 0.1.0"));
+        assert!(doxyfile.contains("INPUT                  = src\n"));
+        assert!(doxyfile.contains("EXTRACT_ALL            = YES"));
+
+        data.is_library = true;
+        let doxyfile = renderer.render_to_string("docs-Doxyfile", &data).unwrap();
+        assert!(doxyfile.contains("INPUT                  = include src\n"));
+        assert!(doxyfile.contains("EXTRACT_ALL            = NO"));
+
+        let docs_cmake = renderer
+            .render_to_string("docs-CMakeLists.txt", &data)
+            .unwrap();
+        assert!(docs_cmake.contains("find_package(Doxygen)\n"));
+        assert!(docs_cmake.contains("add_custom_target(docs"));
+    }
+
+    #[test]
+    fn test_render_examples() {
+        let renderer = TemplateRenderer::new();
+        let mut data = create_test_data();
+
+        let cmake = renderer.render_to_string("example.cmake", &data).unwrap();
+        assert!(cmake.contains("add_executable(${PROJECT_NAME}_example example.cpp)"));
+
+        data.example_names = vec!["example_01".to_string(), "example_02".to_string()];
+        let cmake = renderer.render_to_string("example.cmake", &data).unwrap();
+        for name in &data.example_names {
+            assert!(cmake.contains(&format!(
+                "add_executable(${{PROJECT_NAME}}_{name} {name}.cpp)"
+            )));
+            assert!(cmake.contains(&format!(
+                "target_link_libraries(${{PROJECT_NAME}}_{name} PRIVATE ${{PROJECT_NAME}})"
+            )));
+        }
+
+        data.is_library = true;
+        let makefile = renderer.render_to_string("Makefile", &data).unwrap();
+        assert!(makefile.contains("EXAMPLE_NAMES = example_01 example_02\n"));
+        let meson = renderer.render_to_string("meson.build", &data).unwrap();
+        assert!(meson.contains("'examples/example_02.cpp'"));
+    }
+
+    #[test]
+    fn test_render_meson_tests() {
+        let renderer = TemplateRenderer::new();
+        let mut data = create_test_data();
+        data.build_system = "meson".to_string();
+        data.is_meson = true;
+
+        let meson = renderer.render_to_string("meson.build", &data).unwrap();
+        assert!(meson.ends_with("subdir('tests')\n"));
+
+        let tests = renderer.render_to_string("tests.meson", &data).unwrap();
+        assert!(tests.contains("test_dep = dependency('doctest')"));
+        assert!(tests.contains("include_directories: inc,\n  dependencies: test_dep,"));
+        assert!(tests.contains("test('test-project_tests', test_exe)"));
+
+        data.test_runner = "valgrind".to_string();
+        let tests = renderer.render_to_string("tests.meson", &data).unwrap();
+        assert!(tests.contains("test('test-project_tests', valgrind,"));
+
+        data.enable_tests = false;
+        let meson = renderer.render_to_string("meson.build", &data).unwrap();
+        assert!(!meson.contains("subdir('tests')"));
+    }
+
+    #[test]
+    fn test_render_snippets() {
+        let renderer = TemplateRenderer::new();
+        let mut data = create_test_data();
+
+        let logger = renderer.render_to_string("snippet-logger", &data).unwrap();
+        assert!(logger.contains("namespace test_project {\nnamespace snippets {"));
+        assert!(logger.contains("explicit Logger(std::ostream& out = std::clog"));
+        assert!(logger.ends_with("} // namespace snippets\n} // namespace test_project\n"));
+
+        let readme = renderer.render_to_string("README.md", &data).unwrap();
+        assert!(!readme.contains("## Included Components"));
+
+        data.snippets = vec!["logger".to_string()];
+        let readme = renderer.render_to_string("README.md", &data).unwrap();
+        assert!(readme.contains("- [`logger.hpp`](include/snippets/logger.hpp)"));
+    }
+
+    #[test]
+    fn test_render_readme_workflow() {
+        let renderer = TemplateRenderer::new();
+        let mut data = create_test_data();
+
+        let readme = renderer.render_to_string("README.md", &data).unwrap();
+        assert!(readme.contains("```bash\ncmake -S . -B build\ncmake --build build\n```"));
+        assert!(readme.contains("## Testing\n\n```bash\nctest --test-dir build\n```"));
+        assert!(!readme.contains("## Code Quality"));
+
+        data.enable_cppcheck = true;
+        data.build_system = "make".to_string();
+        data.test_steps = vec!["make test".to_string()];
+        let readme = renderer.render_to_string("README.md", &data).unwrap();
+        assert!(readme.contains("- cppcheck: `cppcheck --enable=warning,style,performance"));
+        assert!(!readme.contains("clang-tidy"));
+
+        let makefile = renderer.render_to_string("Makefile", &data).unwrap();
+        assert!(makefile.contains("test: $(TEST_BINARY)\n\t./$(TEST_BINARY)\n"));
+        assert!(makefile.contains(".PHONY: all clean test\n"));
+
+        data.enable_tests = false;
+        let makefile = renderer.render_to_string("Makefile", &data).unwrap();
+        assert!(!makefile.contains("TEST_BINARY"));
+    }
+
+    #[test]
+    fn test_render_mingw_makefile() {
+        let renderer = TemplateRenderer::new();
+        let mut data = create_test_data();
+        data.build_system = "make".to_string();
+        data.is_library = true;
+        data.mingw = true;
+
+        let makefile = renderer.render_to_string("Makefile", &data).unwrap();
+        assert!(makefile.starts_with("# Cross-compile for 64-bit Windows with MinGW-w64\n"));
+        assert!(makefile.contains("CXX = x86_64-w64-mingw32-g++\nAR = x86_64-w64-mingw32-ar\n"));
+        assert!(makefile.contains("LDFLAGS = -static -static-libgcc -static-libstdc++\n"));
+        assert!(makefile.contains("EXAMPLES = $(EXAMPLE_NAMES:%=%.exe)\n"));
+        assert!(makefile.contains("$(EXAMPLES): %.exe: examples/%.o $(LIB_NAME)\n"));
+        assert!(makefile.contains("TEST_BINARY = tests/main_test.exe\n"));
+        assert!(makefile.contains("\twine $(TEST_BINARY)\n"));
+
+        data.features.make = true;
+        let gitignore = renderer.render_to_string("gitignore", &data).unwrap();
+        assert!(gitignore.contains("/example.exe\n"));
+    }
+
+    #[test]
+    fn test_render_readme_badges() {
+        let renderer = TemplateRenderer::new();
+        let mut data = create_test_data();
+        data.license = "Apache-2.0".to_string();
+        data.version = "1.0.0-rc_1".to_string();
+
+        let readme = renderer.render_to_string("README.md", &data).unwrap();
+        assert!(readme.contains(
+            "[![License: Apache-2.0](https://img.shields.io/badge/license-Apache--2.0-blue.svg)](LICENSE)\n"
+        ));
+        assert!(readme.contains("https://img.shields.io/badge/C%2B%2B-17-blue.svg"));
+        assert!(readme.contains("https://img.shields.io/badge/version-1.0.0--rc__1-blue.svg"));
+        assert!(!readme.contains("/v/release/"));
+
+        // CI is configured, but without a repository there is nothing to link
+        data.ci = "github".to_string();
+        let readme = renderer.render_to_string("README.md", &data).unwrap();
+        assert!(!readme.contains("[![CI]"));
+
+        data.repository = Some(TemplateRepository {
+            provider: "github".to_string(),
+            slug: "me/test-project".to_string(),
+            url: "https://github.com/me/test-project".to_string(),
+        });
+        let readme = renderer.render_to_string("README.md", &data).unwrap();
+        assert!(readme.contains(
+            "[![CI](https://github.com/me/test-project/actions/workflows/ci.yml/badge.svg)](https://github.com/me/test-project/actions/workflows/ci.yml)\n"
+        ));
+        assert!(readme.contains(
+            "[![Release](https://img.shields.io/github/v/release/me/test-project)](https://github.com/me/test-project/releases)\n"
+        ));
+        assert!(!readme.contains("badge/version-"));
+
+        data.ci = String::new();
+        let readme = renderer.render_to_string("README.md", &data).unwrap();
+        assert!(!readme.contains("[![CI]"));
+        assert!(readme.contains("/v/release/"));
+    }
+
+    #[test]
+    fn test_render_contributing() {
+        let renderer = TemplateRenderer::new();
+        let mut data = create_test_data();
+
+        let contributing = renderer.render_to_string("CONTRIBUTING.md", &data).unwrap();
+        assert!(contributing.contains("```bash\ncmake -S . -B build\ncmake --build build\n```"));
+        assert!(contributing.contains("The tests use doctest"));
+        assert!(contributing.contains("ctest --test-dir build"));
+        assert!(!contributing.contains("clang-format"));
+
+        data.enable_tests = false;
+        data.test_steps = Vec::new();
+        data.enable_clang_format = true;
+        let contributing = renderer.render_to_string("CONTRIBUTING.md", &data).unwrap();
+        assert!(!contributing.contains("ctest"));
+        assert!(!contributing.contains("## Running the Tests"));
+        assert!(contributing.contains("- clang-format\n"));
+        assert!(contributing.contains("- clang-format (`.clang-format`): `find src include"));
+    }
+
+    #[test]
+    fn test_render_code_of_conduct_contact() {
+        let renderer = TemplateRenderer::new();
+        let mut data = create_test_data();
+
+        let conduct = renderer
+            .render_to_string("CODE_OF_CONDUCT.md", &data)
+            .unwrap();
+        assert!(conduct.contains("enforcement at\n[INSERT CONTACT METHOD]."));
+
+        data.author_email = "test@example.com".to_string();
+        let conduct = renderer
+            .render_to_string("CODE_OF_CONDUCT.md", &data)
+            .unwrap();
+        assert!(conduct.contains("enforcement at\ntest@example.com."));
+    }
+
+    #[test]
+    fn test_render_gitignore_follows_features() {
+        let renderer = TemplateRenderer::new();
+        let mut data = create_test_data();
+
+        data.features.cmake = true;
+        let gitignore = renderer.render_to_string("gitignore", &data).unwrap();
+        assert!(gitignore.contains("build*/\n"));
+        assert!(gitignore.contains("CMakeCache.txt"));
+        assert!(!gitignore.contains(".idea"));
+        assert!(!gitignore.contains("vcpkg_installed/"));
+        assert!(!gitignore.contains(".cache/"));
+
+        data.features.vcpkg = true;
+        data.features.clion = true;
+        data.features.clangd = true;
+        let gitignore = renderer.render_to_string("gitignore", &data).unwrap();
+        assert!(gitignore.contains("vcpkg_installed/\nCMakeUserPresets.json\n"));
+        assert!(gitignore.contains(".idea/*\n!.idea/cmake.xml\n!.idea/runConfigurations/\n"));
+        assert!(gitignore.contains(".cache/"));
+
+        let mut data = create_test_data();
+        data.features.make = true;
+        let gitignore = renderer.render_to_string("gitignore", &data).unwrap();
+        assert!(gitignore.contains("\n/test-project\n"));
+        assert!(!gitignore.contains("CMakeCache.txt"));
+
+        data.is_library = true;
+        let gitignore = renderer.render_to_string("gitignore", &data).unwrap();
+        assert!(gitignore.contains("\n/libtest-project.a\n/example\n"));
+    }
+
+    #[test]
+    fn test_render_gitattributes() {
+        let renderer = TemplateRenderer::new();
+        let mut data = create_test_data();
+
+        let attributes = renderer.render_to_string("gitattributes", &data).unwrap();
+        assert!(attributes.starts_with("# Normalize line endings of text files\n* text=auto\n"));
+        assert!(attributes.contains("CMakeLists.txt text eol=lf"));
+        assert!(attributes.contains("*.bat text eol=crlf"));
+        assert!(!attributes.contains("linguist-vendored"));
+        assert!(!attributes.contains("export-ignore"));
+
+        data.build_system = "meson".to_string();
+        data.is_library = true;
+        data.enable_tests = true;
+        let attributes = renderer.render_to_string("gitattributes", &data).unwrap();
+        assert!(attributes.contains("subprojects/*/ linguist-vendored"));
+        assert!(attributes.contains("tests/ export-ignore"));
+    }
+
+    #[test]
+    fn test_render_changelog() {
+        let renderer = TemplateRenderer::new();
+        let mut data = create_test_data();
+
+        let readme = renderer.render_to_string("README.md", &data).unwrap();
+        assert!(!readme.contains("## Changelog"));
+
+        let changelog = renderer.render_to_string("CHANGELOG.md", &data).unwrap();
+        assert!(changelog.contains("## [Unreleased]"));
+        assert!(changelog.contains(
+            "## [0.1.0] - 2024-01-01\n\n### Added\n\n- Initial project scaffold generated with cppup\n"
+        ));
+        assert!(!changelog.contains("/compare/"));
+
+        data.version = "2.3.4".to_string();
+        data.release_date = "2025-12-31".to_string();
+        data.repository = Some(TemplateRepository {
+            provider: "gitlab".to_string(),
+            slug: "group/test-project".to_string(),
+            url: "https://gitlab.com/group/test-project".to_string(),
+        });
+        let changelog = renderer.render_to_string("CHANGELOG.md", &data).unwrap();
+        assert!(changelog.contains("## [2.3.4] - 2025-12-31\n"));
+        assert!(changelog.ends_with(
+            "[Unreleased]: https://gitlab.com/group/test-project/-/compare/v2.3.4...HEAD\n\
+             [2.3.4]: https://gitlab.com/group/test-project/-/releases/v2.3.4\n"
+        ));
+
+        data.version = "0.1.0".to_string();
+        data.repository = None;
+
+        data.generate_changelog = true;
+        data.homepage = "https://github.com/acme/test-project".to_string();
+        data.changelog_url =
+            "https://github.com/acme/test-project/blob/main/CHANGELOG.md".to_string();
+        let changelog = renderer.render_to_string("CHANGELOG.md", &data).unwrap();
+        assert!(changelog
+            .contains("[Unreleased]: https://github.com/acme/test-project/compare/v0.1.0...HEAD"));
+        let readme = renderer.render_to_string("README.md", &data).unwrap();
+        assert!(readme.contains(
+            "[CHANGELOG.md](https://github.com/acme/test-project/blob/main/CHANGELOG.md)"
+        ));
+
+        let script = renderer
+            .render_to_string("update-changelog.sh", &data)
+            .unwrap();
+        assert!(script.starts_with("#!/usr/bin/env sh\n"));
+        assert!(script.contains("git log --oneline"));
+    }
+
+    #[test]
+    fn test_render_sanitizer_preset() {
+        let renderer = TemplateRenderer::new();
+        let mut data = create_test_data();
+
+        let cmake = renderer.render_to_string("CMakeLists.txt", &data).unwrap();
+        assert!(!cmake.contains("enable_sanitizer_preset"));
+
+        data.sanitizer_preset = "full".to_string();
+        data.sanitizer_flags = "memory,undefined".to_string();
+        let cmake = renderer.render_to_string("CMakeLists.txt", &data).unwrap();
+        assert!(cmake.contains("include(cmake/sanitizers.cmake)"));
+        assert!(cmake.contains("enable_sanitizer_preset(project_options)"));
+
+        let sanitizers = renderer
+            .render_to_string("sanitizers.cmake", &data)
+            .unwrap();
+        assert!(sanitizers.contains("-fsanitize=memory,undefined\n"));
+        assert!(sanitizers.contains("-fsanitize-memory-track-origins=2"));
+        assert!(sanitizers
+            .contains("target_link_options(${target} INTERFACE -fsanitize=memory,undefined)"));
+
+        data.sanitizer_preset = "thread".to_string();
+        data.sanitizer_flags = "thread".to_string();
+        let sanitizers = renderer
+            .render_to_string("sanitizers.cmake", &data)
+            .unwrap();
+        assert!(sanitizers.contains("-fsanitize=thread\n"));
+        assert!(!sanitizers.contains("memory"));
+    }
+
+    #[test]
+    fn test_render_quality_tool_conditionals() {
+        let renderer = TemplateRenderer::new();
+        let mut data = create_test_data();
+
+        let cmake = renderer.render_to_string("CMakeLists.txt", &data).unwrap();
+        assert!(!cmake.contains("include(static-analyzers)"));
+
+        // Only cppcheck: the analyzers module is still included
+        data.enable_cppcheck = true;
+        let cmake = renderer.render_to_string("CMakeLists.txt", &data).unwrap();
+        assert!(cmake.contains("include(static-analyzers)"));
+        assert!(cmake.contains("enable_cppcheck_target"));
+        assert!(!cmake.contains("enable_clang_tidy_target"));
+        assert!(!cmake.contains("enable_include_what_you_use_target"));
+
+        data.enable_iwyu = true;
+        let options = renderer.render_to_string("options.cmake", &data).unwrap();
+        assert!(options.contains("option(ENABLE_CPPCHECK"));
+        assert!(options.contains("option(ENABLE_INCLUDE_WHAT_YOU_USE"));
+        assert!(!options.contains("option(ENABLE_CLANG_TIDY"));
+    }
+
+    #[test]
+    fn test_render_readme_lists_quality_tools() {
+        let renderer = TemplateRenderer::new();
+        let mut data = create_test_data();
+
+        let readme = renderer.render_to_string("README.md", &data).unwrap();
+        assert!(!readme.contains("for code analysis"));
+
+        data.quality_tools = vec!["clang-tidy".to_string(), "cppcheck".to_string()];
+        let readme = renderer.render_to_string("README.md", &data).unwrap();
+        assert!(readme.contains("- clang-tidy (optional, for code analysis)\n"));
+        assert!(readme.contains("- cppcheck (optional, for code analysis)\n"));
+    }
+
+    #[test]
+    fn test_quality_flags_ignore_display_string() {
+        let renderer = TemplateRenderer::new();
+        let mut data = create_test_data();
+
+        // The joined string is for display only and no longer drives output
+        data.quality_config = "clang-tidy, cppcheck".to_string();
+        let options = renderer.render_to_string("options.cmake", &data).unwrap();
+        assert!(!options.contains("option(ENABLE_CLANG_TIDY"));
+
+        data.enable_clang_tidy = true;
+        let options = renderer.render_to_string("options.cmake", &data).unwrap();
+        assert!(options.contains("option(ENABLE_CLANG_TIDY"));
+    }
+
+    #[test]
+    fn test_register_partials_from_dir() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(temp_dir.path().join("copyright.hbs"), "// (c) {{author}}").unwrap();
+        fs::write(temp_dir.path().join("notes.txt"), "{{#if}}").unwrap();
+
+        let mut renderer = TemplateRenderer::new();
+        renderer
+            .register_partials_from_dir(temp_dir.path())
+            .unwrap();
+        renderer
+            .register_template("with_partial", "{{> copyright}} int x;")
+            .unwrap();
+
+        let output = renderer
+            .render_to_string("with_partial", &create_test_data())
+            .unwrap();
+        assert_eq!(output, "// (c) Test Author int x;");
+    }
+
+    #[test]
+    fn test_register_partials_from_dir_reports_malformed() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(temp_dir.path().join("a_broken.hbs"), "{{#if x}}").unwrap();
+        fs::write(temp_dir.path().join("b_broken.hbs"), "{{/each}}").unwrap();
+        fs::write(temp_dir.path().join("good.hbs"), "good").unwrap();
+
+        let mut renderer = TemplateRenderer::new();
+        let error = renderer
+            .register_partials_from_dir(temp_dir.path())
+            .unwrap_err();
+        assert!(format!("{:#}", error).contains("a_broken.hbs"));
+
+        // Valid partials are still registered
+        renderer
+            .register_template("uses_good", "{{> good}}")
+            .unwrap();
+        assert_eq!(
+            renderer
+                .render_to_string("uses_good", &create_test_data())
+                .unwrap(),
+            "good"
+        );
+    }
+
+    #[test]
+    fn test_register_partials_from_missing_dir() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut renderer = TemplateRenderer::new();
+        assert!(renderer
+            .register_partials_from_dir(&temp_dir.path().join("missing"))
+            .is_err());
+    }
+
+    #[test]
+    fn test_comparison_helpers() {
+        let mut handlebars = Handlebars::new();
+        handlebars.register_helper("gte", Box::new(gte_helper));
+        handlebars.register_helper("lt", Box::new(lt_helper));
+        handlebars
+            .register_template_string(
+                "test_compare",
+                "{{#if (gte a b)}}gte{{/if}}|{{#if (lt a b)}}lt{{/if}}",
+            )
+            .unwrap();
+
+        let render = |a: serde_json::Value, b: serde_json::Value| {
+            handlebars.render("test_compare", &serde_json::json!({ "a": a, "b": b }))
+        };
+        assert_eq!(render(20.into(), 17.into()).unwrap(), "gte|");
+        assert_eq!(render(17.into(), 17.into()).unwrap(), "gte|");
+        assert_eq!(render(11.into(), 17.into()).unwrap(), "|lt");
+        // Numeric strings compare as numbers, not lexically
+        assert_eq!(render("9".into(), "11".into()).unwrap(), "|lt");
+        assert!(render("latest".into(), 17.into()).is_err());
+    }
+
+    #[test]
+    fn test_render_main_cpp_gated_by_standard() {
+        let renderer = TemplateRenderer::new();
+        let mut data = create_test_data();
+
+        data.cpp_standard = "11".to_string();
+        data.cpp_standard_num = 11;
+        let cpp11 = renderer.render_to_string("main.cpp", &data).unwrap();
+
+        data.cpp_standard = "23".to_string();
+        data.cpp_standard_num = 23;
+        let cpp23 = renderer.render_to_string("main.cpp", &data).unwrap();
+
+        assert_ne!(cpp11, cpp23);
+        assert!(!cpp11.contains("string_view"));
+        assert!(cpp11.contains("Hello from test-project!"));
+        assert!(cpp23.contains("#include <string_view>"));
+        assert!(cpp23.contains("project_name = \"test-project\""));
+    }
+
+    #[test]
+    fn test_render_header_concepts_for_cpp20() {
+        let renderer = TemplateRenderer::new();
+        let mut data = create_test_data();
+
+        let cpp17 = renderer.render_to_string("header.hpp", &data).unwrap();
+        assert!(!cpp17.contains("concept"));
+
+        data.cpp_standard_num = 20;
+        let cpp20 = renderer.render_to_string("header.hpp", &data).unwrap();
+        assert!(cpp20.contains("#include <concepts>"));
+        assert!(cpp20.contains("concept Arithmetic"));
+        assert!(cpp20.contains("static constexpr T square(T value)"));
+    }
+
+    #[test]
+    fn test_eq_helper_inside_if() {
+        let renderer = TemplateRenderer::new();
+        let mut data = create_test_data();
+
+        for (license, expected) in [
+            ("MIT", "MIT License"),
+            ("Apache-2.0", "Apache License 2.0"),
+            ("GPL-3.0", "GNU General Public License v3.0"),
+            ("BSD-3-Clause", "BSD 3-Clause License"),
+            ("BSL-1.0", "Boost Software License 1.0"),
+        ] {
+            data.license = license.to_string();
+            let readme = renderer.render_to_string("README.md", &data).unwrap();
+            assert!(
+                readme.contains(&format!("licensed under the {} -", expected)),
+                "{}",
+                license
+            );
+        }
+    }
+
+    #[test]
+    fn test_render_tests_cmake_links_framework() {
+        let renderer = TemplateRenderer::new();
+        let mut data = create_test_data();
+
+        for (framework, package, target) in [
+            ("doctest", "find_package(doctest", "doctest::doctest"),
+            ("gtest", "find_package(GTest", "GTest::gtest_main"),
+            ("catch2", "find_package(Catch2", "Catch2::Catch2WithMain"),
+            ("boost", "find_package(Boost", "Boost::unit_test_framework"),
+        ] {
+            data.test_framework = framework.to_string();
+            let content = renderer.render_to_string("tests.cmake", &data).unwrap();
+            assert!(content.contains(package), "{}", framework);
+            assert!(content.contains(target), "{}", framework);
+            assert_eq!(content.matches("add_executable").count(), 1);
+        }
+    }
+
+    #[test]
+    fn test_render_tests_cmake_ctest_runner_with_conan() {
+        let renderer = TemplateRenderer::new();
+        let mut data = create_test_data();
+        data.test_runner = "ctest".to_string();
+        data.features.conan = true;
+
+        let content = renderer.render_to_string("tests.cmake", &data).unwrap();
+        assert!(content.contains("            --build-config $<CONFIG>\n"));
+        assert!(content.contains(
+            "            --build-options -DCMAKE_TOOLCHAIN_FILE=${CMAKE_TOOLCHAIN_FILE} -DCMAKE_BUILD_TYPE=$<CONFIG>\n"
+        ));
+        assert!(content.contains(
+            "    set(CTEST_WRAPPER_TESTS_DIR ${CMAKE_BINARY_DIR}/ctest-wrapper/tests/$<CONFIG>)\n"
+        ));
+        assert!(content.contains(
+            "            --test-command ${CTEST_WRAPPER_TESTS_DIR}/${PROJECT_NAME}_tests)"
+        ));
+        assert!(!content.contains("ctest-wrapper/bin"));
+    }
+
+    #[test]
+    fn test_render_vcpkg_json_escapes_user_strings() {
+        let renderer = TemplateRenderer::new();
+        let mut data = create_test_data();
+        data.description = r#"My "awesome" C++ lib \ with emoji 🚀"#.to_string();
+        data.author = "Jürgen Ünicode".to_string();
+
+        let content = renderer.render_to_string("vcpkg.json", &data).unwrap();
+        let json: serde_json::Value = serde_json::from_str(&content).unwrap();
+
+        assert_eq!(json["name"], "test-project");
+        assert_eq!(json["description"], data.description.as_str());
+        assert!(json.get("features").is_none());
+    }
+
+    #[test]
+    fn test_render_compile_definitions() {
+        let renderer = TemplateRenderer::new();
+        let mut data = create_test_data();
+
+        let flags = renderer
+            .render_to_string("compilation-flags.cmake", &data)
+            .unwrap();
+        assert!(!flags.contains("add_compile_definitions"));
+
+        data.compile_definitions = vec!["USE_SIMD".to_string(), "GREETING=\"hi\"".to_string()];
+        let flags = renderer
+            .render_to_string("compilation-flags.cmake", &data)
+            .unwrap();
+        assert!(flags
+            .ends_with("add_compile_definitions(\n  \"USE_SIMD\"\n  \"GREETING=\\\"hi\\\"\"\n)\n"));
+    }
+
+    #[test]
+    fn test_render_lto() {
+        let renderer = TemplateRenderer::new();
+        let mut data = create_test_data();
+
+        let flags = renderer
+            .render_to_string("compilation-flags.cmake", &data)
+            .unwrap();
+        assert!(!flags.contains("CheckIPOSupported"));
+
+        data.enable_lto = true;
+        let flags = renderer
+            .render_to_string("compilation-flags.cmake", &data)
+            .unwrap();
+        assert!(flags.contains(
+            "check_ipo_supported(RESULT ipo_supported OUTPUT ipo_error)\nif(ipo_supported)\n  set(CMAKE_INTERPROCEDURAL_OPTIMIZATION_RELEASE ON)"
+        ));
+
+        let makefile = renderer.render_to_string("Makefile", &data).unwrap();
+        assert!(makefile.contains("CXXFLAGS += -O2 -flto\n"));
+        assert!(!makefile.contains("gcc-ar"));
+        data.is_library = true;
+        let makefile = renderer.render_to_string("Makefile", &data).unwrap();
+        assert!(makefile.contains("AR = gcc-ar\n"));
+
+        let meson = renderer.render_to_string("meson.build", &data).unwrap();
+        assert!(meson.contains("    'b_lto=true',\n"));
+    }
+
+    #[test]
+    fn test_render_codecov() {
+        let renderer = TemplateRenderer::new();
+        let mut data = create_test_data();
+        data.coverage_service = "codecov".to_string();
+
+        let codecov = renderer.render_to_string("codecov.yml", &data).unwrap();
+        assert!(codecov.contains("        target: auto\n        threshold: 1%\n"));
+        assert!(codecov.contains("ignore:\n  - \"tests\"\n"));
+
+        data.is_library = true;
+        let codecov = renderer.render_to_string("codecov.yml", &data).unwrap();
+        assert!(codecov.contains("ignore:\n  - \"examples\"\n  - \"tests\"\n"));
+    }
+
+    #[test]
+    fn test_render_dependabot() {
+        let renderer = TemplateRenderer::new();
+        let mut data = create_test_data();
+
+        let dependabot = renderer.render_to_string("dependabot.yml", &data).unwrap();
+        assert!(dependabot
+            .contains("version: 2\nupdates:\n  - package-ecosystem: \"github-actions\"\n"));
+        assert!(!dependabot.contains("pip"));
+
+        data.features.conan = true;
+        let dependabot = renderer.render_to_string("dependabot.yml", &data).unwrap();
+        assert!(dependabot.contains("  - package-ecosystem: \"pip\"\n    directory: \"/\"\n"));
+    }
+
+    #[test]
+    fn test_render_vcpkg_configuration() {
+        let renderer = TemplateRenderer::new();
+        let mut data = create_test_data();
+        data.vcpkg_registry_url = "https://github.com/org/vcpkg-registry".to_string();
+
+        let content = renderer
+            .render_to_string("vcpkg-configuration.json", &data)
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_str(&content).unwrap();
+        assert_eq!(
+            json["default-registry"],
+            serde_json::json!({
+                "kind": "git",
+                "repository": "https://github.com/org/vcpkg-registry"
+            })
+        );
+
+        data.vcpkg_baseline = "3f2c6e8d1a4b5c6d7e8f9a0b1c2d3e4f5a6b7c8d".to_string();
+        let content = renderer
+            .render_to_string("vcpkg-configuration.json", &data)
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_str(&content).unwrap();
+        assert_eq!(
+            json["default-registry"]["baseline"],
+            data.vcpkg_baseline.as_str()
+        );
+    }
+
+    #[test]
+    fn test_render_vcpkg_json_features() {
+        let renderer = TemplateRenderer::new();
+        let mut data = create_test_data();
+        data.vcpkg_features = serde_json::json!({
+            "compression": {
+                "description": "Compression support",
+                "dependencies": ["zlib", "bzip2"]
+            },
+            "testing": { "description": "Build the tests", "dependencies": ["doctest"] }
+        });
+
+        let content = renderer.render_to_string("vcpkg.json", &data).unwrap();
+        let json: serde_json::Value = serde_json::from_str(&content).unwrap();
+
+        assert_eq!(json["features"], data.vcpkg_features);
+        assert!(content.contains("  \"features\": {\n    \"compression\": {\n"));
+        assert!(content.ends_with("  }\n}\n"));
+    }
+
+    #[test]
+    fn test_render_vcpkg_json_valid_for_all_test_frameworks() {
+        let renderer = TemplateRenderer::new();
+        let mut data = create_test_data();
+
+        for framework in ["doctest", "gtest", "catch2", "boost", "none"] {
+            data.test_framework = framework.to_string();
+            let content = renderer.render_to_string("vcpkg.json", &data).unwrap();
+            assert!(
+                serde_json::from_str::<serde_json::Value>(&content).is_ok(),
+                "vcpkg.json is not valid JSON for {}",
+                framework
+            );
+        }
+    }
+
+    #[test]
+    fn test_user_template_overrides_embedded() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::create_dir_all(temp_dir.path().join("cmake")).unwrap();
+        fs::write(
+            temp_dir.path().join("cmake/CMakeLists.txt.hbs"),
+            "# custom {{name}}",
+        )
+        .unwrap();
+
+        let renderer = TemplateRenderer::with_user_templates(temp_dir.path()).unwrap();
+        assert_eq!(
+            renderer.overridden_templates(),
+            ["cmake/CMakeLists.txt.hbs"]
+        );
+
+        let data = create_test_data();
+        assert_eq!(
+            renderer.render_to_string("CMakeLists.txt", &data).unwrap(),
+            "# custom test-project"
+        );
+        assert!(renderer
+            .render_to_string("main.cpp", &data)
+            .unwrap()
+            .contains("#include"));
+    }
+
+    #[test]
+    fn test_invalid_user_template_names_file() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(temp_dir.path().join("main.cpp.hbs"), "{{#if name}}").unwrap();
+
+        let error = TemplateRenderer::with_user_templates(temp_dir.path())
+            .err()
+            .unwrap();
+        assert!(format!("{:#}", error).contains("main.cpp.hbs"));
+    }
+
+    #[test]
+    fn test_embedded_templates_have_no_overrides() {
+        assert!(TemplateRenderer::new().overridden_templates().is_empty());
+    }
+
+    #[test]
+    fn test_load_versioned_current_uses_embedded() {
+        let renderer = TemplateRenderer::load_versioned(TEMPLATES_VERSION, None).unwrap();
+        assert_eq!(renderer.version(), TEMPLATES_VERSION);
+        assert!(renderer.overridden_templates().is_empty());
+    }
+
+    #[test]
+    fn test_load_versioned_from_dir() {
+        let temp_dir = TempDir::new().unwrap();
+        let version_dir = temp_dir.path().join("0.9");
+        fs::create_dir_all(&version_dir).unwrap();
+        fs::write(version_dir.join("main.cpp.hbs"), "// old {{name}}\n").unwrap();
+
+        let renderer = TemplateRenderer::load_versioned("0.9", Some(temp_dir.path())).unwrap();
+        assert_eq!(renderer.version(), "0.9");
+        let output = renderer
+            .render_to_string("main.cpp", &create_test_data())
+            .unwrap();
+        assert_eq!(output, "// old test-project\n");
+    }
+
+    #[test]
+    fn test_load_versioned_missing_or_invalid() {
+        let temp_dir = TempDir::new().unwrap();
+
+        let error = TemplateRenderer::load_versioned("0.9", Some(temp_dir.path()))
+            .err()
+            .unwrap();
+        assert!(error.to_string().contains("cppup templates download 0.9"));
+
+        let error = TemplateRenderer::load_versioned("../0.9", Some(temp_dir.path()))
+            .err()
+            .unwrap();
+        assert_eq!(error.to_string(), "Invalid template version: ../0.9");
+    }
 }
@@ -0,0 +1,126 @@
+//! Validation of user input that does not need a full project
+//! configuration.
+//!
+//! Errors are plain messages so callers such as editor plugins or
+//! language bindings can show them as-is.
+
+/// Checks that `name` can be used as a project name.
+///
+/// A valid name is at most 100 bytes long, does not start with a digit,
+/// and contains only alphanumeric characters, `-`, `_` and `.`. Dots
+/// separate namespace components, so they cannot lead, trail or repeat.
+///
+/// # Examples
+///
+/// ```
+/// use cppup::validate_project_name;
+///
+/// assert!(validate_project_name("acme.networking").is_ok());
+/// assert_eq!(
+///     validate_project_name("2fast").unwrap_err(),
+///     "Project name cannot start with a number"
+/// );
+/// ```
+///
+/// # Errors
+///
+/// Returns a message describing the first rule the name breaks.
+pub fn validate_project_name(name: &str) -> Result<(), String> {
+    if name.is_empty() {
+        return Err("Project name cannot be empty".to_string());
+    }
+    if name.len() > 100 {
+        return Err("Project name is too long".to_string());
+    }
+    if name.starts_with(|c: char| c.is_numeric()) {
+        return Err("Project name cannot start with a number".to_string());
+    }
+    if !name
+        .chars()
+        .all(|c| c.is_alphanumeric() || c == '-' || c == '_' || c == '.')
+    {
+        return Err(
+            "Project name can only contain alphanumeric characters, '-', '_' and '.'".to_string(),
+        );
+    }
+    if name.starts_with('.') || name.ends_with('.') {
+        return Err("Project name cannot start or end with '.'".to_string());
+    }
+    if name.contains("..") {
+        return Err("Project name cannot contain consecutive dots".to_string());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_project_name_valid() {
+        assert!(validate_project_name("my-project").is_ok());
+        assert!(validate_project_name("my_project").is_ok());
+        assert!(validate_project_name("MyProject123").is_ok());
+        assert!(validate_project_name("a").is_ok());
+    }
+
+    #[test]
+    fn test_validate_project_name_empty() {
+        let result = validate_project_name("");
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Project name cannot be empty"
+        );
+    }
+
+    #[test]
+    fn test_validate_project_name_starts_with_number() {
+        let result = validate_project_name("123project");
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Project name cannot start with a number"
+        );
+    }
+
+    #[test]
+    fn test_validate_project_name_invalid_characters() {
+        let result = validate_project_name("my project!");
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Project name can only contain alphanumeric characters, '-', '_' and '.'"
+        );
+    }
+
+    #[test]
+    fn test_validate_project_name_dot_notation() {
+        assert!(validate_project_name("acme.networking.http").is_ok());
+        assert!(validate_project_name("acme.net-utils").is_ok());
+
+        assert_eq!(
+            validate_project_name(".acme").unwrap_err().to_string(),
+            "Project name cannot start or end with '.'"
+        );
+        assert!(validate_project_name("acme.").is_err());
+        assert_eq!(
+            validate_project_name("acme..http").unwrap_err().to_string(),
+            "Project name cannot contain consecutive dots"
+        );
+    }
+
+    #[test]
+    fn test_validate_project_name_too_long() {
+        let long_name = "a".repeat(101);
+        let result = validate_project_name(&long_name);
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err().to_string(), "Project name is too long");
+    }
+
+    #[test]
+    fn test_validate_project_name_exactly_100_chars() {
+        let name = "a".repeat(100);
+        assert!(validate_project_name(&name).is_ok());
+    }
+}
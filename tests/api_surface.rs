@@ -0,0 +1,63 @@
+//! Compile-time check that the public API is reachable from the crate root.
+//! A type dropped from the re-exports in `src/lib.rs` fails to compile here.
+
+use cppup::{
+    BuildReport, BuildSystem, CiProvider, ClangTidyCategory, CmakeGenerator, CodeFormatter,
+    CoverageService, CppExtension, CppStandard, DocEngine, HppExtension, IdeConfig, License,
+    LineEndings, PackageManager, ProjectBuilder, ProjectConfig, ProjectConfigBuilder, ProjectType,
+    ProjectValidator, QualityConfig, SanitizerPreset, TargetPlatform, TemplateRenderer,
+    TestFramework, TestRunner,
+};
+use std::any::type_name;
+
+#[test]
+fn test_configuration_types_are_exported_from_crate_root() {
+    let names = [
+        type_name::<BuildSystem>(),
+        type_name::<CiProvider>(),
+        type_name::<ClangTidyCategory>(),
+        type_name::<CmakeGenerator>(),
+        type_name::<CodeFormatter>(),
+        type_name::<CoverageService>(),
+        type_name::<CppExtension>(),
+        type_name::<CppStandard>(),
+        type_name::<DocEngine>(),
+        type_name::<HppExtension>(),
+        type_name::<IdeConfig>(),
+        type_name::<License>(),
+        type_name::<LineEndings>(),
+        type_name::<PackageManager>(),
+        type_name::<ProjectType>(),
+        type_name::<QualityConfig>(),
+        type_name::<SanitizerPreset>(),
+        type_name::<TargetPlatform>(),
+        type_name::<TestFramework>(),
+        type_name::<TestRunner>(),
+    ];
+    for name in names {
+        assert!(name.starts_with("cppup::project::"), "{}", name);
+    }
+}
+
+#[test]
+fn test_entry_points_are_exported_from_crate_root() {
+    let _ = [
+        type_name::<BuildReport>(),
+        type_name::<ProjectBuilder>(),
+        type_name::<ProjectConfig>(),
+        type_name::<ProjectConfigBuilder>(),
+        type_name::<ProjectValidator>(),
+        type_name::<TemplateRenderer>(),
+    ];
+    let _: fn(ProjectConfig) -> anyhow::Result<()> = cppup::generate_project;
+    let _: fn(&str) -> Result<(), String> = cppup::validate_project_name;
+}
+
+#[test]
+fn test_reexported_types_match_project_module() {
+    let from_root: BuildSystem = "meson".parse().unwrap();
+    let from_module: cppup::project::BuildSystem = from_root.clone();
+    assert_eq!(from_root, from_module);
+    assert_eq!(ProjectType::Library, cppup::project::ProjectType::Library);
+    assert_eq!(CppStandard::Cpp20.to_string(), "20");
+}
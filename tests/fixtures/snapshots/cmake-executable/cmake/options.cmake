@@ -0,0 +1,8 @@
+option(WARNINGS_AS_ERRORS "Treat Warnings As Errors" ON)
+option(ENABLE_SANITIZER_ADDRESS "Enable address sanitizer" OFF)
+option(ENABLE_SANITIZER_LEAK "Enable leak sanitizer" OFF)
+option(ENABLE_SANITIZER_UNDEFINED "Enable undefined sanitizer" OFF)
+option(ENABLE_SANITIZER_THREAD "Enable thread sanitizer" OFF)
+option(ENABLE_SANITIZER_MEMORY "Enable memory sanitizer" OFF)
+option(ENABLE_PROFILE "Enable profiling" OFF)
+option(ENABLE_CACHE "Enable ccache" OFF)
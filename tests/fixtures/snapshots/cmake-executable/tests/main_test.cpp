@@ -0,0 +1,16 @@
+#define DOCTEST_CONFIG_IMPLEMENT_WITH_MAIN
+#include "doctest.h"
+
+TEST_CASE("Basic tests") {
+    SUBCASE("Simple arithmetic") {
+        CHECK(1 + 1 == 2);
+        CHECK(2 * 3 == 6);
+        CHECK(10 - 5 == 5);
+    }
+    
+    SUBCASE("Boolean logic") {
+        CHECK(true);
+        CHECK_FALSE(false);
+        CHECK(42 > 0);
+    }
+}
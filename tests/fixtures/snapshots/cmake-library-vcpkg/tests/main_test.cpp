@@ -0,0 +1,16 @@
+#include <gtest/gtest.h>
+#include "snapshot.hpp"
+
+TEST(snapshotTest, Calculator) {
+    using namespace snapshot;
+    
+    EXPECT_EQ(Calculator::add(2, 2), 4);
+    EXPECT_EQ(Calculator::subtract(5, 3), 2);
+    EXPECT_EQ(Calculator::multiply(2, 3), 6);
+    EXPECT_DOUBLE_EQ(Calculator::divide(6.0, 2.0), 3.0);
+}
+
+int main(int argc, char** argv) {
+    testing::InitGoogleTest(&argc, argv);
+    return RUN_ALL_TESTS();
+}
\ No newline at end of file
@@ -0,0 +1,9 @@
+#include <iostream>
+#include <string_view>
+
+constexpr std::string_view project_name = "snapshot";
+
+int main() {
+    std::cout << "Hello from " << project_name << "!\n";
+    return 0;
+}
\ No newline at end of file
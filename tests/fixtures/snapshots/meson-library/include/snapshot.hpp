@@ -0,0 +1,43 @@
+#pragma once
+
+#include <concepts>
+
+namespace snapshot {
+
+template <typename T>
+concept Arithmetic = std::integral<T> || std::floating_point<T>;
+
+/// @brief Basic arithmetic on numbers.
+class Calculator {
+public:
+    /// @brief Adds two integers.
+    /// @param a First operand.
+    /// @param b Second operand.
+    /// @return The sum of @p a and @p b.
+    static int add(int a, int b);
+    /// @brief Subtracts one integer from another.
+    /// @param a Value to subtract from.
+    /// @param b Value to subtract.
+    /// @return The difference @p a - @p b.
+    static int subtract(int a, int b);
+    /// @brief Multiplies two integers.
+    /// @param a First factor.
+    /// @param b Second factor.
+    /// @return The product of @p a and @p b.
+    static int multiply(int a, int b);
+    /// @brief Divides one number by another.
+    /// @param a Dividend.
+    /// @param b Divisor.
+    /// @return The quotient @p a / @p b.
+    static double divide(double a, double b);
+
+    /// @brief Squares a number.
+    /// @param value Number to square.
+    /// @return @p value multiplied by itself.
+    template <Arithmetic T>
+    static constexpr T square(T value) {
+        return value * value;
+    }
+};
+
+} // namespace snapshot
\ No newline at end of file
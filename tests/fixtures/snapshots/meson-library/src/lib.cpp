@@ -0,0 +1,21 @@
+#include "snapshot.hpp"
+
+namespace snapshot {
+
+int Calculator::add(int a, int b) {
+    return a + b;
+}
+
+int Calculator::subtract(int a, int b) {
+    return a - b;
+}
+
+int Calculator::multiply(int a, int b) {
+    return a * b;
+}
+
+double Calculator::divide(double a, double b) {
+    return a / b;
+}
+
+} // namespace snapshot
\ No newline at end of file
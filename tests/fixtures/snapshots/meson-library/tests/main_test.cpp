@@ -0,0 +1,17 @@
+#include <catch2/catch_test_macros.hpp>
+
+#include "snapshot.hpp"
+
+TEST_CASE("Calculator operations", "[calculator]") {
+    using namespace snapshot;
+    
+    SECTION("Addition") {
+        REQUIRE(Calculator::add(2, 2) == 4);
+        REQUIRE(Calculator::add(-1, 1) == 0);
+    }
+    
+    SECTION("Subtraction") {
+        REQUIRE(Calculator::subtract(5, 3) == 2);
+        REQUIRE(Calculator::subtract(1, 1) == 0);
+    }
+}
@@ -1,6 +1,8 @@
 use assert_cmd::Command;
+use cppup::project::SystemRunner;
 use predicates::prelude::*;
 use std::fs;
+use std::path::PathBuf;
 use tempfile::TempDir;
 
 // ============================================================================
@@ -11,9 +13,12 @@ use tempfile::TempDir;
 fn test_help_command() {
     let mut cmd = Command::cargo_bin("cppup").unwrap();
     cmd.arg("--help");
-    cmd.assert().success().stdout(predicate::str::contains(
-        "interactive C++ project generator",
-    ));
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "interactive C++ project generator",
+        ))
+        .stdout(predicate::str::contains("Exit codes:"));
 }
 
 #[test]
@@ -66,6 +71,12 @@ fn test_non_interactive_project_creation() {
     assert!(project_path.join("CMakeLists.txt").exists());
     assert!(project_path.join("README.md").exists());
     assert!(project_path.join("LICENSE").exists());
+
+    let metadata = fs::read_to_string(project_path.join(".cppup.json")).unwrap();
+    let metadata: serde_json::Value = serde_json::from_str(&metadata).unwrap();
+    assert_eq!(metadata["schema_version"], 1);
+    assert_eq!(metadata["cppup_version"], env!("CARGO_PKG_VERSION"));
+    assert_eq!(metadata["template_version"], "1");
 }
 
 #[test]
@@ -107,29 +118,21 @@ fn test_library_project_creation() {
     assert!(project_path.join("include/test-lib.hpp").exists());
 }
 
-// ============================================================================
-// Build System Tests
-// ============================================================================
-
 #[test]
-fn test_make_build_system_executable() {
+fn test_library_generates_docs_dir() {
     let temp_dir = TempDir::new().unwrap();
-    let project_path = temp_dir.path().join("make-project");
+    let project_path = temp_dir.path().join("doc-lib");
 
     let mut cmd = Command::cargo_bin("cppup").unwrap();
     cmd.args([
         "--name",
-        "make-project",
+        "doc-lib",
+        "--description",
+        "A documented library",
         "--project-type",
-        "executable",
-        "--build-system",
-        "make",
-        "--cpp-standard",
-        "17",
-        "--test-framework",
-        "none",
-        "--license",
-        "MIT",
+        "library",
+        "--git",
+        "false",
         "--non-interactive",
         "--path",
         temp_dir.path().to_str().unwrap(),
@@ -137,30 +140,61 @@ fn test_make_build_system_executable() {
 
     cmd.assert().success();
 
-    // Verify Makefile exists
-    assert!(project_path.join("Makefile").exists());
-    assert!(!project_path.join("CMakeLists.txt").exists());
+    let index = fs::read_to_string(project_path.join("docs/index.md")).unwrap();
+    assert!(index.starts_with("# doc-lib"));
+    assert!(index.contains("A documented library"));
+    assert!(index.contains("(api.md)"));
+
+    let api = fs::read_to_string(project_path.join("docs/api.md")).unwrap();
+    assert!(api.contains("Namespace `doc_lib`"));
+    assert!(api.contains("doc-lib.hpp"));
 }
 
 #[test]
-fn test_make_build_system_library() {
+fn test_docs_dir_flag_overrides_project_type_default() {
     let temp_dir = TempDir::new().unwrap();
-    let project_path = temp_dir.path().join("make-lib");
+
+    for (name, project_type, docs) in [
+        ("plain-exe", "executable", None),
+        ("doc-exe", "executable", Some("true")),
+        ("plain-lib", "library", Some("false")),
+    ] {
+        let mut cmd = Command::cargo_bin("cppup").unwrap();
+        cmd.args([
+            "--name",
+            name,
+            "--project-type",
+            project_type,
+            "--git",
+            "false",
+            "--non-interactive",
+            "--path",
+            temp_dir.path().to_str().unwrap(),
+        ]);
+        if let Some(docs) = docs {
+            cmd.args(["--docs", docs]);
+        }
+
+        cmd.assert().success();
+
+        let has_docs = temp_dir.path().join(name).join("docs/index.md").exists();
+        assert_eq!(has_docs, docs == Some("true"), "{}", name);
+    }
+}
+
+#[test]
+fn test_sphinx_doc_engine() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_path = temp_dir.path().join("sphinx-lib");
 
     let mut cmd = Command::cargo_bin("cppup").unwrap();
     cmd.args([
         "--name",
-        "make-lib",
+        "sphinx-lib",
         "--project-type",
         "library",
-        "--build-system",
-        "make",
-        "--cpp-standard",
-        "17",
-        "--test-framework",
-        "none",
-        "--license",
-        "MIT",
+        "--doc-engine",
+        "sphinx",
         "--non-interactive",
         "--path",
         temp_dir.path().to_str().unwrap(),
@@ -168,54 +202,56 @@ fn test_make_build_system_library() {
 
     cmd.assert().success();
 
-    assert!(project_path.join("Makefile").exists());
-    assert!(project_path.join("include").exists());
-    assert!(project_path.join("examples").exists());
-}
-
-// ============================================================================
-// Test Framework Tests
-// ============================================================================
-
-#[test]
-fn test_doctest_framework() {
-    let temp_dir = TempDir::new().unwrap();
-    let project_path = temp_dir.path().join("doctest-project");
+    for file in [
+        "docs/conf.py",
+        "docs/index.rst",
+        "docs/Makefile",
+        "docs/Doxyfile",
+        "docs/requirements.txt",
+        "cmake/docs.cmake",
+    ] {
+        assert!(project_path.join(file).is_file(), "{} missing", file);
+    }
+    assert!(!project_path.join("docs/index.md").exists());
+    let cmake = fs::read_to_string(project_path.join("CMakeLists.txt")).unwrap();
+    assert!(cmake.contains("include(cmake/docs.cmake)"));
+    let gitignore = fs::read_to_string(project_path.join(".gitignore")).unwrap();
+    assert!(gitignore.contains("docs/_build/"));
+    let doxyfile = fs::read_to_string(project_path.join("docs/Doxyfile")).unwrap();
+    assert!(doxyfile.contains("GENERATE_XML           = YES"));
 
     let mut cmd = Command::cargo_bin("cppup").unwrap();
     cmd.args([
         "--name",
-        "doctest-project",
+        "sphinx-app",
         "--project-type",
         "executable",
-        "--test-framework",
-        "doctest",
+        "--doc-engine",
+        "sphinx",
         "--non-interactive",
         "--path",
         temp_dir.path().to_str().unwrap(),
     ]);
-
-    cmd.assert().success();
-
-    // Verify test directory and files
-    assert!(project_path.join("tests").exists());
-    assert!(project_path.join("tests/main_test.cpp").exists());
-    assert!(project_path.join("tests/CMakeLists.txt").exists());
+    cmd.assert().failure().stderr(predicate::str::contains(
+        "The Sphinx documentation engine is only available for library projects",
+    ));
 }
 
 #[test]
-fn test_gtest_framework() {
+fn test_doxygen_doc_engine() {
     let temp_dir = TempDir::new().unwrap();
-    let project_path = temp_dir.path().join("gtest-project");
+    let project_path = temp_dir.path().join("doxygen-lib");
 
     let mut cmd = Command::cargo_bin("cppup").unwrap();
     cmd.args([
         "--name",
-        "gtest-project",
+        "doxygen-lib",
         "--project-type",
-        "executable",
-        "--test-framework",
-        "gtest",
+        "library",
+        "--description",
+        "Parses configuration files",
+        "--doc-engine",
+        "doxygen",
         "--non-interactive",
         "--path",
         temp_dir.path().to_str().unwrap(),
@@ -223,23 +259,47 @@ fn test_gtest_framework() {
 
     cmd.assert().success();
 
-    assert!(project_path.join("tests").exists());
-    assert!(project_path.join("tests/main_test.cpp").exists());
+    let doxyfile = fs::read_to_string(project_path.join("docs/Doxyfile")).unwrap();
+    assert!(doxyfile.contains(r#"PROJECT_NAME           = "doxygen-lib""#));
+    assert!(doxyfile.contains(r#"PROJECT_BRIEF          = "Parses configuration files""#));
+    let docs_cmake = fs::read_to_string(project_path.join("docs/CMakeLists.txt")).unwrap();
+    assert!(docs_cmake.contains("find_package(Doxygen)"));
+    let cmake = fs::read_to_string(project_path.join("CMakeLists.txt")).unwrap();
+    assert!(cmake.contains("option(BUILD_DOCS"));
+    assert!(cmake.contains("add_subdirectory(docs)"));
+    let header = fs::read_to_string(project_path.join("include/doxygen-lib.hpp")).unwrap();
+    assert!(header.contains("/// @brief Adds two integers."));
 }
 
 #[test]
-fn test_catch2_framework() {
+fn test_generate_project_dry_run_writes_nothing() {
     let temp_dir = TempDir::new().unwrap();
-    let project_path = temp_dir.path().join("catch2-project");
+    let config = cppup::ProjectConfig::builder()
+        .name("dry-run-lib")
+        .project_type(cppup::project::ProjectType::Library)
+        .path(temp_dir.path())
+        .build()
+        .unwrap();
+
+    let files = cppup::generate_project_dry_run(config).unwrap();
+
+    assert!(files.contains_key("include/dry-run-lib.hpp"));
+    assert!(files.contains_key("examples/CMakeLists.txt"));
+    assert!(files[".gitignore"].contains("build*/"));
+    assert_eq!(fs::read_dir(temp_dir.path()).unwrap().count(), 0);
+}
+
+#[test]
+fn test_initial_git_commit() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_path = temp_dir.path().join("commit-app");
 
     let mut cmd = Command::cargo_bin("cppup").unwrap();
-    cmd.args([
+    cmd.env("GIT_CONFIG_GLOBAL", "/dev/null").args([
         "--name",
-        "catch2-project",
+        "commit-app",
         "--project-type",
         "executable",
-        "--test-framework",
-        "catch2",
         "--non-interactive",
         "--path",
         temp_dir.path().to_str().unwrap(),
@@ -247,79 +307,82 @@ fn test_catch2_framework() {
 
     cmd.assert().success();
 
-    assert!(project_path.join("tests").exists());
-    assert!(project_path.join("tests/main_test.cpp").exists());
+    let output = std::process::Command::new("git")
+        .args(["log", "--oneline"])
+        .current_dir(&project_path)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let log = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(log.lines().count(), 1, "{}", log);
+    assert!(log.contains("Initial commit from cppup v"));
+
+    let output = std::process::Command::new("git")
+        .args(["status", "--porcelain"])
+        .current_dir(&project_path)
+        .output()
+        .unwrap();
+    assert!(output.stdout.is_empty());
 }
 
 #[test]
-fn test_boosttest_framework() {
+fn test_skips_git_init_inside_existing_repository() {
     let temp_dir = TempDir::new().unwrap();
-    let project_path = temp_dir.path().join("boost-project");
+    let status = std::process::Command::new("git")
+        .args(["init", "--quiet"])
+        .current_dir(temp_dir.path())
+        .status()
+        .unwrap();
+    assert!(status.success());
+    let project_path = temp_dir.path().join("nested-app");
 
     let mut cmd = Command::cargo_bin("cppup").unwrap();
     cmd.args([
         "--name",
-        "boost-project",
+        "nested-app",
         "--project-type",
         "executable",
-        "--test-framework",
-        "boosttest",
         "--non-interactive",
         "--path",
         temp_dir.path().to_str().unwrap(),
     ]);
 
-    cmd.assert().success();
-
-    assert!(project_path.join("tests").exists());
-    assert!(project_path.join("tests/main_test.cpp").exists());
-}
-
-// ============================================================================
-// Package Manager Tests
-// ============================================================================
-
-#[test]
-fn test_conan_package_manager() {
-    let temp_dir = TempDir::new().unwrap();
-    let project_path = temp_dir.path().join("conan-project");
+    cmd.assert()
+        .success()
+        .stderr(predicate::str::contains("skipping git init"))
+        .stderr(predicate::str::contains("--force-git"));
+    assert!(!project_path.join(".git").exists());
+    assert!(project_path.join(".gitignore").is_file());
 
     let mut cmd = Command::cargo_bin("cppup").unwrap();
     cmd.args([
         "--name",
-        "conan-project",
+        "forced-app",
         "--project-type",
         "executable",
-        "--package-manager",
-        "conan",
-        "--test-framework",
-        "none",
+        "--force-git",
         "--non-interactive",
         "--path",
         temp_dir.path().to_str().unwrap(),
     ]);
 
     cmd.assert().success();
-
-    // Verify Conan configuration file exists
-    assert!(project_path.join("conanfile.txt").exists());
+    assert!(temp_dir.path().join("forced-app/.git").is_dir());
 }
 
 #[test]
-fn test_vcpkg_package_manager() {
+fn test_git_branch() {
     let temp_dir = TempDir::new().unwrap();
-    let project_path = temp_dir.path().join("vcpkg-project");
+    let project_path = temp_dir.path().join("trunk-app");
 
     let mut cmd = Command::cargo_bin("cppup").unwrap();
     cmd.args([
         "--name",
-        "vcpkg-project",
+        "trunk-app",
         "--project-type",
         "executable",
-        "--package-manager",
-        "vcpkg",
-        "--test-framework",
-        "none",
+        "--git-branch",
+        "trunk",
         "--non-interactive",
         "--path",
         temp_dir.path().to_str().unwrap(),
@@ -327,181 +390,261 @@ fn test_vcpkg_package_manager() {
 
     cmd.assert().success();
 
-    // Verify vcpkg configuration file exists
-    assert!(project_path.join("vcpkg.json").exists());
+    let head = fs::read_to_string(project_path.join(".git/HEAD")).unwrap();
+    assert_eq!(head.trim(), "ref: refs/heads/trunk");
 }
 
-// ============================================================================
-// C++ Standard Tests
-// ============================================================================
+#[test]
+fn test_gitattributes_vendored_section_follows_meson_subprojects() {
+    let temp_dir = TempDir::new().unwrap();
+
+    for (name, build_system, vendored) in [
+        ("attrs-meson", "meson", true),
+        ("attrs-cmake", "cmake", false),
+    ] {
+        let mut cmd = Command::cargo_bin("cppup").unwrap();
+        cmd.args([
+            "--name",
+            name,
+            "--project-type",
+            "library",
+            "--build-system",
+            build_system,
+            "--test-framework",
+            "gtest",
+            "--non-interactive",
+            "--path",
+            temp_dir.path().to_str().unwrap(),
+        ]);
+        cmd.assert().success();
+
+        let attributes =
+            fs::read_to_string(temp_dir.path().join(name).join(".gitattributes")).unwrap();
+        assert!(attributes.contains("* text=auto"));
+        assert!(attributes.contains("tests/ export-ignore"));
+        assert_eq!(
+            attributes.contains("linguist-vendored"),
+            vendored,
+            "{}",
+            name
+        );
+    }
+}
 
 #[test]
-fn test_cpp11_standard() {
+fn test_no_gitattributes_without_git() {
     let temp_dir = TempDir::new().unwrap();
-    let project_path = temp_dir.path().join("cpp11-project");
 
     let mut cmd = Command::cargo_bin("cppup").unwrap();
     cmd.args([
         "--name",
-        "cpp11-project",
+        "attrs-no-git",
         "--project-type",
         "executable",
-        "--cpp-standard",
-        "11",
-        "--test-framework",
-        "none",
+        "--git",
+        "false",
         "--non-interactive",
         "--path",
         temp_dir.path().to_str().unwrap(),
     ]);
-
     cmd.assert().success();
-    assert!(project_path.exists());
+
+    assert!(!temp_dir.path().join("attrs-no-git/.gitattributes").exists());
 }
 
 #[test]
-fn test_cpp14_standard() {
+fn test_git_remote() {
     let temp_dir = TempDir::new().unwrap();
-    let project_path = temp_dir.path().join("cpp14-project");
+    let project_path = temp_dir.path().join("remote-app");
 
     let mut cmd = Command::cargo_bin("cppup").unwrap();
     cmd.args([
         "--name",
-        "cpp14-project",
+        "remote-app",
         "--project-type",
         "executable",
-        "--cpp-standard",
-        "14",
-        "--test-framework",
-        "none",
+        "--git-remote",
+        "git@github.com:me/remote-app.git",
+        "--changelog",
         "--non-interactive",
         "--path",
         temp_dir.path().to_str().unwrap(),
     ]);
 
-    cmd.assert().success();
-    assert!(project_path.exists());
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("git push -u origin main"));
+
+    let output = std::process::Command::new("git")
+        .args(["remote", "-v"])
+        .current_dir(&project_path)
+        .output()
+        .unwrap();
+    let remotes = String::from_utf8_lossy(&output.stdout);
+    assert!(remotes.contains("origin\tgit@github.com:me/remote-app.git (fetch)"));
+    assert!(remotes.contains("origin\tgit@github.com:me/remote-app.git (push)"));
+
+    let readme = fs::read_to_string(project_path.join("README.md")).unwrap();
+    assert!(readme.contains("git clone git@github.com:me/remote-app.git"));
+    assert!(readme.contains("https://github.com/me/remote-app/blob/main/CHANGELOG.md"));
+    assert!(readme.contains("https://img.shields.io/github/v/release/me/remote-app"));
+    assert!(readme.contains("https://img.shields.io/badge/license-MIT-blue.svg"));
+    assert!(!readme.contains("/badge/version-"));
+    // No CI pipeline is generated, so there is no status to show
+    assert!(!readme.contains("[![CI]"));
+
+    let changelog = fs::read_to_string(project_path.join("CHANGELOG.md")).unwrap();
+    let today = chrono::Local::now().format("%Y-%m-%d").to_string();
+    assert!(changelog.contains(&format!("## [0.1.0] - {}\n", today)));
+    assert!(changelog
+        .contains("[Unreleased]: https://github.com/me/remote-app/compare/v0.1.0...HEAD\n"));
 }
 
+#[cfg(unix)]
 #[test]
-fn test_cpp20_standard() {
+fn test_create_github_repo_with_stub_gh() {
+    use std::os::unix::fs::PermissionsExt;
+
     let temp_dir = TempDir::new().unwrap();
-    let project_path = temp_dir.path().join("cpp20-project");
+    let bin_dir = temp_dir.path().join("bin");
+    fs::create_dir(&bin_dir).unwrap();
+    let log = temp_dir.path().join("gh.log");
+    let gh = bin_dir.join("gh");
+    fs::write(
+        &gh,
+        format!(
+            "#!/bin/sh\necho \"$*\" >> '{}'\n\
+             if [ \"$1\" = repo ]; then echo https://github.com/me/gh-app; fi\n",
+            log.display()
+        ),
+    )
+    .unwrap();
+    fs::set_permissions(&gh, fs::Permissions::from_mode(0o755)).unwrap();
+    let path = format!(
+        "{}:{}",
+        bin_dir.display(),
+        std::env::var("PATH").unwrap_or_default()
+    );
 
     let mut cmd = Command::cargo_bin("cppup").unwrap();
-    cmd.args([
+    cmd.env("PATH", path).args([
         "--name",
-        "cpp20-project",
+        "gh-app",
         "--project-type",
         "executable",
-        "--cpp-standard",
-        "20",
-        "--test-framework",
-        "none",
+        "--create-github-repo",
+        "--private",
         "--non-interactive",
         "--path",
         temp_dir.path().to_str().unwrap(),
     ]);
 
-    cmd.assert().success();
-    assert!(project_path.exists());
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Created GitHub repository https://github.com/me/gh-app",
+        ))
+        .stdout(predicate::str::contains("git push").not());
+
+    let calls = fs::read_to_string(&log).unwrap();
+    let calls: Vec<&str> = calls.lines().collect();
+    assert_eq!(calls[0], "auth status");
+    assert!(calls[1].starts_with("repo create gh-app --private"));
+    assert!(calls[1].ends_with("--source . --push"));
 }
 
 #[test]
-fn test_cpp23_standard() {
+fn test_create_github_repo_requires_initial_commit() {
     let temp_dir = TempDir::new().unwrap();
-    let project_path = temp_dir.path().join("cpp23-project");
 
     let mut cmd = Command::cargo_bin("cppup").unwrap();
     cmd.args([
         "--name",
-        "cpp23-project",
+        "gh-no-commit",
         "--project-type",
         "executable",
-        "--cpp-standard",
-        "23",
-        "--test-framework",
-        "none",
+        "--create-github-repo",
+        "--git-commit",
+        "false",
         "--non-interactive",
         "--path",
         temp_dir.path().to_str().unwrap(),
     ]);
 
-    cmd.assert().success();
-    assert!(project_path.exists());
+    cmd.assert().failure().stderr(predicate::str::contains(
+        "Creating a GitHub repository requires git and the initial commit",
+    ));
 }
 
-// ============================================================================
-// License Tests
-// ============================================================================
+#[test]
+fn test_private_requires_create_github_repo() {
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args(["--name", "gh-private", "--private", "--non-interactive"]);
+
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("--create-github-repo"));
+}
 
 #[test]
-fn test_apache_license() {
+fn test_git_remote_requires_git() {
     let temp_dir = TempDir::new().unwrap();
-    let project_path = temp_dir.path().join("apache-project");
 
     let mut cmd = Command::cargo_bin("cppup").unwrap();
     cmd.args([
         "--name",
-        "apache-project",
+        "remote-no-git",
         "--project-type",
         "executable",
-        "--license",
-        "Apache-2.0",
-        "--test-framework",
-        "none",
+        "--git",
+        "false",
+        "--git-remote",
+        "https://github.com/me/remote-no-git.git",
         "--non-interactive",
         "--path",
         temp_dir.path().to_str().unwrap(),
     ]);
 
-    cmd.assert().success();
-
-    let license_content = fs::read_to_string(project_path.join("LICENSE")).unwrap();
-    assert!(license_content.contains("Apache License"));
+    cmd.assert().failure().stderr(predicate::str::contains(
+        "A git remote requires git to be enabled",
+    ));
 }
 
 #[test]
-fn test_gpl_license() {
+fn test_invalid_git_branch() {
     let temp_dir = TempDir::new().unwrap();
-    let project_path = temp_dir.path().join("gpl-project");
 
     let mut cmd = Command::cargo_bin("cppup").unwrap();
     cmd.args([
         "--name",
-        "gpl-project",
+        "bad-branch",
         "--project-type",
         "executable",
-        "--license",
-        "GPL-3.0",
-        "--test-framework",
-        "none",
+        "--git-branch",
+        "feature..x",
         "--non-interactive",
         "--path",
         temp_dir.path().to_str().unwrap(),
     ]);
 
-    cmd.assert().success();
-
-    let license_content = fs::read_to_string(project_path.join("LICENSE")).unwrap();
-    assert!(license_content.contains("GNU GENERAL PUBLIC LICENSE"));
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid git branch name"));
 }
 
 #[test]
-fn test_bsd_license() {
+fn test_git_commit_disabled() {
     let temp_dir = TempDir::new().unwrap();
-    let project_path = temp_dir.path().join("bsd-project");
+    let project_path = temp_dir.path().join("no-commit-app");
 
     let mut cmd = Command::cargo_bin("cppup").unwrap();
     cmd.args([
         "--name",
-        "bsd-project",
+        "no-commit-app",
         "--project-type",
         "executable",
-        "--license",
-        "BSD-3-Clause",
-        "--test-framework",
-        "none",
+        "--git-commit",
+        "false",
         "--non-interactive",
         "--path",
         temp_dir.path().to_str().unwrap(),
@@ -509,29 +652,29 @@ fn test_bsd_license() {
 
     cmd.assert().success();
 
-    let license_content = fs::read_to_string(project_path.join("LICENSE")).unwrap();
-    assert!(license_content.contains("BSD") || license_content.contains("Redistribution"));
+    assert!(project_path.join(".git").is_dir());
+    let output = std::process::Command::new("git")
+        .args(["rev-parse", "--verify", "HEAD"])
+        .current_dir(&project_path)
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
 }
 
-// ============================================================================
-// Quality Tools and Formatter Tests
-// ============================================================================
-
 #[test]
-fn test_quality_tools_clang_tidy() {
+fn test_changelog() {
     let temp_dir = TempDir::new().unwrap();
-    let project_path = temp_dir.path().join("quality-project");
+    let project_path = temp_dir.path().join("changelog-app");
 
     let mut cmd = Command::cargo_bin("cppup").unwrap();
     cmd.args([
         "--name",
-        "quality-project",
+        "changelog-app",
         "--project-type",
         "executable",
-        "--quality-tools",
-        "clang-tidy",
-        "--test-framework",
-        "none",
+        "--changelog",
+        "--homepage",
+        "https://github.com/acme/changelog-app/",
         "--non-interactive",
         "--path",
         temp_dir.path().to_str().unwrap(),
@@ -539,75 +682,148 @@ fn test_quality_tools_clang_tidy() {
 
     cmd.assert().success();
 
-    // Verify quality tool configuration file exists
-    assert!(project_path.join(".clang-tidy").exists());
+    let changelog = fs::read_to_string(project_path.join("CHANGELOG.md")).unwrap();
+    assert!(changelog.contains("Unreleased"));
+    assert!(changelog.contains("https://github.com/acme/changelog-app/releases/tag/v0.1.0"));
+    let script = project_path.join("scripts/update-changelog.sh");
+    assert!(script.is_file());
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = fs::metadata(&script).unwrap().permissions().mode();
+        assert_eq!(mode & 0o111, 0o111);
+    }
+    let readme = fs::read_to_string(project_path.join("README.md")).unwrap();
+    assert!(readme.contains("https://github.com/acme/changelog-app/blob/main/CHANGELOG.md"));
 }
 
 #[test]
-fn test_quality_tools_cppcheck() {
+fn test_color_option() {
+    let temp_dir = TempDir::new().unwrap();
+
+    for (name, color) in [("never-app", "never"), ("always-app", "always")] {
+        let mut cmd = Command::cargo_bin("cppup").unwrap();
+        cmd.args([
+            "--name",
+            name,
+            "--project-type",
+            "executable",
+            "--color",
+            color,
+            "--verbose",
+            "--non-interactive",
+            "--path",
+            temp_dir.path().to_str().unwrap(),
+        ]);
+        let output = cmd.assert().success().get_output().stdout.clone();
+        let stdout = String::from_utf8(output).unwrap();
+
+        assert!(stdout.contains("Project created successfully!"));
+        assert_eq!(stdout.contains('\u{1b}'), color == "always", "{}", stdout);
+    }
+}
+
+#[test]
+fn test_codeowners() {
     let temp_dir = TempDir::new().unwrap();
-    let project_path = temp_dir.path().join("cppcheck-project");
 
     let mut cmd = Command::cargo_bin("cppup").unwrap();
     cmd.args([
         "--name",
-        "cppcheck-project",
+        "owned-app",
         "--project-type",
         "executable",
-        "--quality-tools",
-        "cppcheck",
-        "--test-framework",
-        "none",
+        "--codeowners",
+        "@org/cpp-team,@jane",
         "--non-interactive",
         "--path",
         temp_dir.path().to_str().unwrap(),
     ]);
-
     cmd.assert().success();
-
-    assert!(project_path.join("cppcheck-suppressions.xml").exists());
-}
-
-#[test]
-fn test_code_formatter_clang_format() {
-    let temp_dir = TempDir::new().unwrap();
-    let project_path = temp_dir.path().join("format-project");
+    let codeowners =
+        fs::read_to_string(temp_dir.path().join("owned-app/.github/CODEOWNERS")).unwrap();
+    assert!(codeowners.contains("\n* @org/cpp-team @jane\n"));
+    assert!(codeowners.contains("\n/.github/ @org/cpp-team @jane\n"));
 
     let mut cmd = Command::cargo_bin("cppup").unwrap();
     cmd.args([
         "--name",
-        "format-project",
+        "unowned-app",
         "--project-type",
         "executable",
-        "--code-formatter",
-        "clang-format",
-        "--test-framework",
-        "none",
+        "--codeowners",
+        "org/cpp-team",
         "--non-interactive",
         "--path",
         temp_dir.path().to_str().unwrap(),
     ]);
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("owners must start with '@'"));
+}
 
-    cmd.assert().success();
+#[test]
+fn test_community_files() {
+    let temp_dir = TempDir::new().unwrap();
 
-    assert!(project_path.join(".clang-format").exists());
+    for (name, extra) in [
+        ("community-app", vec!["--community-files"]),
+        ("plain-app", vec![]),
+    ] {
+        let mut cmd = Command::cargo_bin("cppup").unwrap();
+        cmd.args([
+            "--name",
+            name,
+            "--project-type",
+            "executable",
+            "--author",
+            "Jane Doe <jane@example.com>",
+            "--test-framework",
+            "doctest",
+            "--code-formatter",
+            "clang-format",
+            "--non-interactive",
+            "--path",
+            temp_dir.path().to_str().unwrap(),
+        ])
+        .args(&extra);
+        cmd.assert().success();
+
+        let project_path = temp_dir.path().join(name);
+        let readme = fs::read_to_string(project_path.join("README.md")).unwrap();
+        if extra.is_empty() {
+            assert!(!project_path.join("CONTRIBUTING.md").exists());
+            assert!(!project_path.join("CODE_OF_CONDUCT.md").exists());
+            assert!(!readme.contains("CONTRIBUTING.md"));
+            continue;
+        }
+
+        let contributing = fs::read_to_string(project_path.join("CONTRIBUTING.md")).unwrap();
+        assert!(contributing.contains("cmake -S . -B build\ncmake --build build\n"));
+        assert!(contributing.contains("ctest --test-dir build"));
+        assert!(contributing.contains("clang-format -i"));
+        let conduct = fs::read_to_string(project_path.join("CODE_OF_CONDUCT.md")).unwrap();
+        assert!(conduct.contains(
+            "reported to the community leaders responsible for enforcement at\njane@example.com."
+        ));
+        assert!(readme.contains("[CONTRIBUTING.md](CONTRIBUTING.md)"));
+    }
 }
 
 #[test]
-fn test_code_formatter_cmake_format() {
+fn test_changelog_script_requires_git() {
     let temp_dir = TempDir::new().unwrap();
-    let project_path = temp_dir.path().join("cmake-format-project");
+    let project_path = temp_dir.path().join("no-git-app");
 
     let mut cmd = Command::cargo_bin("cppup").unwrap();
     cmd.args([
         "--name",
-        "cmake-format-project",
+        "no-git-app",
         "--project-type",
         "executable",
-        "--code-formatter",
-        "cmake-format",
-        "--test-framework",
-        "none",
+        "--changelog",
+        "--git",
+        "false",
         "--non-interactive",
         "--path",
         temp_dir.path().to_str().unwrap(),
@@ -615,27 +831,51 @@ fn test_code_formatter_cmake_format() {
 
     cmd.assert().success();
 
-    assert!(project_path.join("cmake-format.yaml").exists());
+    assert!(project_path.join("CHANGELOG.md").is_file());
+    assert!(!project_path.join("scripts").exists());
 }
 
-// ============================================================================
-// Git Tests
-// ============================================================================
-
 #[test]
-fn test_git_initialization() {
+fn test_invalid_homepage() {
     let temp_dir = TempDir::new().unwrap();
-    let project_path = temp_dir.path().join("git-project");
 
     let mut cmd = Command::cargo_bin("cppup").unwrap();
     cmd.args([
         "--name",
-        "git-project",
+        "bad-homepage",
         "--project-type",
         "executable",
-        "--git",
+        "--homepage",
+        "github.com/acme/app",
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("Homepage must be an http"));
+}
+
+#[test]
+fn test_custom_source_and_header_extensions() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_path = temp_dir.path().join("ext-lib");
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args([
+        "--name",
+        "ext-lib",
+        "--project-type",
+        "library",
+        "--cpp-extension",
+        "cxx",
+        "--hpp-extension",
+        "h",
         "--test-framework",
-        "none",
+        "doctest",
+        "--git",
+        "false",
         "--non-interactive",
         "--path",
         temp_dir.path().to_str().unwrap(),
@@ -643,209 +883,374 @@ fn test_git_initialization() {
 
     cmd.assert().success();
 
-    // Verify git repository and .gitignore exist
-    assert!(project_path.join(".git").exists());
-    assert!(project_path.join(".gitignore").exists());
-}
+    assert!(project_path.join("src/lib.cxx").exists());
+    assert!(project_path.join("include/ext-lib.h").exists());
+    assert!(project_path.join("examples/example.cxx").exists());
+    assert!(project_path.join("tests/main_test.cxx").exists());
+    assert!(!project_path.join("src/lib.cpp").exists());
 
-// ============================================================================
-// Error Condition Tests
-// ============================================================================
+    let source_cmake = fs::read_to_string(project_path.join("src/CMakeLists.txt")).unwrap();
+    assert!(source_cmake.contains("lib.cxx"));
+    let tests_cmake = fs::read_to_string(project_path.join("tests/CMakeLists.txt")).unwrap();
+    assert!(tests_cmake.contains("main_test.cxx"));
+    let library = fs::read_to_string(project_path.join("src/lib.cxx")).unwrap();
+    assert!(library.contains("#include \"ext-lib.h\""));
+}
 
 #[test]
-fn test_invalid_project_name() {
+fn test_cc_extension_with_make() {
     let temp_dir = TempDir::new().unwrap();
+    let project_path = temp_dir.path().join("cc-exe");
 
     let mut cmd = Command::cargo_bin("cppup").unwrap();
     cmd.args([
         "--name",
-        "123invalid",
+        "cc-exe",
         "--project-type",
         "executable",
+        "--build-system",
+        "make",
+        "--cpp-extension",
+        "cc",
+        "--git",
+        "false",
         "--non-interactive",
         "--path",
         temp_dir.path().to_str().unwrap(),
     ]);
 
-    cmd.assert().failure();
+    cmd.assert().success();
+
+    assert!(project_path.join("src/main.cc").exists());
+    let makefile = fs::read_to_string(project_path.join("Makefile")).unwrap();
+    assert!(makefile.contains("$(wildcard src/*.cc)"));
+    assert!(makefile.contains("%.o: %.cc"));
+    assert!(!makefile.contains(".cpp"));
 }
 
 #[test]
-fn test_project_name_with_spaces() {
+fn test_sanitizer_preset() {
     let temp_dir = TempDir::new().unwrap();
+    let project_path = temp_dir.path().join("sanitized");
 
     let mut cmd = Command::cargo_bin("cppup").unwrap();
     cmd.args([
         "--name",
-        "invalid name",
+        "sanitized",
         "--project-type",
         "executable",
+        "--sanitizer",
+        "address-undefined",
+        "--git",
+        "false",
         "--non-interactive",
         "--path",
         temp_dir.path().to_str().unwrap(),
     ]);
 
-    cmd.assert().failure();
+    cmd.assert().success();
+
+    let sanitizers = fs::read_to_string(project_path.join("cmake/sanitizers.cmake")).unwrap();
+    assert!(sanitizers.contains("-fsanitize=address,undefined"));
+    let cmake = fs::read_to_string(project_path.join("CMakeLists.txt")).unwrap();
+    assert!(cmake.contains("enable_sanitizer_preset(project_options)"));
 }
 
 #[test]
-fn test_project_name_with_special_chars() {
+fn test_sanitizer_preset_requires_cmake() {
     let temp_dir = TempDir::new().unwrap();
 
     let mut cmd = Command::cargo_bin("cppup").unwrap();
     cmd.args([
         "--name",
-        "invalid@project!",
+        "sanitized-make",
         "--project-type",
         "executable",
+        "--build-system",
+        "make",
+        "--sanitizer",
+        "address",
         "--non-interactive",
         "--path",
         temp_dir.path().to_str().unwrap(),
     ]);
 
-    cmd.assert().failure();
+    cmd.assert().failure().stderr(predicate::str::contains(
+        "Sanitizer presets require the CMake build system",
+    ));
 }
 
 #[test]
-fn test_missing_required_name() {
+fn test_path_expands_home_and_relative_directories() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::create_dir(temp_dir.path().join("projects")).unwrap();
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.env("HOME", temp_dir.path()).args([
+        "--name",
+        "home-app",
+        "--project-type",
+        "executable",
+        "--non-interactive",
+        "--git",
+        "false",
+        "--path",
+        "~/projects",
+    ]);
+    cmd.assert().success();
+    assert!(temp_dir
+        .path()
+        .join("projects/home-app/CMakeLists.txt")
+        .exists());
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.current_dir(temp_dir.path().join("projects")).args([
+        "--name",
+        "home-app",
+        "--project-type",
+        "executable",
+        "--non-interactive",
+        "--path",
+        ".",
+    ]);
+    cmd.assert().failure().stderr(predicate::str::contains(
+        temp_dir
+            .path()
+            .canonicalize()
+            .unwrap()
+            .join("projects/home-app")
+            .display()
+            .to_string(),
+    ));
+}
+
+// ============================================================================
+// Build System Tests
+// ============================================================================
+
+#[test]
+fn test_make_build_system_executable() {
     let temp_dir = TempDir::new().unwrap();
+    let project_path = temp_dir.path().join("make-project");
 
     let mut cmd = Command::cargo_bin("cppup").unwrap();
     cmd.args([
+        "--name",
+        "make-project",
         "--project-type",
         "executable",
+        "--build-system",
+        "make",
+        "--cpp-standard",
+        "17",
+        "--test-framework",
+        "none",
+        "--license",
+        "MIT",
         "--non-interactive",
         "--path",
         temp_dir.path().to_str().unwrap(),
     ]);
 
-    cmd.assert().failure();
+    cmd.assert().success();
+
+    // Verify Makefile exists
+    assert!(project_path.join("Makefile").exists());
+    assert!(!project_path.join("CMakeLists.txt").exists());
 }
 
 #[test]
-fn test_missing_required_project_type() {
+fn test_verify_cmake_project() {
+    if !cppup::ProjectValidator::is_tool_installed(&SystemRunner, "cmake") {
+        return;
+    }
     let temp_dir = TempDir::new().unwrap();
 
     let mut cmd = Command::cargo_bin("cppup").unwrap();
     cmd.args([
         "--name",
-        "test-project",
+        "verified-project",
+        "--project-type",
+        "executable",
+        "--build-system",
+        "cmake",
+        "--verify",
         "--non-interactive",
         "--path",
         temp_dir.path().to_str().unwrap(),
     ]);
 
-    cmd.assert().failure();
+    cmd.assert().success().stdout(predicate::str::contains(
+        "Verified: `cmake -S . -B build` succeeded",
+    ));
+    assert!(!temp_dir.path().join("verified-project/build").exists());
 }
 
 #[test]
-fn test_duplicate_project_creation() {
+fn test_verify_skips_vcpkg_without_root() {
+    if !cppup::ProjectValidator::is_tool_installed(&SystemRunner, "cmake") {
+        return;
+    }
     let temp_dir = TempDir::new().unwrap();
 
-    // Create first project successfully
-    let mut cmd1 = Command::cargo_bin("cppup").unwrap();
-    cmd1.args([
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.env_remove("VCPKG_ROOT").args([
         "--name",
-        "duplicate-project",
+        "vcpkg-project",
         "--project-type",
         "executable",
-        "--test-framework",
-        "none",
+        "--package-manager",
+        "vcpkg",
+        "--verify",
         "--non-interactive",
         "--path",
         temp_dir.path().to_str().unwrap(),
     ]);
-    cmd1.assert().success();
 
-    // Try to create the same project again - should fail
-    let mut cmd2 = Command::cargo_bin("cppup").unwrap();
-    cmd2.args([
+    cmd.assert().success().stderr(predicate::str::contains(
+        "Warning: skipping verification: VCPKG_ROOT is not set",
+    ));
+}
+
+#[test]
+fn test_verify_failure_shows_stderr() {
+    if !cppup::ProjectValidator::is_tool_installed(&SystemRunner, "make") {
+        return;
+    }
+    let temp_dir = TempDir::new().unwrap();
+    let config_dir = temp_dir.path().join("config");
+    let templates_dir = config_dir.join("cppup/templates");
+    fs::create_dir_all(&templates_dir).unwrap();
+    fs::write(templates_dir.join("Makefile.hbs"), "all:\n  missing-tab\n").unwrap();
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.env("XDG_CONFIG_HOME", &config_dir).args([
         "--name",
-        "duplicate-project",
+        "broken-make-project",
         "--project-type",
         "executable",
-        "--test-framework",
-        "none",
+        "--build-system",
+        "make",
+        "--verify",
         "--non-interactive",
         "--path",
         temp_dir.path().to_str().unwrap(),
     ]);
-    cmd2.assert().failure();
+
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("`make -n` exited with an error"))
+        .stderr(predicate::str::contains("missing separator"));
 }
 
-// ============================================================================
-// Complex Integration Tests
-// ============================================================================
+#[test]
+fn test_build_flag_compiles_make_project() {
+    if !cppup::ProjectValidator::is_tool_installed(&SystemRunner, "make")
+        || !cppup::ProjectValidator::is_tool_installed(&SystemRunner, "g++")
+    {
+        return;
+    }
+    let temp_dir = TempDir::new().unwrap();
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args([
+        "--name",
+        "built-project",
+        "--project-type",
+        "executable",
+        "--build-system",
+        "make",
+        "--build",
+        "--jobs",
+        "2",
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("successfully"));
+    assert!(temp_dir
+        .path()
+        .join("built-project/built-project")
+        .is_file());
+}
 
 #[test]
-fn test_full_featured_project() {
+fn test_build_flag_compiles_cmake_project() {
+    if !cppup::ProjectValidator::is_tool_installed(&SystemRunner, "cmake")
+        || !cppup::ProjectValidator::is_tool_installed(&SystemRunner, "g++")
+    {
+        return;
+    }
     let temp_dir = TempDir::new().unwrap();
-    let project_path = temp_dir.path().join("full-project");
 
     let mut cmd = Command::cargo_bin("cppup").unwrap();
     cmd.args([
         "--name",
-        "full-project",
-        "--description",
-        "A fully featured test project",
-        "--author",
-        "Test Author",
+        "built-cmake-project",
         "--project-type",
-        "library",
+        "executable",
         "--build-system",
         "cmake",
-        "--cpp-standard",
-        "20",
-        "--package-manager",
-        "conan",
-        "--test-framework",
-        "doctest",
-        "--license",
-        "MIT",
-        "--quality-tools",
-        "clang-tidy,cppcheck",
-        "--code-formatter",
-        "clang-format",
-        "--git",
+        "--build",
         "--non-interactive",
         "--path",
         temp_dir.path().to_str().unwrap(),
     ]);
 
-    cmd.assert().success();
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("successfully"));
+}
 
-    // Verify all expected files exist
-    assert!(project_path.join("src/lib.cpp").exists());
-    assert!(project_path.join("include/full-project.hpp").exists());
-    assert!(project_path.join("examples").exists());
-    assert!(project_path.join("tests").exists());
-    assert!(project_path.join("CMakeLists.txt").exists());
-    assert!(project_path.join("conanfile.txt").exists());
-    assert!(project_path.join(".clang-tidy").exists());
-    assert!(project_path.join("cppcheck-suppressions.xml").exists());
-    assert!(project_path.join(".clang-format").exists());
-    assert!(project_path.join(".gitignore").exists());
-    assert!(project_path.join("LICENSE").exists());
-    assert!(project_path.join("README.md").exists());
+#[test]
+fn test_build_flag_reports_failing_step() {
+    if !cppup::ProjectValidator::is_tool_installed(&SystemRunner, "make") {
+        return;
+    }
+    let temp_dir = TempDir::new().unwrap();
+    let config_dir = temp_dir.path().join("config");
+    let templates_dir = config_dir.join("cppup/templates");
+    fs::create_dir_all(&templates_dir).unwrap();
+    fs::write(templates_dir.join("Makefile.hbs"), "all:\n\tfalse\n").unwrap();
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.env("XDG_CONFIG_HOME", &config_dir).args([
+        "--name",
+        "failing-build",
+        "--project-type",
+        "executable",
+        "--build-system",
+        "make",
+        "--build",
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("Build step `make` failed"));
+    assert!(temp_dir.path().join("failing-build/Makefile").exists());
 }
 
 #[test]
-fn test_executable_with_make_and_tests() {
+fn test_meson_build_system_executable() {
     let temp_dir = TempDir::new().unwrap();
-    let project_path = temp_dir.path().join("make-test-project");
+    let project_path = temp_dir.path().join("meson-project");
 
     let mut cmd = Command::cargo_bin("cppup").unwrap();
     cmd.args([
         "--name",
-        "make-test-project",
+        "meson-project",
         "--project-type",
         "executable",
         "--build-system",
-        "make",
-        "--cpp-standard",
-        "17",
+        "meson",
         "--test-framework",
-        "catch2",
+        "none",
         "--non-interactive",
         "--path",
         temp_dir.path().to_str().unwrap(),
@@ -853,7 +1258,3018 @@ fn test_executable_with_make_and_tests() {
 
     cmd.assert().success();
 
-    assert!(project_path.join("Makefile").exists());
-    assert!(project_path.join("tests").exists());
-    assert!(project_path.join("src/main.cpp").exists());
+    let meson_build = fs::read_to_string(project_path.join("meson.build")).unwrap();
+    assert!(meson_build.contains("project('meson-project', 'cpp'"));
+    assert!(meson_build.contains("'cpp_std=c++17'"));
+    assert!(!project_path.join("CMakeLists.txt").exists());
+    assert!(!project_path.join("subprojects").exists());
+}
+
+#[test]
+fn test_meson_wrap_file_for_test_framework() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_path = temp_dir.path().join("meson-tests");
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args([
+        "--name",
+        "meson-tests",
+        "--project-type",
+        "library",
+        "--build-system",
+        "meson",
+        "--test-framework",
+        "doctest",
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+
+    cmd.assert().success();
+
+    let wrap = fs::read_to_string(project_path.join("subprojects/doctest.wrap")).unwrap();
+    assert!(wrap.contains("[wrap-git]"));
+    assert!(wrap.contains("url = https://github.com/doctest/doctest.git"));
+    assert!(wrap.contains("dependency_names = doctest"));
+    assert!(project_path.join("meson.build").exists());
+}
+
+#[test]
+fn test_meson_with_gtest() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_path = temp_dir.path().join("meson-gtest");
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args([
+        "--name",
+        "meson-gtest",
+        "--project-type",
+        "library",
+        "--build-system",
+        "meson",
+        "--test-framework",
+        "gtest",
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+
+    cmd.assert().success();
+
+    let meson_build = fs::read_to_string(project_path.join("meson.build")).unwrap();
+    assert!(meson_build.contains("subdir('tests')"));
+    let tests = fs::read_to_string(project_path.join("tests/meson.build")).unwrap();
+    assert!(tests.contains("test_dep = dependency('gtest', main: true)"));
+    assert!(tests.contains("dependencies: [lib_dep, test_dep]"));
+    assert!(tests.contains("test('meson-gtest_tests', test_exe)"));
+    assert!(project_path.join("tests/main_test.cpp").exists());
+    assert!(!project_path.join("tests/CMakeLists.txt").exists());
+
+    let wrap = fs::read_to_string(project_path.join("subprojects/gtest.wrap")).unwrap();
+    assert!(wrap.contains("url = https://github.com/google/googletest.git"));
+    assert!(wrap.contains("method = cmake"));
+    assert!(wrap.contains("gtest = gtest_main_dep"));
+}
+
+#[test]
+fn test_make_build_system_library() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_path = temp_dir.path().join("make-lib");
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args([
+        "--name",
+        "make-lib",
+        "--project-type",
+        "library",
+        "--build-system",
+        "make",
+        "--cpp-standard",
+        "17",
+        "--test-framework",
+        "none",
+        "--license",
+        "MIT",
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+
+    cmd.assert().success();
+
+    assert!(project_path.join("Makefile").exists());
+    assert!(project_path.join("include").exists());
+    assert!(project_path.join("examples").exists());
+}
+
+#[test]
+fn test_multiple_examples() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_path = temp_dir.path().join("examples-lib");
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args([
+        "--name",
+        "examples-lib",
+        "--project-type",
+        "library",
+        "--num-examples",
+        "3",
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+
+    cmd.assert().success();
+
+    for index in 1..=3 {
+        let example = project_path.join(format!("examples/example_{:02}.cpp", index));
+        assert!(example.is_file(), "{} missing", example.display());
+    }
+    assert!(!project_path.join("examples/example.cpp").exists());
+    let cmake = fs::read_to_string(project_path.join("examples/CMakeLists.txt")).unwrap();
+    assert_eq!(cmake.matches("add_executable(").count(), 3);
+    assert!(cmake.contains("add_executable(${PROJECT_NAME}_example_03 example_03.cpp)"));
+}
+
+#[test]
+fn test_multiple_examples_build_with_make() {
+    if !cppup::ProjectValidator::is_tool_installed(&SystemRunner, "make")
+        || !cppup::ProjectValidator::is_tool_installed(&SystemRunner, "g++")
+    {
+        return;
+    }
+    let temp_dir = TempDir::new().unwrap();
+    let project_path = temp_dir.path().join("make-examples");
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args([
+        "--name",
+        "make-examples",
+        "--project-type",
+        "library",
+        "--build-system",
+        "make",
+        "--num-examples",
+        "2",
+        "--build",
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+
+    cmd.assert().success();
+    assert!(project_path.join("example_01").is_file());
+    assert!(project_path.join("example_02").is_file());
+}
+
+#[test]
+fn test_examples_require_library() {
+    let temp_dir = TempDir::new().unwrap();
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args([
+        "--name",
+        "examples-app",
+        "--project-type",
+        "executable",
+        "--num-examples",
+        "2",
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+
+    cmd.assert().failure().stderr(predicate::str::contains(
+        "Examples are only generated for library projects",
+    ));
+}
+
+// ============================================================================
+// Test Framework Tests
+// ============================================================================
+
+#[test]
+fn test_doctest_framework() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_path = temp_dir.path().join("doctest-project");
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args([
+        "--name",
+        "doctest-project",
+        "--project-type",
+        "executable",
+        "--test-framework",
+        "doctest",
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+
+    cmd.assert().success();
+
+    // Verify test directory and files
+    assert!(project_path.join("tests").exists());
+    assert!(project_path.join("tests/main_test.cpp").exists());
+    assert!(project_path.join("tests/CMakeLists.txt").exists());
+}
+
+#[test]
+fn test_gtest_framework() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_path = temp_dir.path().join("gtest-project");
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args([
+        "--name",
+        "gtest-project",
+        "--project-type",
+        "executable",
+        "--test-framework",
+        "gtest",
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+
+    cmd.assert().success();
+
+    assert!(project_path.join("tests").exists());
+    assert!(project_path.join("tests/main_test.cpp").exists());
+}
+
+#[test]
+fn test_catch2_framework() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_path = temp_dir.path().join("catch2-project");
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args([
+        "--name",
+        "catch2-project",
+        "--project-type",
+        "executable",
+        "--test-framework",
+        "catch2",
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+
+    cmd.assert().success();
+
+    assert!(project_path.join("tests").exists());
+    assert!(project_path.join("tests/main_test.cpp").exists());
+}
+
+#[test]
+fn test_boosttest_framework() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_path = temp_dir.path().join("boost-project");
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args([
+        "--name",
+        "boost-project",
+        "--project-type",
+        "executable",
+        "--test-framework",
+        "boosttest",
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+
+    cmd.assert().success();
+
+    assert!(project_path.join("tests").exists());
+    assert!(project_path.join("tests/main_test.cpp").exists());
+}
+
+// ============================================================================
+// Test Runner Tests
+// ============================================================================
+
+fn create_project_with_test_runner(temp_dir: &TempDir, name: &str, runner: &str) -> String {
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args([
+        "--name",
+        name,
+        "--project-type",
+        "executable",
+        "--test-framework",
+        "doctest",
+        "--test-runner",
+        runner,
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+
+    cmd.assert().success();
+
+    fs::read_to_string(temp_dir.path().join(name).join("tests/CMakeLists.txt")).unwrap()
+}
+
+#[test]
+fn test_direct_test_runner() {
+    let temp_dir = TempDir::new().unwrap();
+    let content = create_project_with_test_runner(&temp_dir, "direct-project", "direct");
+
+    assert!(content.contains("add_test(NAME ${PROJECT_NAME}_tests COMMAND ${PROJECT_NAME}_tests)"));
+    assert!(!content.contains("valgrind"));
+    assert!(!content.contains("-fsanitize=address"));
+}
+
+#[test]
+fn test_valgrind_test_runner() {
+    let temp_dir = TempDir::new().unwrap();
+    let content = create_project_with_test_runner(&temp_dir, "valgrind-project", "valgrind");
+
+    assert!(content.contains("find_program(VALGRIND_EXECUTABLE valgrind REQUIRED)"));
+    assert!(content.contains("${VALGRIND_EXECUTABLE} --leak-check=full"));
+}
+
+#[test]
+fn test_asan_test_runner() {
+    let temp_dir = TempDir::new().unwrap();
+    let content = create_project_with_test_runner(&temp_dir, "asan-project", "asan");
+
+    assert!(content.contains("-fsanitize=address"));
+    assert!(content.contains("ASAN_OPTIONS"));
+}
+
+#[test]
+fn test_ctest_wrapper_test_runner() {
+    let temp_dir = TempDir::new().unwrap();
+    let content = create_project_with_test_runner(&temp_dir, "ctest-project", "ctest");
+
+    assert!(content.contains("${CMAKE_CTEST_COMMAND}"));
+    assert!(content.contains("--build-and-test"));
+}
+
+// ============================================================================
+// Package Manager Tests
+// ============================================================================
+
+#[test]
+fn test_conan_package_manager() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_path = temp_dir.path().join("conan-project");
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args([
+        "--name",
+        "conan-project",
+        "--project-type",
+        "executable",
+        "--package-manager",
+        "conan",
+        "--test-framework",
+        "none",
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+
+    cmd.assert().success();
+
+    // Verify Conan configuration file exists
+    let conanfile = fs::read_to_string(project_path.join("conanfile.txt")).unwrap();
+    assert!(conanfile.contains("[generators]\nCMakeDeps\nCMakeToolchain\n"));
+    assert!(conanfile.contains("[layout]\ncmake_layout\n"));
+}
+
+#[test]
+fn test_conan_options() {
+    let temp_dir = TempDir::new().unwrap();
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args([
+        "--name",
+        "conan-options",
+        "--project-type",
+        "executable",
+        "--build-system",
+        "make",
+        "--package-manager",
+        "conan",
+        "--conan-option",
+        "fmt:shared=True",
+        "--conan-option",
+        "zlib/*:fPIC=False",
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+    cmd.assert().success();
+
+    let conanfile =
+        fs::read_to_string(temp_dir.path().join("conan-options/conanfile.txt")).unwrap();
+    assert!(conanfile.contains("[generators]\nMakeDeps\n"));
+    assert!(!conanfile.contains("CMakeDeps"));
+    assert!(conanfile.contains("[options]\nfmt/*:shared=True\nzlib/*:fPIC=False\n"));
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args([
+        "--name",
+        "vcpkg-options",
+        "--project-type",
+        "executable",
+        "--package-manager",
+        "vcpkg",
+        "--conan-option",
+        "fmt:shared=True",
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+    cmd.assert().failure().stderr(predicate::str::contains(
+        "Conan options can only be set with the Conan package manager",
+    ));
+}
+
+#[test]
+fn test_compile_definitions() {
+    let temp_dir = TempDir::new().unwrap();
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args([
+        "--name",
+        "defines-app",
+        "--project-type",
+        "executable",
+        "--define",
+        "USE_SIMD",
+        "--define",
+        "LOG_LEVEL=2",
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+    cmd.assert().success();
+
+    let flags = fs::read_to_string(
+        temp_dir
+            .path()
+            .join("defines-app/cmake/compilation-flags.cmake"),
+    )
+    .unwrap();
+    assert!(flags.contains("add_compile_definitions(\n  \"USE_SIMD\"\n  \"LOG_LEVEL=2\"\n)"));
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args([
+        "--name",
+        "bad-define",
+        "--project-type",
+        "executable",
+        "--define",
+        "log_level=2",
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+    cmd.assert().failure().stderr(predicate::str::contains(
+        "Invalid compile definition 'log_level=2'",
+    ));
+}
+
+#[test]
+fn test_lto() {
+    let temp_dir = TempDir::new().unwrap();
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args([
+        "--name",
+        "lto-app",
+        "--project-type",
+        "executable",
+        "--lto",
+        "--sanitizer",
+        "address",
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+    cmd.assert().success().stderr(predicate::str::contains(
+        "Warning: Link Time Optimization with the address sanitizer preset",
+    ));
+
+    let flags = fs::read_to_string(
+        temp_dir
+            .path()
+            .join("lto-app/cmake/compilation-flags.cmake"),
+    )
+    .unwrap();
+    assert!(flags.contains("include(CheckIPOSupported)"));
+    assert!(flags.contains("set(CMAKE_INTERPROCEDURAL_OPTIMIZATION_RELEASE ON)"));
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args([
+        "--name",
+        "lto-make",
+        "--project-type",
+        "executable",
+        "--build-system",
+        "make",
+        "--lto",
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+    cmd.assert().success();
+    let makefile = fs::read_to_string(temp_dir.path().join("lto-make/Makefile")).unwrap();
+    assert!(makefile.contains("-flto"));
+}
+
+#[test]
+fn test_coverage_services() {
+    let temp_dir = TempDir::new().unwrap();
+
+    for service in ["codecov", "coveralls"] {
+        let name = format!("{}-app", service);
+        let mut cmd = Command::cargo_bin("cppup").unwrap();
+        cmd.args([
+            "--name",
+            &name,
+            "--project-type",
+            "executable",
+            "--coverage-service",
+            service,
+            "--non-interactive",
+            "--path",
+            temp_dir.path().to_str().unwrap(),
+        ]);
+        cmd.assert()
+            .success()
+            .stderr(predicate::str::contains(format!(
+                "Warning: coverage is uploaded to {} from CI",
+                service
+            )));
+
+        let codecov = temp_dir.path().join(&name).join(".github/.codecov.yml");
+        assert_eq!(codecov.is_file(), service == "codecov", "{}", service);
+    }
+
+    let codecov =
+        fs::read_to_string(temp_dir.path().join("codecov-app/.github/.codecov.yml")).unwrap();
+    assert!(codecov.contains("threshold: 1%"));
+}
+
+#[test]
+fn test_coverage_upload_with_github_actions() {
+    let temp_dir = TempDir::new().unwrap();
+
+    for (service, upload) in [
+        ("codecov", "uses: codecov/codecov-action@v4"),
+        ("coveralls", "uses: coverallsapp/github-action@v2"),
+    ] {
+        let name = format!("{}-ci", service);
+        let mut cmd = Command::cargo_bin("cppup").unwrap();
+        cmd.args([
+            "--name",
+            &name,
+            "--project-type",
+            "executable",
+            "--test-framework",
+            "doctest",
+            "--coverage-service",
+            service,
+            "--ci",
+            "github",
+            "--non-interactive",
+            "--path",
+            temp_dir.path().to_str().unwrap(),
+        ]);
+        cmd.assert()
+            .success()
+            .stderr(predicate::str::contains("Warning: coverage").not());
+
+        let workflow =
+            fs::read_to_string(temp_dir.path().join(&name).join(".github/workflows/ci.yml"))
+                .unwrap();
+        assert!(workflow.contains("      CXXFLAGS: --coverage\n      LDFLAGS: --coverage\n"));
+        assert!(
+            workflow.contains("gcovr --root . --filter src/ --filter include/ --xml coverage.xml")
+        );
+        assert!(workflow.contains(upload), "{}", service);
+    }
+
+    let workflow =
+        fs::read_to_string(temp_dir.path().join("codecov-ci/.github/workflows/ci.yml")).unwrap();
+    assert!(workflow.contains("token: ${{ secrets.CODECOV_TOKEN }}"));
+}
+
+#[test]
+fn test_android_target_generates_ndk_preset() {
+    let temp_dir = TempDir::new().unwrap();
+    let ndk = TempDir::new().unwrap();
+    fs::create_dir_all(ndk.path().join("build/cmake")).unwrap();
+    fs::write(ndk.path().join("build/cmake/android.toolchain.cmake"), "").unwrap();
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.env("ANDROID_NDK_HOME", ndk.path()).args([
+        "--name",
+        "native-lib",
+        "--project-type",
+        "library",
+        "--target",
+        "android",
+        "--android-abi",
+        "armeabi-v7a",
+        "--android-api",
+        "28",
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+    cmd.assert().success();
+
+    let project = temp_dir.path().join("native-lib");
+    let presets: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(project.join("CMakePresets.json")).unwrap())
+            .unwrap();
+    let preset = &presets["configurePresets"][0];
+    assert_eq!(preset["name"], "android-armeabi-v7a");
+    assert_eq!(preset["cacheVariables"]["ANDROID_ABI"], "armeabi-v7a");
+    assert_eq!(preset["cacheVariables"]["ANDROID_PLATFORM"], "android-28");
+    let sources = fs::read_to_string(project.join("src/CMakeLists.txt")).unwrap();
+    assert!(!sources.contains("STATIC"));
+    let readme = fs::read_to_string(project.join("README.md")).unwrap();
+    assert!(readme.contains("externalNativeBuild"));
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.env_remove("ANDROID_NDK_HOME").args([
+        "--name",
+        "no-ndk",
+        "--project-type",
+        "library",
+        "--target",
+        "android",
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("ANDROID_NDK_HOME"));
+}
+
+#[cfg(unix)]
+#[test]
+fn test_windows_mingw_target() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let temp_dir = TempDir::new().unwrap();
+    let bin_dir = temp_dir.path().join("bin");
+    fs::create_dir(&bin_dir).unwrap();
+    let compiler = bin_dir.join("x86_64-w64-mingw32-g++");
+    fs::write(&compiler, "#!/bin/sh\n").unwrap();
+    fs::set_permissions(&compiler, fs::Permissions::from_mode(0o755)).unwrap();
+    let path = format!(
+        "{}:{}",
+        bin_dir.display(),
+        std::env::var("PATH").unwrap_or_default()
+    );
+
+    for build_system in ["cmake", "make"] {
+        let name = format!("{}-win-app", build_system);
+        let mut cmd = Command::cargo_bin("cppup").unwrap();
+        cmd.env("PATH", &path).args([
+            "--name",
+            &name,
+            "--project-type",
+            "executable",
+            "--build-system",
+            build_system,
+            "--target",
+            "windows-mingw",
+            "--non-interactive",
+            "--path",
+            temp_dir.path().to_str().unwrap(),
+        ]);
+        cmd.assert().success();
+    }
+
+    let project = temp_dir.path().join("cmake-win-app");
+    let toolchain =
+        fs::read_to_string(project.join("cmake/toolchains/x86_64-w64-mingw32.cmake")).unwrap();
+    assert!(toolchain.contains("set(CMAKE_SYSTEM_NAME Windows)"));
+    assert!(toolchain.contains("set(MINGW_PREFIX x86_64-w64-mingw32)"));
+    assert!(toolchain.contains("set(CMAKE_CXX_COMPILER ${MINGW_PREFIX}-g++)"));
+    let presets: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(project.join("CMakePresets.json")).unwrap())
+            .unwrap();
+    assert_eq!(presets["configurePresets"][0]["name"], "windows-mingw");
+    let flags = fs::read_to_string(project.join("cmake/compilation-flags.cmake")).unwrap();
+    assert!(flags.contains("add_link_options(-static -static-libgcc -static-libstdc++)"));
+
+    let project = temp_dir.path().join("make-win-app");
+    let makefile = fs::read_to_string(project.join("Makefile")).unwrap();
+    assert!(makefile.contains("CXX = x86_64-w64-mingw32-g++\n"));
+    assert!(makefile.contains("EXECUTABLE = make-win-app.exe\n"));
+    assert!(!project
+        .join("cmake/toolchains/x86_64-w64-mingw32.cmake")
+        .exists());
+    let readme = fs::read_to_string(project.join("README.md")).unwrap();
+    assert!(readme.contains("## Building for Windows"));
+}
+
+#[test]
+fn test_macos_bundle_and_universal_preset() {
+    let temp_dir = TempDir::new().unwrap();
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args([
+        "--name",
+        "mac-app",
+        "--project-type",
+        "executable",
+        "--macos-bundle",
+        "--universal",
+        "--git-remote",
+        "git@github.com:me/mac-app.git",
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+    cmd.assert().success();
+
+    let project = temp_dir.path().join("mac-app");
+    let plist = fs::read_to_string(project.join("cmake/Info.plist.in")).unwrap();
+    assert!(plist.contains(
+        "<key>CFBundleIdentifier</key>\n  <string>${MACOSX_BUNDLE_GUI_IDENTIFIER}</string>"
+    ));
+    assert!(plist.contains("<string>${MACOSX_BUNDLE_BUNDLE_VERSION}</string>"));
+    let source = fs::read_to_string(project.join("src/CMakeLists.txt")).unwrap();
+    assert!(source.contains("MACOSX_BUNDLE_GUI_IDENTIFIER \"com.github.me.mac-app\""));
+    assert!(source.contains("MACOSX_BUNDLE_BUNDLE_VERSION \"0.1.0\""));
+
+    let presets: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(project.join("CMakePresets.json")).unwrap())
+            .unwrap();
+    let preset = &presets["configurePresets"][0];
+    assert_eq!(preset["name"], "macos-universal");
+    assert_eq!(
+        preset["cacheVariables"]["CMAKE_OSX_ARCHITECTURES"],
+        "arm64;x86_64"
+    );
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args([
+        "--name",
+        "mac-lib",
+        "--project-type",
+        "library",
+        "--macos-bundle",
+        "--bundle-id",
+        "com.example.lib",
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+    cmd.assert().failure().stderr(predicate::str::contains(
+        "macOS bundles are only available for executable projects",
+    ));
+}
+
+#[test]
+fn test_android_target_rejects_executables() {
+    let temp_dir = TempDir::new().unwrap();
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args([
+        "--name",
+        "android-app",
+        "--project-type",
+        "executable",
+        "--target",
+        "android",
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+    cmd.assert().failure().stderr(predicate::str::contains(
+        "The Android target is only available for library projects",
+    ));
+    assert!(!temp_dir.path().join("android-app").exists());
+}
+
+#[test]
+fn test_dependabot_without_github_actions() {
+    let temp_dir = TempDir::new().unwrap();
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args([
+        "--name",
+        "dependabot-app",
+        "--project-type",
+        "executable",
+        "--dependabot",
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+    cmd.assert().success().stderr(predicate::str::contains(
+        "Warning: Dependabot needs a GitHub Actions pipeline",
+    ));
+
+    assert!(!temp_dir
+        .path()
+        .join("dependabot-app/.github/dependabot.yml")
+        .exists());
+}
+
+#[test]
+fn test_dependabot_with_github_actions() {
+    let temp_dir = TempDir::new().unwrap();
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args([
+        "--name",
+        "dependabot-ci",
+        "--project-type",
+        "executable",
+        "--package-manager",
+        "conan",
+        "--ci",
+        "github",
+        "--dependabot",
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+    cmd.assert()
+        .success()
+        .stderr(predicate::str::contains("Dependabot").not());
+
+    let project = temp_dir.path().join("dependabot-ci");
+    let dependabot = fs::read_to_string(project.join(".github/dependabot.yml")).unwrap();
+    assert!(dependabot.contains(r#"- package-ecosystem: "github-actions""#));
+    assert!(dependabot.contains(r#"- package-ecosystem: "pip""#));
+    let workflow = fs::read_to_string(project.join(".github/workflows/ci.yml")).unwrap();
+    assert!(workflow.contains("conan profile detect"));
+    assert!(workflow.contains("conan install . --build=missing"));
+}
+
+#[test]
+fn test_vcpkg_features() {
+    let temp_dir = TempDir::new().unwrap();
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args([
+        "--name",
+        "vcpkg-features",
+        "--project-type",
+        "executable",
+        "--package-manager",
+        "vcpkg",
+        "--test-framework",
+        "catch2",
+        "--vcpkg-feature",
+        "compression:Compression support:zlib,bzip2",
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+    cmd.assert().success();
+
+    let manifest = fs::read_to_string(temp_dir.path().join("vcpkg-features/vcpkg.json")).unwrap();
+    let json: serde_json::Value = serde_json::from_str(&manifest).unwrap();
+    assert_eq!(
+        json["features"]["compression"],
+        serde_json::json!({
+            "description": "Compression support",
+            "dependencies": ["zlib", "bzip2"]
+        })
+    );
+    assert_eq!(
+        json["features"]["testing"]["dependencies"],
+        serde_json::json!(["catch2"])
+    );
+
+    let cmake = fs::read_to_string(temp_dir.path().join("vcpkg-features/CMakeLists.txt")).unwrap();
+    assert!(cmake.contains("list(APPEND VCPKG_MANIFEST_FEATURES \"testing\")"));
+}
+
+#[test]
+fn test_vcpkg_default_registry() {
+    let temp_dir = TempDir::new().unwrap();
+    let baseline = "3f2c6e8d1a4b5c6d7e8f9a0b1c2d3e4f5a6b7c8d";
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args([
+        "--name",
+        "vcpkg-registry",
+        "--project-type",
+        "executable",
+        "--package-manager",
+        "vcpkg",
+        "--vcpkg-registry-url",
+        "https://github.com/org/vcpkg-registry",
+        "--vcpkg-baseline",
+        baseline,
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+    cmd.assert().success();
+
+    let configuration = fs::read_to_string(
+        temp_dir
+            .path()
+            .join("vcpkg-registry/vcpkg-configuration.json"),
+    )
+    .unwrap();
+    let json: serde_json::Value = serde_json::from_str(&configuration).unwrap();
+    assert_eq!(
+        json["default-registry"],
+        serde_json::json!({
+            "kind": "git",
+            "repository": "https://github.com/org/vcpkg-registry",
+            "baseline": baseline
+        })
+    );
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args([
+        "--name",
+        "no-baseline",
+        "--project-type",
+        "executable",
+        "--package-manager",
+        "vcpkg",
+        "--vcpkg-registry-url",
+        "https://github.com/org/vcpkg-registry",
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+    cmd.assert().success().stderr(predicate::str::contains(
+        "Warning: the vcpkg registry has no baseline",
+    ));
+    assert!(temp_dir
+        .path()
+        .join("no-baseline/vcpkg-configuration.json")
+        .is_file());
+}
+
+#[test]
+fn test_vcpkg_package_manager() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_path = temp_dir.path().join("vcpkg-project");
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args([
+        "--name",
+        "vcpkg-project",
+        "--project-type",
+        "executable",
+        "--package-manager",
+        "vcpkg",
+        "--test-framework",
+        "none",
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+
+    cmd.assert().success();
+
+    // Verify vcpkg configuration file exists
+    assert!(project_path.join("vcpkg.json").exists());
+}
+
+// ============================================================================
+// C++ Standard Tests
+// ============================================================================
+
+#[test]
+fn test_cpp11_standard() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_path = temp_dir.path().join("cpp11-project");
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args([
+        "--name",
+        "cpp11-project",
+        "--project-type",
+        "executable",
+        "--cpp-standard",
+        "11",
+        "--test-framework",
+        "none",
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+
+    cmd.assert().success();
+    assert!(project_path.exists());
+}
+
+#[test]
+fn test_cpp14_standard() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_path = temp_dir.path().join("cpp14-project");
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args([
+        "--name",
+        "cpp14-project",
+        "--project-type",
+        "executable",
+        "--cpp-standard",
+        "14",
+        "--test-framework",
+        "none",
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+
+    cmd.assert().success();
+    assert!(project_path.exists());
+}
+
+#[test]
+fn test_cpp20_standard() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_path = temp_dir.path().join("cpp20-project");
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args([
+        "--name",
+        "cpp20-project",
+        "--project-type",
+        "executable",
+        "--cpp-standard",
+        "20",
+        "--test-framework",
+        "none",
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+
+    cmd.assert().success();
+    assert!(project_path.exists());
+}
+
+#[test]
+fn test_cpp23_standard() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_path = temp_dir.path().join("cpp23-project");
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args([
+        "--name",
+        "cpp23-project",
+        "--project-type",
+        "executable",
+        "--cpp-standard",
+        "23",
+        "--test-framework",
+        "none",
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+
+    cmd.assert().success();
+    assert!(project_path.exists());
+}
+
+// ============================================================================
+// License Tests
+// ============================================================================
+
+fn create_project_with_license(temp: &TempDir, license: &str) -> PathBuf {
+    let name = format!("{}-project", license.to_lowercase());
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args([
+        "--name",
+        &name,
+        "--project-type",
+        "executable",
+        "--license",
+        license,
+        "--test-framework",
+        "none",
+        "--git",
+        "false",
+        "--non-interactive",
+        "--path",
+        temp.path().to_str().unwrap(),
+    ]);
+
+    cmd.assert().success();
+    temp.path().join(name)
+}
+
+/// Checks that `expected_text` appears in both LICENSE and README.md.
+fn test_license_generation(license: &str, expected_text: &str) {
+    let temp_dir = TempDir::new().unwrap();
+    let project_path = create_project_with_license(&temp_dir, license);
+
+    let license_content = fs::read_to_string(project_path.join("LICENSE")).unwrap();
+    assert!(license_content.contains(expected_text), "{}", license);
+    let readme = fs::read_to_string(project_path.join("README.md")).unwrap();
+    assert!(readme.contains(expected_text), "{}", license);
+}
+
+#[test]
+fn test_mit_license() {
+    test_license_generation("MIT", "MIT License");
+}
+
+#[test]
+fn test_apache_license() {
+    test_license_generation("Apache-2.0", "Apache License");
+}
+
+#[test]
+fn test_gpl_license() {
+    test_license_generation("GPL-3.0", "GNU General Public License");
+}
+
+#[test]
+fn test_bsd_license() {
+    test_license_generation("BSD-3-Clause", "BSD 3-Clause License");
+}
+
+#[test]
+fn test_bsl_license() {
+    test_license_generation("BSL-1.0", "Boost Software License");
+}
+
+// ============================================================================
+// Quality Tools and Formatter Tests
+// ============================================================================
+
+#[test]
+fn test_quality_tools_clang_tidy() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_path = temp_dir.path().join("quality-project");
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args([
+        "--name",
+        "quality-project",
+        "--project-type",
+        "executable",
+        "--quality-tools",
+        "clang-tidy",
+        "--test-framework",
+        "none",
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+
+    cmd.assert().success();
+
+    // Verify quality tool configuration file exists
+    assert!(project_path.join(".clang-tidy").exists());
+}
+
+#[test]
+fn test_clang_tidy_check_categories() {
+    let temp_dir = TempDir::new().unwrap();
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args([
+        "--name",
+        "tidy-project",
+        "--project-type",
+        "executable",
+        "--quality-tools",
+        "clang-tidy",
+        "--clang-tidy-checks",
+        "modernize,bugprone",
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+    cmd.assert().success();
+
+    let clang_tidy = fs::read_to_string(temp_dir.path().join("tidy-project/.clang-tidy")).unwrap();
+    assert!(clang_tidy.contains("Checks: \"modernize-*,bugprone-*\"\n"));
+    assert!(!clang_tidy.contains("google-*"));
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args([
+        "--name",
+        "no-tidy-project",
+        "--project-type",
+        "executable",
+        "--clang-tidy-checks",
+        "cert",
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+    cmd.assert().failure().stderr(predicate::str::contains(
+        "clang-tidy check categories require the clang-tidy quality tool",
+    ));
+}
+
+#[test]
+fn test_quality_tools_cppcheck() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_path = temp_dir.path().join("cppcheck-project");
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args([
+        "--name",
+        "cppcheck-project",
+        "--project-type",
+        "executable",
+        "--quality-tools",
+        "cppcheck",
+        "--test-framework",
+        "none",
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+
+    cmd.assert().success();
+
+    assert!(project_path.join("cppcheck-suppressions.xml").exists());
+}
+
+#[test]
+fn test_code_formatter_clang_format() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_path = temp_dir.path().join("format-project");
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args([
+        "--name",
+        "format-project",
+        "--project-type",
+        "executable",
+        "--code-formatter",
+        "clang-format",
+        "--test-framework",
+        "none",
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+
+    cmd.assert().success();
+
+    assert!(project_path.join(".clang-format").exists());
+}
+
+#[test]
+fn test_code_formatter_cmake_format() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_path = temp_dir.path().join("cmake-format-project");
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args([
+        "--name",
+        "cmake-format-project",
+        "--project-type",
+        "executable",
+        "--code-formatter",
+        "cmake-format",
+        "--test-framework",
+        "none",
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+
+    cmd.assert().success();
+
+    assert!(project_path.join("cmake-format.yaml").exists());
+}
+
+#[test]
+fn test_code_formatter_cmake_lint() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_path = temp_dir.path().join("cmake-lint-project");
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args([
+        "--name",
+        "cmake-lint-project",
+        "--project-type",
+        "executable",
+        "--code-formatter",
+        "cmake-lint",
+        "--test-framework",
+        "none",
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+
+    cmd.assert().success();
+
+    let content = fs::read_to_string(project_path.join(".cmake-lint.yml")).unwrap();
+    assert!(content.contains("line_width: 120"));
+    assert!(content.contains("command_case: lower"));
+    assert!(content.contains("local_var_pattern"));
+    assert!(!project_path.join("cmake-format.yaml").exists());
+}
+
+// ============================================================================
+// IDE Tests
+// ============================================================================
+
+#[test]
+fn test_ide_clion() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_path = temp_dir.path().join("clion-project");
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args([
+        "--name",
+        "clion-project",
+        "--project-type",
+        "executable",
+        "--ide",
+        "clion",
+        "--test-framework",
+        "none",
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+
+    cmd.assert().success();
+
+    let cmake_xml = fs::read_to_string(project_path.join(".idea/cmake.xml")).unwrap();
+    assert!(cmake_xml.contains("PROFILE_NAME=\"Debug\""));
+    assert!(cmake_xml.contains("PROFILE_NAME=\"Release\""));
+
+    let run_xml =
+        fs::read_to_string(project_path.join(".idea/runConfigurations/clion-project.xml")).unwrap();
+    assert!(run_xml.contains("build/Debug/bin/clion-project"));
+}
+
+#[test]
+fn test_ide_clion_requires_cmake() {
+    let temp_dir = TempDir::new().unwrap();
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args([
+        "--name",
+        "clion-make-project",
+        "--project-type",
+        "executable",
+        "--build-system",
+        "make",
+        "--ide",
+        "clion",
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+
+    cmd.assert().failure();
+}
+
+#[test]
+fn test_ide_kdevelop() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_path = temp_dir.path().join("kdevelop-project");
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args([
+        "--name",
+        "kdevelop-project",
+        "--project-type",
+        "executable",
+        "--ide",
+        "kdevelop",
+        "--test-framework",
+        "none",
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+
+    cmd.assert().success();
+
+    let project_file = fs::read_to_string(project_path.join("kdevelop-project.kdev4")).unwrap();
+    assert!(project_file.contains("Manager=KDevCMakeManager"));
+    assert!(project_file.contains("Name=kdevelop-project"));
+
+    let builddir_file =
+        fs::read_to_string(project_path.join(".kdev4/kdevelop-project.kdev4")).unwrap();
+    assert!(builddir_file.contains("Build Directory Path=build"));
+}
+
+#[test]
+fn test_dot_notation_library() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_path = temp_dir.path().join("acme.networking.http");
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args([
+        "--name",
+        "acme.networking.http",
+        "--project-type",
+        "library",
+        "--test-framework",
+        "gtest",
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+
+    cmd.assert().success();
+
+    let header = fs::read_to_string(project_path.join("include/acme/networking/http.hpp")).unwrap();
+    assert!(header.contains("namespace acme { namespace networking { namespace http {"));
+
+    let cmake_content = fs::read_to_string(project_path.join("CMakeLists.txt")).unwrap();
+    assert!(cmake_content.contains("project(acme_networking_http LANGUAGES CXX)"));
+
+    let lib_content = fs::read_to_string(project_path.join("src/lib.cpp")).unwrap();
+    assert!(lib_content.contains("#include \"acme/networking/http.hpp\""));
+
+    let test_content = fs::read_to_string(project_path.join("tests/main_test.cpp")).unwrap();
+    assert!(test_content.contains("TEST(acme_networking_httpTest, Calculator)"));
+    assert!(test_content.contains("using namespace acme::networking::http;"));
+}
+
+#[test]
+fn test_template_var_override() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_path = temp_dir.path().join("template-var-project");
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args([
+        "--name",
+        "template-var-project",
+        "--project-type",
+        "executable",
+        "--package-manager",
+        "vcpkg",
+        "--template-var",
+        "description=Overridden via template var",
+        "--template-var",
+        "company=Acme",
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+
+    cmd.assert().success().stderr(predicate::str::contains(
+        "template variable 'description' overrides a built-in template value",
+    ));
+
+    let vcpkg_json = fs::read_to_string(project_path.join("vcpkg.json")).unwrap();
+    assert!(vcpkg_json.contains("\"description\": \"Overridden via template var\""));
+}
+
+#[test]
+fn test_template_var_invalid_format() {
+    let temp_dir = TempDir::new().unwrap();
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args([
+        "--name",
+        "bad-template-var",
+        "--project-type",
+        "executable",
+        "--template-var",
+        "missing-separator",
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("expected KEY=VALUE"));
+}
+
+#[test]
+fn test_invalid_dot_notation_name() {
+    let temp_dir = TempDir::new().unwrap();
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args([
+        "--name",
+        "acme..http",
+        "--project-type",
+        "library",
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("consecutive dots"));
+}
+
+#[test]
+fn test_cmake_generator_ninja_multi_config() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_path = temp_dir.path().join("multi-config-project");
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args([
+        "--name",
+        "multi-config-project",
+        "--project-type",
+        "executable",
+        "--cmake-generator",
+        "ninja-multi-config",
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+
+    cmd.assert().success();
+
+    let presets = fs::read_to_string(project_path.join("CMakePresets.json")).unwrap();
+    assert!(presets.contains("\"generator\": \"Ninja Multi-Config\""));
+    assert!(presets.contains("\"configuration\": \"Debug\""));
+    assert!(presets.contains("\"configuration\": \"Release\""));
+}
+
+#[test]
+fn test_cmake_generator_default_has_no_presets() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_path = temp_dir.path().join("default-generator-project");
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args([
+        "--name",
+        "default-generator-project",
+        "--project-type",
+        "executable",
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+
+    cmd.assert().success();
+    assert!(!project_path.join("CMakePresets.json").exists());
+}
+
+#[test]
+fn test_cmake_generator_requires_cmake() {
+    let temp_dir = TempDir::new().unwrap();
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args([
+        "--name",
+        "make-generator-project",
+        "--project-type",
+        "executable",
+        "--build-system",
+        "make",
+        "--cmake-generator",
+        "ninja",
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+
+    cmd.assert().failure().stderr(predicate::str::contains(
+        "A CMake generator can only be selected with the CMake build system",
+    ));
+}
+
+#[test]
+fn test_ide_emacs() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_path = temp_dir.path().join("emacs-project");
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args([
+        "--name",
+        "emacs-project",
+        "--project-type",
+        "executable",
+        "--ide",
+        "emacs",
+        "--code-formatter",
+        "clang-format",
+        "--test-framework",
+        "none",
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+
+    cmd.assert().success();
+
+    let dir_locals = fs::read_to_string(project_path.join(".dir-locals.el")).unwrap();
+    assert!(dir_locals.contains("lsp-clients-clangd-args"));
+    assert!(dir_locals.contains("--compile-commands-dir=build"));
+    assert!(dir_locals.contains("clang-format-buffer"));
+}
+
+#[test]
+fn test_ide_sublime() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_path = temp_dir.path().join("sublime-project");
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args([
+        "--name",
+        "sublime-project",
+        "--project-type",
+        "executable",
+        "--build-system",
+        "make",
+        "--ide",
+        "sublime",
+        "--test-framework",
+        "none",
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+
+    cmd.assert().success();
+
+    let project_file =
+        fs::read_to_string(project_path.join("sublime-project.sublime-project")).unwrap();
+    assert!(project_file.contains("\"folders\""));
+    assert!(project_file.contains("\"build_systems\""));
+    assert!(project_file.contains("\"shell_cmd\": \"make\""));
+    assert!(project_file.contains("\"file_exclude_patterns\""));
+    assert!(project_file.contains("\"settings\""));
+}
+
+// ============================================================================
+// Git Tests
+// ============================================================================
+
+#[test]
+fn test_git_initialization() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_path = temp_dir.path().join("git-project");
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args([
+        "--name",
+        "git-project",
+        "--project-type",
+        "executable",
+        "--git",
+        "--test-framework",
+        "none",
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+
+    cmd.assert().success();
+
+    // Verify git repository and .gitignore exist
+    assert!(project_path.join(".git").exists());
+    assert!(project_path.join(".gitignore").exists());
+}
+
+// ============================================================================
+// Post-Generation Hook Tests
+// ============================================================================
+
+#[cfg(unix)]
+#[test]
+fn test_post_gen_hooks_run_after_git_init() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_path = temp_dir.path().join("hook-project");
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args([
+        "--name",
+        "hook-project",
+        "--project-type",
+        "executable",
+        "--build-system",
+        "make",
+        "--test-framework",
+        "none",
+        "--git",
+        "true",
+        "--post-gen",
+        "test -d .git && echo \"$CPPUP_PROJECT_NAME $CPPUP_PROJECT_PATH $CPPUP_BUILD_SYSTEM\" > marker",
+        "--post-gen",
+        "touch second",
+        "--verbose",
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Ran `sh -c touch second`"));
+
+    let marker = fs::read_to_string(project_path.join("marker")).unwrap();
+    assert_eq!(
+        marker.trim(),
+        format!("hook-project {} make", project_path.display())
+    );
+    assert!(project_path.join("second").exists());
+}
+
+#[cfg(unix)]
+#[test]
+fn test_failing_post_gen_hook_warns() {
+    let temp_dir = TempDir::new().unwrap();
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args([
+        "--name",
+        "hook-project",
+        "--project-type",
+        "executable",
+        "--git",
+        "false",
+        "--post-gen",
+        "echo bootstrap broke >&2; exit 3",
+        "--post-gen",
+        "touch after",
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+
+    cmd.assert()
+        .success()
+        .stderr(predicate::str::contains(
+            "Warning: post-generation hook `echo bootstrap broke >&2; exit 3` failed with exit code 3:\nbootstrap broke",
+        ));
+    assert!(temp_dir.path().join("hook-project/after").exists());
+}
+
+#[cfg(unix)]
+#[test]
+fn test_failing_post_gen_hook_is_fatal_when_strict() {
+    let temp_dir = TempDir::new().unwrap();
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args([
+        "--name",
+        "hook-project",
+        "--project-type",
+        "executable",
+        "--git",
+        "false",
+        "--post-gen",
+        "exit 1",
+        "--post-gen",
+        "touch after",
+        "--post-gen-strict",
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+
+    cmd.assert().code(5).stderr(predicate::str::contains(
+        "post-generation hook `exit 1` failed with exit code 1",
+    ));
+    let project_path = temp_dir.path().join("hook-project");
+    assert!(project_path.join("CMakeLists.txt").exists());
+    assert!(!project_path.join("after").exists());
+}
+
+#[cfg(unix)]
+#[test]
+fn test_pack_hooks_only_run_when_allowed() {
+    let temp_dir = TempDir::new().unwrap();
+    let pack_dir = temp_dir.path().join("pack");
+    fs::create_dir_all(&pack_dir).unwrap();
+    fs::write(
+        pack_dir.join("pack.toml"),
+        "post_gen = [\"touch pack-hook\"]\n",
+    )
+    .unwrap();
+
+    for (name, allow) in [("skipped-project", false), ("allowed-project", true)] {
+        let mut cmd = Command::cargo_bin("cppup").unwrap();
+        cmd.args([
+            "--name",
+            name,
+            "--project-type",
+            "executable",
+            "--git",
+            "false",
+            "--template-pack",
+            pack_dir.to_str().unwrap(),
+            "--non-interactive",
+            "--path",
+            temp_dir.path().to_str().unwrap(),
+        ]);
+        if allow {
+            cmd.arg("--allow-pack-hooks");
+        }
+
+        let assert = cmd.assert().success();
+        let marker = temp_dir.path().join(name).join("pack-hook");
+        if allow {
+            assert!(marker.exists());
+        } else {
+            assert.stderr(predicate::str::contains(
+                "pass --allow-pack-hooks to run them:\n  touch pack-hook",
+            ));
+            assert!(!marker.exists());
+        }
+    }
+}
+
+// ============================================================================
+// Error Condition Tests
+// ============================================================================
+
+#[test]
+fn test_invalid_project_name() {
+    let temp_dir = TempDir::new().unwrap();
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args([
+        "--name",
+        "123invalid",
+        "--project-type",
+        "executable",
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+
+    cmd.assert().failure();
+}
+
+#[test]
+fn test_project_name_with_spaces() {
+    let temp_dir = TempDir::new().unwrap();
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args([
+        "--name",
+        "invalid name",
+        "--project-type",
+        "executable",
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+
+    cmd.assert().failure();
+}
+
+#[test]
+fn test_project_name_with_special_chars() {
+    let temp_dir = TempDir::new().unwrap();
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args([
+        "--name",
+        "invalid@project!",
+        "--project-type",
+        "executable",
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+
+    cmd.assert().failure();
+}
+
+#[test]
+fn test_description_too_long() {
+    let temp_dir = TempDir::new().unwrap();
+    let description = "a".repeat(501);
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args([
+        "--name",
+        "long-description",
+        "--description",
+        &description,
+        "--project-type",
+        "executable",
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("Description is too long"));
+}
+
+#[test]
+fn test_empty_author() {
+    let temp_dir = TempDir::new().unwrap();
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args([
+        "--name",
+        "empty-author",
+        "--author",
+        "   ",
+        "--project-type",
+        "executable",
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+
+    cmd.assert()
+        .code(3)
+        .stderr(predicate::str::contains("Author cannot be empty"));
+}
+
+#[test]
+fn test_missing_required_name() {
+    let temp_dir = TempDir::new().unwrap();
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args([
+        "--project-type",
+        "executable",
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+
+    cmd.assert()
+        .code(2)
+        .stderr(predicate::str::contains("Project name is required"));
+}
+
+#[test]
+fn test_missing_required_project_type() {
+    let temp_dir = TempDir::new().unwrap();
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args([
+        "--name",
+        "test-project",
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+
+    cmd.assert().code(2);
+}
+
+#[test]
+fn test_non_interactive_reads_environment() {
+    let temp_dir = TempDir::new().unwrap();
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.env("CPPUP_NAME", "env-app")
+        .env("CPPUP_PROJECT_TYPE", "executable")
+        .env("CPPUP_BUILD_SYSTEM", "make")
+        .env("CPPUP_PATH", temp_dir.path())
+        .arg("--non-interactive");
+    cmd.assert().success();
+    assert!(temp_dir.path().join("env-app/Makefile").is_file());
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.env("CPPUP_NAME", "env-app")
+        .env_remove("CPPUP_PROJECT_TYPE")
+        .arg("--non-interactive");
+    cmd.assert().code(2).stderr(predicate::str::contains(
+        "Missing environment variables: CPPUP_PROJECT_TYPE",
+    ));
+}
+
+#[test]
+fn test_duplicate_project_creation() {
+    let temp_dir = TempDir::new().unwrap();
+
+    // Create first project successfully
+    let mut cmd1 = Command::cargo_bin("cppup").unwrap();
+    cmd1.args([
+        "--name",
+        "duplicate-project",
+        "--project-type",
+        "executable",
+        "--test-framework",
+        "none",
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+    cmd1.assert().success();
+
+    // Try to create the same project again - should fail
+    let mut cmd2 = Command::cargo_bin("cppup").unwrap();
+    cmd2.args([
+        "--name",
+        "duplicate-project",
+        "--project-type",
+        "executable",
+        "--test-framework",
+        "none",
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+    cmd2.assert()
+        .code(4)
+        .stderr(predicate::str::contains("Project directory already exists"));
+}
+
+// ============================================================================
+// Complex Integration Tests
+// ============================================================================
+
+#[test]
+fn test_full_featured_project() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_path = temp_dir.path().join("full-project");
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args([
+        "--name",
+        "full-project",
+        "--description",
+        "A fully featured test project",
+        "--author",
+        "Test Author",
+        "--project-type",
+        "library",
+        "--build-system",
+        "cmake",
+        "--cpp-standard",
+        "20",
+        "--package-manager",
+        "conan",
+        "--test-framework",
+        "doctest",
+        "--license",
+        "MIT",
+        "--quality-tools",
+        "clang-tidy,cppcheck",
+        "--code-formatter",
+        "clang-format",
+        "--git",
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+
+    cmd.assert().success();
+
+    // Verify all expected files exist
+    assert!(project_path.join("src/lib.cpp").exists());
+    assert!(project_path.join("include/full-project.hpp").exists());
+    assert!(project_path.join("examples").exists());
+    assert!(project_path.join("tests").exists());
+    assert!(project_path.join("CMakeLists.txt").exists());
+    assert!(project_path.join("conanfile.txt").exists());
+    assert!(project_path.join(".clang-tidy").exists());
+    assert!(project_path.join("cppcheck-suppressions.xml").exists());
+    assert!(project_path.join(".clang-format").exists());
+    assert!(project_path.join(".gitignore").exists());
+    assert!(project_path.join("LICENSE").exists());
+    assert!(project_path.join("README.md").exists());
+}
+
+#[test]
+fn test_full_featured_make_gtest_vcpkg() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_path = temp_dir.path().join("full-make-project");
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args([
+        "--name",
+        "full-make-project",
+        "--project-type",
+        "library",
+        "--build-system",
+        "make",
+        "--cpp-standard",
+        "17",
+        "--test-framework",
+        "gtest",
+        "--package-manager",
+        "vcpkg",
+        "--quality-tools",
+        "clang-tidy,cppcheck",
+        "--code-formatter",
+        "clang-format",
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+
+    cmd.assert().success();
+
+    assert!(project_path.join("Makefile").exists());
+    assert!(project_path.join("vcpkg.json").exists());
+    assert!(project_path.join(".clang-tidy").exists());
+    assert!(project_path.join("cppcheck-suppressions.xml").exists());
+    assert!(project_path.join(".clang-format").exists());
+    assert!(project_path.join("tests/main_test.cpp").exists());
+    assert!(project_path.join("README.md").exists());
+
+    let makefile = fs::read_to_string(project_path.join("Makefile")).unwrap();
+    assert!(!makefile.to_lowercase().contains("cmake"));
+    let test_main = fs::read_to_string(project_path.join("tests/main_test.cpp")).unwrap();
+    assert!(test_main.contains("gtest"));
+}
+
+#[test]
+fn test_executable_with_make_and_tests() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_path = temp_dir.path().join("make-test-project");
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args([
+        "--name",
+        "make-test-project",
+        "--project-type",
+        "executable",
+        "--build-system",
+        "make",
+        "--cpp-standard",
+        "17",
+        "--test-framework",
+        "catch2",
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+
+    cmd.assert().success();
+
+    assert!(project_path.join("Makefile").exists());
+    assert!(project_path.join("tests").exists());
+    assert!(project_path.join("src/main.cpp").exists());
+}
+
+#[test]
+fn test_preset_minimal() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_path = temp_dir.path().join("x");
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args([
+        "--preset",
+        "minimal",
+        "--name",
+        "x",
+        "--yes",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+
+    cmd.assert().success();
+
+    assert!(project_path.join("CMakeLists.txt").exists());
+    assert!(project_path.join("src/main.cpp").exists());
+    assert!(!project_path.join("tests").exists());
+    assert!(!project_path.join(".git").exists());
+    assert!(!project_path.join(".clang-format").exists());
+}
+
+#[test]
+fn test_preset_flags_take_precedence() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_path = temp_dir.path().join("preset-override");
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args([
+        "--preset",
+        "recommended",
+        "--name",
+        "preset-override",
+        "--test-framework",
+        "none",
+        "--git",
+        "false",
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+
+    cmd.assert().success();
+
+    assert!(project_path.join(".clang-format").exists());
+    assert!(project_path.join("CMakePresets.json").exists());
+    assert!(!project_path.join("tests").exists());
+}
+
+#[test]
+fn test_user_template_override() {
+    let temp_dir = TempDir::new().unwrap();
+    let config_dir = temp_dir.path().join("config");
+    let templates_dir = config_dir.join("cppup/templates");
+    fs::create_dir_all(&templates_dir).unwrap();
+    fs::write(
+        templates_dir.join("main.cpp.hbs"),
+        "// CUSTOM-MARKER for {{name}}\nint main() { return 0; }\n",
+    )
+    .unwrap();
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.env("XDG_CONFIG_HOME", &config_dir).args([
+        "--name",
+        "override-project",
+        "--project-type",
+        "executable",
+        "--non-interactive",
+        "--verbose",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Using user template"));
+
+    let main_cpp =
+        fs::read_to_string(temp_dir.path().join("override-project/src/main.cpp")).unwrap();
+    assert!(main_cpp.contains("CUSTOM-MARKER for override-project"));
+}
+
+#[test]
+fn test_render_failure_leaves_no_project_directory() {
+    let temp_dir = TempDir::new().unwrap();
+    let config_dir = temp_dir.path().join("config");
+    let licenses_dir = config_dir.join("cppup/templates/licenses");
+    fs::create_dir_all(&licenses_dir).unwrap();
+    // Parses fine but fails at render time, after most files were rendered
+    fs::write(licenses_dir.join("MIT.hbs"), "{{> missing_partial}}\n").unwrap();
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.env("XDG_CONFIG_HOME", &config_dir).args([
+        "--name",
+        "broken-project",
+        "--project-type",
+        "executable",
+        "--license",
+        "MIT",
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("Failed to render template MIT"));
+
+    assert!(!temp_dir.path().join("broken-project").exists());
+}
+
+#[test]
+fn test_no_user_templates_flag() {
+    let temp_dir = TempDir::new().unwrap();
+    let config_dir = temp_dir.path().join("config");
+    let templates_dir = config_dir.join("cppup/templates");
+    fs::create_dir_all(&templates_dir).unwrap();
+    fs::write(templates_dir.join("main.cpp.hbs"), "// CUSTOM-MARKER\n").unwrap();
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.env("XDG_CONFIG_HOME", &config_dir).args([
+        "--name",
+        "embedded-project",
+        "--project-type",
+        "executable",
+        "--non-interactive",
+        "--no-user-templates",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+
+    cmd.assert().success();
+
+    let main_cpp =
+        fs::read_to_string(temp_dir.path().join("embedded-project/src/main.cpp")).unwrap();
+    assert!(!main_cpp.contains("CUSTOM-MARKER"));
+}
+
+#[test]
+fn test_invalid_user_template() {
+    let temp_dir = TempDir::new().unwrap();
+    let config_dir = temp_dir.path().join("config");
+    let templates_dir = config_dir.join("cppup/templates");
+    fs::create_dir_all(&templates_dir).unwrap();
+    fs::write(templates_dir.join("main.cpp.hbs"), "{{#if name}}").unwrap();
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.env("XDG_CONFIG_HOME", &config_dir).args([
+        "--name",
+        "broken-project",
+        "--project-type",
+        "executable",
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("main.cpp.hbs"));
+    assert!(!temp_dir.path().join("broken-project").exists());
+}
+
+#[test]
+fn test_template_version_from_config_dir() {
+    let temp_dir = TempDir::new().unwrap();
+    let config_dir = temp_dir.path().join("config");
+    let versioned_dir = config_dir.join("cppup/templates/0.9");
+    fs::create_dir_all(&versioned_dir).unwrap();
+    fs::write(
+        versioned_dir.join("main.cpp.hbs"),
+        "// OLD-TEMPLATES for {{name}}\nint main() { return 0; }\n",
+    )
+    .unwrap();
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.env("XDG_CONFIG_HOME", &config_dir).args([
+        "--name",
+        "versioned-project",
+        "--project-type",
+        "executable",
+        "--template-version",
+        "0.9",
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+
+    cmd.assert().success();
+
+    let project_path = temp_dir.path().join("versioned-project");
+    let main_cpp = fs::read_to_string(project_path.join("src/main.cpp")).unwrap();
+    assert!(main_cpp.contains("OLD-TEMPLATES for versioned-project"));
+    let metadata = fs::read_to_string(project_path.join(".cppup.json")).unwrap();
+    let metadata: serde_json::Value = serde_json::from_str(&metadata).unwrap();
+    assert_eq!(metadata["template_version"], "0.9");
+}
+
+#[test]
+fn test_missing_template_version() {
+    let temp_dir = TempDir::new().unwrap();
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.env("XDG_CONFIG_HOME", temp_dir.path().join("config"))
+        .args([
+            "--name",
+            "versioned-project",
+            "--project-type",
+            "executable",
+            "--template-version",
+            "0.9",
+            "--non-interactive",
+            "--path",
+            temp_dir.path().to_str().unwrap(),
+        ]);
+
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("cppup templates download 0.9"));
+    assert!(!temp_dir.path().join("versioned-project").exists());
+}
+
+#[test]
+fn test_template_pack_from_directory() {
+    let temp_dir = TempDir::new().unwrap();
+    let pack_dir = temp_dir.path().join("pack");
+    fs::create_dir_all(pack_dir.join("templates")).unwrap();
+    fs::write(
+        pack_dir.join("pack.toml"),
+        r#"
+[[template]]
+name = "copyright"
+file = "templates/copyright.hbs"
+
+[[output]]
+template = "copyright"
+path = "docs/COPYRIGHT.md"
+
+[[variable]]
+name = "company"
+prompt = "Company name"
+
+[[variable]]
+name = "team"
+prompt = "Team"
+default = "platform"
+"#,
+    )
+    .unwrap();
+    fs::write(
+        pack_dir.join("templates/copyright.hbs"),
+        "Copyright {{company}} ({{team}}) - {{name}}\n",
+    )
+    .unwrap();
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args([
+        "--name",
+        "pack-project",
+        "--project-type",
+        "executable",
+        "--template-pack",
+        pack_dir.to_str().unwrap(),
+        "--template-var",
+        "company=Acme",
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+
+    cmd.assert().success();
+
+    let copyright =
+        fs::read_to_string(temp_dir.path().join("pack-project/docs/COPYRIGHT.md")).unwrap();
+    assert_eq!(copyright, "Copyright Acme (platform) - pack-project\n");
+    assert!(temp_dir.path().join("pack-project/CMakeLists.txt").exists());
+}
+
+/// Writes a template pack whose output collides with the generated `src`
+/// directory, so writing fails after other files exist.
+fn write_colliding_pack(pack_dir: &std::path::Path) {
+    fs::create_dir_all(pack_dir.join("templates")).unwrap();
+    fs::write(
+        pack_dir.join("pack.toml"),
+        r#"
+[[template]]
+name = "collide"
+file = "templates/collide.hbs"
+
+[[output]]
+template = "collide"
+path = "src"
+"#,
+    )
+    .unwrap();
+    fs::write(pack_dir.join("templates/collide.hbs"), "{{name}}\n").unwrap();
+}
+
+#[test]
+fn test_create_path_creates_missing_parents() {
+    let temp_dir = TempDir::new().unwrap();
+    let parent = temp_dir.path().join("src/sandbox/2025");
+    let args = [
+        "--name",
+        "demo",
+        "--project-type",
+        "executable",
+        "--non-interactive",
+        "--path",
+        parent.to_str().unwrap(),
+    ];
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args(args);
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("Directory doesn't exist"));
+    assert!(!temp_dir.path().join("src").exists());
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args(args).arg("--create-path");
+    cmd.assert().success();
+    assert!(parent.join("demo/CMakeLists.txt").exists());
+}
+
+#[test]
+fn test_failed_generation_removes_created_parents() {
+    let temp_dir = TempDir::new().unwrap();
+    let pack_dir = temp_dir.path().join("pack");
+    write_colliding_pack(&pack_dir);
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args([
+        "--name",
+        "rollback-project",
+        "--project-type",
+        "executable",
+        "--template-pack",
+        pack_dir.to_str().unwrap(),
+        "--non-interactive",
+        "--create-path",
+        "--path",
+        temp_dir.path().join("new/parent").to_str().unwrap(),
+    ]);
+
+    cmd.assert().failure();
+    assert!(!temp_dir.path().join("new").exists());
+}
+
+#[test]
+fn test_failed_generation_removes_project_directory() {
+    let temp_dir = TempDir::new().unwrap();
+    let pack_dir = temp_dir.path().join("pack");
+    write_colliding_pack(&pack_dir);
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args([
+        "--name",
+        "rollback-project",
+        "--project-type",
+        "executable",
+        "--template-pack",
+        pack_dir.to_str().unwrap(),
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+
+    cmd.assert()
+        .code(5)
+        .stderr(predicate::str::contains("Failed to write file"));
+
+    assert!(!temp_dir.path().join("rollback-project").exists());
+}
+
+#[test]
+fn test_keep_on_failure_leaves_partial_project() {
+    let temp_dir = TempDir::new().unwrap();
+    let pack_dir = temp_dir.path().join("pack");
+    write_colliding_pack(&pack_dir);
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args([
+        "--name",
+        "kept-project",
+        "--project-type",
+        "executable",
+        "--template-pack",
+        pack_dir.to_str().unwrap(),
+        "--keep-on-failure",
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Keeping the partially created project",
+        ))
+        .stderr(predicate::str::contains("src/main.cpp"));
+
+    assert!(temp_dir.path().join("kept-project/src/main.cpp").exists());
+}
+
+#[test]
+fn test_template_pack_missing_variable() {
+    let temp_dir = TempDir::new().unwrap();
+    let pack_dir = temp_dir.path().join("pack");
+    fs::create_dir_all(&pack_dir).unwrap();
+    fs::write(
+        pack_dir.join("pack.toml"),
+        "[[variable]]\nname = \"company\"\nprompt = \"Company name\"\n",
+    )
+    .unwrap();
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args([
+        "--name",
+        "pack-missing-var",
+        "--project-type",
+        "executable",
+        "--template-pack",
+        pack_dir.to_str().unwrap(),
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("--template-var company=VALUE"));
+    assert!(!temp_dir.path().join("pack-missing-var").exists());
+}
+
+#[test]
+fn test_unicode_path_handling() {
+    let temp_dir = TempDir::new().unwrap();
+    let parent = temp_dir.path().join("プロジェクト").join("Проекты");
+    fs::create_dir_all(&parent).unwrap();
+    let project_path = parent.join("unicode-project");
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args(["--name", "unicode-project", "--project-type", "library"])
+        .args(["--test-framework", "doctest", "--ide", "clion,sublime"])
+        .arg("--non-interactive")
+        .arg("--path")
+        .arg(&parent);
+
+    cmd.assert().success();
+
+    assert!(project_path.join("CMakeLists.txt").exists());
+    assert!(project_path.join("include/unicode-project.hpp").exists());
+    assert!(project_path.join("tests/main_test.cpp").exists());
+    assert!(project_path
+        .join(".idea/runConfigurations/unicode-project.xml")
+        .exists());
+}
+
+#[cfg(unix)]
+#[test]
+fn test_non_utf8_path_does_not_panic() {
+    use std::ffi::OsStr;
+    use std::os::unix::ffi::OsStrExt;
+
+    let temp_dir = TempDir::new().unwrap();
+    let parent = temp_dir.path().join(OsStr::from_bytes(b"non-utf8-\xff"));
+    if fs::create_dir_all(&parent).is_err() {
+        // Some filesystems reject invalid UTF-8 names outright
+        return;
+    }
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args(["--name", "bytes-project", "--project-type", "executable"])
+        .arg("--non-interactive")
+        .arg("--path")
+        .arg(&parent);
+
+    let output = cmd.output().unwrap();
+    // Either the project is created or a clear error is reported; a panic
+    // exits with code 101
+    assert_ne!(output.status.code(), Some(101));
+    if output.status.success() {
+        assert!(parent.join("bytes-project/src/main.cpp").exists());
+    } else {
+        assert!(String::from_utf8_lossy(&output.stderr).contains("Error"));
+    }
+}
+
+/// Documents the generation time baseline; run with
+/// `cargo test -- --include-ignored`.
+#[test]
+#[ignore]
+fn test_full_project_generation_performance() {
+    use cppup::project::{
+        BuildSystem, CodeFormatter, IdeConfig, PackageManager, ProjectType, QualityConfig,
+        TestFramework,
+    };
+    use cppup::{ProjectBuilder, ProjectConfig};
+    use std::time::{Duration, Instant};
+
+    let temp_dir = TempDir::new().unwrap();
+    let configurations = [
+        (
+            ProjectType::Executable,
+            BuildSystem::CMake,
+            TestFramework::None,
+            PackageManager::None,
+        ),
+        (
+            ProjectType::Library,
+            BuildSystem::CMake,
+            TestFramework::Doctest,
+            PackageManager::None,
+        ),
+        (
+            ProjectType::Executable,
+            BuildSystem::CMake,
+            TestFramework::GTest,
+            PackageManager::Vcpkg,
+        ),
+        (
+            ProjectType::Library,
+            BuildSystem::CMake,
+            TestFramework::Catch2,
+            PackageManager::Conan,
+        ),
+        (
+            ProjectType::Executable,
+            BuildSystem::CMake,
+            TestFramework::BoostTest,
+            PackageManager::None,
+        ),
+        (
+            ProjectType::Executable,
+            BuildSystem::Make,
+            TestFramework::None,
+            PackageManager::None,
+        ),
+        (
+            ProjectType::Library,
+            BuildSystem::Make,
+            TestFramework::Doctest,
+            PackageManager::None,
+        ),
+        (
+            ProjectType::Executable,
+            BuildSystem::Meson,
+            TestFramework::None,
+            PackageManager::None,
+        ),
+        (
+            ProjectType::Library,
+            BuildSystem::Meson,
+            TestFramework::Catch2,
+            PackageManager::None,
+        ),
+        (
+            ProjectType::Library,
+            BuildSystem::Meson,
+            TestFramework::Doctest,
+            PackageManager::Vcpkg,
+        ),
+    ];
+
+    for (index, (project_type, build_system, test_framework, package_manager)) in
+        configurations.into_iter().enumerate()
+    {
+        let is_cmake = build_system == BuildSystem::CMake;
+        let config = ProjectConfig::builder()
+            .name(format!("perf-project-{}", index))
+            .path(temp_dir.path())
+            .project_type(project_type)
+            .build_system(build_system)
+            .test_framework(test_framework)
+            .package_manager(package_manager)
+            .quality_config(QualityConfig::new(&["clang-tidy", "cppcheck"]))
+            .code_formatter(CodeFormatter::new(&["clang-format"]))
+            .ide(IdeConfig::new(if is_cmake { &["clion"] } else { &[] }))
+            .use_git(false)
+            .build()
+            .unwrap();
+
+        let started = Instant::now();
+        ProjectBuilder::new(config).build().unwrap();
+        let elapsed = started.elapsed();
+
+        assert!(
+            elapsed < Duration::from_millis(500),
+            "project {} took {:?}",
+            index,
+            elapsed
+        );
+    }
+}
+
+#[test]
+fn test_gitignore_adapts_to_selected_tools() {
+    let temp_dir = TempDir::new().unwrap();
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args([
+        "--name",
+        "conan-app",
+        "--project-type",
+        "executable",
+        "--build-system",
+        "cmake",
+        "--package-manager",
+        "conan",
+        "--ide",
+        "clion",
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+    cmd.assert().success();
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args([
+        "--name",
+        "make-app",
+        "--project-type",
+        "executable",
+        "--build-system",
+        "make",
+        "--package-manager",
+        "none",
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+    cmd.assert().success();
+
+    let conan = fs::read_to_string(temp_dir.path().join("conan-app/.gitignore")).unwrap();
+    let make = fs::read_to_string(temp_dir.path().join("make-app/.gitignore")).unwrap();
+
+    for entry in [
+        "build*/",
+        "CMakeCache.txt",
+        "CMakeUserPresets.json",
+        ".cache/",
+        ".idea/*",
+        "!.idea/cmake.xml",
+    ] {
+        assert!(conan.contains(entry), "{} missing for conan", entry);
+        assert!(!make.contains(entry), "{} unexpected for make", entry);
+    }
+    for entry in ["\n*.o\n", "\n/make-app\n", "Makefile.bak"] {
+        assert!(make.contains(entry), "{:?} missing for make", entry);
+        assert!(!conan.contains(entry), "{:?} unexpected for conan", entry);
+    }
+}
+
+#[test]
+fn test_snippets_are_copied_into_include() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_path = temp_dir.path().join("snippet-app");
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args([
+        "--name",
+        "snippet-app",
+        "--project-type",
+        "executable",
+        "--hpp-extension",
+        "h",
+        "--snippet",
+        "thread-pool",
+        "--snippet",
+        "logger",
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+    cmd.assert().success();
+
+    let snippets = project_path.join("include/snippets");
+    assert!(snippets.join("thread_pool.h").is_file());
+    assert!(snippets.join("logger.h").is_file());
+    assert_eq!(fs::read_dir(&snippets).unwrap().count(), 2);
+    let readme = fs::read_to_string(project_path.join("README.md")).unwrap();
+    assert!(readme.contains("## Included Components"));
+    assert!(readme.contains("include/snippets/thread_pool.h"));
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args(["--name", "bad", "--snippet", "json", "--non-interactive"]);
+    cmd.assert().failure();
+}
+
+#[test]
+fn test_readme_build_steps_follow_package_manager() {
+    let temp_dir = TempDir::new().unwrap();
+
+    for (name, package_manager) in [("readme-conan", "conan"), ("readme-plain", "none")] {
+        let mut cmd = Command::cargo_bin("cppup").unwrap();
+        cmd.args([
+            "--name",
+            name,
+            "--project-type",
+            "executable",
+            "--build-system",
+            "cmake",
+            "--package-manager",
+            package_manager,
+            "--non-interactive",
+            "--path",
+            temp_dir.path().to_str().unwrap(),
+        ]);
+        let output = cmd.assert().success().get_output().stdout.clone();
+        let stdout = String::from_utf8(output).unwrap();
+        let readme = fs::read_to_string(temp_dir.path().join(name).join("README.md")).unwrap();
+
+        let toolchain = "cmake -S . -B build \
+                         -DCMAKE_TOOLCHAIN_FILE=build/Release/generators/conan_toolchain.cmake";
+        assert_eq!(readme.contains(toolchain), package_manager == "conan");
+        assert_eq!(stdout.contains(toolchain), package_manager == "conan");
+        assert_eq!(readme.contains("conan install"), package_manager == "conan");
+        assert!(readme.contains("cmake --build build\n"));
+        assert!(readme.contains("https://img.shields.io/badge/version-0.1.0-blue.svg"));
+        assert!(!readme.contains("/v/release/"));
+    }
+}
+
+#[test]
+fn test_openapi_spec_generates_api_headers() {
+    let temp_dir = TempDir::new().unwrap();
+    let spec = temp_dir.path().join("api.yaml");
+    fs::write(
+        &spec,
+        r#"openapi: 3.0.0
+info: {title: Orders, version: "2.1"}
+paths:
+  /orders/{orderId}:
+    get:
+      operationId: getOrder
+      parameters:
+        - {name: orderId, in: path, required: true, schema: {type: integer}}
+  /health:
+    get: {}
+"#,
+    )
+    .unwrap();
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args([
+        "--name",
+        "orders",
+        "--project-type",
+        "library",
+        "--openapi-spec",
+        spec.to_str().unwrap(),
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+    cmd.assert().success();
+
+    let api = temp_dir.path().join("orders/include/api");
+    let orders = fs::read_to_string(api.join("orders.hpp")).unwrap();
+    assert!(orders.contains("namespace api {"));
+    assert!(
+        orders.contains("// GET /orders/{orderId}\nstd::string get_order(std::int64_t order_id);")
+    );
+    let health = fs::read_to_string(api.join("health.hpp")).unwrap();
+    assert!(health.contains("std::string get_health();"));
+
+    fs::write(&spec, "openapi: [3").unwrap();
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args([
+        "--name",
+        "broken-spec",
+        "--project-type",
+        "library",
+        "--openapi-spec",
+        spec.to_str().unwrap(),
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("Not a valid YAML document"));
+    assert!(!temp_dir.path().join("broken-spec").exists());
+}
+
+// ============================================================================
+// Generate Subcommand Tests
+// ============================================================================
+
+#[test]
+fn test_generate_license_into_existing_project() {
+    let temp_dir = TempDir::new().unwrap();
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args([
+        "generate",
+        "license",
+        "--license",
+        "BSD-3-Clause",
+        "--author",
+        "Jane Doe",
+        "--into",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Created"));
+
+    let license = fs::read_to_string(temp_dir.path().join("LICENSE")).unwrap();
+    assert!(license.contains("BSD 3-Clause License"));
+    assert!(license.contains("Jane Doe"));
+    assert_eq!(fs::read_dir(temp_dir.path()).unwrap().count(), 1);
+}
+
+#[test]
+fn test_generate_refuses_to_overwrite_without_force() {
+    let temp_dir = TempDir::new().unwrap();
+    let clang_format = temp_dir.path().join(".clang-format");
+    fs::write(&clang_format, "BasedOnStyle: LLVM\n").unwrap();
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.current_dir(temp_dir.path())
+        .args(["generate", "clang-format"]);
+    cmd.assert()
+        .code(4)
+        .stderr(predicate::str::contains("use --force"));
+    assert_eq!(
+        fs::read_to_string(&clang_format).unwrap(),
+        "BasedOnStyle: LLVM\n"
+    );
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.current_dir(temp_dir.path())
+        .args(["generate", "clang-format", "--force"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("-BasedOnStyle: LLVM"))
+        .stdout(predicate::str::contains("Summary: 1 changed"));
+    assert_ne!(
+        fs::read_to_string(&clang_format).unwrap(),
+        "BasedOnStyle: LLVM\n"
+    );
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.current_dir(temp_dir.path())
+        .args(["generate", "clang-format", "--force", "--assume-yes"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Summary: 1 unchanged"));
+}
+
+#[test]
+fn test_generate_rejects_project_options() {
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args(["--name", "demo", "generate", "gitignore"]);
+    cmd.assert()
+        .code(2)
+        .stderr(predicate::str::contains("cannot be used with"));
 }
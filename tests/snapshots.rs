@@ -0,0 +1,212 @@
+//! Golden-file tests: generates representative projects with `--reproducible`
+//! and compares every file with its snapshot in `tests/fixtures/snapshots`.
+//!
+//! After an intended template change, regenerate the snapshots with
+//! `UPDATE_SNAPSHOTS=1 cargo test --test snapshots` and review the diff.
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tempfile::TempDir;
+
+/// 2023-11-14, so the snapshots do not change with the current date
+const SOURCE_DATE_EPOCH: &str = "1700000000";
+
+/// Snapshot names and the options that differ between them.
+const CASES: &[(&str, &[&str])] = &[
+    (
+        "cmake-executable",
+        &[
+            "--project-type",
+            "executable",
+            "--build-system",
+            "cmake",
+            "--test-framework",
+            "doctest",
+        ],
+    ),
+    (
+        "cmake-library-vcpkg",
+        &[
+            "--project-type",
+            "library",
+            "--build-system",
+            "cmake",
+            "--cmake-generator",
+            "ninja",
+            "--package-manager",
+            "vcpkg",
+            "--test-framework",
+            "gtest",
+            "--quality-tools",
+            "clang-tidy,cppcheck",
+            "--changelog",
+            "--community-files",
+        ],
+    ),
+    (
+        "meson-library",
+        &[
+            "--project-type",
+            "library",
+            "--build-system",
+            "meson",
+            "--test-framework",
+            "catch2",
+            "--cpp-standard",
+            "20",
+            "--license",
+            "Apache-2.0",
+        ],
+    ),
+    (
+        "make-executable",
+        &[
+            "--project-type",
+            "executable",
+            "--build-system",
+            "make",
+            "--test-framework",
+            "none",
+            "--license",
+            "GPL-3.0",
+        ],
+    ),
+];
+
+/// Generates a project named `snapshot` in `dir` and returns its files.
+fn generate(dir: &Path, args: &[&str]) -> BTreeMap<PathBuf, String> {
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.env("SOURCE_DATE_EPOCH", SOURCE_DATE_EPOCH)
+        .args([
+            "--name",
+            "snapshot",
+            "--author",
+            "Jane Doe <jane@example.com>",
+            "--project-version",
+            "1.2.3",
+            "--git",
+            "false",
+            "--reproducible",
+            "--non-interactive",
+            "--path",
+            dir.to_str().unwrap(),
+        ])
+        .args(args);
+    cmd.assert().success();
+    read_tree(&dir.join("snapshot"))
+}
+
+/// Reads every file below `root`, keyed by its path relative to `root`.
+fn read_tree(root: &Path) -> BTreeMap<PathBuf, String> {
+    let mut files = BTreeMap::new();
+    let mut dirs = vec![root.to_path_buf()];
+    while let Some(dir) = dirs.pop() {
+        for entry in fs::read_dir(&dir).unwrap() {
+            let path = entry.unwrap().path();
+            if path.is_dir() {
+                dirs.push(path);
+            } else {
+                let content = fs::read_to_string(&path).unwrap();
+                files.insert(path.strip_prefix(root).unwrap().to_path_buf(), content);
+            }
+        }
+    }
+    files
+}
+
+fn assert_matches_snapshot(name: &str, files: &BTreeMap<PathBuf, String>) {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures/snapshots")
+        .join(name);
+    if std::env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        let _ = fs::remove_dir_all(&dir);
+        for (path, content) in files {
+            let path = dir.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, content).unwrap();
+        }
+        return;
+    }
+
+    let expected = read_tree(&dir);
+    assert_eq!(
+        files.keys().collect::<Vec<_>>(),
+        expected.keys().collect::<Vec<_>>(),
+        "the files of {} differ from its snapshot; rerun with UPDATE_SNAPSHOTS=1 if intended",
+        name
+    );
+    for (path, content) in files {
+        assert_eq!(
+            content,
+            &expected[path],
+            "{}/{} differs from its snapshot; rerun with UPDATE_SNAPSHOTS=1 if intended",
+            name,
+            path.display()
+        );
+    }
+}
+
+#[test]
+fn test_generated_projects_match_snapshots() {
+    for (name, args) in CASES {
+        let temp_dir = TempDir::new().unwrap();
+        assert_matches_snapshot(name, &generate(temp_dir.path(), args));
+    }
+}
+
+#[test]
+fn test_reproducible_runs_are_identical() {
+    let (_, args) = CASES[1];
+    let first = TempDir::new().unwrap();
+    let second = TempDir::new().unwrap();
+
+    assert_eq!(generate(first.path(), args), generate(second.path(), args));
+}
+
+#[test]
+fn test_reproducible_requires_author_and_version() {
+    let temp_dir = TempDir::new().unwrap();
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.args([
+        "--name",
+        "snapshot",
+        "--project-type",
+        "executable",
+        "--reproducible",
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+    cmd.assert()
+        .code(2)
+        .stderr(predicate::str::contains("--author"))
+        .stderr(predicate::str::contains("--project-version"));
+}
+
+#[test]
+fn test_reproducible_rejects_invalid_source_date_epoch() {
+    let temp_dir = TempDir::new().unwrap();
+
+    let mut cmd = Command::cargo_bin("cppup").unwrap();
+    cmd.env("SOURCE_DATE_EPOCH", "yesterday").args([
+        "--name",
+        "snapshot",
+        "--project-type",
+        "executable",
+        "--author",
+        "Jane Doe",
+        "--project-version",
+        "1.0",
+        "--reproducible",
+        "--non-interactive",
+        "--path",
+        temp_dir.path().to_str().unwrap(),
+    ]);
+    cmd.assert().code(3).stderr(predicate::str::contains(
+        "SOURCE_DATE_EPOCH must be a number",
+    ));
+}